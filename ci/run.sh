@@ -11,5 +11,7 @@ $CMD --release
 $CMD --features 'stable'
 $CMD --release --features 'stable'
 
+$CMD --features 'stable soft-fenv'
+
 $CMD --features 'stable checked musl-reference-tests'
 $CMD --release --features  'stable checked musl-reference-tests'
@@ -106,10 +106,12 @@ macro_rules! bessel {
 }
 
 unary!(
-    acos, acosh, asin, atan, cbrt, ceil, cos, cosh, erf, exp, exp2, exp10, expm1, fabs, floor, j0,
-    j1, lgamma, log, log1p, log2, log10, round, sin, sinh, sqrt, tan, tanh, tgamma, trunc, y0, y1
+    acos, acospi, acosh, asin, asinpi, atan, atanpi, cbrt, ceil, cos, cospi, cosh, erf, exp, exp2,
+    exp2m1, exp10, exp10m1, expm1, fabs, floor, j0, j1, lgamma, log, log1p, log2, log2p1, log10,
+    log10p1, nearbyint, rint, round, rsqrt, sin, sinpi, sinh, sqrt, tan, tanpi, tanh, tgamma,
+    trunc, y0, y1
 );
-binary!(atan2, copysign, fdim, fmax, fmin, fmod, hypot, pow);
+binary!(atan2, atan2pi, copysign, fdim, fmax, fmin, fmod, hypot, pow, powr);
 trinary!(fma);
 bessel!(jn, yn);
 binary!(ldexp; scalbn);
@@ -0,0 +1,44 @@
+use super::Complex;
+use crate::math::hypot;
+
+/// Absolute value (f64)
+///
+/// Computes the magnitude `|z|` of `z`, without undue overflow or
+/// underflow.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cabs(z: Complex<f64>) -> f64 {
+    hypot(z.re, z.im)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cabs;
+    use crate::complex::Complex;
+    use core::f64;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        assert_eq!(cabs(c(-0.0, -0.0)).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(cabs(c(-3.0, 4.0)), 5.0);
+        /* an infinite part wins over NAN */
+        assert_eq!(cabs(c(inf, nan)), inf);
+        assert_eq!(cabs(c(nan, -inf)), inf);
+        assert!(cabs(c(nan, 1.0)).is_nan());
+        assert!(cabs(c(0.0, nan)).is_nan());
+    }
+
+    #[test]
+    fn extreme_parts() {
+        assert_eq!(cabs(c(1e300, 1e300)), 1.4142135623730952e300);
+        assert_eq!(cabs(c(f64::MAX, f64::MAX)), f64::INFINITY);
+        assert_eq!(cabs(c(3e-320, 4e-320)), 5e-320);
+    }
+}
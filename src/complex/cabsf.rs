@@ -0,0 +1,24 @@
+use super::Complex;
+use crate::math::hypotf;
+
+/// Absolute value (f32)
+///
+/// Computes the magnitude `|z|` of `z`, without undue overflow or
+/// underflow.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cabsf(z: Complex<f32>) -> f32 {
+    hypotf(z.re, z.im)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cabsf;
+    use crate::complex::cabs;
+    use crate::complex::testutil::check_f32_real;
+
+    #[test]
+    fn agrees_with_cabs() {
+        check_f32_real(cabsf, cabs);
+    }
+}
@@ -0,0 +1,146 @@
+use super::catrig::{M_LN2, PIO2_HI, PIO2_LO, RECIP_EPSILON, SQRT_6_EPSILON, TINY};
+use super::{clog_for_large_values, do_hard_work, Complex};
+use crate::math::{acos, atan2, fabs};
+use core::f64;
+
+/// Inverse cosine (f64)
+///
+/// Computes the principal value of the inverse cosine of `z`, with branch
+/// cuts along the real axis outside [-1, 1]. The real part of the result
+/// is in the range [0, pi].
+///
+/// The result is accurate even close to 1, where `pi/2 - casin(z)` would
+/// lose all significant digits.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacos(z: Complex<f64>) -> Complex<f64> {
+    /*
+     * cacos(z) = PI/2 - z + O(z^3)   as z -> 0
+     *
+     * cacos(z) = -sign(y)*I*clog(z) + O(1/z^2)   as z -> infinity
+     * The above formula works for the real part as well, because
+     * Re(cacos(z)) = atan2(fabs(y), x) + O(y/z^3)
+     *    as z -> infinity, uniformly in y
+     */
+    let x = z.re;
+    let y = z.im;
+    let sx = x.is_sign_negative();
+    let sy = y.is_sign_negative();
+    let ax = fabs(x);
+    let ay = fabs(y);
+
+    if x.is_nan() || y.is_nan() {
+        /* cacos(+-Inf + I*NaN) = NaN + I*opt(-)Inf */
+        if x.is_infinite() {
+            return Complex::new(y + y, f64::NEG_INFINITY);
+        }
+        /* cacos(NaN + I*+-Inf) = NaN + I*-+Inf */
+        if y.is_infinite() {
+            return Complex::new(x + x, -y);
+        }
+        /* cacos(0 + I*NaN) = PI/2 + I*NaN with inexact */
+        if x == 0.0 {
+            return Complex::new(PIO2_HI + PIO2_LO, y + y);
+        }
+        /*
+         * All other cases involving NaN return NaN + I*NaN.
+         * C99 leaves it optional whether to raise invalid if one of
+         * the arguments is not NaN, so we opt not to raise it.
+         */
+        return Complex::new(x + y, x + y);
+    }
+
+    if ax > RECIP_EPSILON || ay > RECIP_EPSILON {
+        /* clog...() will raise inexact unless x or y is infinite. */
+        let w = clog_for_large_values(z);
+        let rx = fabs(w.im);
+        let ry = w.re + M_LN2;
+        return Complex::new(rx, if sy { ry } else { -ry });
+    }
+
+    /* Avoid spuriously raising inexact for z = 1. */
+    if x == 1.0 && y == 0.0 {
+        return Complex::new(0.0, -y);
+    }
+
+    /* All remaining cases are inexact. */
+    force_eval!(1.0 + TINY);
+
+    if ax < SQRT_6_EPSILON / 4.0 && ay < SQRT_6_EPSILON / 4.0 {
+        return Complex::new(PIO2_HI - (x - PIO2_LO), -y);
+    }
+
+    let hw = do_hard_work(ay, ax);
+    let (b, new_x) = if sx {
+        (-hw.b, -hw.new_y)
+    } else {
+        (hw.b, hw.new_y)
+    };
+    let rx = if hw.b_is_usable {
+        acos(b)
+    } else {
+        atan2(hw.sqrt_a2my2, new_x)
+    };
+    Complex::new(rx, if sy { hw.rx } else { -hw.rx })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cacos;
+    use crate::complex::testutil::{check_cases, same, same_abs, Parity};
+    use crate::complex::Complex;
+    use core::f64;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn annex_g() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // z, cacos(z)
+            (c(0.0, 0.0),  c(FRAC_PI_2, -0.0)),
+            (c(-0.0, 0.0), c(FRAC_PI_2, -0.0)),
+            (c(0.0, nan),  c(FRAC_PI_2, nan)),
+            (c(-0.0, nan), c(FRAC_PI_2, nan)),
+            (c(1.0, inf),  c(FRAC_PI_2, -inf)),
+            (c(0.0, inf),  c(FRAC_PI_2, -inf)),
+            (c(-2.0, inf), c(FRAC_PI_2, -inf)),
+            (c(1.0, nan),  c(nan, nan)),
+            (c(-2.0, nan), c(nan, nan)),
+            (c(-inf, 2.0), c(PI, -inf)),
+            (c(-inf, 0.0), c(PI, -inf)),
+            (c(inf, 2.0),  c(0.0, -inf)),
+            (c(inf, 0.0),  c(0.0, -inf)),
+            (c(-inf, inf), c(3.0 * FRAC_PI_4, -inf)),
+            (c(inf, inf),  c(FRAC_PI_4, -inf)),
+            (c(nan, 2.0),  c(nan, nan)),
+            (c(nan, 0.0),  c(nan, nan)),
+            (c(nan, inf),  c(nan, -inf)),
+            (c(nan, nan),  c(nan, nan)),
+        ];
+        check_cases(cacos, &cases, Parity::None, same);
+
+        /* the signs of the infinite imaginary parts are unspecified */
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            (c(inf, nan),  c(nan, inf)),
+            (c(-inf, nan), c(nan, inf)),
+        ];
+        check_cases(cacos, &cases, Parity::None, same_abs);
+    }
+
+    #[test]
+    fn branch_cuts() {
+        /* the cuts along the real axis take the side of the sign of 0 */
+        assert_eq!(cacos(c(2.0, 0.0)), c(0.0, -1.3169578969248166));
+        assert_eq!(cacos(c(2.0, -0.0)), c(0.0, 1.3169578969248166));
+        assert_eq!(cacos(c(-2.0, 0.0)), c(PI, -1.3169578969248166));
+        assert_eq!(cacos(c(-2.0, -0.0)), c(PI, 1.3169578969248166));
+    }
+}
@@ -0,0 +1,24 @@
+use super::{cacos, narrow, Complex};
+
+/// Inverse cosine (f32)
+///
+/// Computes the principal value of the inverse cosine of `z`, with branch cuts
+/// along the real axis outside [-1, 1], by evaluating `cacos` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacosf(z: Complex<f32>) -> Complex<f32> {
+    narrow(cacos(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cacosf;
+    use crate::complex::cacos;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_cacos() {
+        check_f32(cacosf, cacos);
+    }
+}
@@ -0,0 +1,86 @@
+use super::{cacos, Complex};
+use crate::math::{copysign, fabs};
+
+/// Inverse hyperbolic cosine (f64)
+///
+/// Computes the principal value of the inverse hyperbolic cosine of `z`,
+/// with the branch cut along the real axis below 1. The real part of the
+/// result is non-negative.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacosh(z: Complex<f64>) -> Complex<f64> {
+    /*
+     * cacosh(z) = I*cacos(z) or -I*cacos(z)
+     * where the sign is chosen so Re(cacosh(z)) >= 0.
+     */
+    let w = cacos(z);
+    let rx = w.re;
+    let ry = w.im;
+    /* cacosh(NaN + I*NaN) = NaN + I*NaN */
+    if rx.is_nan() && ry.is_nan() {
+        return Complex::new(ry, rx);
+    }
+    /* cacosh(NaN + I*+-Inf) = +Inf + I*NaN */
+    /* cacosh(+-Inf + I*NaN) = +Inf + I*NaN */
+    if rx.is_nan() {
+        return Complex::new(fabs(ry), rx);
+    }
+    /* cacosh(0 + I*NaN) = NaN + I*NaN */
+    if ry.is_nan() {
+        return Complex::new(ry, ry);
+    }
+    Complex::new(fabs(ry), copysign(rx, z.im))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cacosh;
+    use crate::complex::testutil::{check_cases, same, Parity};
+    use crate::complex::Complex;
+    use core::f64;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, PI};
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn annex_g() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // z, cacosh(z)
+            (c(0.0, 0.0),  c(0.0, FRAC_PI_2)),
+            (c(-0.0, 0.0), c(0.0, FRAC_PI_2)),
+            (c(1.0, inf),  c(inf, FRAC_PI_2)),
+            (c(0.0, inf),  c(inf, FRAC_PI_2)),
+            (c(-2.0, inf), c(inf, FRAC_PI_2)),
+            (c(1.0, nan),  c(nan, nan)),
+            (c(0.0, nan),  c(nan, nan)),
+            (c(-inf, 2.0), c(inf, PI)),
+            (c(-inf, 0.0), c(inf, PI)),
+            (c(inf, 2.0),  c(inf, 0.0)),
+            (c(inf, 0.0),  c(inf, 0.0)),
+            (c(-inf, inf), c(inf, 3.0 * FRAC_PI_4)),
+            (c(inf, inf),  c(inf, FRAC_PI_4)),
+            (c(inf, nan),  c(inf, nan)),
+            (c(-inf, nan), c(inf, nan)),
+            (c(nan, 2.0),  c(nan, nan)),
+            (c(nan, 0.0),  c(nan, nan)),
+            (c(nan, inf),  c(inf, nan)),
+            (c(nan, nan),  c(nan, nan)),
+        ];
+        check_cases(cacosh, &cases, Parity::None, same);
+    }
+
+    #[test]
+    fn branch_cuts() {
+        /* the cut along the real axis below 1 takes the side of the sign of 0 */
+        assert_eq!(cacosh(c(-2.0, 0.0)), c(1.3169578969248166, PI));
+        assert_eq!(cacosh(c(-2.0, -0.0)), c(1.3169578969248166, -PI));
+        assert_eq!(cacosh(c(0.5, 0.0)), c(0.0, FRAC_PI_3));
+        assert_eq!(cacosh(c(0.5, -0.0)), c(0.0, -FRAC_PI_3));
+    }
+}
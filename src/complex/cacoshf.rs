@@ -0,0 +1,24 @@
+use super::{cacosh, narrow, Complex};
+
+/// Inverse hyperbolic cosine (f32)
+///
+/// Computes the principal value of the inverse hyperbolic cosine of `z`, with
+/// the branch cut along the real axis below 1, by evaluating `cacosh` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacoshf(z: Complex<f32>) -> Complex<f32> {
+    narrow(cacosh(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cacoshf;
+    use crate::complex::cacosh;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_cacosh() {
+        check_f32(cacoshf, cacosh);
+    }
+}
@@ -0,0 +1,49 @@
+use super::Complex;
+use crate::math::atan2;
+
+/// Argument (f64)
+///
+/// Computes the phase angle of `z`, in the range [-pi, pi], with the branch
+/// cut along the negative real axis.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn carg(z: Complex<f64>) -> f64 {
+    atan2(z.im, z.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::carg;
+    use crate::complex::Complex;
+    use core::f64;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        /* the signed zeros pick the side of the cut along the negative axis */
+        assert_eq!(carg(c(0.0, 0.0)).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(carg(c(0.0, -0.0)).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(carg(c(-0.0, 0.0)), PI);
+        assert_eq!(carg(c(-0.0, -0.0)), -PI);
+        assert_eq!(carg(c(-1.0, 0.0)), PI);
+        assert_eq!(carg(c(-1.0, -0.0)), -PI);
+        assert_eq!(carg(c(1.0, -0.0)).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(carg(c(0.0, 2.0)), FRAC_PI_2);
+        assert_eq!(carg(c(-0.0, -2.0)), -FRAC_PI_2);
+        assert_eq!(carg(c(inf, inf)), FRAC_PI_4);
+        assert_eq!(carg(c(-inf, inf)), 3.0 * FRAC_PI_4);
+        assert_eq!(carg(c(-inf, -inf)), -3.0 * FRAC_PI_4);
+        assert_eq!(carg(c(-inf, 1.0)), PI);
+        assert_eq!(carg(c(inf, -1.0)).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(carg(c(1.0, -inf)), -FRAC_PI_2);
+        assert!(carg(c(nan, 1.0)).is_nan());
+        assert!(carg(c(inf, nan)).is_nan());
+    }
+}
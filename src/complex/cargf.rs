@@ -0,0 +1,24 @@
+use super::Complex;
+use crate::math::atan2f;
+
+/// Argument (f32)
+///
+/// Computes the phase angle of `z`, in the range [-pi, pi], with the branch
+/// cut along the negative real axis.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cargf(z: Complex<f32>) -> f32 {
+    atan2f(z.im, z.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cargf;
+    use crate::complex::carg;
+    use crate::complex::testutil::check_f32_real;
+
+    #[test]
+    fn agrees_with_carg() {
+        check_f32_real(cargf, carg);
+    }
+}
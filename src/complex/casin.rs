@@ -0,0 +1,70 @@
+use super::{casinh, Complex};
+
+/// Inverse sine (f64)
+///
+/// Computes the principal value of the inverse sine of `z`, with branch
+/// cuts along the real axis outside [-1, 1]. The real part of the result
+/// is in the range [-pi/2, pi/2].
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casin(z: Complex<f64>) -> Complex<f64> {
+    /*
+     * casin(z) = reverse(casinh(reverse(z)))
+     * where reverse(x + I*y) = y + I*x = I*conj(z).
+     */
+    let w = casinh(Complex::new(z.im, z.re));
+    Complex::new(w.im, w.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::casin;
+    use crate::complex::testutil::{check_cases, same, same_abs, Parity};
+    use crate::complex::Complex;
+    use core::f64;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn annex_g() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // z, casin(z)
+            (c(0.0, 0.0), c(0.0, 0.0)),
+            (c(inf, 1.0), c(FRAC_PI_2, inf)),
+            (c(inf, 0.0), c(FRAC_PI_2, inf)),
+            (c(nan, 1.0), c(nan, nan)),
+            (c(nan, 0.0), c(nan, nan)),
+            (c(2.0, inf), c(0.0, inf)),
+            (c(0.0, inf), c(0.0, inf)),
+            (c(inf, inf), c(FRAC_PI_4, inf)),
+            (c(nan, inf), c(nan, inf)),
+            (c(0.0, nan), c(0.0, nan)),
+            (c(2.0, nan), c(nan, nan)),
+            (c(nan, nan), c(nan, nan)),
+        ];
+        check_cases(casin, &cases, Parity::Odd, same);
+
+        /* the sign of the infinite imaginary part is unspecified */
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            (c(inf, nan), c(nan, inf)),
+        ];
+        check_cases(casin, &cases, Parity::Odd, same_abs);
+    }
+
+    #[test]
+    fn branch_cuts() {
+        /* the cuts along the real axis take the side of the sign of 0 */
+        assert_eq!(casin(c(2.0, 0.0)), c(FRAC_PI_2, 1.3169578969248166));
+        assert_eq!(casin(c(2.0, -0.0)), c(FRAC_PI_2, -1.3169578969248166));
+        assert_eq!(casin(c(-2.0, 0.0)), c(-FRAC_PI_2, 1.3169578969248166));
+        assert_eq!(casin(c(-2.0, -0.0)), c(-FRAC_PI_2, -1.3169578969248166));
+    }
+}
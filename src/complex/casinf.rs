@@ -0,0 +1,24 @@
+use super::{casin, narrow, Complex};
+
+/// Inverse sine (f32)
+///
+/// Computes the principal value of the inverse sine of `z`, with branch cuts
+/// along the real axis outside [-1, 1], by evaluating `casin` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casinf(z: Complex<f32>) -> Complex<f32> {
+    narrow(casin(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::casinf;
+    use crate::complex::casin;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_casin() {
+        check_f32(casinf, casin);
+    }
+}
@@ -0,0 +1,156 @@
+use super::catrig::{M_LN2, RECIP_EPSILON, SQRT_6_EPSILON, TINY};
+use super::{clog_for_large_values, do_hard_work, Complex};
+use crate::math::{asin, atan2, copysign, fabs};
+
+/// Inverse hyperbolic sine (f64)
+///
+/// Computes the principal value of the inverse hyperbolic sine of `z`, with
+/// branch cuts along the imaginary axis outside [-i, i].
+///
+/// The algorithm is that of Hull, Fairgrieve and Tang, as in FreeBSD's
+/// catrig.c. It is accurate to a few ulp over the whole plane.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casinh(z: Complex<f64>) -> Complex<f64> {
+    /*
+     * casinh(z) = z + O(z^3)   as z -> 0
+     *
+     * casinh(z) = sign(x)*clog(sign(x)*z) + O(1/z^2)   as z -> infinity
+     * The above formula works for the imaginary part as well, because
+     * Im(casinh(z)) = sign(x)*atan2(sign(x)*y, fabs(x)) + O(y/z^3)
+     *    as z -> infinity, uniformly in y
+     */
+    let x = z.re;
+    let y = z.im;
+    let ax = fabs(x);
+    let ay = fabs(y);
+
+    if x.is_nan() || y.is_nan() {
+        /* casinh(+-Inf + I*NaN) = +-Inf + I*NaN */
+        if x.is_infinite() {
+            return Complex::new(x, y + y);
+        }
+        /* casinh(NaN + I*+-Inf) = opt(+-)Inf + I*NaN */
+        if y.is_infinite() {
+            return Complex::new(y, x + x);
+        }
+        /* casinh(NaN + I*0) = NaN + I*0 */
+        if y == 0.0 {
+            return Complex::new(x + x, y);
+        }
+        /*
+         * All other cases involving NaN return NaN + I*NaN.
+         * C99 leaves it optional whether to raise invalid if one of
+         * the arguments is not NaN, so we opt not to raise it.
+         */
+        return Complex::new(x + y, x + y);
+    }
+
+    if ax > RECIP_EPSILON || ay > RECIP_EPSILON {
+        /* clog...() will raise inexact unless x or y is infinite. */
+        let w = if x.is_sign_negative() {
+            clog_for_large_values(Complex::new(-x, -y))
+        } else {
+            clog_for_large_values(z)
+        };
+        return Complex::new(copysign(w.re + M_LN2, x), copysign(w.im, y));
+    }
+
+    /* Avoid spuriously raising inexact for z = 0. */
+    if x == 0.0 && y == 0.0 {
+        return z;
+    }
+
+    /* All remaining cases are inexact. */
+    force_eval!(1.0 + TINY);
+
+    if ax < SQRT_6_EPSILON / 4.0 && ay < SQRT_6_EPSILON / 4.0 {
+        return z;
+    }
+
+    let hw = do_hard_work(ax, ay);
+    let ry = if hw.b_is_usable {
+        asin(hw.b)
+    } else {
+        atan2(hw.new_y, hw.sqrt_a2my2)
+    };
+    Complex::new(copysign(hw.rx, x), copysign(ry, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::casinh;
+    use crate::complex::testutil::{check_cases, same, same_abs, Parity};
+    use crate::complex::{casin, Complex};
+    use core::f64;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn annex_g() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // z, casinh(z)
+            (c(0.0, 0.0), c(0.0, 0.0)),
+            (c(1.0, inf), c(inf, FRAC_PI_2)),
+            (c(0.0, inf), c(inf, FRAC_PI_2)),
+            (c(1.0, nan), c(nan, nan)),
+            (c(0.0, nan), c(nan, nan)),
+            (c(inf, 2.0), c(inf, 0.0)),
+            (c(inf, 0.0), c(inf, 0.0)),
+            (c(inf, inf), c(inf, FRAC_PI_4)),
+            (c(inf, nan), c(inf, nan)),
+            (c(nan, 0.0), c(nan, 0.0)),
+            (c(nan, 2.0), c(nan, nan)),
+            (c(nan, nan), c(nan, nan)),
+        ];
+        check_cases(casinh, &cases, Parity::Odd, same);
+
+        /* the sign of the infinite real part is unspecified */
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            (c(nan, inf), c(inf, nan)),
+        ];
+        check_cases(casinh, &cases, Parity::Odd, same_abs);
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        assert_eq!(casinh(c(0.0, 0.0)), c(0.0, 0.0));
+        assert_eq!(casinh(c(-0.0, -0.0)).re.to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(casinh(c(1.0, inf)), c(inf, FRAC_PI_2));
+        assert_eq!(casinh(c(inf, 1.0)), c(inf, 0.0));
+        assert_eq!(casinh(c(-inf, -inf)), c(-inf, -FRAC_PI_4));
+        let z = casinh(c(inf, nan));
+        assert!(z.re == inf && z.im.is_nan());
+        let z = casinh(c(nan, 0.0));
+        assert!(z.re.is_nan() && z.im.to_bits() == 0);
+        let z = casinh(c(nan, -inf));
+        assert!(z.re.is_infinite() && z.im.is_nan());
+        let z = casinh(c(nan, 1.0));
+        assert!(z.re.is_nan() && z.im.is_nan());
+
+        assert_eq!(casin(c(0.0, inf)), c(0.0, inf));
+        assert_eq!(casin(c(inf, 1.0)), c(FRAC_PI_2, inf));
+    }
+
+    #[test]
+    fn branch_cuts() {
+        /* the cut along the imaginary axis takes the side of the sign of 0 */
+        assert_eq!(casinh(c(0.0, 2.0)), c(1.3169578969248166, FRAC_PI_2));
+        assert_eq!(casinh(c(-0.0, 2.0)), c(-1.3169578969248166, FRAC_PI_2));
+        assert_eq!(casin(c(2.0, 0.0)), c(FRAC_PI_2, 1.3169578969248166));
+        assert_eq!(casin(c(2.0, -0.0)), c(FRAC_PI_2, -1.3169578969248166));
+        assert_eq!(casinh(c(1e-20, 1e-20)), c(1e-20, 1e-20));
+        assert_eq!(casinh(c(1e300, 0.0)).re, 691.4686750787736);
+    }
+}
@@ -0,0 +1,24 @@
+use super::{casinh, narrow, Complex};
+
+/// Inverse hyperbolic sine (f32)
+///
+/// Computes the principal value of the inverse hyperbolic sine of `z`, with
+/// branch cuts along the imaginary axis outside [-i, i], by evaluating `casinh`
+/// in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casinhf(z: Complex<f32>) -> Complex<f32> {
+    narrow(casinh(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::casinhf;
+    use crate::complex::casinh;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_casinh() {
+        check_f32(casinhf, casinh);
+    }
+}
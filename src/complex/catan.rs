@@ -0,0 +1,71 @@
+use super::{catanh, Complex};
+
+/// Inverse tangent (f64)
+///
+/// Computes the principal value of the inverse tangent of `z`, with branch
+/// cuts along the imaginary axis outside [-i, i]. The real part of the
+/// result is in the range [-pi/2, pi/2].
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catan(z: Complex<f64>) -> Complex<f64> {
+    /*
+     * catan(z) = reverse(catanh(reverse(z)))
+     * where reverse(x + I*y) = y + I*x = I*conj(z).
+     */
+    let w = catanh(Complex::new(z.im, z.re));
+    Complex::new(w.im, w.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catan;
+    use crate::complex::testutil::{check_cases, same, same_abs, Parity};
+    use crate::complex::Complex;
+    use core::f64;
+    use core::f64::consts::FRAC_PI_2;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn annex_g() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // z, catan(z)
+            (c(0.0, 0.0), c(0.0, 0.0)),
+            (c(nan, 0.0), c(nan, 0.0)),
+            (c(0.0, 1.0), c(0.0, inf)),
+            (c(inf, 1.0), c(FRAC_PI_2, 0.0)),
+            (c(inf, 0.0), c(FRAC_PI_2, 0.0)),
+            (c(nan, 1.0), c(nan, nan)),
+            (c(2.0, inf), c(FRAC_PI_2, 0.0)),
+            (c(0.0, inf), c(FRAC_PI_2, 0.0)),
+            (c(inf, inf), c(FRAC_PI_2, 0.0)),
+            (c(nan, inf), c(nan, 0.0)),
+            (c(2.0, nan), c(nan, nan)),
+            (c(0.0, nan), c(nan, nan)),
+            (c(nan, nan), c(nan, nan)),
+        ];
+        check_cases(catan, &cases, Parity::Odd, same);
+
+        /* the sign of the zero imaginary part is unspecified */
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            (c(inf, nan), c(FRAC_PI_2, 0.0)),
+        ];
+        check_cases(catan, &cases, Parity::Odd, same_abs);
+    }
+
+    #[test]
+    fn branch_cuts() {
+        /* the cuts along the imaginary axis take the side of the sign of 0 */
+        assert_eq!(catan(c(0.0, 2.0)), c(FRAC_PI_2, 0.5493061443340549));
+        assert_eq!(catan(c(-0.0, 2.0)), c(-FRAC_PI_2, 0.5493061443340549));
+        assert_eq!(catan(c(0.0, -2.0)), c(FRAC_PI_2, -0.5493061443340549));
+        assert_eq!(catan(c(-0.0, -2.0)), c(-FRAC_PI_2, -0.5493061443340549));
+    }
+}
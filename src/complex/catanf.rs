@@ -0,0 +1,24 @@
+use super::{catan, narrow, Complex};
+
+/// Inverse tangent (f32)
+///
+/// Computes the principal value of the inverse tangent of `z`, with branch cuts
+/// along the imaginary axis outside [-i, i], by evaluating `catan` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catanf(z: Complex<f32>) -> Complex<f32> {
+    narrow(catan(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catanf;
+    use crate::complex::catan;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_catan() {
+        check_f32(catanf, catan);
+    }
+}
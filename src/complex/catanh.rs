@@ -0,0 +1,241 @@
+use super::catrig::{M_LN2, PIO2_HI, PIO2_LO, RECIP_EPSILON, SQRT_3_EPSILON, SQRT_MIN, TINY};
+use super::{get_high_word, Complex};
+use crate::math::{atan, atan2, atanh, copysign, fabs, log, log1p};
+use core::f64;
+
+/*
+ * sum_squares(x,y) = x*x + y*y (or just x*x if y*y would underflow).
+ * Assumes x*x and y*y will not overflow.
+ * Assumes x and y are finite.
+ * Assumes y is non-negative.
+ * Assumes fabs(x) >= DBL_EPSILON.
+ */
+#[inline]
+fn sum_squares(x: f64, y: f64) -> f64 {
+    /* Avoid underflow when y is small. */
+    if y < SQRT_MIN {
+        return x * x;
+    }
+    x * x + y * y
+}
+
+/*
+ * real_part_reciprocal(x, y) = Re(1/(x+I*y)) = x/(x*x + y*y).
+ * Assumes x and y are not NaN, and one of x and y is larger than
+ * RECIP_EPSILON.  We avoid unwarranted underflow.  It is important to not use
+ * the code creal(1/z), because the imaginary part may produce an unwanted
+ * underflow.
+ * This is only called in a context where inexact is always raised before
+ * the call, so no effort is made to avoid or force inexact.
+ */
+#[inline]
+fn real_part_reciprocal(mut x: f64, mut y: f64) -> f64 {
+    const BIAS: i32 = 1023; /* DBL_MAX_EXP - 1 */
+    /* XXX more guard digits are useful iff there is extra precision. */
+    const CUTOFF: i32 = 53 / 2 + 1; /* just half or 1 guard digit */
+
+    /*
+     * This code is inspired by the C99 document n1124.pdf, Section G.5.1,
+     * example 2.
+     */
+    let ix = (get_high_word(x) & 0x7ff00000) as i32;
+    let iy = (get_high_word(y) & 0x7ff00000) as i32;
+    if ix - iy >= CUTOFF << 20 || x.is_infinite() {
+        return 1.0 / x; /* +-Inf -> +-0 is special */
+    }
+    if iy - ix >= CUTOFF << 20 {
+        return x / y / y; /* should avoid double div, but hard */
+    }
+    if ix <= (BIAS + 1024 / 2 - CUTOFF) << 20 {
+        return x / (x * x + y * y);
+    }
+    let scale = f64::from_bits(((0x7ff00000 - ix) as u64) << 32); /* 2**(1-ilogb(x)) */
+    x *= scale;
+    y *= scale;
+    x / (x * x + y * y) * scale
+}
+
+/// Inverse hyperbolic tangent (f64)
+///
+/// Computes the principal value of the inverse hyperbolic tangent of `z`,
+/// with branch cuts along the real axis outside [-1, 1]. The imaginary
+/// part of the result is in the range [-pi/2, pi/2].
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catanh(z: Complex<f64>) -> Complex<f64> {
+    /*
+     * catanh(z) = log((1+z)/(1-z)) / 2
+     *           = log1p(4*x / |z-1|^2) / 4
+     *             + I * atan2(2*y, (1-x)*(1+x)-y*y) / 2
+     *
+     * catanh(z) = z + O(z^3)   as z -> 0
+     *
+     * catanh(z) = 1/z + sign(y)*I*PI/2 + O(1/z^3)   as z -> infinity
+     * The above formula works for the real part as well, because
+     * Re(catanh(z)) = x/|z|^2 + O(x/z^4)
+     *    as z -> infinity, uniformly in x
+     */
+    let eps = f64::EPSILON;
+    let x = z.re;
+    let y = z.im;
+    let ax = fabs(x);
+    let ay = fabs(y);
+
+    /* This helps handle many cases. */
+    if y == 0.0 && ax <= 1.0 {
+        return Complex::new(atanh(x), y);
+    }
+
+    /* To ensure the same accuracy as atan(), and to filter out z = 0. */
+    if x == 0.0 {
+        return Complex::new(x, atan(y));
+    }
+
+    if x.is_nan() || y.is_nan() {
+        /* catanh(+-Inf + I*NaN) = +-0 + I*NaN */
+        if x.is_infinite() {
+            return Complex::new(copysign(0.0, x), y + y);
+        }
+        /* catanh(NaN + I*+-Inf) = sign(NaN)0 + I*+-PI/2 */
+        if y.is_infinite() {
+            return Complex::new(copysign(0.0, x), copysign(PIO2_HI + PIO2_LO, y));
+        }
+        /*
+         * All other cases involving NaN return NaN + I*NaN.
+         * C99 leaves it optional whether to raise invalid if one of
+         * the arguments is not NaN, so we opt not to raise it.
+         */
+        return Complex::new(x + y, x + y);
+    }
+
+    if ax > RECIP_EPSILON || ay > RECIP_EPSILON {
+        return Complex::new(real_part_reciprocal(x, y), copysign(PIO2_HI + PIO2_LO, y));
+    }
+
+    if ax < SQRT_3_EPSILON / 2.0 && ay < SQRT_3_EPSILON / 2.0 {
+        /*
+         * z = 0 was filtered out above.  All other cases must raise
+         * inexact, but this is the only case that needs to do it
+         * explicitly.
+         */
+        force_eval!(1.0 + TINY);
+        return z;
+    }
+
+    let rx = if ax == 1.0 && ay < eps {
+        (M_LN2 - log(ay)) / 2.0
+    } else {
+        log1p(4.0 * ax / sum_squares(ax - 1.0, ay)) / 4.0
+    };
+
+    let ry = if ax == 1.0 {
+        atan2(2.0, -ay) / 2.0
+    } else if ay < eps {
+        atan2(2.0 * ay, (1.0 - ax) * (1.0 + ax)) / 2.0
+    } else {
+        atan2(2.0 * ay, (1.0 - ax) * (1.0 + ax) - ay * ay) / 2.0
+    };
+
+    Complex::new(copysign(rx, x), copysign(ry, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catanh;
+    use crate::complex::testutil::{check_cases, same, same_abs, Parity};
+    use crate::complex::{cacos, cacosh, catan, Complex};
+    use core::f64;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn annex_g() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // z, catanh(z)
+            (c(0.0, 0.0), c(0.0, 0.0)),
+            (c(0.0, nan), c(0.0, nan)),
+            (c(1.0, 0.0), c(inf, 0.0)),
+            (c(1.0, inf), c(0.0, FRAC_PI_2)),
+            (c(0.0, inf), c(0.0, FRAC_PI_2)),
+            (c(1.0, nan), c(nan, nan)),
+            (c(inf, 2.0), c(0.0, FRAC_PI_2)),
+            (c(inf, 0.0), c(0.0, FRAC_PI_2)),
+            (c(inf, inf), c(0.0, FRAC_PI_2)),
+            (c(inf, nan), c(0.0, nan)),
+            (c(nan, 2.0), c(nan, nan)),
+            (c(nan, 0.0), c(nan, nan)),
+            (c(nan, nan), c(nan, nan)),
+        ];
+        check_cases(catanh, &cases, Parity::Odd, same);
+
+        /* the sign of the zero real part is unspecified */
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            (c(nan, inf), c(0.0, FRAC_PI_2)),
+        ];
+        check_cases(catanh, &cases, Parity::Odd, same_abs);
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        assert_eq!(catanh(c(0.0, 0.0)), c(0.0, 0.0));
+        assert_eq!(catanh(c(1.0, 0.0)), c(inf, 0.0));
+        assert_eq!(catanh(c(-1.0, -0.0)), c(-inf, -0.0));
+        assert_eq!(catanh(c(1.0, inf)), c(0.0, FRAC_PI_2));
+        assert_eq!(catanh(c(-inf, 1.0)), c(-0.0, FRAC_PI_2));
+        assert_eq!(catanh(c(inf, -inf)), c(0.0, -FRAC_PI_2));
+        let z = catanh(c(inf, nan));
+        assert!(z.re.to_bits() == 0 && z.im.is_nan());
+        let z = catanh(c(nan, -inf));
+        assert!(z.re == 0.0 && z.im == -FRAC_PI_2);
+        let z = catanh(c(nan, 1.0));
+        assert!(z.re.is_nan() && z.im.is_nan());
+
+        assert_eq!(cacos(c(0.0, 0.0)), c(FRAC_PI_2, -0.0));
+        assert_eq!(cacos(c(-0.0, -0.0)), c(FRAC_PI_2, 0.0));
+        assert_eq!(cacos(c(1.0, inf)), c(FRAC_PI_2, -inf));
+        assert_eq!(cacos(c(-inf, 1.0)), c(PI, -inf));
+        assert_eq!(cacos(c(inf, 1.0)), c(0.0, -inf));
+        assert_eq!(cacos(c(-inf, inf)), c(3.0 * FRAC_PI_4, -inf));
+        assert_eq!(cacos(c(inf, inf)), c(FRAC_PI_4, -inf));
+        let z = cacos(c(0.0, nan));
+        assert!(z.re == FRAC_PI_2 && z.im.is_nan());
+        let z = cacos(c(nan, inf));
+        assert!(z.re.is_nan() && z.im == -inf);
+
+        assert_eq!(cacosh(c(0.0, 0.0)), c(0.0, FRAC_PI_2));
+        assert_eq!(cacosh(c(-0.0, -0.0)), c(0.0, -FRAC_PI_2));
+        assert_eq!(cacosh(c(1.0, inf)), c(inf, FRAC_PI_2));
+        assert_eq!(cacosh(c(-inf, 1.0)), c(inf, PI));
+        assert_eq!(cacosh(c(-inf, -inf)), c(inf, -3.0 * FRAC_PI_4));
+        let z = cacosh(c(inf, nan));
+        assert!(z.re == inf && z.im.is_nan());
+        let z = cacosh(c(0.0, nan));
+        assert!(z.re.is_nan() && z.im.is_nan());
+
+        assert_eq!(catan(c(0.0, 1.0)), c(0.0, inf));
+        assert_eq!(catan(c(inf, 1.0)), c(FRAC_PI_2, 0.0));
+    }
+
+    #[test]
+    fn branch_cuts() {
+        /* the cut along the real axis takes the side of the sign of 0 */
+        let z = catanh(c(2.0, 0.0));
+        assert_eq!(z, c(0.5493061443340549, FRAC_PI_2));
+        let z = catanh(c(2.0, -0.0));
+        assert_eq!(z, c(0.5493061443340549, -FRAC_PI_2));
+        assert_eq!(cacos(c(2.0, 0.0)), c(0.0, -1.3169578969248166));
+        assert_eq!(cacos(c(-2.0, -0.0)), c(PI, 1.3169578969248166));
+        assert_eq!(cacos(c(1.0, 1e-20)).re, 1e-10);
+    }
+}
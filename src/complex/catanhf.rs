@@ -0,0 +1,24 @@
+use super::{catanh, narrow, Complex};
+
+/// Inverse hyperbolic tangent (f32)
+///
+/// Computes the principal value of the inverse hyperbolic tangent of `z`, with
+/// branch cuts along the real axis outside [-1, 1], by evaluating `catanh` in
+/// double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catanhf(z: Complex<f32>) -> Complex<f32> {
+    narrow(catanh(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catanhf;
+    use crate::complex::catanh;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_catanh() {
+        check_f32(catanhf, catanh);
+    }
+}
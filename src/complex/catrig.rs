@@ -0,0 +1,283 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/catrig.c */
+/*-
+ * Copyright (c) 2012 Stephen Montgomery-Smith <stephen@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * The algorithm is very close to that in "Implementing the complex arcsine
+ * and arccosine functions using exception handling" by T. E. Hull, Thomas F.
+ * Fairgrieve, and Ping Tak Peter Tang, published in ACM Transactions on
+ * Mathematical Software, Volume 23 Issue 3, 1997, Pages 299-335,
+ * http://dl.acm.org/citation.cfm?id=275324.
+ *
+ * Throughout we use the convention z = x + I*y.
+ *
+ * casinh(z) = sign(x)*log(A+sqrt(A*A-1)) + I*asin(B)
+ * where
+ * A = (|z+I| + |z-I|) / 2
+ * B = (|z+I| - |z-I|) / 2 = y/A
+ *
+ * These formulas become numerically unstable:
+ *   (a) for Re(casinh(z)) when z is close to the line segment [-I, I] (that
+ *       is, Re(casinh(z)) is close to 0);
+ *   (b) for Im(casinh(z)) when z is close to either of the intervals
+ *       [I, I*infinity) or (-I*infinity, -I] (that is, |Im(casinh(z))| is
+ *       close to PI/2).
+ *
+ * These numerical problems are overcome by defining
+ * f(a, b) = (hypot(a, b) - b) / 2 = a*a / (hypot(a, b) + b) / 2
+ * Then if A < A_crossover, we use
+ *   log(A + sqrt(A*A-1)) = log1p((A-1) + sqrt((A-1)*(A+1)))
+ *   A-1 = f(x, 1+y) + f(x, 1-y)
+ * and if B > B_crossover, we use
+ *   asin(B) = atan2(y, sqrt(A*A - y*y)) = atan2(y, sqrt((A+y)*(A-y)))
+ *   A-y = f(x, y+1) + f(x, y-1)
+ * where without loss of generality we have assumed that x and y are
+ * non-negative.
+ *
+ * Much of the difficulty comes because the intermediate computations may
+ * produce overflows or underflows.  This is dealt with in the paper by Hull
+ * et al by using exception handling.  We do this by detecting when
+ * computations risk underflow or overflow.  The hardest part is handling the
+ * underflows when computing f(a, b).
+ *
+ * Note that the function f(a, b) does not appear explicitly in the paper by
+ * Hull et al, but the idea may be found on pages 308 and 309.  Introducing the
+ * function f(a, b) allows us to concentrate many of the clever tricks in this
+ * paper into one function.
+ */
+
+use super::Complex;
+use crate::math::{atan2, fabs, hypot, log, log1p, sqrt};
+use core::f64;
+use core::f64::consts::E as M_E;
+pub(crate) use core::f64::consts::FRAC_PI_2 as PIO2_HI;
+pub(crate) use core::f64::consts::LN_2 as M_LN2;
+
+const A_CROSSOVER: f64 = 10.0; /* Hull et al suggest 1.5, but 10 works better */
+const B_CROSSOVER: f64 = 0.6417; /* suggested by Hull et al */
+pub(crate) const FOUR_SQRT_MIN: f64 = 5.966672584960166e-154; /* 0x1p-509 */
+const QUARTER_SQRT_MAX: f64 = 1.6759759912428246e+153; /* 0x1p509 */
+pub(crate) const PIO2_LO: f64 = 6.123233995736766e-17; /* 0x11a62633145c07.0p-106 */
+pub(crate) const RECIP_EPSILON: f64 = 1.0 / f64::EPSILON;
+pub(crate) const SQRT_3_EPSILON: f64 = 2.580956827951785e-08; /* 0x1bb67ae8584caa.0p-78 */
+pub(crate) const SQRT_6_EPSILON: f64 = 3.6500241499888574e-08; /* 0x13988e1409212e.0p-77 */
+pub(crate) const SQRT_MIN: f64 = 1.4916681462400413e-154; /* 0x1p-511 */
+pub(crate) const TINY: f64 = 9.332636185032189e-302; /* 0x1p-1000 */
+
+/*
+ * Function f(a, b, hypot_a_b) = (hypot(a, b) - b) / 2.
+ * Pass hypot(a, b) as the third argument.
+ */
+#[inline]
+fn f(a: f64, b: f64, hypot_a_b: f64) -> f64 {
+    if b < 0.0 {
+        return (hypot_a_b - b) / 2.0;
+    }
+    if b == 0.0 {
+        return a / 2.0;
+    }
+    a * a / (hypot_a_b + b) / 2.0
+}
+
+/* the results of do_hard_work, as in the FreeBSD output arguments */
+pub(crate) struct HardWork {
+    pub(crate) rx: f64,
+    pub(crate) b_is_usable: bool,
+    pub(crate) b: f64,
+    pub(crate) sqrt_a2my2: f64,
+    pub(crate) new_y: f64,
+}
+
+/*
+ * All the hard work is contained in this function.
+ * x and y are assumed positive or zero, and less than RECIP_EPSILON.
+ * Upon return:
+ * rx = Re(casinh(z)) = -Im(cacos(y + I*x)).
+ * B_is_usable is set to 1 if the value of B is usable.
+ * If B_is_usable is set to 0, sqrt_A2my2 = sqrt(A*A - y*y), and new_y = y.
+ * If returning sqrt_A2my2 has potential to result in an underflow, it is
+ * rescaled, and new_y is similarly rescaled.
+ */
+#[inline]
+pub(crate) fn do_hard_work(x: f64, y: f64) -> HardWork {
+    let eps = f64::EPSILON;
+
+    let r = hypot(x, y + 1.0); /* |z+I| */
+    let s = hypot(x, y - 1.0); /* |z-I| */
+
+    /* A = (|z+I| + |z-I|) / 2 */
+    let mut a = (r + s) / 2.0;
+    /*
+     * Mathematically A >= 1.  There is a small chance that this will not
+     * be so because of rounding errors.  So we will make certain it is
+     * so.
+     */
+    if a < 1.0 {
+        a = 1.0;
+    }
+
+    let rx = if a < A_CROSSOVER {
+        /*
+         * Am1 = fp + fm, where fp = f(x, 1+y), and fm = f(x, 1-y).
+         * rx = log1p(Am1 + sqrt(Am1*(A+1)))
+         */
+        if y == 1.0 && x < eps * eps / 128.0 {
+            /*
+             * fp is of order x^2, and fm = x/2.
+             * A = 1 (inexactly).
+             */
+            sqrt(x)
+        } else if x >= eps * fabs(y - 1.0) {
+            /*
+             * Underflow will not occur because
+             * x >= DBL_EPSILON^2/128 >= FOUR_SQRT_MIN
+             */
+            let am1 = f(x, 1.0 + y, r) + f(x, 1.0 - y, s);
+            log1p(am1 + sqrt(am1 * (a + 1.0)))
+        } else if y < 1.0 {
+            /*
+             * fp = x*x/(1+y)/4, fm = x*x/(1-y)/4, and
+             * A = 1 (inexactly).
+             */
+            x / sqrt((1.0 - y) * (1.0 + y))
+        } else {
+            /* if (y > 1) */
+            /*
+             * A-1 = y-1 (inexactly).
+             */
+            log1p((y - 1.0) + sqrt((y - 1.0) * (y + 1.0)))
+        }
+    } else {
+        log(a + sqrt(a * a - 1.0))
+    };
+
+    if y < FOUR_SQRT_MIN {
+        /*
+         * Avoid a possible underflow caused by y/A.  For casinh this
+         * would be legitimate, but will be picked up by invoking atan2
+         * later on.  For cacos this would not be legitimate.
+         */
+        return HardWork {
+            rx,
+            b_is_usable: false,
+            b: 0.0,
+            sqrt_a2my2: a * (2.0 / eps),
+            new_y: y * (2.0 / eps),
+        };
+    }
+
+    /* B = (|z+I| - |z-I|) / 2 = y/A */
+    let b = y / a;
+    let mut hw = HardWork {
+        rx,
+        b_is_usable: true,
+        b,
+        sqrt_a2my2: 0.0,
+        new_y: y,
+    };
+
+    if b > B_CROSSOVER {
+        hw.b_is_usable = false;
+        /*
+         * Amy = fp + fm, where fp = f(x, y+1), and fm = f(x, y-1).
+         * sqrt_A2my2 = sqrt(Amy*(A+y))
+         */
+        if y == 1.0 && x < eps / 128.0 {
+            /*
+             * fp is of order x^2, and fm = x/2.
+             * A = 1 (inexactly).
+             */
+            hw.sqrt_a2my2 = sqrt(x) * sqrt((a + y) / 2.0);
+        } else if x >= eps * fabs(y - 1.0) {
+            /*
+             * Underflow will not occur because
+             * x >= DBL_EPSILON/128 >= FOUR_SQRT_MIN
+             * and
+             * x >= DBL_EPSILON^2 >= FOUR_SQRT_MIN
+             */
+            let amy = f(x, y + 1.0, r) + f(x, y - 1.0, s);
+            hw.sqrt_a2my2 = sqrt(amy * (a + y));
+        } else if y > 1.0 {
+            /*
+             * fp = x*x/(y+1)/4, fm = x*x/(y-1)/4, and
+             * A = y (inexactly).
+             *
+             * y < RECIP_EPSILON.  So the following
+             * scaling should avoid any underflow problems.
+             */
+            hw.sqrt_a2my2 = x * (4.0 / eps / eps) * y / sqrt((y + 1.0) * (y - 1.0));
+            hw.new_y = y * (4.0 / eps / eps);
+        } else {
+            /* if (y < 1) */
+            /*
+             * fm = 1-y >= DBL_EPSILON, fp is of order x^2, and
+             * A = 1 (inexactly).
+             */
+            hw.sqrt_a2my2 = sqrt((1.0 - y) * (1.0 + y));
+        }
+    }
+    hw
+}
+
+/*
+ * Optimized version of clog() for |z| finite and larger than ~RECIP_EPSILON.
+ */
+#[inline]
+pub(crate) fn clog_for_large_values(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let mut ax = fabs(x);
+    let mut ay = fabs(y);
+    if ax < ay {
+        core::mem::swap(&mut ax, &mut ay);
+    }
+
+    /*
+     * Avoid overflow in hypot() when x and y are both very large.
+     * Divide x and y by E, and then add 1 to the logarithm.  This
+     * depends on E being larger than sqrt(2), since the return value of
+     * hypot cannot overflow if neither argument is greater in magnitude
+     * than 1/sqrt(2) of the maximum value of the return type.  Likewise
+     * this determines the necessary threshold for using this method
+     * (however, actually use 1/2 instead as it is simpler).
+     *
+     * Dividing by E causes an insignificant loss of accuracy; however
+     * this method is still poor since it is uneccessarily slow.
+     */
+    if ax > f64::MAX / 2.0 {
+        return Complex::new(log(hypot(x / M_E, y / M_E)) + 1.0, atan2(y, x));
+    }
+
+    /*
+     * Avoid overflow when x or y is large.  Avoid underflow when x or
+     * y is small.
+     */
+    if ax > QUARTER_SQRT_MAX || ay < SQRT_MIN {
+        return Complex::new(log(hypot(x, y)), atan2(y, x));
+    }
+
+    Complex::new(log(ax * ax + ay * ay) / 2.0, atan2(y, x))
+}
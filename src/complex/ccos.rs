@@ -0,0 +1,54 @@
+use super::{ccosh, Complex};
+
+/// Cosine (f64)
+///
+/// Computes the cosine of `z`, as `ccosh(i*z)`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ccos(z: Complex<f64>) -> Complex<f64> {
+    /* ccos(z) = ccosh(I * z) */
+    ccosh(Complex::new(-z.im, z.re))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ccos;
+    use crate::complex::testutil::{check_cases, same, same_abs, Parity};
+    use crate::complex::Complex;
+    use core::f64;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn annex_g() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // z, ccos(z)
+            (c(0.0, 0.0), c(1.0, -0.0)),
+            (c(inf, 1.0), c(nan, nan)),
+            (c(nan, 1.0), c(nan, nan)),
+            (c(0.0, inf), c(inf, -0.0)),
+            (c(1.0, inf), c(inf, -inf)),
+            (c(2.0, inf), c(-inf, -inf)),
+            (c(nan, inf), c(inf, nan)),
+            (c(2.0, nan), c(nan, nan)),
+            (c(nan, nan), c(nan, nan)),
+        ];
+        check_cases(ccos, &cases, Parity::Even, same);
+
+        /* the signs of these zeros and infinities are unspecified */
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            (c(inf, 0.0), c(nan, 0.0)),
+            (c(nan, 0.0), c(nan, 0.0)),
+            (c(inf, inf), c(inf, nan)),
+            (c(0.0, nan), c(nan, 0.0)),
+        ];
+        check_cases(ccos, &cases, Parity::Even, same_abs);
+    }
+}
@@ -0,0 +1,22 @@
+use super::{ccos, narrow, Complex};
+
+/// Cosine (f32)
+///
+/// Computes the cosine of `z` by evaluating `ccos` in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ccosf(z: Complex<f32>) -> Complex<f32> {
+    narrow(ccos(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ccosf;
+    use crate::complex::ccos;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_ccos() {
+        check_f32(ccosf, ccos);
+    }
+}
@@ -0,0 +1,23 @@
+use super::{ccosh, narrow, Complex};
+
+/// Hyperbolic cosine (f32)
+///
+/// Computes the hyperbolic cosine of `z` by evaluating `ccosh` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ccoshf(z: Complex<f32>) -> Complex<f32> {
+    narrow(ccosh(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ccoshf;
+    use crate::complex::ccosh;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_ccosh() {
+        check_f32(ccoshf, ccosh);
+    }
+}
@@ -0,0 +1,201 @@
+// cerf(z) return the error function of a complex argument
+//
+// Method:
+//      erf(z) = 1 - exp(-z^2)*w(i*z), with the Faddeeva function w. For
+//      x < 0 the mirror image erf(z) = exp(-z^2)*w(-i*z) - 1 avoids the
+//      cancellation between 1 and exp(-z^2)*w(i*z) when |x| is large.
+//      The cancellation for |z| < 1/2 and near the imaginary axis is
+//      avoided by Taylor series:
+//              erf(z) = 2/sqrt(pi)*sum (-1)^k*z^(2k+1)/(k!*(2k+1))
+//      and, with erfi(y) = exp(y^2)*Im w(y),
+//              erf(x + i*y) = i*erfi(y) + 2/sqrt(pi)*exp(y^2)*
+//                      (x*(1 - x^2*(1 + 2*y^2)/3 + x^4*(3 + 12*y^2 + 4*y^4)/30)
+//                      - i*x^2*y*(1 - x^2*(3 + 2*y^2)/6))
+//      On the real axis the real erf is used. Where exp(-z^2) overflows
+//      while erf(z) does not, it is evaluated as 2^k*exp(-z^2 - k*ln2) and
+//      the 2^k is applied to each part of the product with w.
+//
+// Accuracy:
+//      The error is below about (30 + 2*|z|^2)*eps*max(1, |erf(z)|), where
+//      2*|z|^2 is the condition number of exp(-z^2). Close to the zeros of
+//      erf(z) off the real axis this is an absolute rather than a relative
+//      bound.
+//
+// Special cases:
+//      1. cerf(conj(z)) = conj(cerf(z)) and cerf(-z) = -cerf(z)
+//      2. cerf(+-INF + i*y) is +-1 for finite y
+//      3. cerf(x + i*0) is erf(x) + i*0
+//
+use super::{exp_mul, exp_split, faddeeva, mul, Complex};
+use crate::math::{erf, fabs, scalbn};
+use core::f64;
+use core::f64::consts::FRAC_2_SQRT_PI;
+
+/// Error function (f64)
+///
+/// Computes the error function of the complex argument `z`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cerf(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+
+    if y == 0.0 {
+        return Complex::new(erf(x), y);
+    }
+    if x == 0.0 {
+        /* erf(i*y) = i*erfi(y) */
+        if fabs(y) == f64::INFINITY {
+            return Complex::new(x, y);
+        }
+        let w = faddeeva(Complex::new(y, 0.0));
+        return Complex::new(x, exp_mul(y * y, 0.0, w).im);
+    }
+
+    /* -z^2, careful of overflow */
+    let mre = (y - x) * (x + y);
+    let mim = -2.0 * x * y;
+    if mre < -750.0 {
+        return Complex::new(if x >= 0.0 { 1.0 } else { -1.0 }, 0.0);
+    }
+
+    if x * x + y * y < 0.25 {
+        /* 2/sqrt(pi)*sum (-z^2)^k/(k!*(2k+1))*z, to below 1e-17 */
+        let mz2 = Complex::new(mre, mim);
+        let mut t = z;
+        let mut s = z;
+        for k in 1..14 {
+            t = mul(t, mz2);
+            t.re /= k as f64;
+            t.im /= k as f64;
+            let d = (2 * k + 1) as f64;
+            s.re += t.re / d;
+            s.im += t.im / d;
+        }
+        return Complex::new(FRAC_2_SQRT_PI * s.re, FRAC_2_SQRT_PI * s.im);
+    }
+    if fabs(x) < 5e-3 && mim > -5e-3 && mim < 5e-3 {
+        let x2 = x * x;
+        let y2 = y * y;
+        let (ey2, k) = exp_split(y2);
+        let re = ey2
+            * x
+            * (FRAC_2_SQRT_PI - x2 * (0.37612638903183754 + 0.7522527780636751 * y2)
+                + x2 * x2
+                    * (0.11283791670955126
+                        + y2 * (0.45135166683820505 + 0.15045055561273502 * y2)));
+        let im = ey2
+            * (faddeeva(Complex::new(y, 0.0)).im
+                - x2 * y * (FRAC_2_SQRT_PI - x2 * (0.5641895835477563 + 0.37612638903183754 * y2)));
+        return Complex::new(scalbn(re, k), scalbn(im, k));
+    }
+
+    /* exp(-z^2)*w, without the NAN of a complex exp times an overflow */
+    if x >= 0.0 {
+        let t = exp_mul(mre, mim, faddeeva(Complex::new(-y, x)));
+        Complex::new(1.0 - t.re, -t.im)
+    } else {
+        let t = exp_mul(mre, mim, faddeeva(Complex::new(y, -x)));
+        Complex::new(t.re - 1.0, t.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cerf;
+    use crate::complex::{cerfc, Complex};
+    use crate::math::{fabs, hypot};
+    use core::f64;
+
+    fn check(w: Complex<f64>, re: f64, im: f64) {
+        let err = hypot(w.re - re, w.im - im);
+        assert!(err <= 4e-14 * hypot(re, im), "{:?}", w);
+    }
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        assert_eq!(cerf(c(0.5, 0.0)), c(crate::math::erf(0.5), 0.0));
+        assert_eq!(cerf(c(inf, 2.0)), c(1.0, 0.0));
+        assert_eq!(cerf(c(-inf, 2.0)), c(-1.0, 0.0));
+        assert_eq!(cerf(c(0.0, 30.0)), c(0.0, inf));
+        assert_eq!(cerfc(c(inf, -2.0)), c(0.0, 0.0));
+        assert_eq!(cerfc(c(-inf, -2.0)), c(2.0, 0.0));
+        assert_eq!(cerfc(c(0.0, 0.0)), c(1.0, -0.0));
+    }
+
+    #[test]
+    fn near_overflow() {
+        let inf = f64::INFINITY;
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // x, y, erf(x + i*y)
+            (0.0, 26.66, 0.0, 1.0071775473582596e307),
+            (0.0, -26.66, 0.0, -1.0071775473582596e307),
+            (0.0, 26.72, 0.0, inf),
+            (0.05, 26.6, 1.9164122670537226e305, -3.650060617029062e305),
+            (0.1, 26.674, -1.713106613074967e307, 1.2195317069250018e307),
+            (0.3, 26.68, -7.61168404970846e306, -2.5621548676521235e307),
+            (-0.2, -26.7, 7.743854415370612e307, 2.5926885694210373e307),
+            (1.0, 26.75, -2.5048104922566076e307, -inf),
+            (0.3, 26.8, -inf, -inf),
+            (5e-5, 26.66, 2.683240563303954e304, 1.0071739705964718e307),
+            (-2e-5, 26.7, -9.071480604878096e304, 8.499862417097882e307),
+            (3e-5, 26.8, 2.8657929433107336e307, inf),
+        ];
+        /* the condition number of exp(-z^2) is 2*|z|^2, about 1400 here */
+        let near = |a: f64, b: f64| {
+            if b.is_infinite() || b == 0.0 {
+                a == b
+            } else {
+                fabs(a - b) <= 2e-13 * fabs(b)
+            }
+        };
+        for &(x, y, re, im) in cases.iter() {
+            let w = cerf(c(x, y));
+            assert!(
+                near(w.re, re) && near(w.im, im),
+                "cerf({}, {}) = {:?}",
+                x,
+                y,
+                w
+            );
+            let w = cerfc(c(x, y));
+            let re = if x == 0.0 { 1.0 } else { -re };
+            assert!(
+                near(w.re, re) && near(w.im, -im),
+                "cerfc({}, {}) = {:?}",
+                x,
+                y,
+                w
+            );
+        }
+    }
+
+    #[test]
+    fn values() {
+        check(cerf(c(0.3, 0.2)), 0.34123748147213856, 0.20852883788276888);
+        check(cerf(c(2.0, 1.0)), 1.0036063427256519, -0.011259006028815025);
+        check(cerf(c(-1.0, -3.0)), 330.81538696857206, -443.3888818393928);
+        check(
+            cerf(c(0.001, 2.0)),
+            0.061607230237346114,
+            18.564679199971327,
+        );
+        check(
+            cerfc(c(2.0, 1.0)),
+            -0.0036063427256517507,
+            0.011259006028815025,
+        );
+        check(cerfc(c(-1.0, -3.0)), -329.81538696857206, 443.3888818393928);
+        check(
+            cerfc(c(5.0, 0.5)),
+            7.357207765898195e-13,
+            1.82243807707677e-12,
+        );
+    }
+}
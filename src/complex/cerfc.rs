@@ -0,0 +1,60 @@
+// cerfc(z) return the complementary error function of a complex argument
+//
+// Method:
+//      erfc(z) = exp(-z^2)*w(i*z) for x >= 0, with the Faddeeva function w,
+//      and erfc(z) = 2 - erfc(-z) for x < 0. On the real axis the real erfc
+//      is used, and on the imaginary axis erfc(i*y) = 1 - i*erfi(y) with
+//      erfi(y) = exp(y^2)*Im w(y). Where exp(-z^2) overflows while erfc(z)
+//      does not, it is evaluated as 2^k*exp(-z^2 - k*ln2) and the 2^k is
+//      applied to each part of the product with w.
+//
+// Accuracy:
+//      The error is below about (30 + 2*|z|^2)*eps*max(1, |erfc(z)|), where
+//      2*|z|^2 is the condition number of exp(-z^2). Close to the zeros of
+//      erfc(z) this is an absolute rather than a relative bound.
+//
+// Special cases:
+//      1. cerfc(conj(z)) = conj(cerfc(z))
+//      2. cerfc(+INF + i*y) is 0 and cerfc(-INF + i*y) is 2 for finite y
+//      3. cerfc(x + i*0) is erfc(x) - i*0
+//
+use super::{exp_mul, faddeeva, Complex};
+use crate::math::{erfc, fabs};
+use core::f64;
+
+/// Complementary error function (f64)
+///
+/// Computes `1 - erf(z)` for the complex argument `z`, without the loss of
+/// accuracy of the subtraction for large `z.re`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cerfc(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+
+    if y == 0.0 {
+        return Complex::new(erfc(x), -y);
+    }
+    if x == 0.0 {
+        if fabs(y) == f64::INFINITY {
+            return Complex::new(1.0, -y);
+        }
+        let w = faddeeva(Complex::new(y, 0.0));
+        return Complex::new(1.0, -exp_mul(y * y, 0.0, w).im);
+    }
+
+    /* -z^2, careful of overflow */
+    let mre = (y - x) * (x + y);
+    let mim = -2.0 * x * y;
+    if mre < -750.0 {
+        return Complex::new(if x >= 0.0 { 0.0 } else { 2.0 }, 0.0);
+    }
+
+    /* exp(-z^2)*w, without the NAN of a complex exp times an overflow */
+    if x >= 0.0 {
+        exp_mul(mre, mim, faddeeva(Complex::new(-y, x)))
+    } else {
+        let t = exp_mul(mre, mim, faddeeva(Complex::new(y, -x)));
+        Complex::new(2.0 - t.re, -t.im)
+    }
+}
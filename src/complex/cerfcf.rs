@@ -0,0 +1,11 @@
+use super::{cerfc, narrow, Complex};
+
+/// Complementary error function (f32)
+///
+/// Computes `1 - erf(z)` for the complex argument `z` by evaluating `cerfc` in
+/// double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cerfcf(z: Complex<f32>) -> Complex<f32> {
+    narrow(cerfc(z.into()))
+}
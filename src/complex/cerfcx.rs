@@ -0,0 +1,12 @@
+use super::{faddeeva, Complex};
+
+/// Scaled complementary error function (f64)
+///
+/// Computes `exp(z^2)*erfc(z)`, which is `w(i*z)` for the Faddeeva
+/// function `w`. It stays finite where `erfc` underflows, and has the
+/// accuracy of `faddeeva`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cerfcx(z: Complex<f64>) -> Complex<f64> {
+    faddeeva(Complex::new(-z.im, z.re))
+}
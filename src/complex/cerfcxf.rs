@@ -0,0 +1,10 @@
+use super::{cerfcx, narrow, Complex};
+
+/// Scaled complementary error function (f32)
+///
+/// Computes `exp(z^2)*erfc(z)` by evaluating `cerfcx` in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cerfcxf(z: Complex<f32>) -> Complex<f32> {
+    narrow(cerfcx(z.into()))
+}
@@ -0,0 +1,11 @@
+use super::{cerf, narrow, Complex};
+
+/// Error function (f32)
+///
+/// Computes the error function of the complex argument `z` by evaluating
+/// `cerf` in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cerff(z: Complex<f32>) -> Complex<f32> {
+    narrow(cerf(z.into()))
+}
@@ -0,0 +1,131 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_cexp.c */
+/*-
+ * Copyright (c) 2011 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::{get_high_word, get_low_word, ldexp_cexp, Complex};
+use crate::math::{exp, sincos};
+
+const EXP_OVFL: u32 = 0x40862e42; /* high bits of MAX_EXP * ln2 ~= 710 */
+const CEXP_OVFL: u32 = 0x4096b8e4; /* (MAX_EXP - MIN_DENORM_EXP) * ln2 */
+
+/// Exponential (f64)
+///
+/// Computes `e` raised to the complex power `z`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cexp(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+
+    let hy = get_high_word(y) & 0x7fffffff;
+    let ly = get_low_word(y);
+
+    /* cexp(x + I 0) = exp(x) + I 0 */
+    if (hy | ly) == 0 {
+        return Complex::new(exp(x), y);
+    }
+    let hx = get_high_word(x);
+    let lx = get_low_word(x);
+    /* cexp(0 + I y) = cos(y) + I sin(y) */
+    if ((hx & 0x7fffffff) | lx) == 0 {
+        let (s, c) = sincos(y);
+        return Complex::new(c, s);
+    }
+
+    if hy >= 0x7ff00000 {
+        if lx != 0 || (hx & 0x7fffffff) != 0x7ff00000 {
+            /* cexp(finite|NaN +- I Inf|NaN) = NaN + I NaN */
+            return Complex::new(y - y, y - y);
+        } else if hx & 0x80000000 != 0 {
+            /* cexp(-Inf +- I Inf|NaN) = 0 + I 0 */
+            return Complex::new(0.0, 0.0);
+        } else {
+            /* cexp(+Inf +- I Inf|NaN) = Inf + I NaN */
+            return Complex::new(x, y - y);
+        }
+    }
+
+    if hx >= EXP_OVFL && hx <= CEXP_OVFL {
+        /*
+         * x is between 709.7 and 1454.3, so we must scale to avoid
+         * overflow in exp(x).
+         */
+        ldexp_cexp(z, 0)
+    } else {
+        /*
+         * Cases covered here:
+         *  -  x < EXP_OVFL and exp(x) won't overflow (common case)
+         *  -  x > CEXP_OVFL and exp(x) * cos(y) will overflow
+         *  -  x = +-Inf (generated by exp())
+         *  -  x = NaN (spurious inexact exception from y)
+         */
+        let exp_x = exp(x);
+        let (s, c) = sincos(y);
+        Complex::new(exp_x * c, exp_x * s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cexp;
+    use crate::complex::Complex;
+    use core::f64;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        assert_eq!(cexp(c(0.0, 0.0)), c(1.0, 0.0));
+        assert_eq!(cexp(c(-0.0, -0.0)).im.to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(cexp(c(inf, 0.0)), c(inf, 0.0));
+        assert_eq!(cexp(c(-inf, 2.0)), c(-0.0, 0.0));
+        assert_eq!(cexp(c(inf, 2.0)), c(-inf, inf));
+        assert_eq!(cexp(c(-inf, inf)), c(0.0, 0.0));
+        let z = cexp(c(inf, inf));
+        assert!(z.re.is_infinite() && z.im.is_nan());
+        let z = cexp(c(inf, nan));
+        assert!(z.re.is_infinite() && z.im.is_nan());
+        let z = cexp(c(nan, 0.0));
+        assert!(z.re.is_nan() && z.im.to_bits() == 0);
+        let z = cexp(c(1.0, inf));
+        assert!(z.re.is_nan() && z.im.is_nan());
+    }
+
+    #[test]
+    fn large_real_part() {
+        /* exp(710) overflows, but its product with cos(2) does not */
+        let z = cexp(c(710.0, 2.0));
+        assert!(z.re.is_finite() && z.re < -9e307);
+        assert_eq!(z.im, f64::INFINITY);
+        assert_eq!(cexp(c(1455.0, 1.0)).re, f64::INFINITY);
+        assert_eq!(cexp(c(0.0, f64::consts::PI)).re, -1.0);
+    }
+}
@@ -0,0 +1,23 @@
+use super::{cexp, narrow, Complex};
+
+/// Exponential (f32)
+///
+/// Computes `e` raised to the complex power `z` by evaluating `cexp` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cexpf(z: Complex<f32>) -> Complex<f32> {
+    narrow(cexp(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cexpf;
+    use crate::complex::cexp;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_cexp() {
+        check_f32(cexpf, cexp);
+    }
+}
@@ -0,0 +1,10 @@
+use super::Complex;
+
+/// Imaginary part (f64)
+///
+/// Returns the imaginary part of `z`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cimag(z: Complex<f64>) -> f64 {
+    z.im
+}
@@ -0,0 +1,22 @@
+use super::Complex;
+
+/// Imaginary part (f32)
+///
+/// Returns the imaginary part of `z`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cimagf(z: Complex<f32>) -> f32 {
+    z.im
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cimagf;
+    use crate::complex::cimag;
+    use crate::complex::testutil::check_f32_real;
+
+    #[test]
+    fn agrees_with_cimag() {
+        check_f32_real(cimagf, cimag);
+    }
+}
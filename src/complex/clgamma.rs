@@ -0,0 +1,302 @@
+// clgamma(z) return the principal branch of log(gamma(z))
+//
+// Method:
+//      The principal branch is the analytic continuation of the real
+//      lgamma to the plane cut along the negative real axis. Its imaginary
+//      part differs from arg(gamma(z)) by a multiple of 2*pi. Following
+//      D.E.G. Hare, "Computing the principal branch of log-Gamma",
+//      J. Algorithms 25 (1997):
+//      1. For x > 7 or |y| > 7, the Stirling series
+//              (z - 1/2)*log(z) - z + log(2*pi)/2 + sum B_2k/(2k*(2k-1)*z^(2k-1))
+//      2. Within 0.2 of 1, where log(gamma(z)) vanishes, the Taylor series
+//              log(gamma(1 + t)) = -euler*t + sum (-1)^k*zeta(k)/k*t^k
+//         and within 0.2 of 2 the same after log(gamma(z)) =
+//         log(z - 1) + log(gamma(z - 1)).
+//      3. For x < 0.1, the reflection
+//              log(gamma(z)) = log(pi) - log(sin(pi*z)) - log(gamma(1 - z))
+//         plus 2*pi*i*floor(x/2 + 1/4) with the sign of y, to stay on the
+//         principal branch.
+//      4. Otherwise the recurrence log(gamma(z)) = log(gamma(z + n)) -
+//         log(z*(z + 1)*...*(z + n - 1)) to reach x > 7, counting how often
+//         the product crosses the negative real axis.
+//      On the real axis the real lgamma_r is used.
+//
+// Accuracy:
+//      The error is below 2e-14*max(1, |log(gamma(z))|).
+//
+// Special cases:
+//      1. clgamma(conj(z)) = conj(clgamma(z))
+//      2. clgamma(x + i*0) is lgamma(x) + i*pi*floor(x) for x < 0, where
+//         the sign of the zero selects the side of the cut
+//      3. clgamma(-n + i*0) is +INF + i*NAN for integers n >= 0, raise
+//         divbyzero
+//      4. clgamma(+INF + i*y) is +INF + i*INF*sign(y) for finite y != 0
+//      5. clgamma(x +- i*INF) is -INF +- i*INF
+//
+use super::{clog, mul, Complex};
+use crate::math::{copysign, cosh, cospi, fabs, floor, lgamma_r, sinh, sinpi};
+use core::f64::consts::PI;
+
+const HLOG2PI: f64 = 0.9189385332046728; /* log(2*pi)/2 */
+const LOGPI: f64 = 1.1447298858494002; /* log(pi) */
+
+/* B_2k/(2k*(2k-1)), highest first */
+const STIRLING: [f64; 8] = [
+    -0.029550653594771242,
+    0.00641025641025641,
+    -0.0019175269175269176,
+    0.0008417508417508417,
+    -0.0005952380952380953,
+    0.0007936507936507937,
+    -0.002777777777777778,
+    0.08333333333333333,
+];
+
+/* (-1)^k*zeta(k)/k for k = 23..2, then -euler */
+const TAYLOR: [f64; 23] = [
+    -0.04347826605304026,
+    0.04545455629320467,
+    -0.047619070330142226,
+    0.05000004769810169,
+    -0.05263167937961666,
+    0.055555767627403614,
+    -0.058823978658684585,
+    0.06250095514121304,
+    -0.06666870588242046,
+    0.07143294629536133,
+    -0.0769325164113522,
+    0.083353840546109,
+    -0.09095401714582904,
+    0.1000994575127818,
+    -0.11133426586956469,
+    0.12550966952474304,
+    -0.1440498967688461,
+    0.1695571769974082,
+    -0.20738555102867398,
+    0.27058080842778454,
+    -0.40068563438653143,
+    0.8224670334241132,
+    -0.5772156649015329,
+];
+
+/* evaluate the polynomial with coefficients c, highest first */
+#[inline]
+fn poly(c: &[f64], z: Complex<f64>) -> Complex<f64> {
+    let mut p = Complex::new(0.0, 0.0);
+    for &ci in c {
+        p = mul(p, z);
+        p.re += ci;
+    }
+    p
+}
+
+#[inline]
+fn stirling(z: Complex<f64>) -> Complex<f64> {
+    /* 1/z, scaled against overflow */
+    let rz = if fabs(z.re) >= fabs(z.im) {
+        let r = z.im / z.re;
+        let d = 1.0 / (z.re + r * z.im);
+        Complex::new(d, -r * d)
+    } else {
+        let r = z.re / z.im;
+        let d = 1.0 / (z.im + r * z.re);
+        Complex::new(r * d, -d)
+    };
+    let l = mul(Complex::new(z.re - 0.5, z.im), clog(z));
+    let s = mul(rz, poly(&STIRLING, mul(rz, rz)));
+    Complex::new(l.re - z.re + HLOG2PI + s.re, l.im - z.im + s.im)
+}
+
+#[inline]
+fn taylor(z: Complex<f64>) -> Complex<f64> {
+    let t = Complex::new(z.re - 1.0, z.im);
+    mul(t, poly(&TAYLOR, t))
+}
+
+/* for y >= 0 and x >= 0.1 */
+#[inline]
+fn recurrence(z: Complex<f64>) -> Complex<f64> {
+    let mut signflips = 0;
+    let mut sb = false;
+    let mut prod = z;
+    let mut z = Complex::new(z.re + 1.0, z.im);
+    while z.re <= 7.0 {
+        prod = mul(prod, z);
+        let nsb = prod.im.is_sign_negative();
+        if nsb && !sb {
+            signflips += 1;
+        }
+        sb = nsb;
+        z.re += 1.0;
+    }
+    let s = stirling(z);
+    let l = clog(prod);
+    Complex::new(s.re - l.re, s.im - l.im - signflips as f64 * 2.0 * PI)
+}
+
+/* for finite z off the real axis, with x >= 0.1 or |y| > 7 */
+#[inline]
+fn nonreal(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    if x > 7.0 || fabs(y) > 7.0 {
+        stirling(z)
+    } else if (x - 1.0) * (x - 1.0) + y * y <= 0.04 {
+        taylor(z)
+    } else if (x - 2.0) * (x - 2.0) + y * y <= 0.04 {
+        /* log(gamma(z)) = log(z - 1) + log(gamma(z - 1)) */
+        let t = Complex::new(x - 1.0, y);
+        let l = clog(t);
+        let g = taylor(t);
+        Complex::new(l.re + g.re, l.im + g.im)
+    } else if !y.is_sign_negative() {
+        recurrence(z)
+    } else {
+        let r = recurrence(Complex::new(x, -y));
+        Complex::new(r.re, -r.im)
+    }
+}
+
+/// Log-gamma function (f64)
+///
+/// Computes the principal branch of the natural logarithm of the gamma
+/// function of `z`, which is continuous except on the negative real axis.
+/// Its real part is `log|gamma(z)|`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn clgamma(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+
+    if x.is_nan() || y.is_nan() {
+        return Complex::new(x + y, x + y);
+    }
+    if y == 0.0 {
+        if x <= 0.0 && floor(x) == x {
+            /* pole */
+            return Complex::new(1.0 / 0.0, 0.0 / 0.0);
+        }
+        let (l, _) = lgamma_r(x);
+        if x > 0.0 {
+            return Complex::new(l, y);
+        }
+        let k = PI * floor(x);
+        return Complex::new(l, if y.is_sign_negative() { -k } else { k });
+    }
+    if y.is_infinite() {
+        return Complex::new(-fabs(y), y);
+    }
+    if x.is_infinite() {
+        if x > 0.0 {
+            return Complex::new(x, copysign(x, y));
+        }
+        return Complex::new(x, x - x);
+    }
+
+    if x < 0.1 && fabs(y) <= 7.0 {
+        /* reflection, with sin(pi*z) for |y| <= 7 */
+        let tmp = 2.0 * PI * copysign(1.0, y) * floor(0.5 * x + 0.25);
+        let s = Complex::new(sinpi(x) * cosh(PI * y), cospi(x) * sinh(PI * y));
+        let l = clog(s);
+        let g = nonreal(Complex::new(1.0 - x, -y));
+        Complex::new(LOGPI - l.re - g.re, tmp - l.im - g.im)
+    } else {
+        nonreal(z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clgamma;
+    use crate::complex::{ctgamma, Complex};
+    use crate::math::hypot;
+    use core::f64;
+    use core::f64::consts::PI;
+
+    fn check(w: Complex<f64>, re: f64, im: f64) {
+        let err = hypot(w.re - re, w.im - im);
+        let scale = hypot(re, im);
+        assert!(
+            err <= 2e-14 * if scale > 1.0 { scale } else { 1.0 },
+            "{:?}",
+            w
+        );
+    }
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn real_axis() {
+        assert_eq!(clgamma(c(1.0, 0.0)), c(0.0, 0.0));
+        assert_eq!(clgamma(c(2.0, 0.0)), c(0.0, 0.0));
+        let z = clgamma(c(-2.5, 0.0));
+        assert_eq!(z.im, -3.0 * PI);
+        check(z, -0.056243716497674054, -3.0 * PI);
+        assert_eq!(clgamma(c(-2.5, -0.0)).im, 3.0 * PI);
+        let z = clgamma(c(-3.0, 0.0));
+        assert!(z.re == f64::INFINITY && z.im.is_nan());
+        assert_eq!(ctgamma(c(5.0, 0.0)), c(24.0, 0.0));
+    }
+
+    #[test]
+    fn values() {
+        check(
+            clgamma(c(1.0, 1.0)),
+            -0.6509231993018564,
+            -0.3016403204675332,
+        );
+        check(
+            clgamma(c(0.5, 0.5)),
+            0.11238724280962312,
+            -0.7507292021220507,
+        );
+        check(
+            clgamma(c(1.1, 0.05)),
+            -0.0516628914717815,
+            -0.021149007244415596,
+        );
+        check(
+            clgamma(c(20.0, -30.0)),
+            21.345074493863446,
+            -96.71434768953618,
+        );
+        check(
+            clgamma(c(-100.5, 3.0)),
+            -373.5880515535142,
+            -303.4550432028437,
+        );
+    }
+
+    #[test]
+    fn branch_cut() {
+        check(
+            clgamma(c(-2.5, 0.1)),
+            -0.10314924404281921,
+            -9.314444268359837,
+        );
+        check(
+            clgamma(c(-2.5, -0.1)),
+            -0.10314924404281921,
+            9.314444268359837,
+        );
+    }
+
+    #[test]
+    fn gamma() {
+        check(
+            ctgamma(c(1.0, 1.0)),
+            0.49801566811835607,
+            -0.15494982830181067,
+        );
+        check(
+            ctgamma(c(-1.5, 0.5)),
+            0.9379166627878851,
+            0.34920566814780485,
+        );
+        let z = ctgamma(c(10.0, 2.0));
+        let err = hypot(z.re + 56872.8360270976, z.im + 288950.4411249291);
+        assert!(err <= 1e-13 * hypot(56872.8360270976, 288950.4411249291));
+    }
+}
@@ -0,0 +1,11 @@
+use super::{clgamma, narrow, Complex};
+
+/// Log-gamma function (f32)
+///
+/// Computes the principal branch of `log(gamma(z))` by evaluating `clgamma` in
+/// double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn clgammaf(z: Complex<f32>) -> Complex<f32> {
+    narrow(clgamma(z.into()))
+}
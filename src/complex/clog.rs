@@ -0,0 +1,112 @@
+use super::Complex;
+use crate::math::{atan2, fabs, hypot, log, log1p};
+use core::f64::consts::LN_2;
+
+const SPLIT: f64 = 134217729.0; /* 0x1p27 + 1 */
+
+/* x*x = hi + lo exactly, by Dekker's algorithm */
+#[inline]
+fn sq(x: f64) -> (f64, f64) {
+    let c = SPLIT * x;
+    let xh = c - (c - x);
+    let xl = x - xh;
+    let hi = x * x;
+    (hi, ((xh * xh - hi) + 2.0 * xh * xl) + xl * xl)
+}
+
+/// Natural logarithm (f64)
+///
+/// Computes the principal value of the natural logarithm of `z`, with the
+/// branch cut along the negative real axis. The imaginary part is in the
+/// range [-pi, pi].
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn clog(z: Complex<f64>) -> Complex<f64> {
+    let x1p600 = f64::from_bits(0x6570000000000000); // 0x1p600 === 2 ^ 600
+    let x1p_500 = f64::from_bits(0x20b0000000000000); // 0x1p-500 === 2 ^ -500
+
+    let x = z.re;
+    let y = z.im;
+    let mut ax = fabs(x);
+    let mut ay = fabs(y);
+    if ax < ay {
+        core::mem::swap(&mut ax, &mut ay);
+    }
+
+    /* the argument handles all the special values of Annex G */
+    let im = atan2(y, x);
+
+    let re = if ax == 1.0 {
+        log1p(ay * ay) * 0.5
+    } else if ax >= 0.71 && ax < 1.41 {
+        /*
+         * |z| may be close to 1, so compute |z|^2 - 1 exactly enough for
+         * log1p: ax*ax - 1 is exact here.
+         */
+        let (p1, e1) = sq(ax);
+        let (p2, e2) = sq(ay);
+        let t = p1 - 1.0;
+        let s = t + p2;
+        let es = (t - s) + p2;
+        log1p(s + (es + e1 + e2)) * 0.5
+    } else if ax > f64::MAX * 0.5 {
+        /* ax is finite here, or the result is infinite anyway */
+        log(hypot(x * 0.5, y * 0.5)) + LN_2
+    } else if ax < x1p_500 {
+        /* avoid the loss of precision of a subnormal |z| */
+        log(hypot(x * x1p600, y * x1p600)) - 600.0 * LN_2
+    } else {
+        log(hypot(x, y))
+    };
+    Complex::new(re, im)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clog;
+    use crate::complex::Complex;
+    use core::f64;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        assert_eq!(clog(c(-0.0, 0.0)), c(-inf, PI));
+        assert_eq!(clog(c(0.0, -0.0)), c(-inf, -0.0));
+        assert_eq!(clog(c(1.0, inf)), c(inf, FRAC_PI_2));
+        assert_eq!(clog(c(-inf, 1.0)), c(inf, PI));
+        assert_eq!(clog(c(inf, -1.0)), c(inf, -0.0));
+        assert_eq!(clog(c(-inf, inf)), c(inf, 3.0 * FRAC_PI_4));
+        assert_eq!(clog(c(inf, inf)), c(inf, FRAC_PI_4));
+        let z = clog(c(-inf, nan));
+        assert!(z.re == inf && z.im.is_nan());
+        let z = clog(c(nan, inf));
+        assert!(z.re == inf && z.im.is_nan());
+        let z = clog(c(nan, 1.0));
+        assert!(z.re.is_nan() && z.im.is_nan());
+    }
+
+    #[test]
+    fn branch_cuts() {
+        /* the cut along the negative real axis takes the side of the sign of 0 */
+        assert_eq!(clog(c(-1.0, 0.0)), c(0.0, PI));
+        assert_eq!(clog(c(-1.0, -0.0)), c(0.0, -PI));
+        assert_eq!(clog(c(-2.0, 0.0)), c(0.6931471805599453, PI));
+        assert_eq!(clog(c(-2.0, -0.0)), c(0.6931471805599453, -PI));
+    }
+
+    #[test]
+    fn near_unit_circle() {
+        /* log|z| close to zero, and beyond the range of hypot */
+        assert_eq!(clog(c(0.6, 0.8000000000000002)).re, 1.1102230246251564e-16);
+        assert_eq!(clog(c(1.0, 1e-10)).re, 5.0000000000000005e-21);
+        assert_eq!(clog(c(f64::MAX, f64::MAX)).re, 710.1292864836639);
+        assert_eq!(clog(c(f64::from_bits(1), 0.0)).re, -744.4400719213812);
+    }
+}
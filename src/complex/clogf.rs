@@ -0,0 +1,24 @@
+use super::{clog, narrow, Complex};
+
+/// Natural logarithm (f32)
+///
+/// Computes the principal value of the natural logarithm of `z`, with the
+/// branch cut along the negative real axis, by evaluating `clog` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn clogf(z: Complex<f32>) -> Complex<f32> {
+    narrow(clog(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clogf;
+    use crate::complex::clog;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_clog() {
+        check_f32(clogf, clog);
+    }
+}
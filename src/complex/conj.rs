@@ -0,0 +1,11 @@
+use super::Complex;
+
+/// Complex conjugate (f64)
+///
+/// Returns `z` with the sign of its imaginary part flipped, even for a
+/// zero or NaN imaginary part.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn conj(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(z.re, -z.im)
+}
@@ -0,0 +1,23 @@
+use super::Complex;
+
+/// Complex conjugate (f32)
+///
+/// Returns `z` with the sign of its imaginary part flipped, even for a
+/// zero or NaN imaginary part.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn conjf(z: Complex<f32>) -> Complex<f32> {
+    Complex::new(z.re, -z.im)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::conjf;
+    use crate::complex::conj;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_conj() {
+        check_f32(conjf, conj);
+    }
+}
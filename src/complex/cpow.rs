@@ -0,0 +1,41 @@
+use super::{cexp, clog, Complex};
+
+/// Power function (f64)
+///
+/// Computes `z` raised to the complex power `c`, as `cexp(c * clog(z))`,
+/// with the branch cut of `clog` along the negative real axis.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cpow(z: Complex<f64>, c: Complex<f64>) -> Complex<f64> {
+    let l = clog(z);
+    /* a real exponent keeps the infinities of clog(0) out of the product */
+    let w = if c.im == 0.0 {
+        Complex::new(c.re * l.re, c.re * l.im)
+    } else {
+        Complex::new(c.re * l.re - c.im * l.im, c.re * l.im + c.im * l.re)
+    };
+    cexp(w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cpow;
+    use crate::complex::Complex;
+    use crate::math::fabs;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!(cpow(c(0.0, 0.0), c(2.0, 0.0)), c(0.0, 0.0));
+        let z = cpow(c(2.0, 0.0), c(3.0, 0.0));
+        assert!(fabs(z.re - 8.0) < 1e-14 && z.im == 0.0);
+        assert_eq!(cpow(c(4.0, 0.0), c(0.5, 0.0)), c(2.0, 0.0));
+        let z = cpow(c(-4.0, 0.0), c(0.5, 0.0));
+        assert!(fabs(z.re) < 1e-15 && z.im == 2.0);
+        let z = cpow(c(0.0, 1.0), c(0.0, 1.0));
+        assert_eq!(z, c(0.20787957635076193, 0.0));
+    }
+}
@@ -0,0 +1,23 @@
+use super::{cpow, narrow, Complex};
+
+/// Power function (f32)
+///
+/// Computes `z` raised to the complex power `c` by evaluating `cpow` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cpowf(z: Complex<f32>, c: Complex<f32>) -> Complex<f32> {
+    narrow(cpow(z.into(), c.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cpowf;
+    use crate::complex::cpow;
+    use crate::complex::testutil::check_f32_2;
+
+    #[test]
+    fn agrees_with_cpow() {
+        check_f32_2(cpowf, cpow);
+    }
+}
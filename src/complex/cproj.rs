@@ -0,0 +1,47 @@
+use super::Complex;
+use core::f64;
+
+/// Projection onto the Riemann sphere (f64)
+///
+/// Returns `z`, except that every complex infinity, including those with
+/// a NaN part, is projected to `inf + i*copysign(0, im)`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cproj(z: Complex<f64>) -> Complex<f64> {
+    if z.re.is_infinite() || z.im.is_infinite() {
+        let im = if z.im.is_sign_negative() { -0.0 } else { 0.0 };
+        return Complex::new(f64::INFINITY, im);
+    }
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cproj;
+    use crate::complex::testutil::same;
+    use crate::complex::Complex;
+    use core::f64;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        /* every infinity projects to +INF, keeping the sign of im as 0 */
+        assert!(same(cproj(c(inf, 2.0)), c(inf, 0.0)));
+        assert!(same(cproj(c(-inf, -2.0)), c(inf, -0.0)));
+        assert!(same(cproj(c(1.0, -inf)), c(inf, -0.0)));
+        assert!(same(cproj(c(nan, inf)), c(inf, 0.0)));
+        assert!(same(cproj(c(-inf, nan)), c(inf, 0.0)));
+        assert!(same(cproj(c(-inf, -nan)), c(inf, -0.0)));
+        /* finite and NAN arguments are left alone */
+        assert!(same(cproj(c(-0.0, -0.0)), c(-0.0, -0.0)));
+        assert!(same(cproj(c(f64::MAX, -2.0)), c(f64::MAX, -2.0)));
+        assert!(same(cproj(c(nan, 1.0)), c(nan, 1.0)));
+        assert!(same(cproj(c(0.0, nan)), c(0.0, nan)));
+    }
+}
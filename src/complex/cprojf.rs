@@ -0,0 +1,28 @@
+use super::Complex;
+use core::f32;
+
+/// Projection onto the Riemann sphere (f32)
+///
+/// Returns `z`, except that every complex infinity, including those with
+/// a NaN part, is projected to `inf + i*copysign(0, im)`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cprojf(z: Complex<f32>) -> Complex<f32> {
+    if z.re.is_infinite() || z.im.is_infinite() {
+        let im = if z.im.is_sign_negative() { -0.0 } else { 0.0 };
+        return Complex::new(f32::INFINITY, im);
+    }
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cprojf;
+    use crate::complex::cproj;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_cproj() {
+        check_f32(cprojf, cproj);
+    }
+}
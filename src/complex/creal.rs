@@ -0,0 +1,10 @@
+use super::Complex;
+
+/// Real part (f64)
+///
+/// Returns the real part of `z`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn creal(z: Complex<f64>) -> f64 {
+    z.re
+}
@@ -0,0 +1,22 @@
+use super::Complex;
+
+/// Real part (f32)
+///
+/// Returns the real part of `z`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn crealf(z: Complex<f32>) -> f32 {
+    z.re
+}
+
+#[cfg(test)]
+mod tests {
+    use super::crealf;
+    use crate::complex::creal;
+    use crate::complex::testutil::check_f32_real;
+
+    #[test]
+    fn agrees_with_creal() {
+        check_f32_real(crealf, creal);
+    }
+}
@@ -0,0 +1,55 @@
+use super::{csinh, Complex};
+
+/// Sine (f64)
+///
+/// Computes the sine of `z`, as `-i*csinh(i*z)`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csin(z: Complex<f64>) -> Complex<f64> {
+    /* csin(z) = -I * csinh(I * z) = I * conj(csinh(I * conj(z))) */
+    let w = csinh(Complex::new(z.im, z.re));
+    Complex::new(w.im, w.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::csin;
+    use crate::complex::testutil::{check_cases, same, same_abs, Parity};
+    use crate::complex::Complex;
+    use core::f64;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn annex_g() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // z, csin(z)
+            (c(0.0, 0.0), c(0.0, 0.0)),
+            (c(inf, 1.0), c(nan, nan)),
+            (c(nan, 1.0), c(nan, nan)),
+            (c(0.0, inf), c(0.0, inf)),
+            (c(1.0, inf), c(inf, inf)),
+            (c(2.0, inf), c(inf, -inf)),
+            (c(0.0, nan), c(0.0, nan)),
+            (c(2.0, nan), c(nan, nan)),
+            (c(nan, nan), c(nan, nan)),
+        ];
+        check_cases(csin, &cases, Parity::Odd, same);
+
+        /* the signs of these zeros and infinities are unspecified */
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            (c(inf, 0.0), c(nan, 0.0)),
+            (c(nan, 0.0), c(nan, 0.0)),
+            (c(inf, inf), c(nan, inf)),
+            (c(nan, inf), c(nan, inf)),
+        ];
+        check_cases(csin, &cases, Parity::Odd, same_abs);
+    }
+}
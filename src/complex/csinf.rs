@@ -0,0 +1,22 @@
+use super::{csin, narrow, Complex};
+
+/// Sine (f32)
+///
+/// Computes the sine of `z` by evaluating `csin` in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csinf(z: Complex<f32>) -> Complex<f32> {
+    narrow(csin(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::csinf;
+    use crate::complex::csin;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_csin() {
+        check_f32(csinf, csin);
+    }
+}
@@ -0,0 +1,23 @@
+use super::{csinh, narrow, Complex};
+
+/// Hyperbolic sine (f32)
+///
+/// Computes the hyperbolic sine of `z` by evaluating `csinh` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csinhf(z: Complex<f32>) -> Complex<f32> {
+    narrow(csinh(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::csinhf;
+    use crate::complex::csinh;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_csinh() {
+        check_f32(csinhf, csinh);
+    }
+}
@@ -0,0 +1,140 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_csqrt.c */
+/*-
+ * Copyright (c) 2007 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::Complex;
+use crate::math::{copysign, fabs, hypot, sqrt};
+use core::f64;
+
+/* We risk spurious overflow for components >= DBL_MAX / (1 + sqrt(2)). */
+const THRESH: f64 = 7.446288774449766e+307; /* 0x7fea8279, 0x99fcef32 */
+
+/// Square root (f64)
+///
+/// Computes the principal square root of `z`, with the branch cut along the
+/// negative real axis. The real part of the result is non-negative.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csqrt(z: Complex<f64>) -> Complex<f64> {
+    let x1p54 = f64::from_bits(0x4350000000000000); // 0x1p54 === 2 ^ 54
+    let x1p_27 = f64::from_bits(0x3e40000000000000); // 0x1p-27 === 2 ^ -27
+    let x1p_1020 = f64::from_bits(0x0030000000000000); // 0x1p-1020 === 2 ^ -1020
+
+    let mut a = z.re;
+    let mut b = z.im;
+
+    /* Handle special cases. */
+    if a == 0.0 && b == 0.0 {
+        return Complex::new(0.0, b);
+    }
+    if b.is_infinite() {
+        return Complex::new(f64::INFINITY, b);
+    }
+    if a.is_nan() {
+        let t = (b - b) / (b - b); /* raise invalid if b is not a NaN */
+        return Complex::new(a, t); /* return NaN + NaN i */
+    }
+    if a.is_infinite() {
+        /*
+         * csqrt(inf + NaN i)  = inf +  NaN i
+         * csqrt(inf + y i)    = inf +  0 i
+         * csqrt(-inf + NaN i) = NaN +- inf i
+         * csqrt(-inf + y i)   = 0   +  inf i
+         */
+        if a.is_sign_negative() {
+            return Complex::new(fabs(b - b), copysign(a, b));
+        } else {
+            return Complex::new(a, copysign(b - b, b));
+        }
+    }
+    /*
+     * The remaining special case (b is NaN) is handled just fine by
+     * the normal code path below.
+     */
+
+    /* Scale to avoid overflow, or the loss of precision of subnormals. */
+    let scale = if fabs(a) >= THRESH || fabs(b) >= THRESH {
+        a *= 0.25;
+        b *= 0.25;
+        2.0
+    } else if fabs(a) < x1p_1020 && fabs(b) < x1p_1020 {
+        a *= x1p54;
+        b *= x1p54;
+        x1p_27
+    } else {
+        1.0
+    };
+
+    /* Algorithm 312, CACM vol 10, Oct 1967. */
+    let (re, im) = if a >= 0.0 {
+        let t = sqrt((a + hypot(a, b)) * 0.5);
+        (t, b / (2.0 * t))
+    } else {
+        let t = sqrt((-a + hypot(a, b)) * 0.5);
+        (fabs(b) / (2.0 * t), copysign(t, b))
+    };
+    Complex::new(re * scale, im * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::csqrt;
+    use crate::complex::Complex;
+    use core::f64;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        assert_eq!(csqrt(c(-0.0, 0.0)), c(0.0, 0.0));
+        assert_eq!(csqrt(c(0.0, -0.0)).im.to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(csqrt(c(nan, inf)), c(inf, inf));
+        assert_eq!(csqrt(c(1.0, -inf)), c(inf, -inf));
+        assert_eq!(csqrt(c(-inf, 1.0)), c(0.0, inf));
+        assert_eq!(csqrt(c(inf, -1.0)), c(inf, -0.0));
+        let z = csqrt(c(-inf, nan));
+        assert!(z.re.is_nan() && z.im.is_infinite());
+        let z = csqrt(c(inf, nan));
+        assert!(z.re == inf && z.im.is_nan());
+        let z = csqrt(c(nan, 1.0));
+        assert!(z.re.is_nan() && z.im.is_nan());
+    }
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(csqrt(c(-4.0, 0.0)), c(0.0, 2.0));
+        assert_eq!(csqrt(c(-4.0, -0.0)), c(0.0, -2.0));
+        assert_eq!(csqrt(c(3.0, 4.0)), c(2.0, 1.0));
+        assert_eq!(csqrt(c(0.0, 2.0)), c(1.0, 1.0));
+        assert_eq!(csqrt(c(f64::MAX, 0.0)).re, 1.3407807929942596e154);
+        assert_eq!(csqrt(c(f64::from_bits(4), 0.0)).re, 4.445517498970155e-162);
+    }
+}
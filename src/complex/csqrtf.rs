@@ -0,0 +1,23 @@
+use super::{csqrt, narrow, Complex};
+
+/// Square root (f32)
+///
+/// Computes the principal square root of `z`, with the branch cut along the
+/// negative real axis, by evaluating `csqrt` in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csqrtf(z: Complex<f32>) -> Complex<f32> {
+    narrow(csqrt(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::csqrtf;
+    use crate::complex::csqrt;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_csqrt() {
+        check_f32(csqrtf, csqrt);
+    }
+}
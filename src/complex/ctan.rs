@@ -0,0 +1,53 @@
+use super::{ctanh, Complex};
+
+/// Tangent (f64)
+///
+/// Computes the tangent of `z`, as `-i*ctanh(i*z)`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctan(z: Complex<f64>) -> Complex<f64> {
+    /* ctan(z) = -I * ctanh(I * z) = I * conj(ctanh(I * conj(z))) */
+    let w = ctanh(Complex::new(z.im, z.re));
+    Complex::new(w.im, w.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ctan;
+    use crate::complex::testutil::{check_cases, same, same_abs, Parity};
+    use crate::complex::Complex;
+    use core::f64;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn annex_g() {
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            // z, ctan(z)
+            (c(0.0, 0.0), c(0.0, 0.0)),
+            (c(inf, 1.0), c(nan, nan)),
+            (c(nan, 1.0), c(nan, nan)),
+            (c(1.0, inf), c(0.0, 1.0)),
+            (c(2.0, inf), c(-0.0, 1.0)),
+            (c(0.0, inf), c(0.0, 1.0)),
+            (c(0.0, nan), c(0.0, nan)),
+            (c(2.0, nan), c(nan, nan)),
+            (c(nan, nan), c(nan, nan)),
+        ];
+        check_cases(ctan, &cases, Parity::Odd, same);
+
+        /* the signs of the zero real parts are unspecified */
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let cases = [
+            (c(inf, inf), c(0.0, 1.0)),
+            (c(nan, inf), c(0.0, 1.0)),
+        ];
+        check_cases(ctan, &cases, Parity::Odd, same_abs);
+    }
+}
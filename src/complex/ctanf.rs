@@ -0,0 +1,22 @@
+use super::{ctan, narrow, Complex};
+
+/// Tangent (f32)
+///
+/// Computes the tangent of `z` by evaluating `ctan` in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctanf(z: Complex<f32>) -> Complex<f32> {
+    narrow(ctan(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ctanf;
+    use crate::complex::ctan;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_ctan() {
+        check_f32(ctanf, ctan);
+    }
+}
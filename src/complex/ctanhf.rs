@@ -0,0 +1,23 @@
+use super::{ctanh, narrow, Complex};
+
+/// Hyperbolic tangent (f32)
+///
+/// Computes the hyperbolic tangent of `z` by evaluating `ctanh` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctanhf(z: Complex<f32>) -> Complex<f32> {
+    narrow(ctanh(z.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ctanhf;
+    use crate::complex::ctanh;
+    use crate::complex::testutil::check_f32;
+
+    #[test]
+    fn agrees_with_ctanh() {
+        check_f32(ctanhf, ctanh);
+    }
+}
@@ -0,0 +1,16 @@
+use super::{cexp, clgamma, Complex};
+use crate::math::tgamma;
+
+/// Gamma function (f64)
+///
+/// Computes the gamma function of `z` as `cexp(clgamma(z))`, or with the real
+/// `tgamma` on the real axis. The relative error grows with the magnitude of
+/// `clgamma(z)`, to about `2e-14*max(1, |clgamma(z)|)`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctgamma(z: Complex<f64>) -> Complex<f64> {
+    if z.im == 0.0 {
+        return Complex::new(tgamma(z.re), z.im);
+    }
+    cexp(clgamma(z))
+}
@@ -0,0 +1,11 @@
+use super::{ctgamma, narrow, Complex};
+
+/// Gamma function (f32)
+///
+/// Computes the gamma function of `z` by evaluating `ctgamma` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctgammaf(z: Complex<f32>) -> Complex<f32> {
+    narrow(ctgamma(z.into()))
+}
@@ -0,0 +1,280 @@
+// faddeeva(z) return the Faddeeva function w(z) = exp(-z^2)*erfc(-i*z)
+//
+// Method:
+//      w(x + i*y) is evaluated for x, y >= 0 in one of four ways:
+//      1. Inside the ellipse (x/6.3)^2 + (y/4.4)^2 < 0.085264, the Taylor
+//         series of erfc(-i*z) around 0, multiplied by exp(-z^2).
+//      2. For y < 1 and x < 10, the sums of M.R. Zaghloul and A.N. Ali,
+//         "Algorithm 916: computing the Faddeyeva and Voigt functions",
+//         ACM TOMS 38 (2011), which keep Re w accurate near the real axis.
+//      3. Elsewhere the Laplace continued fraction
+//              w(z) = i/sqrt(pi) / (z - (1/2) / (z - 1 / (z - (3/2) / ...)))
+//         as in G.P.M. Poppe and C.M.J. Wijers, "More efficient computation
+//         of the complex error function", ACM TOMS 16 (1990). Inside the
+//         ellipse (x/6.3)^2 + (y/4.4)^2 < 1 it is combined with a Taylor
+//         series at a point above z to converge faster. The fraction misses
+//         the term exp(-z^2), which is added back for y < 1.
+//      4. For x + y > 1e8 only the first term of the continued fraction
+//         matters, and it is computed without overflow.
+//      The other quadrants follow from w(-conj(z)) = conj(w(z)) and
+//      w(-z) = 2*exp(-z^2) - w(z).
+//
+// Accuracy:
+//      For y >= 0 the error is below 1e-14*|w(z)|. Re w(z) is accurate to
+//      about 1e-14 relative to itself everywhere, and so is Im w(z) except
+//      close to the imaginary axis for y > 1, where its relative error
+//      can reach 2e-13. For y < 0 the reflection adds the conditioning of
+//      exp(-z^2), an error of about 2*|x*y|*eps relative to |w(z)|.
+//
+// Special cases:
+//      1. faddeeva(x + i*INF) is 0
+//      2. faddeeva(+-INF + i*y) is 0 for finite y
+//      3. faddeeva(x - i*INF) is INF + i*NAN, or INF for x = 0
+//      4. faddeeva(NAN + i*y) and faddeeva(x + i*NAN) are NAN + i*NAN
+//
+use super::Complex;
+use crate::math::{cos, erfc, exp, fabs, sin, sincos, sinh, sqrt};
+use core::f64::consts::FRAC_2_SQRT_PI;
+
+const FRAC_1_SQRT_PI: f64 = 0.5641895835477563; /* 1/sqrt(pi) */
+const A: f64 = 0.5183214804300859; /* pi/sqrt(53*ln(2)) */
+const C: f64 = 0.329973702884629; /* 2*A/pi */
+
+/* sin(t)/t, given s = sin(t) */
+#[inline]
+fn sinc(t: f64, s: f64) -> f64 {
+    if t == 0.0 {
+        1.0
+    } else {
+        s / t
+    }
+}
+
+/// Faddeeva function (f64)
+///
+/// Computes the scaled complementary error function of `-i*z`,
+/// `w(z) = exp(-z^2)*erfc(-i*z)`, for any complex `z`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn faddeeva(z: Complex<f64>) -> Complex<f64> {
+    let xi = z.re;
+    let yi = z.im;
+    let x = fabs(xi);
+    let y = fabs(yi);
+
+    if yi.is_infinite() && yi > 0.0 {
+        return Complex::new(0.0, 0.0);
+    }
+    if xi.is_nan() || yi.is_nan() {
+        return Complex::new(xi + yi, xi + yi);
+    }
+    if yi.is_infinite() {
+        /* the factor exp(-z^2) grows without bound, in a direction only
+         * known on the imaginary axis */
+        return Complex::new(-yi, if x == 0.0 { 0.0 } else { xi - xi });
+    }
+    if x.is_infinite() {
+        return Complex::new(0.0, 0.0);
+    }
+
+    let (mut u, mut v);
+    if x + y > 1e8 {
+        /* w(z) = i/(sqrt(pi)*z), scaled to avoid overflow */
+        if x > y {
+            let r = y / x;
+            let d = FRAC_1_SQRT_PI / (x + r * y);
+            u = r * d;
+            v = d;
+        } else {
+            let r = x / y;
+            let d = FRAC_1_SQRT_PI / (y + r * x);
+            u = d;
+            v = r * d;
+        }
+    } else if y < 1.0 && x < 10.0 {
+        /* the sums of Zaghloul and Ali */
+        let ex2 = exp(-x * x);
+        let mut s1 = 0.0;
+        let mut s23 = 0.0;
+        let mut s45 = 0.0;
+        let mut n = 1;
+        loop {
+            let an = A * n as f64;
+            let d = an * an + y * y;
+            let t = exp(-an * an - x * x) / d;
+            let tp = exp(-(an - x) * (an - x)) / d;
+            let tm = exp(-(an + x) * (an + x)) / d;
+            s1 += t;
+            s23 += tp + tm;
+            /* exp(2*a*n*x) - exp(-2*a*n*x) without cancellation */
+            s45 += an
+                * if x < 0.5 {
+                    2.0 * t * sinh(2.0 * an * x)
+                } else {
+                    tp - tm
+                };
+            if (an > x && tp < 1e-17 * s23) || n == 40 {
+                break;
+            }
+            n += 1;
+        }
+        let sxy = sin(x * y);
+        let (s2xy, c2xy) = sincos(2.0 * x * y);
+        let c1 = exp(y * y - x * x) * erfc(y) - C * y * s1;
+        let c2 = C * x * ex2;
+        u = c1 * c2xy + c2 * sxy * sinc(x * y, sxy) + 0.5 * C * y * s23;
+        v = c2 * sinc(2.0 * x * y, s2xy) - c1 * s2xy + 0.5 * C * s45;
+    } else {
+        let xs = x / 6.3;
+        let ys = y / 4.4;
+        let mut qrho = xs * xs + ys * ys;
+        let xquad = x * x - y * y;
+        let yquad = 2.0 * x * y;
+
+        if qrho < 0.085264 {
+            /* Taylor series of erfc(-i*z) */
+            qrho = (1.0 - 0.85 * ys) * sqrt(qrho);
+            let n = (6.0 + 72.0 * qrho + 0.5) as i32;
+            let mut j = (2 * n + 1) as f64;
+            let mut xsum = 1.0 / j;
+            let mut ysum = 0.0;
+            let mut i = n;
+            while i > 0 {
+                j -= 2.0;
+                let xaux = (xsum * xquad - ysum * yquad) / i as f64;
+                ysum = (xsum * yquad + ysum * xquad) / i as f64;
+                xsum = xaux + 1.0 / j;
+                i -= 1;
+            }
+            let u1 = 1.0 - FRAC_2_SQRT_PI * (xsum * y + ysum * x);
+            let v1 = FRAC_2_SQRT_PI * (xsum * x - ysum * y);
+            let e = exp(-xquad);
+            let u2 = e * cos(yquad);
+            let v2 = -e * sin(yquad);
+            u = u1 * u2 - v1 * v2;
+            v = u1 * v2 + v1 * u2;
+        } else {
+            /* continued fraction, with a Taylor series at x + i*(y + h) */
+            let h;
+            let nu;
+            let kapn;
+            if qrho > 1.0 {
+                h = 0.0;
+                kapn = 0;
+                qrho = sqrt(qrho);
+                nu = (3.0 + 1442.0 / (26.0 * qrho + 77.0)) as i32;
+            } else {
+                qrho = (1.0 - ys) * sqrt(1.0 - qrho);
+                h = 1.88 * qrho;
+                kapn = (7.0 + 34.0 * qrho + 0.5) as i32;
+                nu = (16.0 + 26.0 * qrho + 0.5) as i32;
+            }
+            let h2 = 2.0 * h;
+            let mut qlambda = 1.0;
+            for _ in 0..kapn {
+                qlambda *= h2;
+            }
+
+            let mut rx = 0.0;
+            let mut ry = 0.0;
+            let mut sx = 0.0;
+            let mut sy = 0.0;
+            let mut n = nu;
+            while n >= 0 {
+                let np1 = (n + 1) as f64;
+                let tx = y + h + np1 * rx;
+                let ty = x - np1 * ry;
+                let c = 0.5 / (tx * tx + ty * ty);
+                rx = c * tx;
+                ry = c * ty;
+                if h > 0.0 && n <= kapn {
+                    let tx = qlambda + sx;
+                    sx = rx * tx - ry * sy;
+                    sy = ry * tx + rx * sy;
+                    qlambda /= h2;
+                }
+                n -= 1;
+            }
+            if h == 0.0 {
+                u = FRAC_2_SQRT_PI * rx;
+                v = FRAC_2_SQRT_PI * ry;
+            } else {
+                u = FRAC_2_SQRT_PI * sx;
+                v = FRAC_2_SQRT_PI * sy;
+            }
+
+            if y == 0.0 {
+                u = exp(-x * x);
+            } else if h == 0.0 && y < 1.0 && x < 27.0 {
+                /* the continued fraction drops the exp(-z^2) term */
+                let e = exp(-xquad);
+                u += e * cos(yquad);
+                v -= e * sin(yquad);
+            }
+        }
+    }
+
+    if yi < 0.0 {
+        /* w(z) = 2*exp(-z^2) - w(-z) */
+        let t = (y - x) * (y + x);
+        if t > -750.0 {
+            let e = 2.0 * exp(t);
+            let yquad = 2.0 * x * y;
+            u = e * cos(yquad) - u;
+            v = -e * sin(yquad) - v;
+        } else {
+            u = -u;
+            v = -v;
+        }
+        if xi > 0.0 {
+            v = -v;
+        }
+    } else if xi < 0.0 {
+        v = -v;
+    }
+    Complex::new(u, v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::faddeeva;
+    use crate::complex::Complex;
+    use crate::math::hypot;
+    use core::f64;
+
+    fn check(x: f64, y: f64, re: f64, im: f64) {
+        let w = faddeeva(Complex::new(x, y));
+        let err = hypot(w.re - re, w.im - im);
+        assert!(err <= 1e-14 * hypot(re, im), "w({} + {}i) = {:?}", x, y, w);
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        assert_eq!(faddeeva(Complex::new(0.0, 0.0)), Complex::new(1.0, 0.0));
+        assert_eq!(faddeeva(Complex::new(1.0, inf)), Complex::new(0.0, 0.0));
+        assert_eq!(faddeeva(Complex::new(-inf, -1.0)), Complex::new(0.0, 0.0));
+        assert_eq!(faddeeva(Complex::new(0.0, -inf)), Complex::new(inf, 0.0));
+        let w = faddeeva(Complex::new(f64::NAN, 1.0));
+        assert!(w.re.is_nan() && w.im.is_nan());
+    }
+
+    #[test]
+    fn values() {
+        check(1.0, 1.0, 0.3047442052569126, 0.20821893820283163);
+        check(1e-3, 1e-3, 0.9988716223354113, 0.0011263806715998664);
+        check(100.0, 1.0, 5.6421779161441334e-05, 0.005641613670145867);
+        check(1e9, 1e9, 2.8209479177387817e-10, 2.8209479177387817e-10);
+        check(3.0, -2.0, -0.08133907992862736, 0.12108616246299844);
+        check(-2.0, 0.5, 0.10335882374136666, -0.28478588475009375);
+    }
+
+    #[test]
+    fn voigt() {
+        /* the real part stays accurate next to the real axis */
+        let w = faddeeva(Complex::new(5.0, 1e-8));
+        assert!(crate::math::fabs(w.re / 2.5469245600349706e-10 - 1.0) < 1e-14);
+        check(5.0, 1e-8, 2.5469245600349706e-10, 0.11524596183093659);
+        assert_eq!(faddeeva(Complex::new(2.0, 0.0)).re, crate::math::exp(-4.0));
+    }
+}
@@ -0,0 +1,11 @@
+use super::{faddeeva, narrow, Complex};
+
+/// Faddeeva function (f32)
+///
+/// Computes `w(z) = exp(-z^2)*erfc(-i*z)` by evaluating `faddeeva` in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn faddeevaf(z: Complex<f32>) -> Complex<f32> {
+    narrow(faddeeva(z.into()))
+}
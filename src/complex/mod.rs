@@ -0,0 +1,210 @@
+//! Complex functions
+//!
+//! The functions of C99's `<complex.h>`, with the special values of its
+//! Annex G. They take and return a [`Complex`], which has the same layout
+//! as C's `double _Complex` and `float _Complex`.
+//!
+//! Beyond C99, the module has the special functions `faddeeva`, `cerf`,
+//! `cerfc`, `cerfcx`, `clgamma` and `ctgamma`, each with its accuracy
+//! documented in its source.
+
+/// A complex number `re + i*im`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex<T> {
+    /// Real part
+    pub re: T,
+    /// Imaginary part
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Creates the complex number `re + i*im`.
+    #[inline]
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+}
+
+impl From<Complex<f32>> for Complex<f64> {
+    #[inline]
+    fn from(z: Complex<f32>) -> Self {
+        Complex::new(z.re as f64, z.im as f64)
+    }
+}
+
+// Public modules
+mod cabs;
+mod cabsf;
+mod cacos;
+mod cacosf;
+mod cacosh;
+mod cacoshf;
+mod carg;
+mod cargf;
+mod casin;
+mod casinf;
+mod casinh;
+mod casinhf;
+mod catan;
+mod catanf;
+mod catanh;
+mod catanhf;
+mod ccos;
+mod ccosf;
+mod ccosh;
+mod ccoshf;
+mod cerf;
+mod cerfc;
+mod cerfcf;
+mod cerfcx;
+mod cerfcxf;
+mod cerff;
+mod cexp;
+mod cexpf;
+mod cimag;
+mod cimagf;
+mod clgamma;
+mod clgammaf;
+mod clog;
+mod clogf;
+mod conj;
+mod conjf;
+mod cpow;
+mod cpowf;
+mod cproj;
+mod cprojf;
+mod creal;
+mod crealf;
+mod csin;
+mod csinf;
+mod csinh;
+mod csinhf;
+mod csqrt;
+mod csqrtf;
+mod ctan;
+mod ctanf;
+mod ctanh;
+mod ctanhf;
+mod ctgamma;
+mod ctgammaf;
+mod faddeeva;
+mod faddeevaf;
+
+// Use separated imports instead of {}-grouped imports for easier merging.
+pub use self::cabs::cabs;
+pub use self::cabsf::cabsf;
+pub use self::cacos::cacos;
+pub use self::cacosf::cacosf;
+pub use self::cacosh::cacosh;
+pub use self::cacoshf::cacoshf;
+pub use self::carg::carg;
+pub use self::cargf::cargf;
+pub use self::casin::casin;
+pub use self::casinf::casinf;
+pub use self::casinh::casinh;
+pub use self::casinhf::casinhf;
+pub use self::catan::catan;
+pub use self::catanf::catanf;
+pub use self::catanh::catanh;
+pub use self::catanhf::catanhf;
+pub use self::ccos::ccos;
+pub use self::ccosf::ccosf;
+pub use self::ccosh::ccosh;
+pub use self::ccoshf::ccoshf;
+pub use self::cerf::cerf;
+pub use self::cerfc::cerfc;
+pub use self::cerfcf::cerfcf;
+pub use self::cerfcx::cerfcx;
+pub use self::cerfcxf::cerfcxf;
+pub use self::cerff::cerff;
+pub use self::cexp::cexp;
+pub use self::cexpf::cexpf;
+pub use self::cimag::cimag;
+pub use self::cimagf::cimagf;
+pub use self::clgamma::clgamma;
+pub use self::clgammaf::clgammaf;
+pub use self::clog::clog;
+pub use self::clogf::clogf;
+pub use self::conj::conj;
+pub use self::conjf::conjf;
+pub use self::cpow::cpow;
+pub use self::cpowf::cpowf;
+pub use self::cproj::cproj;
+pub use self::cprojf::cprojf;
+pub use self::creal::creal;
+pub use self::crealf::crealf;
+pub use self::csin::csin;
+pub use self::csinf::csinf;
+pub use self::csinh::csinh;
+pub use self::csinhf::csinhf;
+pub use self::csqrt::csqrt;
+pub use self::csqrtf::csqrtf;
+pub use self::ctan::ctan;
+pub use self::ctanf::ctanf;
+pub use self::ctanh::ctanh;
+pub use self::ctanhf::ctanhf;
+pub use self::ctgamma::ctgamma;
+pub use self::ctgammaf::ctgammaf;
+pub use self::faddeeva::faddeeva;
+pub use self::faddeevaf::faddeevaf;
+
+// Private modules
+mod catrig;
+mod k_cexp;
+#[cfg(test)]
+mod testutil;
+
+// Private re-imports
+use self::catrig::{clog_for_large_values, do_hard_work};
+use self::k_cexp::ldexp_cexp;
+use crate::math::{exp, scalbn, sincos};
+use core::f64::consts::LOG2_E;
+
+#[inline]
+fn get_high_word(x: f64) -> u32 {
+    (x.to_bits() >> 32) as u32
+}
+
+#[inline]
+fn get_low_word(x: f64) -> u32 {
+    x.to_bits() as u32
+}
+
+/* the product a*b, without the special values of Annex G */
+#[inline]
+fn mul(a: Complex<f64>, b: Complex<f64>) -> Complex<f64> {
+    Complex::new(a.re * b.re - a.im * b.im, a.re * b.im + a.im * b.re)
+}
+
+const LN2_HI: f64 = 6.931471803691238e-01; /* 0x3fe62e42, 0xfee00000 */
+const LN2_LO: f64 = 1.9082149292705877e-10; /* 0x3dea39ef, 0x35793c76 */
+
+/* exp(x) as e*2^k, with e below 2 wherever exp(x) alone would overflow */
+#[inline]
+fn exp_split(x: f64) -> (f64, i32) {
+    if x > 709.0 {
+        /* past 1e9 any factor above 2^-1074 overflows, so x can be cut there */
+        let x = if x < 1e9 { x } else { 1e9 };
+        let k = (x * LOG2_E) as i32;
+        let kf = k as f64;
+        (exp(x - kf * LN2_HI - kf * LN2_LO), k)
+    } else {
+        (exp(x), 0)
+    }
+}
+
+/* exp(re + i*im)*w, which only overflows where the product does */
+#[inline]
+fn exp_mul(re: f64, im: f64, w: Complex<f64>) -> Complex<f64> {
+    let (e, k) = exp_split(re);
+    let (s, c) = sincos(im);
+    let t = mul(Complex::new(e * c, e * s), w);
+    Complex::new(scalbn(t.re, k), scalbn(t.im, k))
+}
+
+/* the f32 functions are evaluated in double precision */
+#[inline]
+fn narrow(z: Complex<f64>) -> Complex<f32> {
+    Complex::new(z.re as f32, z.im as f32)
+}
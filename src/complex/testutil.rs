@@ -0,0 +1,128 @@
+// Helpers shared by the unit tests of the complex functions
+
+use super::{conj, narrow, Complex};
+use core::f32;
+
+/* parts with the signed zeros, infinities and NAN of Annex G */
+const PARTS: [f32; 13] = [
+    0.0,
+    -0.0,
+    0.5,
+    -0.5,
+    1.0,
+    -1.0,
+    2.0,
+    -2.0,
+    1e-30,
+    -3e30,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+];
+
+/// Whether the parts of `a` and `b` have the same bits or are both NaN.
+pub(crate) fn same(a: Complex<f64>, b: Complex<f64>) -> bool {
+    let eq = |x: f64, y: f64| x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan());
+    eq(a.re, b.re) && eq(a.im, b.im)
+}
+
+/// Whether `a` and `b` are the `same` up to the signs of their parts,
+/// which Annex G leaves unspecified for some results.
+pub(crate) fn same_abs(a: Complex<f64>, b: Complex<f64>) -> bool {
+    let abs = |z: Complex<f64>| Complex::new(z.re.abs(), z.im.abs());
+    same(abs(a), abs(b))
+}
+
+/// The negation `-z`, with the signs of zeros and NaN flipped.
+fn neg(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(-z.re, -z.im)
+}
+
+/// The symmetry of a function under `z -> -z`, besides the symmetry
+/// `f(conj(z)) = conj(f(z))` that all the functions of Annex G have.
+pub(crate) enum Parity {
+    None,
+    Odd,
+    Even,
+}
+
+/// Checks that `f(z)` is `w` in the sense of `eq` for each `(z, w)` of
+/// `cases`, and likewise for the images of `z` under the symmetries of `f`.
+pub(crate) fn check_cases(
+    f: fn(Complex<f64>) -> Complex<f64>,
+    cases: &[(Complex<f64>, Complex<f64>)],
+    parity: Parity,
+    eq: fn(Complex<f64>, Complex<f64>) -> bool,
+) {
+    for &(z, w) in cases.iter() {
+        let (nz, nw) = match parity {
+            Parity::None => (z, w),
+            Parity::Odd => (neg(z), neg(w)),
+            Parity::Even => (neg(z), w),
+        };
+        for &(z, w) in [(z, w), (conj(z), conj(w)), (nz, nw), (conj(nz), conj(nw))].iter() {
+            let r = f(z);
+            assert!(eq(r, w), "{:?}: {:?} != {:?}", z, r, w);
+        }
+    }
+}
+
+/// Checks that the f32 function `f` is `g` rounded to single precision
+/// on every argument made of two of `PARTS`.
+pub(crate) fn check_f32(f: fn(Complex<f32>) -> Complex<f32>, g: fn(Complex<f64>) -> Complex<f64>) {
+    for &re in PARTS.iter() {
+        for &im in PARTS.iter() {
+            let z = Complex::new(re, im);
+            let w = f(z);
+            let r = narrow(g(z.into()));
+            assert!(same(w.into(), r.into()), "{:?}: {:?} != {:?}", z, w, r);
+        }
+    }
+}
+
+/// Checks that the real f32 function `f` is within an ulp of `g` rounded
+/// to single precision on every argument made of two of `PARTS`, as it
+/// calls the f32 functions of the real library such as `atan2f`.
+pub(crate) fn check_f32_real(f: fn(Complex<f32>) -> f32, g: fn(Complex<f64>) -> f64) {
+    for &re in PARTS.iter() {
+        for &im in PARTS.iter() {
+            let z = Complex::new(re, im);
+            let w = f(z);
+            let r = g(z.into()) as f32;
+            let d = w.to_bits() as i64 - r.to_bits() as i64;
+            assert!(
+                d.abs() <= 1 || (w.is_nan() && r.is_nan()),
+                "{:?}: {:?} != {:?}",
+                z,
+                w,
+                r
+            );
+        }
+    }
+}
+
+/// Checks that `f` is `g` rounded to single precision on every pair of
+/// arguments made of `PARTS`, for the functions of two arguments.
+pub(crate) fn check_f32_2(
+    f: fn(Complex<f32>, Complex<f32>) -> Complex<f32>,
+    g: fn(Complex<f64>, Complex<f64>) -> Complex<f64>,
+) {
+    for &re in PARTS.iter() {
+        for &im in PARTS.iter() {
+            let z = Complex::new(re, im);
+            for &cre in PARTS.iter() {
+                let c = Complex::new(cre, 0.5 - cre);
+                let w = f(z, c);
+                let r = narrow(g(z.into(), c.into()));
+                assert!(
+                    same(w.into(), r.into()),
+                    "{:?}, {:?}: {:?} != {:?}",
+                    z,
+                    c,
+                    w,
+                    r
+                );
+            }
+        }
+    }
+}
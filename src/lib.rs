@@ -6,7 +6,13 @@
     feature(core_intrinsics)
 )]
 
+/* the software fenv keeps its flags in thread-local storage */
+#[cfg(feature = "soft-fenv")]
+extern crate std;
+
+#[macro_use]
 mod math;
+pub mod complex;
 
 use core::{f32, f64};
 
@@ -33,7 +33,8 @@
  * Function needed: sqrt
  */
 
-use super::sqrt;
+use super::fenv::{inexact, invalid};
+use super::{fabs, sqrt};
 
 const PIO2_HI: f64 = 1.57079632679489655800e+00; /* 0x3FF921FB, 0x54442D18 */
 const PIO2_LO: f64 = 6.12323399573676603587e-17; /* 0x3C91A626, 0x33145C07 */
@@ -74,6 +75,8 @@ pub fn acos(x: f64) -> f64 {
 
     hx = (x.to_bits() >> 32) as u32;
     ix = hx & 0x7fffffff;
+    /* acos(x) is inexact for |x| <= 1, x != 1 */
+    inexact(x != 1.0 && fabs(x) <= 1.0);
     /* |x| >= 1 or nan */
     if ix >= 0x3ff00000 {
         let lx: u32 = x.to_bits() as u32;
@@ -85,7 +88,11 @@ pub fn acos(x: f64) -> f64 {
             }
             return 0.;
         }
-        return 0. / (x - x);
+        return if x.is_nan() {
+            0. / (x - x)
+        } else {
+            invalid(0. / (x - x))
+        };
     }
     /* |x| < 0.5 */
     if ix < 0x3fe00000 {
@@ -112,3 +119,25 @@ pub fn acos(x: f64) -> f64 {
     w = r(z) * s + c;
     2. * (df + w)
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::acos;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (2.0, FE_INVALID),
+            (1.0, 0),
+            (0.5, FE_INEXACT),
+            (-1.0, FE_INEXACT),
+            (f64::NEG_INFINITY, FE_INVALID),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(acos, x), e, "acos({:?})", x);
+        }
+    }
+}
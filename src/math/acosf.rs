@@ -13,6 +13,8 @@
  * ====================================================
  */
 
+use super::fabsf::fabsf;
+use super::fenv::{inexact, invalid};
 use super::sqrtf::sqrtf;
 
 const PIO2_HI: f32 = 1.5707962513e+00; /* 0x3fc90fda */
@@ -45,6 +47,8 @@ pub fn acosf(x: f32) -> f32 {
 
     let mut hx = x.to_bits();
     let ix = hx & 0x7fffffff;
+    /* acosf(x) is inexact for |x| <= 1, x != 1 */
+    inexact(x != 1.0 && fabsf(x) <= 1.0);
     /* |x| >= 1 or nan */
     if ix >= 0x3f800000 {
         if ix == 0x3f800000 {
@@ -53,7 +57,11 @@ pub fn acosf(x: f32) -> f32 {
             }
             return 0.;
         }
-        return 0. / (x - x);
+        return if x.is_nan() {
+            0. / (x - x)
+        } else {
+            invalid(0. / (x - x))
+        };
     }
     /* |x| < 0.5 */
     if ix < 0x3f000000 {
@@ -79,3 +87,25 @@ pub fn acosf(x: f32) -> f32 {
     w = r(z) * s + c;
     2. * (df + w)
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::acosf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (2.0, FE_INVALID),
+            (1.0, 0),
+            (0.5, FE_INEXACT),
+            (-1.0, FE_INEXACT),
+            (f32::NEG_INFINITY, FE_INVALID),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(acosf, x), e, "acosf({:?})", x);
+        }
+    }
+}
@@ -7,6 +7,7 @@ const LN2: f64 = 0.693147180559945309417232121458176568; /* 0x3fe62e42,  0xfefa3
 /// Calculates the inverse hyperbolic cosine of `x`.
 /// Is defined as `log(x + sqrt(x*x-1))`.
 /// `x` must be a number greater than or equal to 1.
+#[inline]
 pub fn acosh(x: f64) -> f64 {
     let u = x.to_bits();
     let e = ((u >> 52) as usize) & 0x7ff;
@@ -24,3 +25,25 @@ pub fn acosh(x: f64) -> f64 {
     /* |x| >= 0x1p26 or nan */
     return log(x) + LN2;
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::acosh;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (0.5, FE_INVALID),
+            (1.0, 0),
+            (2.0, FE_INEXACT),
+            (f64::INFINITY, 0),
+            (f64::NEG_INFINITY, FE_INVALID),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(acosh, x), e, "acosh({:?})", x);
+        }
+    }
+}
@@ -23,3 +23,25 @@ pub fn acoshf(x: f32) -> f32 {
     /* x >= 0x1p12 */
     return logf(x) + LN2;
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::acoshf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (0.5, FE_INVALID),
+            (1.0, 0),
+            (2.0, FE_INEXACT),
+            (f32::INFINITY, 0),
+            (f32::NEG_INFINITY, FE_INVALID),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(acoshf, x), e, "acoshf({:?})", x);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use core::f64::consts::PI;
+
+use super::acos;
+use super::double_double::{div, sqrt, two_sum};
+use super::k_cratan::k_cratanpi;
+
+/// Arccosine divided by pi (f64)
+///
+/// Computes `acos(x) / pi`, in the range [0, 1], in half-revolutions.
+/// It is evaluated as `2 atan(sqrt((1 - x)/(1 + x))) / pi` in
+/// double-double precision, so that the result is the double nearest to the
+/// exact value but in rare cases very close to halfway between two doubles,
+/// as at `acospi(0.5)`, which is the double nearest to 1/3.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospi(x: f64) -> f64 {
+    if !(x.abs() < 1.0) {
+        /* acos(1) = 0 and acos(-1) = PI exactly, and NaN for |x| > 1 or
+         * NaN */
+        return acos(x) / PI;
+    }
+    let q = div(two_sum(1.0, -x), two_sum(1.0, x));
+    let (y, _) = k_cratanpi(sqrt(q));
+    2.0 * y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::acospi;
+    use core::f64;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(acospi(1.0), 0.0);
+        assert_eq!(acospi(-1.0), 1.0);
+        assert_eq!(acospi(0.0), 0.5);
+        assert_eq!(acospi(-0.0), 0.5);
+        assert!(acospi(-1.5).is_nan());
+        assert!(acospi(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(acospi(0.5), 1.0 / 3.0);
+        assert_eq!(acospi(-0.5), 2.0 / 3.0);
+        assert_eq!(acospi(-f64::consts::FRAC_1_SQRT_2), 0.75);
+    }
+}
@@ -0,0 +1,13 @@
+use core::f64::consts::PI;
+
+use super::acos;
+
+/// Arccosine divided by pi (f32)
+///
+/// Computes `acos(x) / pi`, in the range [0, 1], in half-revolutions, by
+/// evaluating it in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospif(x: f32) -> f32 {
+    (acos(x as f64) / PI) as f32
+}
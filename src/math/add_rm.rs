@@ -0,0 +1,69 @@
+use super::soft_float::{self, Soft};
+use super::RoundingMode;
+
+/// Addition with explicit rounding (f64)
+///
+/// Returns `x + y`, correctly rounded in the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn add_rm(x: f64, y: f64, rm: RoundingMode) -> f64 {
+    soft_float::add_rm(Soft::from_f64(x), Soft::from_f64(y), rm).to_f64_rm(rm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{addf_rm, nextafter, sub_rm};
+    use super::add_rm;
+    use super::RoundingMode::*;
+    use core::f64;
+
+    /* values with nearby exponents, so that their sums are often
+     * inexact but not far from either term */
+    fn sample(i: u64) -> f64 {
+        let h = i.wrapping_mul(0x9e3779b97f4a7c15);
+        f64::from_bits(h & 0x800fffffffffffff | (0x3e8 + h % 47) << 52)
+    }
+
+    #[test]
+    fn directed() {
+        for i in 0..20000 {
+            let (a, b) = (sample(i), sample(i + 20000));
+            /* the rounding error of the sum, exactly */
+            let s = a + b;
+            let bb = s - a;
+            let err = (a - (s - bb)) + (b - bb);
+            let up = if err > 0.0 {
+                nextafter(s, 1.0 / 0.0)
+            } else {
+                s
+            };
+            let down = if err < 0.0 {
+                nextafter(s, -1.0 / 0.0)
+            } else {
+                s
+            };
+            assert_eq!(add_rm(a, b, TowardPositive), up, "{:e} + {:e}", a, b);
+            assert_eq!(add_rm(a, b, TowardNegative), down, "{:e} + {:e}", a, b);
+            assert_eq!(add_rm(a, b, TiesToEven), s);
+            assert_eq!(sub_rm(a, -b, TowardPositive), up);
+        }
+    }
+
+    #[test]
+    fn zeros_and_limits() {
+        assert_eq!(add_rm(1.0, -1.0, TowardNegative).to_bits(), 1 << 63);
+        assert_eq!(add_rm(1.0, -1.0, TowardZero).to_bits(), 0);
+        assert_eq!(sub_rm(0.0, 0.0, TowardNegative).to_bits(), 1 << 63);
+        assert_eq!(add_rm(0.0, 0.0, TowardNegative).to_bits(), 0);
+        assert_eq!(add_rm(-0.0, -0.0, TowardPositive).to_bits(), 1 << 63);
+        assert_eq!(add_rm(f64::MAX, f64::MAX, TowardZero), f64::MAX);
+        assert_eq!(add_rm(f64::MAX, f64::MAX, TowardNegative), f64::MAX);
+        assert_eq!(add_rm(f64::MAX, f64::MAX, TiesToAway), 1.0 / 0.0);
+        assert_eq!(sub_rm(-f64::MAX, f64::MAX, TowardPositive), -f64::MAX);
+        assert_eq!(sub_rm(-f64::MAX, f64::MAX, TowardNegative), -1.0 / 0.0);
+        assert_eq!(add_rm(1.0 / 0.0, 1.0, TowardZero), 1.0 / 0.0);
+        assert!(add_rm(1.0 / 0.0, -1.0 / 0.0, TowardZero).is_nan());
+        assert_eq!(addf_rm(1.0, 1e-10, TowardPositive), 1.0000001);
+        assert_eq!(addf_rm(-1.0, -1e-10, TowardPositive), -1.0);
+    }
+}
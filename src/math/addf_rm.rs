@@ -0,0 +1,11 @@
+use super::soft_float::{self, Soft};
+use super::RoundingMode;
+
+/// Addition with explicit rounding (f32)
+///
+/// Returns `x + y`, correctly rounded in the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn addf_rm(x: f32, y: f32, rm: RoundingMode) -> f32 {
+    soft_float::add_rm(Soft::from_f64(x as f64), Soft::from_f64(y as f64), rm).to_f32_rm(rm)
+}
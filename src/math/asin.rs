@@ -39,6 +39,7 @@
  *
  */
 
+use super::fenv::{inexact, invalid};
 use super::{fabs, get_high_word, get_low_word, sqrt, with_set_low_word};
 
 const PIO2_HI: f64 = 1.57079632679489655800e+00; /* 0x3FF921FB, 0x54442D18 */
@@ -78,6 +79,8 @@ pub fn asin(mut x: f64) -> f64 {
 
     hx = get_high_word(x);
     ix = hx & 0x7fffffff;
+    /* asin(x) is inexact for 0 < |x| <= 1 */
+    inexact(x != 0.0 && fabs(x) <= 1.0);
     /* |x| >= 1 or nan */
     if ix >= 0x3ff00000 {
         let lx: u32;
@@ -85,8 +88,10 @@ pub fn asin(mut x: f64) -> f64 {
         if ((ix - 0x3ff00000) | lx) == 0 {
             /* asin(1) = +-pi/2 with inexact */
             return x * PIO2_HI + f64::from_bits(0x3870000000000000);
-        } else {
+        } else if x.is_nan() {
             return 0.0 / (x - x);
+        } else {
+            return invalid(0.0 / (x - x));
         }
     }
     /* |x| < 0.5 */
@@ -119,3 +124,25 @@ pub fn asin(mut x: f64) -> f64 {
         x
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::asin;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (2.0, FE_INVALID),
+            (0.5, FE_INEXACT),
+            (0.0, 0),
+            (1.0, FE_INEXACT),
+            (f64::INFINITY, FE_INVALID),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(asin, x), e, "asin({:?})", x);
+        }
+    }
+}
@@ -14,6 +14,7 @@
  */
 
 use super::fabsf::fabsf;
+use super::fenv::{inexact, invalid};
 use super::sqrt::sqrt;
 
 const PIO2: f64 = 1.570796326794896558e+00;
@@ -43,6 +44,8 @@ pub fn asinf(mut x: f32) -> f32 {
 
     let hx = x.to_bits();
     let ix = hx & 0x7fffffff;
+    /* asinf(x) is inexact for 0 < |x| <= 1 */
+    inexact(x != 0.0 && fabsf(x) <= 1.0);
 
     if ix >= 0x3f800000 {
         /* |x| >= 1 */
@@ -50,7 +53,12 @@ pub fn asinf(mut x: f32) -> f32 {
             /* |x| == 1 */
             return ((x as f64) * PIO2 + x1p_120) as f32; /* asin(+-1) = +-pi/2 with inexact */
         }
-        return 0. / (x - x); /* asin(|x|>1) is NaN */
+        /* asin(|x|>1) is NaN */
+        return if x.is_nan() {
+            0. / (x - x)
+        } else {
+            invalid(0. / (x - x))
+        };
     }
 
     if ix < 0x3f000000 {
@@ -72,3 +80,25 @@ pub fn asinf(mut x: f32) -> f32 {
         x
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::asinf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (2.0, FE_INVALID),
+            (0.5, FE_INEXACT),
+            (0.0, 0),
+            (1.0, FE_INEXACT),
+            (f32::INFINITY, FE_INVALID),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(asinf, x), e, "asinf({:?})", x);
+        }
+    }
+}
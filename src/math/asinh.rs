@@ -7,6 +7,7 @@ const LN2: f64 = 0.693147180559945309417232121458176568; /* 0x3fe62e42,  0xfefa3
 ///
 /// Calculates the inverse hyperbolic sine of `x`.
 /// Is defined as `sgn(x)*log(|x|+sqrt(x*x+1))`.
+#[inline]
 pub fn asinh(mut x: f64) -> f64 {
     let mut u = x.to_bits();
     let e = ((u >> 52) as usize) & 0x7ff;
@@ -28,7 +29,9 @@ pub fn asinh(mut x: f64) -> f64 {
     } else {
         /* |x| < 0x1p-26, raise inexact if x != 0 */
         let x1p120 = f64::from_bits(0x4770000000000000);
-        force_eval!(x + x1p120);
+        if x != 0.0 {
+            force_eval!(x + x1p120);
+        }
     }
 
     if sign {
@@ -37,3 +40,19 @@ pub fn asinh(mut x: f64) -> f64 {
         x
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INEXACT;
+    use super::asinh;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [(1.0, FE_INEXACT), (0.0, 0), (f64::NEG_INFINITY, 0)];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(asinh, x), e, "asinh({:?})", x);
+        }
+    }
+}
@@ -27,7 +27,9 @@ pub fn asinhf(mut x: f32) -> f32 {
     } else {
         /* |x| < 0x1p-12, raise inexact if x!=0 */
         let x1p120 = f32::from_bits(0x7b800000);
-        force_eval!(x + x1p120);
+        if x != 0.0 {
+            force_eval!(x + x1p120);
+        }
     }
 
     if sign {
@@ -36,3 +38,19 @@ pub fn asinhf(mut x: f32) -> f32 {
         x
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INEXACT;
+    use super::asinhf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [(1.0, FE_INEXACT), (0.0, 0), (f32::NEG_INFINITY, 0)];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(asinhf, x), e, "asinhf({:?})", x);
+        }
+    }
+}
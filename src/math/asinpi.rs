@@ -0,0 +1,57 @@
+use core::f64::consts::PI;
+
+use super::asin;
+use super::double_double::{div, mul, sqrt, two_sum};
+use super::k_cratan::k_cratanpi;
+
+/// Arcsine divided by pi (f64)
+///
+/// Computes `asin(x) / pi`, in the range [-0.5, 0.5], in half-revolutions.
+/// It is evaluated as `atan(x / sqrt(1 - x^2)) / pi` in double-double
+/// precision, so that the result is the double nearest to the exact value
+/// but in rare cases very close to halfway between two doubles, as at
+/// `asinpi(0.5)`, which is the double nearest to 1/6.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpi(x: f64) -> f64 {
+    let a = x.abs();
+    if !(a < 1.0) {
+        /* asin(+-1) = +-PI/2 exactly, and NaN for |x| > 1 or NaN */
+        return asin(x) / PI;
+    }
+    if x == 0.0 {
+        return x;
+    }
+    /* 1 - a^2 = (1 - a)(1 + a), both factors exact as double-doubles */
+    let w = mul(two_sum(1.0, -a), two_sum(1.0, a));
+    let (y, _) = k_cratanpi(div((a, 0.0), sqrt(w)));
+    if x < 0.0 {
+        -y
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::asinpi;
+    use core::f64;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(asinpi(1.0), 0.5);
+        assert_eq!(asinpi(-1.0), -0.5);
+        assert_eq!(asinpi(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert!(asinpi(1.5).is_nan());
+        assert!(asinpi(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(asinpi(0.5), 1.0 / 6.0);
+        assert_eq!(asinpi(-0.5), -1.0 / 6.0);
+        /* asin(sqrt(2)/2) = pi/4, within the rounding of sqrt(2)/2 */
+        assert_eq!(asinpi(f64::consts::FRAC_1_SQRT_2), 0.25);
+        assert_eq!(asinpi(1e-300), 3.183098861837907e-301);
+    }
+}
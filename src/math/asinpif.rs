@@ -0,0 +1,13 @@
+use core::f64::consts::PI;
+
+use super::asin;
+
+/// Arcsine divided by pi (f32)
+///
+/// Computes `asin(x) / pi`, in the range [-0.5, 0.5], in half-revolutions, by
+/// evaluating it in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpif(x: f32) -> f32 {
+    (asin(x as f64) / PI) as f32
+}
@@ -30,6 +30,7 @@
  */
 
 use super::fabs;
+use super::fenv::inexact;
 use core::f64;
 
 const ATANHI: [f64; 4] = [
@@ -71,6 +72,8 @@ pub fn atan(x: f64) -> f64 {
     let mut ix = (x.to_bits() >> 32) as u32;
     let sign = ix >> 31;
     ix &= 0x7fff_ffff;
+    /* atan(x) is inexact for x != 0 */
+    inexact(x != 0.0 && !x.is_nan());
     if ix >= 0x4410_0000 {
         if x.is_nan() {
             return x;
@@ -84,7 +87,7 @@ pub fn atan(x: f64) -> f64 {
         /* |x| < 0.4375 */
         if ix < 0x3e40_0000 {
             /* |x| < 2^-27 */
-            if ix < 0x0010_0000 {
+            if ix < 0x0010_0000 && x != 0.0 {
                 /* raise underflow for subnormal x */
                 force_eval!(x as f32);
             }
@@ -183,3 +186,24 @@ mod tests {
         assert!(atan(f64::NAN).is_nan());
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INEXACT;
+    use super::atan;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f64::INFINITY, FE_INEXACT),
+            (f64::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(atan, x), e, "atan({:?})", x);
+        }
+    }
+}
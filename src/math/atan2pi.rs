@@ -0,0 +1,84 @@
+use core::f64::consts::PI;
+
+use super::atan2;
+use super::double_double::{add, div};
+use super::k_cratan::k_cratanpi;
+
+/// Arctangent of y/x divided by pi (f64)
+///
+/// Computes `atan2(y, x) / pi`, in the range [-1, 1], in half-revolutions.
+/// It is evaluated in double-double precision, so that the result is the
+/// double nearest to the exact value but in rare cases very close to halfway
+/// between two doubles. It is exact where the direction of `(x, y)` is along
+/// an axis or a diagonal, a multiple of 0.25 such as `atan2pi(1, -1) = 0.75`
+/// and `atan2pi(0, -1) = 1`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pi(y: f64, x: f64) -> f64 {
+    let x1p600 = f64::from_bits(0x6570000000000000); // 0x1p600 === 2 ^ 600
+    let x1p_600 = f64::from_bits(0x1a70000000000000); // 0x1p-600 === 2 ^ -600
+    let x1p900 = f64::from_bits(0x7830000000000000); // 0x1p900 === 2 ^ 900
+    let x1p_900 = f64::from_bits(0x07b0000000000000); // 0x1p-900 === 2 ^ -900
+
+    let (mut ax, mut ay) = (x.abs(), y.abs());
+    let q = ay / ax;
+    /* zeros, infinities and NaN, where atan2 is 0, PI/4, PI/2, PI or their
+     * negatives, exact multiples of PI, and quotients so large or small
+     * that atan2 is nearly one of those, where its error is about an ulp */
+    if !(q > x1p_900 && q < x1p900) {
+        return atan2(y, x) / PI;
+    }
+    /* scale to keep the double-double quotient away from the ends of the
+     * range, which the bounds on q allow for both */
+    if ax > x1p900 || ay > x1p900 {
+        ax *= x1p_600;
+        ay *= x1p_600;
+    } else if ax < x1p_900 || ay < x1p_900 {
+        ax *= x1p600;
+        ay *= x1p600;
+    }
+    let mut r = k_cratanpi(div((ay, 0.0), (ax, 0.0)));
+    if x < 0.0 {
+        r = add((1.0, 0.0), (-r.0, -r.1));
+    }
+    if y < 0.0 {
+        -r.0
+    } else {
+        r.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::atan2pi;
+    use core::f64;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(atan2pi(1.0, 1.0), 0.25);
+        assert_eq!(atan2pi(1.0, -1.0), 0.75);
+        assert_eq!(atan2pi(-1.0, -1.0), -0.75);
+        assert_eq!(atan2pi(1.0, 0.0), 0.5);
+        assert_eq!(atan2pi(0.0, -1.0), 1.0);
+        assert_eq!(atan2pi(-0.0, -1.0), -1.0);
+        assert_eq!(atan2pi(-0.0, 1.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(atan2pi(f64::INFINITY, f64::NEG_INFINITY), 0.75);
+        assert_eq!(atan2pi(f64::NEG_INFINITY, f64::INFINITY), -0.25);
+        assert_eq!(atan2pi(-1.0, 0.0), -0.5);
+        assert_eq!(atan2pi(-1.0, 1.0), -0.25);
+        assert_eq!(atan2pi(1e-300, 1e-300), 0.25);
+        assert_eq!(atan2pi(-1e300, -1e300), -0.75);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* atan2(sqrt(3), -1) = 2pi/3, within the rounding of sqrt(3) */
+        assert_eq!(atan2pi(1.7320508075688772, -1.0), 2.0 / 3.0);
+        assert_eq!(atan2pi(-1.0, -1.7320508075688772), -5.0 / 6.0);
+        assert_eq!(atan2pi(3.0, 4.0), 0.20483276469913345);
+        assert_eq!(atan2pi(-5.0, -12.0), -0.8743340836219976);
+        assert_eq!(atan2pi(1.0, 1e250), 3.183098861837907e-251);
+        assert_eq!(atan2pi(1e300, 1e-300), 0.5);
+        assert_eq!(atan2pi(1e-300, -1e300), 1.0);
+    }
+}
@@ -0,0 +1,13 @@
+use core::f64::consts::PI;
+
+use super::atan2;
+
+/// Arctangent of y/x divided by pi (f32)
+///
+/// Computes `atan2(y, x) / pi`, in the range [-1, 1], in half-revolutions,
+/// by evaluating it in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pif(y: f32, x: f32) -> f32 {
+    (atan2(y as f64, x as f64) / PI) as f32
+}
@@ -14,6 +14,7 @@
  */
 
 use super::fabsf;
+use super::fenv::inexact;
 
 const ATAN_HI: [f32; 4] = [
     4.6364760399e-01, /* atan(0.5)hi 0x3eed6338 */
@@ -51,6 +52,8 @@ pub fn atanf(mut x: f32) -> f32 {
     let mut ix = x.to_bits();
     let sign = (ix >> 31) != 0;
     ix &= 0x7fffffff;
+    /* atanf(x) is inexact for x != 0 */
+    inexact(x != 0.0 && !x.is_nan());
 
     if ix >= 0x4c800000 {
         /* if |x| >= 2**26 */
@@ -64,7 +67,7 @@ pub fn atanf(mut x: f32) -> f32 {
         /* |x| < 0.4375 */
         if ix < 0x39800000 {
             /* |x| < 2**-12 */
-            if ix < 0x00800000 {
+            if ix < 0x00800000 && x != 0.0 {
                 /* raise underflow for subnormal x */
                 force_eval!(x * x);
             }
@@ -111,3 +114,24 @@ pub fn atanf(mut x: f32) -> f32 {
         z
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INEXACT;
+    use super::atanf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f32::INFINITY, FE_INEXACT),
+            (f32::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(atanf, x), e, "atanf({:?})", x);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::fenv::{divzero, invalid};
 use super::log1p;
 
 /* atanh(x) = log((1+x)/(1-x))/2 = log1p(2x/(1-x))/2 ~= x + x^3/3 + o(x^5) */
@@ -5,6 +6,7 @@ use super::log1p;
 ///
 /// Calculates the inverse hyperbolic tangent of `x`.
 /// Is defined as `log((1+x)/(1-x))/2 = log1p(2x/(1-x))/2`.
+#[inline]
 pub fn atanh(x: f64) -> f64 {
     let u = x.to_bits();
     let e = ((u >> 52) as usize) & 0x7ff;
@@ -16,13 +18,18 @@ pub fn atanh(x: f64) -> f64 {
     if e < 0x3ff - 1 {
         if e < 0x3ff - 32 {
             /* handle underflow */
-            if e == 0 {
+            if e == 0 && y != 0.0 {
                 force_eval!(y as f32);
             }
         } else {
             /* |x| < 0.5, up to 1.7ulp error */
             y = 0.5 * log1p(2.0 * y + 2.0 * y * y / (1.0 - y));
         }
+    } else if y == 1.0 {
+        /* atanh(+-1) = +-inf */
+        y = divzero(y / 0.0);
+    } else if y.is_infinite() {
+        y = invalid(y - y);
     } else {
         /* avoid overflow */
         y = 0.5 * log1p(2.0 * (y / (1.0 - y)));
@@ -34,3 +41,24 @@ pub fn atanh(x: f64) -> f64 {
         y
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::atanh;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_DIVBYZERO),
+            (-1.0, FE_DIVBYZERO),
+            (2.0, FE_INVALID),
+            (0.5, FE_INEXACT),
+            (0.0, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(atanh, x), e, "atanh({:?})", x);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::fenv::{divzero, invalid};
 use super::log1pf;
 
 /* atanh(x) = log((1+x)/(1-x))/2 = log1p(2x/(1-x))/2 ~= x + x^3/3 + o(x^5) */
@@ -16,13 +17,18 @@ pub fn atanhf(mut x: f32) -> f32 {
     if u < 0x3f800000 - (1 << 23) {
         if u < 0x3f800000 - (32 << 23) {
             /* handle underflow */
-            if u < (1 << 23) {
+            if u < (1 << 23) && u != 0 {
                 force_eval!((x * x) as f32);
             }
         } else {
             /* |x| < 0.5, up to 1.7ulp error */
             x = 0.5 * log1pf(2.0 * x + 2.0 * x * x / (1.0 - x));
         }
+    } else if x == 1.0 {
+        /* atanh(+-1) = +-inf */
+        x = divzero(x / 0.0);
+    } else if x.is_infinite() {
+        x = invalid(x - x);
     } else {
         /* avoid overflow */
         x = 0.5 * log1pf(2.0 * (x / (1.0 - x)));
@@ -34,3 +40,24 @@ pub fn atanhf(mut x: f32) -> f32 {
         x
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::atanhf;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_DIVBYZERO),
+            (-1.0, FE_DIVBYZERO),
+            (2.0, FE_INVALID),
+            (0.5, FE_INEXACT),
+            (0.0, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(atanhf, x), e, "atanhf({:?})", x);
+        }
+    }
+}
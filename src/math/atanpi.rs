@@ -0,0 +1,51 @@
+use super::k_cratan::k_cratanpi;
+
+/// Arctangent divided by pi (f64)
+///
+/// Computes `atan(x) / pi`, in the range [-0.5, 0.5], in half-revolutions.
+/// It is evaluated in double-double precision, so that the result is the
+/// double nearest to the exact value but in rare cases very close to halfway
+/// between two doubles. It is exact for `x` of 0, ±1 and ±infinity, where it
+/// is 0, ±0.25 and ±0.5.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanpi(x: f64) -> f64 {
+    let a = x.abs();
+    if x.is_nan() || x == 0.0 {
+        return x + x;
+    }
+    let y = if a == 1.0 / 0.0 {
+        0.5
+    } else {
+        k_cratanpi((a, 0.0)).0
+    };
+    if x < 0.0 {
+        -y
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::atanpi;
+    use core::f64;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(atanpi(1.0), 0.25);
+        assert_eq!(atanpi(-1.0), -0.25);
+        assert_eq!(atanpi(f64::INFINITY), 0.5);
+        assert_eq!(atanpi(f64::NEG_INFINITY), -0.5);
+        assert_eq!(atanpi(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert!(atanpi(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* atan(sqrt(3)) = pi/3, within the rounding of sqrt(3) */
+        assert_eq!(atanpi(1.7320508075688772), 1.0 / 3.0);
+        assert_eq!(atanpi(1e300), 0.5);
+        assert_eq!(atanpi(1e-300), 3.183098861837907e-301);
+    }
+}
@@ -0,0 +1,21 @@
+use super::beta::{beta, lbeta};
+
+/// Beta function (f32)
+///
+/// Computes `gamma(a)*gamma(b)/gamma(a + b)` for `a >= 0` and `b >= 0`, by
+/// evaluating [`beta`](fn.beta.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn betaf(a: f32, b: f32) -> f32 {
+    beta(a as f64, b as f64) as f32
+}
+
+/// Logarithm of the beta function (f32)
+///
+/// Computes `log(beta(a, b))` for `a >= 0` and `b >= 0`, by evaluating
+/// [`lbeta`](fn.lbeta.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lbetaf(a: f32, b: f32) -> f32 {
+    lbeta(a as f64, b as f64) as f32
+}
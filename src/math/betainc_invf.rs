@@ -0,0 +1,12 @@
+use super::betainc_inv::betainc_inv;
+
+/// Inverse of the regularized incomplete beta function (f32)
+///
+/// Returns the `x` in `[0, 1]` with `betaincf(a, b, x) == p`, for `a > 0`,
+/// `b > 0` and `0 <= p <= 1`, by evaluating
+/// [`betainc_inv`](fn.betainc_inv.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn betainc_invf(a: f32, b: f32, p: f32) -> f32 {
+    betainc_inv(a as f64, b as f64, p as f64) as f32
+}
@@ -0,0 +1,12 @@
+use super::betainc::betainc;
+
+/// Regularized incomplete beta function (f32)
+///
+/// Computes `I_x(a, b)`, the integral of `t^(a-1)*(1-t)^(b-1)` from 0 to
+/// `x` divided by `beta(a, b)`, for `a >= 0`, `b >= 0` and `0 <= x <= 1`,
+/// by evaluating [`betainc`](fn.betainc.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn betaincf(a: f32, b: f32, x: f32) -> f32 {
+    betainc(a as f64, b as f64, x as f64) as f32
+}
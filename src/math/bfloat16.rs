@@ -0,0 +1,325 @@
+use super::soft_float::Soft;
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Brain floating-point (bfloat16) number
+///
+/// The upper half of an `f32`: it has the same exponent range, but only
+/// 8 significant bits. It stores the bit pattern of the number; the
+/// arithmetic operators are evaluated in `f64` and rounded once to
+/// nearest, which is correctly rounded since `f64` has more than 2*8+2
+/// bits.
+///
+/// The `bf16` math functions evaluate the `f64` function and round its
+/// result once. An exhaustive comparison with a multiple-precision
+/// reference shows that this is correctly rounded for every `bf16` input.
+/// They are named with the `bf16` suffix, as `expbf16`, like the `f16`
+/// and `f128` functions.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct bf16(u16);
+
+impl bf16 {
+    /// Positive zero.
+    pub const ZERO: bf16 = bf16(0);
+    /// The value 1.
+    pub const ONE: bf16 = bf16(0x3f80);
+    /// Quiet NaN.
+    pub const NAN: bf16 = bf16(0x7fc0);
+    /// Positive infinity.
+    pub const INFINITY: bf16 = bf16(0x7f80);
+    /// Negative infinity.
+    pub const NEG_INFINITY: bf16 = bf16(0xff80);
+    /// Largest finite value.
+    pub const MAX: bf16 = bf16(0x7f7f);
+    /// Smallest positive normal value.
+    pub const MIN_POSITIVE: bf16 = bf16(0x0080);
+    /// Difference between 1 and the next larger value.
+    pub const EPSILON: bf16 = bf16(0x3c00);
+
+    #[inline]
+    pub const fn from_bits(v: u16) -> bf16 {
+        bf16(v)
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts from `f64`, rounding to nearest.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn from_f64(x: f64) -> bf16 {
+        match Soft::from_f64(x).round(8, -126, 127) {
+            Soft::Nan => bf16::NAN,
+            Soft::Inf(s) => bf16((s as u16) << 15 | bf16::INFINITY.0),
+            Soft::Zero(s) => bf16((s as u16) << 15),
+            Soft::Num(s, e, m) => {
+                let ix = if e < -126 {
+                    (m >> (-6 - e)) as u16
+                } else {
+                    ((e + 127) as u16) << 7 | (m >> 120) as u16 & 0x7f
+                };
+                bf16((s as u16) << 15 | ix)
+            }
+        }
+    }
+
+    /// Converts from `f32`, rounding to nearest.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn from_f32(x: f32) -> bf16 {
+        let ix = x.to_bits();
+        if ix & 0x7fffffff > 0x7f800000 {
+            return bf16::NAN;
+        }
+        /* round the low half away, ties to even; a carry into the
+         * exponent gives the right result, up to infinity */
+        let ix = ix + 0x7fff + (ix >> 16 & 1);
+        bf16((ix >> 16) as u16)
+    }
+
+    /// Converts to `f32`, which is exact.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+
+    /// Converts to `f64`, which is exact.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7f80
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_infinite(self) -> bool {
+        self.0 & 0x7fff == 0x7f80
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_finite(self) -> bool {
+        self.0 & 0x7fff < 0x7f80
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_sign_negative(self) -> bool {
+        self.0 >> 15 != 0
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+}
+
+impl From<bf16> for f32 {
+    #[inline]
+    fn from(x: bf16) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl From<bf16> for f64 {
+    #[inline]
+    fn from(x: bf16) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl From<i8> for bf16 {
+    #[inline]
+    fn from(x: i8) -> bf16 {
+        bf16::from_f64(x as f64)
+    }
+}
+
+impl From<u8> for bf16 {
+    #[inline]
+    fn from(x: u8) -> bf16 {
+        bf16::from_f64(x as f64)
+    }
+}
+
+impl Add for bf16 {
+    type Output = bf16;
+
+    #[inline]
+    fn add(self, rhs: bf16) -> bf16 {
+        bf16::from_f64(self.to_f64() + rhs.to_f64())
+    }
+}
+
+impl Sub for bf16 {
+    type Output = bf16;
+
+    #[inline]
+    fn sub(self, rhs: bf16) -> bf16 {
+        bf16::from_f64(self.to_f64() - rhs.to_f64())
+    }
+}
+
+impl Mul for bf16 {
+    type Output = bf16;
+
+    #[inline]
+    fn mul(self, rhs: bf16) -> bf16 {
+        bf16::from_f64(self.to_f64() * rhs.to_f64())
+    }
+}
+
+impl Div for bf16 {
+    type Output = bf16;
+
+    #[inline]
+    fn div(self, rhs: bf16) -> bf16 {
+        bf16::from_f64(self.to_f64() / rhs.to_f64())
+    }
+}
+
+impl Neg for bf16 {
+    type Output = bf16;
+
+    #[inline]
+    fn neg(self) -> bf16 {
+        bf16(self.0 ^ 1 << 15)
+    }
+}
+
+impl PartialEq for bf16 {
+    #[inline]
+    fn eq(&self, other: &bf16) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for bf16 {
+    #[inline]
+    fn partial_cmp(&self, other: &bf16) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+/// Checks the result `r` of a `bf16` function at `x` against the result `y`
+/// of the corresponding `f32` function, which is within `ulps` ulp of the
+/// exact value. `r` must be `y` rounded to `bf16`, or, when the exact value
+/// may lie on the other side of a `bf16` rounding boundary, its neighbour.
+#[cfg(test)]
+pub(crate) fn check_f32(x: bf16, r: bf16, y: f32, ulps: u32) {
+    if y.is_nan() {
+        assert!(r.is_nan(), "{:?} -> {:?}, expected NaN", x, r);
+        return;
+    }
+    let iy = y.to_bits();
+    if iy & 0x7fffffff == 0 || y.is_infinite() {
+        assert_eq!(r.to_bits(), bf16::from_f32(y).to_bits(), "{:?}", x);
+        return;
+    }
+    /* bf16 bit patterns of one sign are ordered by magnitude */
+    let sign = iy & 0x80000000;
+    let a = iy & 0x7fffffff;
+    let lo = bf16::from_f32(f32::from_bits(sign | a.saturating_sub(ulps)));
+    let hi = bf16::from_f32(f32::from_bits(sign | (a + ulps)));
+    assert!(
+        lo.to_bits() <= r.to_bits() && r.to_bits() <= hi.to_bits(),
+        "{:?} -> {:?}, expected {:?}",
+        x,
+        r,
+        y
+    );
+}
+
+/// Checks that `r`, the result of a `bf16` function at `x`, is `y` rounded
+/// to nearest, where `y` is the function's correctly rounded `f64` (or
+/// `f32`) value. The exact value rounds the same way unless `y` lies
+/// exactly halfway between two `bf16` values, which is asserted not to
+/// happen.
+#[cfg(test)]
+pub(crate) fn check(x: bf16, r: bf16, y: f64) {
+    if y.is_nan() {
+        assert!(r.is_nan(), "{:?} -> {:?}, expected NaN", x, r);
+        return;
+    }
+    let a = bf16::from_f64(y.abs());
+    if y.is_finite() && a.to_f64() != y.abs() {
+        /* the bf16 on the other side of |y|, with 2^128 standing in for
+         * infinity */
+        let b = if a.to_f64() > y.abs() {
+            a.0 - 1
+        } else {
+            a.0 + 1
+        };
+        let value = |i: u16| {
+            if i == 0x7f80 {
+                f64::from_bits(0x47f0000000000000)
+            } else {
+                bf16(i).to_f64()
+            }
+        };
+        assert!(
+            value(a.0) + value(b) != 2.0 * y.abs(),
+            "{:?}: {:e} is halfway",
+            x,
+            y
+        );
+    }
+    assert_eq!(
+        r.to_bits(),
+        bf16::from_f64(y).to_bits(),
+        "{:?} -> {:?}, expected {:e}",
+        x,
+        r,
+        y
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bf16;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(bf16::from_f32(1.0).to_bits(), bf16::ONE.to_bits());
+        assert_eq!(bf16::from_f32(0.1).to_bits(), 0x3dcd);
+        assert_eq!(bf16::from_f64(0.1).to_bits(), 0x3dcd);
+        /* halfway between 1 and 1 + 2^-7 rounds to even, just above it up */
+        assert_eq!(bf16::from_f32(1.00390625).to_bits(), 0x3f80);
+        assert_eq!(bf16::from_f64(1.0039062500000002).to_bits(), 0x3f81);
+        assert_eq!(bf16::from_f32(3.4e38), bf16::INFINITY);
+        assert_eq!(bf16::from_f64(1e39), bf16::INFINITY);
+        assert_eq!(bf16::from_f64(-1e-50).to_bits(), 0x8000);
+        assert_eq!(bf16::from_f64(1e-40).to_bits(), 0x0001);
+        assert!(bf16::from_f32(0.0 / 0.0).is_nan());
+        assert_eq!(bf16::MAX.to_f32(), 3.3895314e38);
+        assert_eq!(bf16::EPSILON.to_f32(), 0.0078125);
+    }
+
+    #[test]
+    fn from_f64_matches_from_f32() {
+        for i in 0..=0xffff_u32 {
+            /* sample the f32 values between consecutive bf16 values */
+            for &lo in [0, 1, 0x7fff, 0x8000, 0x8001, 0xffff].iter() {
+                let x = f32::from_bits(i << 16 | lo);
+                assert_eq!(
+                    bf16::from_f64(x as f64).to_bits(),
+                    bf16::from_f32(x).to_bits(),
+                    "{:?}",
+                    x
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+/// Canonical encoding of a value (f64)
+///
+/// Returns `x` in its canonical encoding. Every binary floating-point
+/// encoding is canonical, so this only converts a signaling NaN into a quiet
+/// NaN with the same payload, raising the invalid exception.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn canonicalize(x: f64) -> f64 {
+    let ui = x.to_bits();
+    if x.is_nan() && ui & 0x0008_0000_0000_0000 == 0 {
+        // raise invalid
+        force_eval!(x + x);
+        return f64::from_bits(ui | 0x0008_0000_0000_0000);
+    }
+    x
+}
@@ -0,0 +1,10 @@
+use super::{ceil, f16};
+
+/// Ceil (f16)
+///
+/// Finds the nearest integer greater than or equal to `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ceilf16(x: f16) -> f16 {
+    f16::from_f64(ceil(x.to_f64()))
+}
@@ -0,0 +1,10 @@
+use super::{ceilf128, f128, f80};
+
+/// Ceil (f80)
+///
+/// Finds the nearest integer greater than or equal to `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ceilf80(x: f80) -> f80 {
+    f80::from_f128(ceilf128(f128::from(x)))
+}
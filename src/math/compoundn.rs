@@ -0,0 +1,128 @@
+// compoundn(x,n) return (1+x)**n for an integer n
+//
+// Method:
+//      Let s + e = 1 + x exactly, then (1+x)^n = s^n * (1 + e/s)^n, where
+//      s^n is pown(s, n) and (1 + e/s)^n ~= exp(n*e/s) is a tiny
+//      correction, as |e/s| <= 2^-53. For |n| > 2^53, where n*e/s need
+//      not be small, (1+x)^n is exp(n*log1p(x)) in double-double, with
+//      log1p(x) = log(s) + log1p(e/s).
+//
+// Special cases:
+//      1.  compoundn(x, 0) is 1 for x >= -1 or NAN
+//      2.  compoundn(x < -1, n) is NAN, raise invalid
+//      3.  compoundn(NAN, n) is NAN for n != 0
+//      4.  compoundn(-1, n > 0) is +0
+//      5.  compoundn(-1, n < 0) is +INF, raise divbyzero
+//      6.  compoundn(+INF, n > 0) is +INF, compoundn(+INF, n < 0) is +0
+//
+use super::double_double::{add, add_f64, div};
+use super::fenv::invalid;
+use super::k_crlog::k_crlog;
+use super::pown::{exp_mul_n, split};
+use super::{exp, pown};
+
+/// Compound interest (f64)
+///
+/// Computes `(1+x)` raised to the integer power `n`, the growth of a unit
+/// amount over `n` periods at the rate `x`. The result is accurate even for
+/// rates `x` near zero, where `1+x` itself would round.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn compoundn(x: f64, n: i64) -> f64 {
+    if x < -1.0 {
+        return invalid(f64::NAN);
+    }
+    if n == 0 {
+        return 1.0;
+    }
+
+    /* two-sum: s + e = 1 + x exactly */
+    let s = 1.0 + x;
+    let t = s - x;
+    let e = (1.0 - t) + (x - (s - t));
+    if !(-(1 << 53)..=1 << 53).contains(&n) && s.is_finite() && e != 0.0 {
+        /* n*log(1+x) = n*(log(s) + c - c^2/2) with c = e/s, whose next
+         * term n*c^3/3 is below 2^-95, in double-double, as n*c itself
+         * may be large */
+        let c = div((e, 0.0), (s, 0.0));
+        let l = add_f64(add(k_crlog(s), c), -0.5 * c.0 * c.0);
+        if let Some(r) = exp_mul_n(l, n) {
+            return r;
+        }
+    }
+
+    let y = pown(s, n);
+    if !y.is_finite() || y == 0.0 || e == 0.0 {
+        return y;
+    }
+    /* n*(e/s) without the rounding of n */
+    let (hi, lo) = split(n);
+    let c = e / s;
+    y * exp(hi * c + lo * c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compoundn;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(compoundn(f64::NAN, 0), 1.0);
+        assert_eq!(compoundn(-1.0, 0), 1.0);
+        assert!(compoundn(-1.5, 0).is_nan());
+        assert!(compoundn(f64::NEG_INFINITY, 2).is_nan());
+        assert!(compoundn(f64::NAN, 2).is_nan());
+        assert_eq!(compoundn(-1.0, 3).to_bits(), 0);
+        assert_eq!(compoundn(-1.0, -3), f64::INFINITY);
+        assert_eq!(compoundn(f64::INFINITY, 3), f64::INFINITY);
+        assert_eq!(compoundn(f64::INFINITY, -3).to_bits(), 0);
+    }
+
+    #[test]
+    fn small_rates() {
+        assert_eq!(compoundn(0.5, 2), 2.25);
+        assert_eq!(compoundn(1.0, 10), 1024.0);
+        /* 1 + x rounds to 1, but (1+x)^n ~= 1 + n*x */
+        assert_eq!(compoundn(1e-17, 1000000), 1.00000000001);
+        assert_eq!(compoundn(-1e-17, 1000000), 0.99999999999);
+    }
+
+    #[test]
+    fn large_periods() {
+        /* 2^60 + 1 and 2^61 + 255, which are not doubles */
+        let cases = [
+            (1e-17, 1152921504606846977, 101642.29796757424),
+            (3e-16, 2305843009213694207, 2.657470859769973e+300),
+        ];
+        for &(x, n, y) in cases.iter() {
+            let r = compoundn(x, n);
+            assert!((r - y).abs() <= 1e-15 * y, "compoundn({}, {})", x, n);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INVALID};
+    use super::compoundn;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (-1.5, 2, FE_INVALID),
+            (-1.5, 0, FE_INVALID),
+            (f64::NEG_INFINITY, 3, FE_INVALID),
+            (-1.0, -3, FE_DIVBYZERO),
+            (-1.0, 3, 0),
+            (f64::NAN, 2, 0),
+            (1.0, 10, 0),
+        ];
+        for &(x, n, e) in cases.iter() {
+            let r = raised(|(x, n)| compoundn(x, n), (x, n));
+            assert_eq!(r, e, "compoundn({}, {})", x, n);
+        }
+    }
+}
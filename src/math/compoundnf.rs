@@ -0,0 +1,36 @@
+use super::compoundn;
+
+/// Compound interest (f32)
+///
+/// Computes `(1+x)` raised to the integer power `n`, by evaluating
+/// `compoundn` in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn compoundnf(x: f32, n: i64) -> f32 {
+    compoundn(x as f64, n) as f32
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INVALID};
+    use super::compoundnf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (-1.5, 2, FE_INVALID),
+            (-1.5, 0, FE_INVALID),
+            (f32::NEG_INFINITY, 3, FE_INVALID),
+            (-1.0, -3, FE_DIVBYZERO),
+            (-1.0, 3, 0),
+            (f32::NAN, 2, 0),
+            (1.0, 10, 0),
+        ];
+        for &(x, n, e) in cases.iter() {
+            let r = raised(|(x, n)| compoundnf(x, n), (x, n));
+            assert_eq!(r, e, "compoundnf({}, {})", x, n);
+        }
+    }
+}
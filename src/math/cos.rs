@@ -9,6 +9,7 @@
 // is preserved.
 // ====================================================
 
+use super::fenv::{inexact, invalid};
 use super::{k_cos, k_sin, rem_pio2};
 
 // cos(x)
@@ -45,6 +46,8 @@ use super::{k_cos, k_sin, rem_pio2};
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn cos(x: f64) -> f64 {
     let ix = (f64::to_bits(x) >> 32) as u32 & 0x7fffffff;
+    /* cos(x) is inexact for finite x != 0 */
+    inexact(x != 0.0 && ix < 0x7ff00000);
 
     /* |x| ~< pi/4 */
     if ix <= 0x3fe921fb {
@@ -60,7 +63,7 @@ pub fn cos(x: f64) -> f64 {
 
     /* cos(Inf or NaN) is NaN */
     if ix >= 0x7ff00000 {
-        return x - x;
+        return if x.is_nan() { x - x } else { invalid(x - x) };
     }
 
     /* argument reduction needed */
@@ -72,3 +75,24 @@ pub fn cos(x: f64) -> f64 {
         _ => k_sin(y0, y1, 1),
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::cos;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (f64::INFINITY, FE_INVALID),
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f64::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(cos, x), e, "cos({:?})", x);
+        }
+    }
+}
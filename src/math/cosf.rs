@@ -14,6 +14,7 @@
  * ====================================================
  */
 
+use super::fenv::{inexact, invalid};
 use super::{k_cosf, k_sinf, rem_pio2f};
 
 use core::f64::consts::FRAC_PI_2;
@@ -34,13 +35,17 @@ pub fn cosf(x: f32) -> f32 {
     let mut ix = x.to_bits();
     let sign = (ix >> 31) != 0;
     ix &= 0x7fffffff;
+    /* cos(x) is inexact for finite x != 0 */
+    inexact(x != 0.0 && ix < 0x7f800000);
 
     if ix <= 0x3f490fda {
         /* |x| ~<= pi/4 */
         if ix < 0x39800000 {
             /* |x| < 2**-12 */
             /* raise inexact if x != 0 */
-            force_eval!(x + x1p120);
+            if x != 0.0 {
+                force_eval!(x + x1p120);
+            }
             return 1.;
         }
         return k_cosf(x64);
@@ -70,7 +75,7 @@ pub fn cosf(x: f32) -> f32 {
 
     /* cos(Inf or NaN) is NaN */
     if ix >= 0x7f800000 {
-        return x - x;
+        return if x.is_nan() { x - x } else { invalid(x - x) };
     }
 
     /* general argument reduction needed */
@@ -82,3 +87,24 @@ pub fn cosf(x: f32) -> f32 {
         _ => k_sinf(y),
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::cosf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (f32::INFINITY, FE_INVALID),
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f32::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(cosf, x), e, "cosf({:?})", x);
+        }
+    }
+}
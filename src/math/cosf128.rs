@@ -0,0 +1,31 @@
+use super::{f128, k_cosf128, k_sinf128, rem_pio2f128};
+
+const PIO4: f128 = f128::from_bits(0x3ffe921fb54442d18469898cc51701b8); /* 0.785398163397448309615660845819875699 */
+
+/// Cosine (f128)
+///
+/// Computes the cosine of `x` (in radians).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosf128(x: f128) -> f128 {
+    let ix = x.to_bits() & !(1 << 127);
+    let e = (ix >> 112) as i32;
+
+    if e == 0x7fff {
+        return x - x;
+    }
+    if f128::from_bits(ix) < PIO4 {
+        if e < 0x3fff - 113 {
+            /* cos(x) rounds to 1 */
+            return f128::ONE + x;
+        }
+        return k_cosf128(x, f128::ZERO);
+    }
+    let (n, hi, lo) = rem_pio2f128(x);
+    match n & 3 {
+        0 => k_cosf128(hi, lo),
+        1 => -k_sinf128(hi, lo, 1),
+        2 => -k_cosf128(hi, lo),
+        _ => k_sinf128(hi, lo, 1),
+    }
+}
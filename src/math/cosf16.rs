@@ -0,0 +1,25 @@
+use super::{cos, f16};
+
+/// Cosine (f16)
+///
+/// Computes the cosine of `x` (in radians), correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosf16(x: f16) -> f16 {
+    f16::from_f64(cos(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cr_cos;
+    use super::super::float16::check;
+    use super::{cosf16, f16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = f16::from_bits(i);
+            check(x, cosf16(x), cr_cos(x.to_f64()));
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::{f128, f80, k_cosf128, k_sinf128, rem_pio2f80};
+
+const PIO4: f80 = f80::from_bits(0x3ffec90fdaa22168c235); /* 0.785398163397448309616 */
+
+/// Cosine (f80)
+///
+/// Computes the cosine of `x` (in radians).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosf80(x: f80) -> f80 {
+    let e = (x.to_bits() >> 64) as i32 & 0x7fff;
+
+    if e == 0x7fff {
+        return x - x;
+    }
+    if (if x.is_sign_negative() { -x } else { x }) < PIO4 {
+        if e < 0x3fff - 64 {
+            /* cos(x) rounds to 1 */
+            return f80::ONE + x;
+        }
+        return f80::from_f128(k_cosf128(f128::from(x), f128::ZERO));
+    }
+    let (n, y0, y1) = rem_pio2f80(x);
+    let hi = f128::from(y0) + f128::from(y1);
+    let lo = f128::from(y1) - (hi - f128::from(y0));
+    f80::from_f128(match n & 3 {
+        0 => k_cosf128(hi, lo),
+        1 => -k_sinf128(hi, lo, 1),
+        2 => -k_cosf128(hi, lo),
+        _ => k_sinf128(hi, lo, 1),
+    })
+}
@@ -1,5 +1,6 @@
 use super::exp;
 use super::expm1;
+use super::fenv::oflow;
 use super::k_expo2;
 
 /// Hyperbolic cosine (f64)
@@ -20,7 +21,9 @@ pub fn cosh(mut x: f64) -> f64 {
     if w < 0x3fe62e42 {
         if w < 0x3ff00000 - (26 << 20) {
             let x1p120 = f64::from_bits(0x4770000000000000);
-            force_eval!(x + x1p120);
+            if x != 0.0 {
+                force_eval!(x + x1p120);
+            }
             return 1.;
         }
         let t = expm1(x); // exponential minus 1
@@ -35,5 +38,30 @@ pub fn cosh(mut x: f64) -> f64 {
     }
 
     /* |x| > log(DBL_MAX) or nan */
-    k_expo2(x)
+    let t = k_expo2(x);
+    if w < 0x7ff00000 && t.is_infinite() {
+        return oflow(t);
+    }
+    t
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW};
+    use super::cosh;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1000.0, FE_OVERFLOW | FE_INEXACT),
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f64::NEG_INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(cosh, x), e, "cosh({:?})", x);
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use super::expf;
 use super::expm1f;
+use super::fenv::oflow;
 use super::k_expo2f;
 
 /// Hyperbolic cosine (f64)
@@ -21,7 +22,9 @@ pub fn coshf(mut x: f32) -> f32 {
     /* |x| < log(2) */
     if w < 0x3f317217 {
         if w < (0x3f800000 - (12 << 23)) {
-            force_eval!(x + x1p120);
+            if x != 0.0 {
+                force_eval!(x + x1p120);
+            }
             return 1.;
         }
         let t = expm1f(x);
@@ -35,5 +38,30 @@ pub fn coshf(mut x: f32) -> f32 {
     }
 
     /* |x| > log(FLT_MAX) or nan */
-    k_expo2f(x)
+    let t = k_expo2f(x);
+    if w < 0x7f800000 && t.is_infinite() {
+        return oflow(t);
+    }
+    t
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW};
+    use super::coshf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (100.0, FE_OVERFLOW | FE_INEXACT),
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f32::NEG_INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(coshf, x), e, "coshf({:?})", x);
+        }
+    }
 }
@@ -0,0 +1,128 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_cospi.c */
+/*-
+ * Copyright (c) 2017 Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice unmodified, this list of conditions, and the following
+ *    disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ * IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ * OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ * IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ * INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ * NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ * DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ * THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ * (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ * THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use super::{get_high_word, k_cospi, k_sinpi};
+
+/// Cosine of pi times x (f64)
+///
+/// Computes `cos(pi * x)` without the rounding error of the product
+/// `pi * x`. The result is exact at integers and half-integers, with
+/// `cospi(n + 0.5)` being +0 for any integer `n`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospi(x: f64) -> f64 {
+    let ix = get_high_word(x) & 0x7fffffff;
+    let mut ax = f64::from_bits(x.to_bits() & 0x7fffffffffffffff);
+    let c: f64;
+
+    /* |x| < 1 */
+    if ix < 0x3ff00000 {
+        /* |x| < 0.25 */
+        if ix < 0x3fd00000 {
+            /* |x| < 0x1p-29 */
+            if ix < 0x3e200000 {
+                /* raise inexact if x != 0 */
+                if x != 0.0 {
+                    force_eval!(x + 1.0);
+                }
+                return 1.0;
+            }
+            return k_cospi(ax);
+        }
+
+        if ix < 0x3fe00000 {
+            /* |x| < 0.5 */
+            c = k_sinpi(0.5 - ax);
+        } else if ix < 0x3fe80000 {
+            /* |x| < 0.75 */
+            if ax == 0.5 {
+                return 0.0;
+            }
+            c = -k_sinpi(ax - 0.5);
+        } else {
+            c = -k_cospi(1.0 - ax);
+        }
+        return c;
+    }
+
+    /* 1 <= |x| < 0x1p52 */
+    if ix < 0x43300000 {
+        /* integer part of |x| */
+        let j0 = ((ix >> 20) & 0x7ff) as i32 - 0x3ff;
+        let xi = f64::from_bits(ax.to_bits() & !(0x000fffffffffffff >> j0));
+        ax -= xi;
+
+        let c = if ax < 0.25 {
+            if ax == 0.0 {
+                1.0
+            } else {
+                k_cospi(ax)
+            }
+        } else if ax < 0.5 {
+            k_sinpi(0.5 - ax)
+        } else if ax < 0.75 {
+            if ax == 0.5 {
+                return 0.0;
+            }
+            -k_sinpi(ax - 0.5)
+        } else {
+            -k_cospi(1.0 - ax)
+        };
+        return if (xi as u64) & 1 != 0 { -c } else { c };
+    }
+
+    /* cospi(Inf or NaN) is NaN */
+    if ix >= 0x7ff00000 {
+        return x - x;
+    }
+
+    /* |x| >= 0x1p52 is always an integer, and even from 0x1p53 on */
+    if ix >= 0x43400000 || x.to_bits() & 1 == 0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cospi;
+    use core::f64;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(cospi(0.0), 1.0);
+        assert_eq!(cospi(1.0), -1.0);
+        assert_eq!(cospi(-3.0), -1.0);
+        assert_eq!(cospi(0.5).to_bits(), 0);
+        assert_eq!(cospi(-0.5).to_bits(), 0);
+        assert_eq!(cospi(7.5).to_bits(), 0);
+        assert_eq!(cospi(4503599627370497.0), -1.0);
+        assert_eq!(cospi(1e300), 1.0);
+        assert!(cospi(f64::NEG_INFINITY).is_nan());
+    }
+}
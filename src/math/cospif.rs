@@ -0,0 +1,11 @@
+use super::cospi;
+
+/// Cosine of pi times x (f32)
+///
+/// Computes `cos(pi * x)` by evaluating `cospi` in double precision. Like
+/// `cospi`, the result is exact at integers and half-integers.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospif(x: f32) -> f32 {
+    cospi(x as f64) as f32
+}
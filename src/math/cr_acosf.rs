@@ -0,0 +1,81 @@
+use super::acos;
+use super::crf32::round_approx;
+use super::soft_math;
+
+/* the relative error of acos, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Arccosine, correctly rounded (f32)
+///
+/// Computes the inverse cosine (arc cosine) of `x`, rounded to nearest.
+/// Arguments must be in the range -1 to 1. Returns values in radians, in
+/// the range of 0 to pi.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_acosf(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(acos(x), EPS, || soft_math::acos(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::{cr_atan, sqrt};
+    use super::cr_acosf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 12] = [
+        (0x328885a2, 0x3fc90fdb), (0x328885a3, 0x3fc90fdb), (0x39826222, 0x3fc907b5),
+        (0x3a6a490f, 0x3fc8f292), (0x3b7d281b, 0x3fc89147), (0x3c8a2f9b, 0x3fc6e716),
+        (0xb3ddde97, 0x3fc90fdb), (0xba66deed, 0x3fc92cb6), (0xba9d5f75, 0x3fc93733),
+        (0xbc406ccd, 0x3fca90b6), (0xbe668cd1, 0x3fe62141), (0xbe6dff37, 0x3fe7160b),
+    ];
+
+    /* acos(x) = 2*atan(sqrt((1-x)/(1+x))), where 1-x and 1+x are exact */
+    fn reference(x: f32) -> Option<f32> {
+        let x = x as f64;
+        rounded(2.0 * cr_atan(sqrt((1.0 - x) / (1.0 + x))), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_acosf(f32::NAN).is_nan());
+        assert!(cr_acosf(-2.0).is_nan());
+        assert_eq!(cr_acosf(1.0), 0.0);
+        assert_eq!(cr_acosf(0.0), 1.5707964);
+        assert_eq!(cr_acosf(-1.0), 3.1415927);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* within 2^-52 relative of halfway between two floats */
+        assert_eq!(cr_acosf(1.5893255e-8), 1.5707964);
+        assert_eq!(cr_acosf(0.00024868647), 1.5705477);
+        /* where acosf is an ulp off */
+        assert_eq!(cr_acosf(0.0), 1.5707964);
+        assert_eq!(cr_acosf(1e-45), 1.5707964);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_acosf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_acosf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 18 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_acosf, reference, 1);
+    }
+}
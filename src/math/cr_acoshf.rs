@@ -0,0 +1,93 @@
+use super::acosh;
+use super::crf32::round_approx;
+use super::soft_math;
+
+/* the relative error of acosh, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Inverse hyperbolic cosine, correctly rounded (f32)
+///
+/// Calculates the inverse hyperbolic cosine of `x`, rounded to nearest.
+/// `x` must be a number greater than or equal to 1.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_acoshf(x: f32) -> f32 {
+    let x = x as f64;
+    if x < 1.0 {
+        /* acosh cancels to a finite result for some large negative x */
+        return ((x - x) / (x - x)) as f32;
+    }
+    round_approx(acosh(x), EPS, || soft_math::acosh(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::{cr_log1p, sqrt};
+    use super::cr_acoshf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 29] = [
+        (0x41b5b653, 0x40743387), (0x424d0074, 0x40942720), (0x42afb693, 0x40a566fb),
+        (0x4559f1c7, 0x410d9981), (0x4a2be14f, 0x4178b43f), (0x4af7325a, 0x4184cde8),
+        (0x4bdd65a5, 0x418f034a), (0x4ce04ebe, 0x419a352c), (0x4e05f412, 0x41a6b811),
+        (0x4f64ab24, 0x41b6166f), (0x51ea304f, 0x41d2011d), (0x542f989d, 0x41eb6d3c),
+        (0x5d000341, 0x42265b10), (0x5d0b2d5b, 0x4226b0b1), (0x5e68984e, 0x422e4a21),
+        (0x5ee4c24a, 0x4230fee2), (0x623467ba, 0x42437434), (0x633134d9, 0x4248ed72),
+        (0x64627fa3, 0x424f7453), (0x655890d3, 0x4254d1f9), (0x6628c860, 0x42595e46),
+        (0x662bbd63, 0x4259700e), (0x6894cb96, 0x4266ba1e), (0x6c9f23eb, 0x427d2d35),
+        (0x6cec9d3a, 0x427ec360), (0x6eb1a8ec, 0x42845a89), (0x7385dee8, 0x4291a68d),
+        (0x7967ec37, 0x42a1ffb7), (0x7d18b8f4, 0x42ac40f2),
+    ];
+
+    /* acosh(x) = log1p((x-1) + sqrt((x-1)*(x+1))) */
+    fn reference(x: f32) -> Option<f32> {
+        let x = x as f64;
+        if x < 1.0 {
+            return Some(f32::NAN);
+        }
+        rounded(cr_log1p((x - 1.0) + sqrt((x - 1.0) * (x + 1.0))), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_acoshf(f32::NAN).is_nan());
+        assert_eq!(cr_acoshf(1.0 / 0.0), 1.0 / 0.0);
+        assert!(cr_acoshf(0.5).is_nan());
+        assert_eq!(cr_acoshf(1.0), 0.0);
+        assert!(cr_acoshf(-1e7).is_nan());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* within 2^-52 relative of halfway between two floats */
+        assert_eq!(cr_acoshf(4.190058e18), 43.57239);
+        assert_eq!(cr_acoshf(6.391892e22), 53.20505);
+        /* where acoshf is an ulp off */
+        assert_eq!(cr_acoshf(1.0000001), 0.00048828125);
+        assert_eq!(cr_acoshf(1.0000004), 0.0008457279);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_acoshf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_acoshf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 6 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_acoshf, reference, 1);
+    }
+}
@@ -0,0 +1,78 @@
+use super::asin;
+use super::crf32::round_approx;
+use super::soft_math;
+
+/* the relative error of asin, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Arcsine, correctly rounded (f32)
+///
+/// Computes the inverse sine (arc sine) of `x`, rounded to nearest.
+/// Arguments must be in the range -1 to 1. Returns values in radians, in
+/// the range of -pi/2 to pi/2.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_asinf(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(asin(x), EPS, || soft_math::asin(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::{cr_atan, sqrt};
+    use super::cr_asinf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 10] = [
+        (0x39e89768, 0x39e89769), (0x3d07959c, 0x3d079bf3), (0x3d09bf86, 0x3d09c62d),
+        (0x3de5fa1e, 0x3de6768e), (0x3f083a1a, 0x3f0fa5b2), (0xb9e89768, 0xb9e89769),
+        (0xbd07959c, 0xbd079bf3), (0xbd09bf86, 0xbd09c62d), (0xbde5fa1e, 0xbde6768e),
+        (0xbf083a1a, 0xbf0fa5b2),
+    ];
+
+    /* asin(x) = atan(x/sqrt((1-x)*(1+x))), where 1-x and 1+x are exact */
+    fn reference(x: f32) -> Option<f32> {
+        let x = x as f64;
+        rounded(cr_atan(x / sqrt((1.0 - x) * (1.0 + x))), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_asinf(f32::NAN).is_nan());
+        assert!(cr_asinf(2.0).is_nan());
+        assert_eq!(cr_asinf(-0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(cr_asinf(1.0), 1.5707964);
+        assert_eq!(cr_asinf(-1.0), -1.5707964);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where asinf is an ulp off */
+        assert_eq!(cr_asinf(0.00044363295), 0.00044363298);
+        assert_eq!(cr_asinf(0.00044363298), 0.000443633);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_asinf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_asinf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 8 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_asinf, reference, 1);
+    }
+}
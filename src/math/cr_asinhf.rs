@@ -0,0 +1,97 @@
+use super::asinh;
+use super::crf32::round_approx;
+use super::soft_math;
+
+/* the relative error of asinh, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Inverse hyperbolic sine, correctly rounded (f32)
+///
+/// Calculates the inverse hyperbolic sine of `x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_asinhf(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(asinh(x), EPS, || soft_math::asinh(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::{copysign, cr_log1p, fabs, sqrt};
+    use super::cr_asinhf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 58] = [
+        (0x3a5352c8, 0x3a5352c7), (0x3fe1a91f, 0x3faa8959), (0x4041409b, 0x3fe98d07),
+        (0x42eb3bb2, 0x40aebd33), (0x469142e5, 0x41286120), (0x492b0ca7, 0x41627223),
+        (0x4bdd65a5, 0x418f034b), (0x4ce04ebe, 0x419a352c), (0x4e05f412, 0x41a6b811),
+        (0x4f64ab24, 0x41b6166f), (0x51ea304f, 0x41d2011d), (0x542f989d, 0x41eb6d3c),
+        (0x5d000341, 0x42265b10), (0x5d0b2d5b, 0x4226b0b1), (0x5e68984e, 0x422e4a21),
+        (0x5ee4c24a, 0x4230fee2), (0x623467ba, 0x42437434), (0x633134d9, 0x4248ed72),
+        (0x64627fa3, 0x424f7453), (0x655890d3, 0x4254d1f9), (0x6628c860, 0x42595e46),
+        (0x662bbd63, 0x4259700e), (0x6894cb96, 0x4266ba1e), (0x6c9f23eb, 0x427d2d35),
+        (0x6cec9d3a, 0x427ec360), (0x6eb1a8ec, 0x42845a89), (0x7385dee8, 0x4291a68d),
+        (0x7967ec37, 0x42a1ffb7), (0x7d18b8f4, 0x42ac40f2), (0xba5352c8, 0xba5352c7),
+        (0xbfe1a91f, 0xbfaa8959), (0xc041409b, 0xbfe98d07), (0xc2eb3bb2, 0xc0aebd33),
+        (0xc69142e5, 0xc1286120), (0xc92b0ca7, 0xc1627223), (0xcbdd65a5, 0xc18f034b),
+        (0xcce04ebe, 0xc19a352c), (0xce05f412, 0xc1a6b811), (0xcf64ab24, 0xc1b6166f),
+        (0xd1ea304f, 0xc1d2011d), (0xd42f989d, 0xc1eb6d3c), (0xdd000341, 0xc2265b10),
+        (0xdd0b2d5b, 0xc226b0b1), (0xde68984e, 0xc22e4a21), (0xdee4c24a, 0xc230fee2),
+        (0xe23467ba, 0xc2437434), (0xe33134d9, 0xc248ed72), (0xe4627fa3, 0xc24f7453),
+        (0xe55890d3, 0xc254d1f9), (0xe628c860, 0xc2595e46), (0xe62bbd63, 0xc259700e),
+        (0xe894cb96, 0xc266ba1e), (0xec9f23eb, 0xc27d2d35), (0xecec9d3a, 0xc27ec360),
+        (0xeeb1a8ec, 0xc2845a89), (0xf385dee8, 0xc291a68d), (0xf967ec37, 0xc2a1ffb7),
+        (0xfd18b8f4, 0xc2ac40f2),
+    ];
+
+    /* asinh(|x|) = log1p(|x| + x^2/(1 + sqrt(1 + x^2))) */
+    fn reference(x: f32) -> Option<f32> {
+        let x = x as f64;
+        let a = fabs(x);
+        let y = if a.is_infinite() {
+            a
+        } else {
+            cr_log1p(a + a * a / (1.0 + sqrt(1.0 + a * a)))
+        };
+        rounded(copysign(y, x), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_asinhf(f32::NAN).is_nan());
+        assert_eq!(cr_asinhf(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_asinhf(-1.0 / 0.0), -1.0 / 0.0);
+        assert_eq!(cr_asinhf(-0.0).to_bits(), (-0.0_f32).to_bits());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where asinhf is an ulp off */
+        assert_eq!(cr_asinhf(0.00024561677), 0.00024561677);
+        assert_eq!(cr_asinhf(0.00024573607), 0.00024573607);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_asinhf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_asinhf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 29 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_asinhf, reference, 1);
+    }
+}
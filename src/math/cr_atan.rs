@@ -0,0 +1,66 @@
+// Correctly rounded arctangent
+//
+// The double-double arctangent of k_cratan is rounded where its error
+// bound decides the result, and soft_math::atan gives it elsewhere.
+
+use super::double_double::round;
+use super::k_cratan::k_cratan;
+use super::soft_math;
+
+const PIO2_HI: f64 = 1.5707963267948966; /* 0x3ff921fb, 0x54442d18 */
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 5.421010862427522e-20; /* 0x1p-64 */
+
+/// Arctangent, correctly rounded (f64)
+///
+/// Computes the inverse tangent (arc tangent) of the input value, rounded
+/// to nearest. Returns a value in radians, in the range of -pi/2 to pi/2.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_atan(x: f64) -> f64 {
+    let x1p_27 = f64::from_bits(0x3e40000000000000); // 0x1p-27 === 2 ^ -27
+    let x1p54 = f64::from_bits(0x4350000000000000); // 0x1p54 === 2 ^ 54
+
+    if x.is_nan() {
+        return x;
+    }
+    let a = x.abs();
+    if a < x1p_27 {
+        /* atan(x) = x - x^3/3 + ..., within half an ulp of x */
+        return x;
+    }
+    if a >= x1p54 {
+        /* pi/2 - 1/|x| is within half an ulp of PIO2_HI */
+        return if x < 0.0 { -PIO2_HI } else { PIO2_HI };
+    }
+
+    let r = k_cratan((a, 0.0));
+    if let Some(y) = round(r.0, r.1, EPS) {
+        return if x < 0.0 { -y } else { y };
+    }
+    soft_math::atan(x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_atan;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_atan(f64::NAN).is_nan());
+        assert_eq!(cr_atan(1.0 / 0.0), 1.5707963267948966);
+        assert_eq!(cr_atan(-1.0 / 0.0), -1.5707963267948966);
+        assert_eq!(cr_atan(-0.0).to_bits(), 0x8000000000000000);
+        assert_eq!(cr_atan(5e-324), 5e-324);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* pi/4 = 0.78539816339744830961... */
+        assert_eq!(cr_atan(1.0).to_bits(), 0x3fe921fb54442d18);
+        assert_eq!(cr_atan(0.5), 0.4636476090008061);
+        assert_eq!(cr_atan(-3.0), -1.2490457723982544);
+        assert_eq!(cr_atan(1e10), 1.5707963266948965);
+        assert_eq!(cr_atan(0.001), 0.0009999996666668668);
+    }
+}
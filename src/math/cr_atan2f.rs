@@ -0,0 +1,80 @@
+use super::atan2;
+use super::crf32::round_approx;
+use super::soft_math;
+
+/* the relative error of atan2, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Arctangent of y/x, correctly rounded (f32)
+///
+/// Computes the inverse tangent (arc tangent) of `y/x`, rounded to
+/// nearest. Returns a value in radians, in the range of -pi to pi.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_atan2f(y: f32, x: f32) -> f32 {
+    let (y, x) = (y as f64, x as f64);
+    round_approx(atan2(y, x), EPS, || soft_math::atan2(y, x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check2, check2_sweep, rounded};
+    use super::super::{copysign, cr_atan};
+    use super::cr_atan2f;
+    use core::f64::consts::PI;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+    /* pi - PI */
+    const PI_LO: f64 = 1.2246467991473532e-16;
+
+    /* atan(y/x), plus or minus pi for negative x */
+    fn reference(y: f32, x: f32) -> Option<f32> {
+        let (y, x) = (y as f64, x as f64);
+        if y == 0.0 || x == 0.0 || !y.is_finite() || !x.is_finite() {
+            return None;
+        }
+        let a = cr_atan(y / x);
+        if x > 0.0 {
+            rounded(a, ERR)
+        } else {
+            rounded((copysign(PI, y) + a) + copysign(PI_LO, y), ERR)
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_atan2f(f32::NAN, 1.0).is_nan());
+        assert!(cr_atan2f(1.0, f32::NAN).is_nan());
+        assert_eq!(cr_atan2f(0.0, -1.0), 3.1415927);
+        assert_eq!(cr_atan2f(-0.0, 1.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(cr_atan2f(1.0, 0.0), 1.5707964);
+        assert_eq!(cr_atan2f(1.0 / 0.0, -1.0 / 0.0), 2.3561945);
+        assert_eq!(cr_atan2f(1.0, 1.0 / 0.0), 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_atan2f(1.270592, -0.60154533), 2.0129688);
+        assert_eq!(cr_atan2f(-1.3905716, -0.54637814), -1.9451811);
+    }
+
+    #[test]
+    fn fixed_x() {
+        /* every quadrant, and ratios y/x of all magnitudes */
+        let xs = [1.0, -1.0, 2.5, -0.3, 1e-45, -3e-39, 1e30, -3e38];
+        check2_sweep(cr_atan2f, reference, &xs, 65_537);
+    }
+
+    #[test]
+    fn sampled() {
+        check2(cr_atan2f, reference, 4096);
+    }
+
+    /* random pairs, as all of them would take far too long */
+    #[test]
+    #[ignore]
+    fn random() {
+        check2(cr_atan2f, reference, 1 << 28);
+    }
+}
@@ -0,0 +1,78 @@
+use super::cr_atan;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// Arctangent, correctly rounded (f32)
+///
+/// Computes the inverse tangent (arc tangent) of the input value, rounded
+/// to nearest. Returns a value in radians, in the range of -pi/2 to pi/2.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_atanf(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_atan(x), || soft_math::atan(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::atan;
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::cr_atanf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 28] = [
+        (0x3a27ba3b, 0x3a27ba3a), (0x3aac434b, 0x3aac4344), (0x3ad637fa, 0x3ad637ee),
+        (0x3b7c1bc9, 0x3b7c1b78), (0x3d8d6b23, 0x3d8d31c3), (0x3ee06db1, 0x3ed38381),
+        (0x40357f1d, 0x3f9dab29), (0x48b90f72, 0x3fc90fc4), (0x4a2367a3, 0x3fc90fd7),
+        (0x4ae1e272, 0x3fc90fd9), (0x4c700516, 0x3fc90fda), (0x4c700517, 0x3fc90fda),
+        (0x4c700518, 0x3fc90fdb), (0x4c700519, 0x3fc90fdb), (0xba27ba3b, 0xba27ba3a),
+        (0xbaac434b, 0xbaac4344), (0xbad637fa, 0xbad637ee), (0xbb7c1bc9, 0xbb7c1b78),
+        (0xbd8d6b23, 0xbd8d31c3), (0xbee06db1, 0xbed38381), (0xc0357f1d, 0xbf9dab29),
+        (0xc8b90f72, 0xbfc90fc4), (0xca2367a3, 0xbfc90fd7), (0xcae1e272, 0xbfc90fd9),
+        (0xcc700516, 0xbfc90fda), (0xcc700517, 0xbfc90fda), (0xcc700518, 0xbfc90fdb),
+        (0xcc700519, 0xbfc90fdb),
+    ];
+
+    /* atan in double precision, evaluated independently of cr_atanf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(atan(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_atanf(f32::NAN).is_nan());
+        assert_eq!(cr_atanf(1.0 / 0.0), 1.5707964);
+        assert_eq!(cr_atanf(-1.0 / 0.0), -1.5707964);
+        assert_eq!(cr_atanf(-0.0).to_bits(), (-0.0_f32).to_bits());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where atanf is an ulp off */
+        assert_eq!(cr_atanf(0.0057698907), 0.0057698265);
+        assert_eq!(cr_atanf(0.015622584), 0.015621313);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_atanf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_atanf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_atanf, reference, 1);
+    }
+}
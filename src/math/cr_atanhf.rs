@@ -0,0 +1,77 @@
+use super::atanh;
+use super::crf32::round_approx;
+use super::soft_math;
+
+/* the relative error of atanh, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Inverse hyperbolic tangent, correctly rounded (f32)
+///
+/// Calculates the inverse hyperbolic tangent of `x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_atanhf(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(atanh(x), EPS, || soft_math::atanh(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::{copysign, cr_log1p, fabs};
+    use super::cr_atanhf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 10] = [
+        (0x39b89ba2, 0x39b89ba3), (0x3a71e7a1, 0x3a71e7a6), (0x3ad637eb, 0x3ad637f7),
+        (0x3dcd9576, 0x3dce4751), (0x3ddc2ece, 0x3ddd0980), (0xb9b89ba2, 0xb9b89ba3),
+        (0xba71e7a1, 0xba71e7a6), (0xbad637eb, 0xbad637f7), (0xbdcd9576, 0xbdce4751),
+        (0xbddc2ece, 0xbddd0980),
+    ];
+
+    /* atanh(|x|) = log1p(2|x|/(1-|x|))/2 */
+    fn reference(x: f32) -> Option<f32> {
+        let x = x as f64;
+        let a = fabs(x);
+        rounded(copysign(0.5 * cr_log1p(2.0 * a / (1.0 - a)), x), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_atanhf(f32::NAN).is_nan());
+        assert!(cr_atanhf(2.0).is_nan());
+        assert_eq!(cr_atanhf(1.0), 1.0 / 0.0);
+        assert_eq!(cr_atanhf(-1.0), -1.0 / 0.0);
+        assert_eq!(cr_atanhf(-0.0).to_bits(), (-0.0_f32).to_bits());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where atanhf is an ulp off */
+        assert_eq!(cr_atanhf(6.926438e-6), 6.926438e-6);
+        assert_eq!(cr_atanhf(1.1815509e-5), 1.1815509e-5);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_atanhf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_atanhf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 3 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_atanhf, reference, 1);
+    }
+}
@@ -0,0 +1,68 @@
+use super::cbrt;
+use super::crf32::round_approx;
+use super::soft_math;
+
+/* the relative error of cbrt, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Cube root, correctly rounded (f32)
+///
+/// Computes the cube root of the argument, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_cbrtf(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(cbrt(x), EPS, || soft_math::cbrt(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cbrt;
+    use super::super::crf32::{check, nearest};
+    use super::super::soft_float::{cmp, mul, Soft};
+    use super::cr_cbrtf;
+
+    /* the float nearest to the cube root, found by comparing |x| exactly
+     * with the cubes of the halfway points around cbrt(|x|) */
+    fn reference(x: f32) -> Option<f32> {
+        let a = x.abs();
+        if a == 0.0 || !a.is_finite() {
+            return None;
+        }
+        let s = Soft::from_f64(a as f64);
+        let r = nearest(cbrt(a as f64) as f32, |m| {
+            let m = Soft::from_f64(m);
+            cmp(s, mul(mul(m, m), m)).unwrap()
+        });
+        Some(if x < 0.0 { -r } else { r })
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_cbrtf(f32::NAN).is_nan());
+        assert_eq!(cr_cbrtf(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_cbrtf(-1.0 / 0.0), -1.0 / 0.0);
+        assert_eq!(cr_cbrtf(-0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(cr_cbrtf(-27.0), -3.0);
+        assert_eq!(cr_cbrtf(1e-45), 1.1190347e-15);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_cbrtf(2.0), 1.2599211);
+        assert_eq!(cr_cbrtf(-0.1), -0.4641589);
+        assert_eq!(cr_cbrtf(1e30), 1e10);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_cbrtf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 18 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_cbrtf, reference, 1);
+    }
+}
@@ -0,0 +1,64 @@
+use super::double_double::round;
+use super::k_crsin::k_crsincos;
+use super::rem_pio2_cr::rem_pio2_cr_dd;
+use super::soft_math;
+
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 1.0842021724855044e-19; /* 0x1p-63 */
+
+/// The cosine of `x`, correctly rounded (f64).
+///
+/// `x` is specified in radians. Unlike [`cos`](fn.cos.html), the result is
+/// always the double nearest to the exact value.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_cos(x: f64) -> f64 {
+    let x1p_27 = f64::from_bits(0x3e40000000000000); // 0x1p-27 === 2 ^ -27
+
+    if x.is_nan() || x.is_infinite() {
+        return x - x;
+    }
+    if x.abs() < x1p_27 {
+        /* cos(x) = 1 - x^2/2 + ..., within half an ulp of 1 */
+        return 1.0;
+    }
+    let (n, rh, rl) = rem_pio2_cr_dd(x);
+    let (s, c) = k_crsincos(rh, rl);
+    let (h, l) = match n {
+        0 => c,
+        1 => (-s.0, -s.1),
+        2 => (-c.0, -c.1),
+        _ => s,
+    };
+    if let Some(y) = round(h, l, EPS) {
+        return y;
+    }
+    soft_math::sin_cos(x).1.to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_cos;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_cos(f64::NAN).is_nan());
+        assert!(cr_cos(1.0 / 0.0).is_nan());
+        assert!(cr_cos(-1.0 / 0.0).is_nan());
+        assert_eq!(cr_cos(0.0), 1.0);
+        assert_eq!(cr_cos(-0.0), 1.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_cos(1.0), 0.5403023058681398);
+        assert_eq!(cr_cos(-3.0), -0.9899924966004454);
+        assert_eq!(cr_cos(1e22), 0.523214785395139);
+        assert_eq!(cr_cos(f64::MAX), -0.9999876894265599);
+        /* the double closest to a multiple of pi/2 */
+        assert_eq!(
+            cr_cos(f64::from_bits(0x7506ac5b262ca1ff)),
+            -4.687165924254628e-19
+        );
+    }
+}
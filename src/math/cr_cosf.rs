@@ -0,0 +1,96 @@
+use super::cr_cos;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// The cosine of `x`, correctly rounded (f32).
+///
+/// `x` is specified in radians. Unlike [`cosf`](fn.cosf.html), the result
+/// is always the float nearest to the exact value.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_cosf(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_cos(x), || soft_math::sin_cos(x).1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cos;
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::cr_cosf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 86] = [
+        (0x39800000, 0x3f800000), (0x3a0f1bbd, 0x3f7ffffd), (0x3a544395, 0x3f7ffffb),
+        (0x3c107fe6, 0x3f7ffd74), (0x3e5fa70e, 0x3f79eb10), (0x3faa2672, 0x3e74e5b3),
+        (0x42378db8, 0xbea87a07), (0x424790ce, 0x3f6e4c01), (0x46f85a22, 0xbf4232b9),
+        (0x47a0e238, 0x3f7e19dd), (0x4986afee, 0x3f090d16), (0x4a01dca4, 0x3f30b318),
+        (0x4e5b65ff, 0xbf62075d), (0x4ea2216b, 0xbf7fe8c2), (0x504be581, 0x3df8dc9c),
+        (0x51abf5aa, 0xbcc6b7a4), (0x52d9d3fe, 0xbf5e187b), (0x52f88494, 0x3f5c5971),
+        (0x543f6e04, 0x3f0f0187), (0x55e5235d, 0xbe83c11a), (0x5922aa80, 0x3f08aebf),
+        (0x59443c0a, 0x3f425f62), (0x5a1a3626, 0xbf5fdc79), (0x5a8c921b, 0x3f5ea913),
+        (0x5da7ab87, 0xbf73e8cb), (0x5f18b878, 0x3f7f14bb), (0x6115cb11, 0x3f78142f),
+        (0x61703976, 0x3edacc56), (0x64933b14, 0x3d4bf754), (0x6978e912, 0xbd769ca5),
+        (0x71510edb, 0xbf7e37e8), (0x744fd5d8, 0x3f6cb54a), (0x75da4c95, 0x3f7aeabb),
+        (0x76d7173f, 0x3f266b3e), (0x77f7b624, 0x3f67741e), (0x7908cd73, 0x3f798bb5),
+        (0x797cef4f, 0x3f7de018), (0x7a38ab34, 0x3f7b3195), (0x7a4b1a27, 0x3f7c54da),
+        (0x7c64841e, 0xbf7fc6f8), (0x7c69ae1e, 0xbf7ff9fe), (0x7c75f2a4, 0xbe61ec6f),
+        (0x7db91687, 0x3edfe26d), (0xb9800000, 0x3f800000), (0xba0f1bbd, 0x3f7ffffd),
+        (0xba544395, 0x3f7ffffb), (0xbc107fe6, 0x3f7ffd74), (0xbe5fa70e, 0x3f79eb10),
+        (0xbfaa2672, 0x3e74e5b3), (0xc2378db8, 0xbea87a07), (0xc24790ce, 0x3f6e4c01),
+        (0xc6f85a22, 0xbf4232b9), (0xc7a0e238, 0x3f7e19dd), (0xc986afee, 0x3f090d16),
+        (0xca01dca4, 0x3f30b318), (0xce5b65ff, 0xbf62075d), (0xcea2216b, 0xbf7fe8c2),
+        (0xd04be581, 0x3df8dc9c), (0xd1abf5aa, 0xbcc6b7a4), (0xd2d9d3fe, 0xbf5e187b),
+        (0xd2f88494, 0x3f5c5971), (0xd43f6e04, 0x3f0f0187), (0xd5e5235d, 0xbe83c11a),
+        (0xd922aa80, 0x3f08aebf), (0xd9443c0a, 0x3f425f62), (0xda1a3626, 0xbf5fdc79),
+        (0xda8c921b, 0x3f5ea913), (0xdda7ab87, 0xbf73e8cb), (0xdf18b878, 0x3f7f14bb),
+        (0xe115cb11, 0x3f78142f), (0xe1703976, 0x3edacc56), (0xe4933b14, 0x3d4bf754),
+        (0xe978e912, 0xbd769ca5), (0xf1510edb, 0xbf7e37e8), (0xf44fd5d8, 0x3f6cb54a),
+        (0xf5da4c95, 0x3f7aeabb), (0xf6d7173f, 0x3f266b3e), (0xf7f7b624, 0x3f67741e),
+        (0xf908cd73, 0x3f798bb5), (0xf97cef4f, 0x3f7de018), (0xfa38ab34, 0x3f7b3195),
+        (0xfa4b1a27, 0x3f7c54da), (0xfc64841e, 0xbf7fc6f8), (0xfc69ae1e, 0xbf7ff9fe),
+        (0xfc75f2a4, 0xbe61ec6f), (0xfdb91687, 0x3edfe26d),
+    ];
+
+    /* cos in double precision, evaluated independently of cr_cosf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(cos(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_cosf(f32::NAN).is_nan());
+        assert!(cr_cosf(-1.0 / 0.0).is_nan());
+        assert_eq!(cr_cosf(-0.0), 1.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where cosf is an ulp off */
+        assert_eq!(cr_cosf(0.12963375), 0.9916093);
+        assert_eq!(cr_cosf(0.23902778), 0.9715686);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_cosf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_cosf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 11 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_cosf, reference, 1);
+    }
+}
@@ -0,0 +1,65 @@
+use super::double_double::{add, div, mul, round};
+use super::k_crexp::k_crexp;
+use super::scalbn;
+use super::soft_math;
+
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 8.470329472543003e-22; /* 0x1p-70 */
+
+/// Hyperbolic cosine, correctly rounded (f64)
+///
+/// Computes the hyperbolic cosine of `x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_cosh(x: f64) -> f64 {
+    let x1p_27 = f64::from_bits(0x3e40000000000000); // 0x1p-27 === 2 ^ -27
+
+    if x.is_nan() {
+        return x;
+    }
+    let a = x.abs();
+    if a < x1p_27 {
+        /* cosh(x) = 1 + x^2/2 + ..., within half an ulp of 1 */
+        return 1.0;
+    }
+    if a > 710.5 {
+        return a * f64::MAX;
+    }
+    if a < 709.0 {
+        /* cosh(a) = 2^(m-1)*(u + 2^(-2m)/u), with exp(a) = 2^m*u */
+        let (m, t, p) = k_crexp(a, 0.0);
+        let mut u = add(t, mul(t, p));
+        if m < 60 {
+            let s = f64::from_bits(((0x3ff - 2 * m) as u64) << 52);
+            let w = div((1.0, 0.0), u);
+            u = add(u, (s * w.0, s * w.1));
+        }
+        if let Some(y) = round(u.0, u.1, EPS) {
+            return scalbn(y, m - 1);
+        }
+    }
+    soft_math::cosh(x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_cosh;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_cosh(f64::NAN).is_nan());
+        assert_eq!(cr_cosh(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_cosh(-1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_cosh(0.0), 1.0);
+        assert_eq!(cr_cosh(-711.0), 1.0 / 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_cosh(1.0), 1.5430806348152437);
+        assert_eq!(cr_cosh(-0.25), 1.0314130998795732);
+        assert_eq!(cr_cosh(1e-5), 1.00000000005);
+        assert_eq!(cr_cosh(710.0), 1.1169973830808555e308);
+        assert_eq!(cr_cosh(40.0), 1.1769263341851e17);
+    }
+}
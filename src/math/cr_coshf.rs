@@ -0,0 +1,70 @@
+use super::cr_cosh;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// Hyperbolic cosine, correctly rounded (f32)
+///
+/// Computes the hyperbolic cosine of `x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_coshf(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_cosh(x), || soft_math::cosh(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cosh;
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::cr_coshf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 6] = [
+        (0x3a6f7750, 0x3f800004), (0x3a87c3b6, 0x3f800004), (0x3d609528, 0x3f803145),
+        (0xba6f7750, 0x3f800004), (0xba87c3b6, 0x3f800004), (0xbd609528, 0x3f803145),
+    ];
+
+    /* cosh in double precision, evaluated independently of cr_coshf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(cosh(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_coshf(f32::NAN).is_nan());
+        assert_eq!(cr_coshf(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_coshf(-1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_coshf(-0.0), 1.0);
+        assert_eq!(cr_coshf(-90.0), 1.0 / 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where coshf is an ulp off */
+        assert_eq!(cr_coshf(0.016322587), 1.0001332);
+        assert_eq!(cr_coshf(0.043402847), 1.0009421);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_coshf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_coshf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_coshf, reference, 1);
+    }
+}
@@ -0,0 +1,150 @@
+use super::crf32::round_approx;
+use super::erfc;
+use super::soft_math;
+
+/* the relative error of erfc, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Complementary error function, correctly rounded (f32)
+///
+/// Calculates `1 - erf(x)` without the loss of precision of the
+/// subtraction, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_erfcf(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(erfc(x), EPS, || soft_math::erfc(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::erfc;
+    use super::cr_erfcf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+    /* the 40 floats nearest to halfway between two floats in a comparison
+     * of all of them with MPFR, with their correctly rounded results */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 40] = [
+        (0x32e2dfc4, 0x3f800000), (0x32e2dfc5, 0x3f7fffff), (0x32e2dfc6, 0x3f7fffff),
+        (0x340dcbdb, 0x3f7ffffd), (0x344683cc, 0x3f7ffffc), (0x347f3bbd, 0x3f7ffffc),
+        (0x3514e2d9, 0x3f7ffff5), (0x357824bf, 0x3f7fffee), (0x36bafe6f, 0x3f7fff97),
+        (0x36ec9f62, 0x3f7fff7a), (0x37ca4803, 0x3f7ffe37), (0x382f956e, 0x3f7ffce7),
+        (0x38707ff5, 0x3f7ffbc3), (0x3a73ad88, 0x3f7fbb43), (0x3ca07463, 0x3f7a57c1),
+        (0x3cfa05d7, 0x3f772fbd), (0x3e05c13f, 0x3f5a7b60), (0x3e33a78f, 0x3f4dd5eb),
+        (0x400df51a, 0x3adfd6ed), (0x40c0d2a2, 0x23911688), (0xb362dfc4, 0x3f800000),
+        (0xb362dfc5, 0x3f800001), (0xb362dfc6, 0x3f800001), (0xb48dcbdb, 0x3f800003),
+        (0xb4c683cc, 0x3f800004), (0xb4ff3bbd, 0x3f800004), (0xb594e2d9, 0x3f80000b),
+        (0xb5f824bf, 0x3f800012), (0xb73afe6f, 0x3f800069), (0xb76c9f62, 0x3f800085),
+        (0xb7a7f0a4, 0x3f8000be), (0xb918edf6, 0x3f800565), (0xba0ed5ba, 0x3f801425),
+        (0xbb282a57, 0x3f805ee0), (0xbb6c9b37, 0x3f80857d), (0xbbbfcf4b, 0x3f80d86e),
+        (0xbce492e9, 0x3f840768), (0xbde0b0ca, 0x3f8fc85d), (0xbe83501c, 0x3fa43f0d),
+        (0xbf857a68, 0x3fee0b38),
+    ];
+
+    /* the 24 floats nearest to halfway between two floats among every 11th
+     * float of the tail 3.5 <= x < 10.06, up to where erfc underflows, in a
+     * comparison with MPFR 4.2.0, with their correctly rounded results */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const TAIL_CASES: [(u32, u32); 24] = [
+        (0x4073b2fc, 0x339b89b0), (0x4078c9a0, 0x3325772d), (0x40799982, 0x33157037),
+        (0x407d2707, 0x32beaede), (0x408acc16, 0x306b84ec), (0x408f2195, 0x2f8ab553),
+        (0x40baa449, 0x2538f530), (0x40c1a0c6, 0x235543e6), (0x40c332e0, 0x22e914fb),
+        (0x40c866d2, 0x2173817f), (0x40cd4ef8, 0x20082520), (0x40d898a9, 0x1c9e00c7),
+        (0x40dc3236, 0x1b85eb9b), (0x40dcd300, 0x1b4bca8e), (0x40e34b46, 0x193ace98),
+        (0x40e78e6c, 0x17d95192), (0x40f0db31, 0x14b74e1d), (0x40f574f7, 0x1321e994),
+        (0x41035d24, 0x0ceb17b8), (0x410946cd, 0x0868cdc9), (0x410ca080, 0x05bf896b),
+        (0x410f8288, 0x0374ee47), (0x41120c01, 0x015a7ac5), (0x411ce387, 0x00000048),
+    ];
+
+    /* 96 floats spread evenly over the bit patterns of -4.5 < x < 10.1,
+     * where erfc is neither 2 nor 0, with their correctly rounded results
+     * from MPFR 4.2.0, as a reference independent of the double function
+     * that is rounded */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const SAMPLES: [(u32, u32); 96] = [
+        (0x00aceccd, 0x3f800000), (0x0206c666, 0x3f800000), (0x03609fff, 0x3f800000),
+        (0x04ba7998, 0x3f800000), (0x06145331, 0x3f800000), (0x076e2cca, 0x3f800000),
+        (0x08c80663, 0x3f800000), (0x0a21dffc, 0x3f800000), (0x0b7bb995, 0x3f800000),
+        (0x0cd5932e, 0x3f800000), (0x0e2f6cc7, 0x3f800000), (0x0f894660, 0x3f800000),
+        (0x10e31ff9, 0x3f800000), (0x123cf992, 0x3f800000), (0x1396d32b, 0x3f800000),
+        (0x14f0acc4, 0x3f800000), (0x164a865d, 0x3f800000), (0x17a45ff6, 0x3f800000),
+        (0x18fe398f, 0x3f800000), (0x1a581328, 0x3f800000), (0x1bb1ecc1, 0x3f800000),
+        (0x1d0bc65a, 0x3f800000), (0x1e659ff3, 0x3f800000), (0x1fbf798c, 0x3f800000),
+        (0x21195325, 0x3f800000), (0x22732cbe, 0x3f800000), (0x23cd0657, 0x3f800000),
+        (0x2526dff0, 0x3f800000), (0x2680b989, 0x3f800000), (0x27da9322, 0x3f800000),
+        (0x29346cbb, 0x3f800000), (0x2a8e4654, 0x3f800000), (0x2be81fed, 0x3f800000),
+        (0x2d41f986, 0x3f800000), (0x2e9bd31f, 0x3f800000), (0x2ff5acb8, 0x3f800000),
+        (0x314f8651, 0x3f800000), (0x32a95fea, 0x3f800000), (0x34033983, 0x3f7ffffe),
+        (0x355d131c, 0x3f7ffff0), (0x36b6ecb5, 0x3f7fff99), (0x3810c64e, 0x3f7ffd73),
+        (0x396a9fe7, 0x3f7fef74), (0x3ac47980, 0x3f7f9127), (0x3c1e5319, 0x3f7d356c),
+        (0x3d782cb2, 0x3f6e84eb), (0x3ed2064b, 0x3f0fd47e), (0x402bdfe4, 0x3918ffb4),
+        (0x80641fe4, 0x3f800000), (0x81bdf97d, 0x3f800000), (0x8317d316, 0x3f800000),
+        (0x8471acaf, 0x3f800000), (0x85cb8648, 0x3f800000), (0x87255fe1, 0x3f800000),
+        (0x887f397a, 0x3f800000), (0x89d91313, 0x3f800000), (0x8b32ecac, 0x3f800000),
+        (0x8c8cc645, 0x3f800000), (0x8de69fde, 0x3f800000), (0x8f407977, 0x3f800000),
+        (0x909a5310, 0x3f800000), (0x91f42ca9, 0x3f800000), (0x934e0642, 0x3f800000),
+        (0x94a7dfdb, 0x3f800000), (0x9601b974, 0x3f800000), (0x975b930d, 0x3f800000),
+        (0x98b56ca6, 0x3f800000), (0x9a0f463f, 0x3f800000), (0x9b691fd8, 0x3f800000),
+        (0x9cc2f971, 0x3f800000), (0x9e1cd30a, 0x3f800000), (0x9f76aca3, 0x3f800000),
+        (0xa0d0863c, 0x3f800000), (0xa22a5fd5, 0x3f800000), (0xa384396e, 0x3f800000),
+        (0xa4de1307, 0x3f800000), (0xa637eca0, 0x3f800000), (0xa791c639, 0x3f800000),
+        (0xa8eb9fd2, 0x3f800000), (0xaa45796b, 0x3f800000), (0xab9f5304, 0x3f800000),
+        (0xacf92c9d, 0x3f800000), (0xae530636, 0x3f800000), (0xafacdfcf, 0x3f800000),
+        (0xb106b968, 0x3f800000), (0xb2609301, 0x3f800000), (0xb3ba6c9a, 0x3f800001),
+        (0xb5144633, 0x3f800005), (0xb66e1fcc, 0x3f800022), (0xb7c7f965, 0x3f8000e2),
+        (0xb921d2fe, 0x3f8005b5), (0xba7bac97, 0x3f80237f), (0xbbd58630, 0x3f80f0ef),
+        (0xbd2f5fc9, 0x3f862e24), (0xbe893962, 0x3fa5cd90), (0xbfe312fb, 0x3ffe7316),
+    ];
+
+    /* erfc in double precision, evaluated independently of cr_erfcf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(erfc(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_erfcf(f32::NAN).is_nan());
+        assert_eq!(cr_erfcf(1.0 / 0.0), 0.0);
+        assert_eq!(cr_erfcf(-1.0 / 0.0), 2.0);
+        assert_eq!(cr_erfcf(0.0), 1.0);
+        assert_eq!(cr_erfcf(11.0), 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where erfcf is an ulp off */
+        assert_eq!(cr_erfcf(0.00036920805), 0.9995834);
+        assert_eq!(cr_erfcf(0.00044722803), 0.9994953);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_erfcf, &HARD_CASES);
+    }
+
+    #[test]
+    fn tail_cases() {
+        check_cases(cr_erfcf, &TAIL_CASES);
+    }
+
+    #[test]
+    fn samples() {
+        check_cases(cr_erfcf, &SAMPLES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_erfcf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_erfcf, reference, 1);
+    }
+}
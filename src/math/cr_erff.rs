@@ -0,0 +1,149 @@
+use super::crf32::round_approx;
+use super::erf;
+use super::soft_math;
+
+/* the relative error of erf, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Error function, correctly rounded (f32)
+///
+/// Calculates the error function of `x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_erff(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(erf(x), EPS, || soft_math::erf(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::erf;
+    use super::cr_erff;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+    /* the 40 floats nearest to halfway between two floats in a comparison
+     * of all of them with MPFR, with their correctly rounded results */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 40] = [
+        (0x00aa320c, 0x00c00b88), (0x012a320c, 0x01400b88), (0x01aa320c, 0x01c00b88),
+        (0x022a320c, 0x02400b88), (0x02aa320c, 0x02c00b88), (0x032a320c, 0x03400b88),
+        (0x03aa320c, 0x03c00b88), (0x042a320c, 0x04400b88), (0x04aa320c, 0x04c00b88),
+        (0x052a320c, 0x05400b88), (0x05aa320c, 0x05c00b88), (0x062a320c, 0x06400b88),
+        (0x06aa320c, 0x06c00b88), (0x072a320c, 0x07400b88), (0x07aa320c, 0x07c00b88),
+        (0x082a320c, 0x08400b88), (0x08aa320c, 0x08c00b88), (0x092a320c, 0x09400b88),
+        (0x09aa320c, 0x09c00b88), (0x0a2a320c, 0x0a400b88), (0x0aaa320c, 0x0ac00b88),
+        (0x0b2a320c, 0x0b400b88), (0x0baa320c, 0x0bc00b88), (0x0c2a320c, 0x0c400b88),
+        (0x0caa320c, 0x0cc00b88), (0x0d2a320c, 0x0d400b88), (0x0daa320c, 0x0dc00b88),
+        (0x0e2a320c, 0x0e400b88), (0x0eaa320c, 0x0ec00b88), (0x0f2a320c, 0x0f400b88),
+        (0x0faa320c, 0x0fc00b88), (0x102a320c, 0x10400b88), (0x358e9d2d, 0x35a0ec32),
+        (0x36aea466, 0x36c51007), (0x3940ead6, 0x3959af14), (0x39c0057d, 0x39d8ac49),
+        (0xb58e9d2d, 0xb5a0ec32), (0xb6aea466, 0xb6c51007), (0xb940ead6, 0xb959af14),
+        (0xb9c0057d, 0xb9d8ac49),
+    ];
+
+    /* the 12 floats nearest to halfway between two floats among every 31st
+     * float of 0.5 <= x < 3.9, where erf turns from linear to +-1, in a
+     * comparison with MPFR 4.2.0, and their negatives, with their correctly
+     * rounded results */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const MIDDLE_CASES: [(u32, u32); 24] = [
+        (0x3f043a75, 0x3f08eed3), (0x3f087e1e, 0x3f0c9608), (0x3f22133b, 0x3f212040),
+        (0x3f827a04, 0x3f59bf7d), (0x3fa9db12, 0x3f707ed1), (0x3fbbed75, 0x3f764e8f),
+        (0x3fdd837a, 0x3f7c5100), (0x3fdfa59c, 0x3f7c8ced), (0x3fe506cf, 0x3f7d155b),
+        (0x3ffc873b, 0x3f7ea6a6), (0x400f5078, 0x3f7f9b01), (0x40303a4b, 0x3f7ff98b),
+        (0xbf043a75, 0xbf08eed3), (0xbf087e1e, 0xbf0c9608), (0xbf22133b, 0xbf212040),
+        (0xbf827a04, 0xbf59bf7d), (0xbfa9db12, 0xbf707ed1), (0xbfbbed75, 0xbf764e8f),
+        (0xbfdd837a, 0xbf7c5100), (0xbfdfa59c, 0xbf7c8ced), (0xbfe506cf, 0xbf7d155b),
+        (0xbffc873b, 0xbf7ea6a6), (0xc00f5078, 0xbf7f9b01), (0xc0303a4b, 0xbf7ff98b),
+    ];
+
+    /* 96 floats spread evenly over the bit patterns of 0 < |x| < 4, where
+     * erf is not +-1, with their correctly rounded results from MPFR 4.2.0,
+     * as a reference independent of the double function that is rounded */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const SAMPLES: [(u32, u32); 96] = [
+        (0x00ac0000, 0x00c214cb), (0x0203ffff, 0x0214f22f), (0x035bfffe, 0x03783e4e),
+        (0x04b3fffd, 0x04cb1bb3), (0x060bfffc, 0x061df917), (0x0763fffb, 0x0780a29b),
+        (0x08bbfffa, 0x08d4229b), (0x0a13fff9, 0x0a270000), (0x0b6bfff8, 0x0b85260f),
+        (0x0cc3fff7, 0x0cdd2983), (0x0e1bfff6, 0x0e3006e8), (0x0f73fff5, 0x0f89a984),
+        (0x10cbfff4, 0x10e6306c), (0x1223fff3, 0x12390dd0), (0x137bfff2, 0x138e2cf8),
+        (0x14d3fff1, 0x14ef3754), (0x162bfff0, 0x164214b9), (0x1783ffef, 0x1794f21d),
+        (0x18dbffee, 0x18f83e3c), (0x1a33ffed, 0x1a4b1ba1), (0x1b8bffec, 0x1b9df905),
+        (0x1ce3ffeb, 0x1d00a292), (0x1e3bffea, 0x1e542289), (0x1f93ffe9, 0x1fa6ffee),
+        (0x20ebffe8, 0x21052606), (0x2243ffe7, 0x225d2971), (0x239bffe6, 0x23b006d6),
+        (0x24f3ffe5, 0x2509a97b), (0x264bffe4, 0x2666305a), (0x27a3ffe3, 0x27b90dbe),
+        (0x28fbffe2, 0x290e2cef), (0x2a53ffe1, 0x2a6f3742), (0x2babffe0, 0x2bc214a7),
+        (0x2d03ffdf, 0x2d14f20b), (0x2e5bffde, 0x2e783e2a), (0x2fb3ffdd, 0x2fcb1b8f),
+        (0x310bffdc, 0x311df8f3), (0x3263ffdb, 0x3280a289), (0x33bbffda, 0x33d42277),
+        (0x3513ffd9, 0x3526ffdc), (0x366bffd8, 0x368525fd), (0x37c3ffd7, 0x37dd295f),
+        (0x391bffd6, 0x393006c4), (0x3a73ffd5, 0x3a89a96f), (0x3bcbffd4, 0x3be62f85),
+        (0x3d23ffd3, 0x3d38f45f), (0x3e7bffd2, 0x3e8b5b4d), (0x3fd3ffd1, 0x3f7b17ed),
+        (0x80abffd1, 0x80c21496), (0x8203ffd0, 0x8214f1fa), (0x835bffcf, 0x83783e19),
+        (0x84b3ffce, 0x84cb1b7e), (0x860bffcd, 0x861df8e2), (0x8763ffcc, 0x8780a281),
+        (0x88bbffcb, 0x88d42266), (0x8a13ffca, 0x8a26ffcb), (0x8b6bffc9, 0x8b8525f5),
+        (0x8cc3ffc8, 0x8cdd294e), (0x8e1bffc7, 0x8e3006b3), (0x8f73ffc6, 0x8f89a969),
+        (0x90cbffc5, 0x90e63037), (0x9223ffc4, 0x92390d9b), (0x937bffc3, 0x938e2cdd),
+        (0x94d3ffc2, 0x94ef371f), (0x962bffc1, 0x96421484), (0x9783ffc0, 0x9794f1e8),
+        (0x98dbffbf, 0x98f83e07), (0x9a33ffbe, 0x9a4b1b6c), (0x9b8bffbd, 0x9b9df8d0),
+        (0x9ce3ffbc, 0x9d00a278), (0x9e3bffbb, 0x9e542254), (0x9f93ffba, 0x9fa6ffb9),
+        (0xa0ebffb9, 0xa10525ec), (0xa243ffb8, 0xa25d293c), (0xa39bffb7, 0xa3b006a1),
+        (0xa4f3ffb6, 0xa509a960), (0xa64bffb5, 0xa6663025), (0xa7a3ffb4, 0xa7b90d89),
+        (0xa8fbffb3, 0xa90e2cd4), (0xaa53ffb2, 0xaa6f370d), (0xababffb1, 0xabc21471),
+        (0xad03ffb0, 0xad14f1d6), (0xae5bffaf, 0xae783df5), (0xafb3ffae, 0xafcb1b5a),
+        (0xb10bffad, 0xb11df8be), (0xb263ffac, 0xb280a26f), (0xb3bbffab, 0xb3d42242),
+        (0xb513ffaa, 0xb526ffa7), (0xb66bffa9, 0xb68525e3), (0xb7c3ffa8, 0xb7dd292a),
+        (0xb91bffa7, 0xb930068f), (0xba73ffa6, 0xba89a954), (0xbbcbffa5, 0xbbe62f50),
+        (0xbd23ffa4, 0xbd38f42a), (0xbe7bffa3, 0xbe8b5b34), (0xbfd3ffa2, 0xbf7b17e6),
+    ];
+
+    /* erf in double precision, evaluated independently of cr_erff */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(erf(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_erff(f32::NAN).is_nan());
+        assert_eq!(cr_erff(1.0 / 0.0), 1.0);
+        assert_eq!(cr_erff(-1.0 / 0.0), -1.0);
+        assert_eq!(cr_erff(-0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(cr_erff(4.0), 1.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where erff is an ulp off */
+        assert_eq!(cr_erff(6.3183e-41), 7.1294e-41);
+        assert_eq!(cr_erff(1.14878e-40), 1.29627e-40);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_erff, &HARD_CASES);
+    }
+
+    #[test]
+    fn middle_cases() {
+        check_cases(cr_erff, &MIDDLE_CASES);
+    }
+
+    #[test]
+    fn samples() {
+        check_cases(cr_erff, &SAMPLES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_erff, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_erff, reference, 1);
+    }
+}
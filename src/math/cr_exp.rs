@@ -0,0 +1,62 @@
+use super::double_double::{add, mul, round};
+use super::k_crexp::k_crexp;
+use super::scalbn;
+use super::soft_float::Soft;
+use super::soft_math;
+
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 8.470329472543003e-22; /* 0x1p-70 */
+
+/// Exponential, base *e*, correctly rounded (f64)
+///
+/// Calculates Euler's number raised to `x`, rounded to nearest. Unlike
+/// [`exp`](fn.exp.html), the result is always the double nearest to the
+/// exact value.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.79 {
+        return x * f64::MAX;
+    }
+    if x < -745.14 {
+        /* below half the least subnormal */
+        return 0.0;
+    }
+    if x > -708.0 {
+        let (m, t, p) = k_crexp(x, 0.0);
+        let (h, l) = add(t, mul(t, p));
+        if let Some(y) = round(h, l, EPS) {
+            return scalbn(y, m);
+        }
+    }
+    soft_math::exp(Soft::from_f64(x)).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_exp;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_exp(f64::NAN).is_nan());
+        assert_eq!(cr_exp(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_exp(-1.0 / 0.0), 0.0);
+        assert_eq!(cr_exp(0.0), 1.0);
+        assert_eq!(cr_exp(-0.0), 1.0);
+        assert_eq!(cr_exp(710.0), 1.0 / 0.0);
+        assert_eq!(cr_exp(-746.0), 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* e = 2.71828182845904523536... */
+        assert_eq!(cr_exp(1.0).to_bits(), 0x4005bf0a8b145769);
+        /* the largest and least results */
+        assert_eq!(cr_exp(709.782712893384), 1.7976931348622732e308);
+        assert_eq!(cr_exp(-745.1332191019411), 5e-324);
+        assert_eq!(cr_exp(-740.0), 4.2e-322);
+    }
+}
@@ -0,0 +1,72 @@
+use super::cr_pow;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// Exponential, base 10, correctly rounded (f32)
+///
+/// Calculates `10^x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_exp10f(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_pow(10.0, x), || soft_math::exp10(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::pow;
+    use super::cr_exp10f;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 4] = [
+        (0x417d7f60, 0x59c64406), (0xb25e5bd9, 0x3f7fffff), (0xb326c4e3, 0x3f7ffffe),
+        (0xbac4c65c, 0x3f7f1dd8),
+    ];
+
+    /* pow in double precision, evaluated independently of cr_exp10f */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(pow(10.0, x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_exp10f(f32::NAN).is_nan());
+        assert_eq!(cr_exp10f(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_exp10f(-1.0 / 0.0).to_bits(), (0.0_f32).to_bits());
+        assert_eq!(cr_exp10f(0.0), 1.0);
+        assert_eq!(cr_exp10f(3.0), 1000.0);
+        assert_eq!(cr_exp10f(10.0), 1e10);
+        assert_eq!(cr_exp10f(39.0), 1.0 / 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where exp10f is an ulp off */
+        assert_eq!(cr_exp10f(0.0008318251), 1.0019172);
+        assert_eq!(cr_exp10f(0.0012601885), 1.002906);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_exp10f, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_exp10f, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 21 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_exp10f, reference, 1);
+    }
+}
@@ -0,0 +1,63 @@
+use super::double_double::{add, mul, round, two_prod};
+use super::k_crexp::k_crexp;
+use super::scalbn;
+use super::soft_math;
+
+/* ln2 as a double-double */
+const LN2_HI: f64 = 6.931471805599453e-01; /* 0x3fe62e42, 0xfefa39ef */
+const LN2_LO: f64 = 2.3190468138462996e-17; /* 0x3c7abc9e, 0x3b39803f */
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 8.470329472543003e-22; /* 0x1p-70 */
+
+/// Exponential, base 2, correctly rounded (f64)
+///
+/// Calculates `2^x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_exp2(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x >= 1024.0 {
+        return x * f64::MAX;
+    }
+    if x <= -1075.0 {
+        /* at most half the least subnormal, which rounds to even */
+        return 0.0;
+    }
+    if x > -1022.0 {
+        /* x*ln2 to about 2^-96 absolute */
+        let (ph, pl) = two_prod(x, LN2_HI);
+        let (m, t, p) = k_crexp(ph, pl + x * LN2_LO);
+        let (h, l) = add(t, mul(t, p));
+        if let Some(y) = round(h, l, EPS) {
+            return scalbn(y, m);
+        }
+    }
+    soft_math::exp2(x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_exp2;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_exp2(f64::NAN).is_nan());
+        assert_eq!(cr_exp2(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_exp2(-1.0 / 0.0), 0.0);
+        assert_eq!(cr_exp2(1024.0), 1.0 / 0.0);
+        assert_eq!(cr_exp2(-1075.0), 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_exp2(0.0), 1.0);
+        assert_eq!(cr_exp2(-3.0), 0.125);
+        assert_eq!(cr_exp2(1023.0), 8.98846567431158e307);
+        assert_eq!(cr_exp2(-1074.0), 5e-324);
+        /* sqrt(2) = 1.41421356237309504880... */
+        assert_eq!(cr_exp2(0.5).to_bits(), 0x3ff6a09e667f3bcd);
+        assert_eq!(cr_exp2(-1074.5), 5e-324);
+    }
+}
@@ -0,0 +1,75 @@
+use super::cr_exp2;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// Exponential, base 2, correctly rounded (f32)
+///
+/// Calculates `2^x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_exp2f(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_exp2(x), || soft_math::exp2(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::exp2;
+    use super::cr_exp2f;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 17] = [
+        (0x33b8aa3b, 0x3f800001), (0x36879cf7, 0x3f800018), (0x3a07857c, 0x3f800bbe),
+        (0x3b429d37, 0x3f804385), (0x3c02a9ad, 0x3f80b5a3), (0x3dc9abe2, 0x3f890ab5),
+        (0xb338aa3b, 0x3f800000), (0xb466d4cb, 0x3f7ffffe), (0xb5160a52, 0x3f7ffff9),
+        (0xb52d1f9a, 0x3f7ffff8), (0xb8bbd3a2, 0x3f7ffbee), (0xb8d3d026, 0x3f7ffb69),
+        (0xbaec2b40, 0x3f7fae34), (0xbcaf4d02, 0x3f7c3b19), (0xbcf3a937, 0x3f7ac6b1),
+        (0xbe1f29de, 0x3f65da56), (0xc3160000, 0x00000000),
+    ];
+
+    /* exp2 in double precision, evaluated independently of cr_exp2f */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(exp2(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_exp2f(f32::NAN).is_nan());
+        assert_eq!(cr_exp2f(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_exp2f(-1.0 / 0.0).to_bits(), (0.0_f32).to_bits());
+        assert_eq!(cr_exp2f(-149.0), 1e-45);
+        assert_eq!(cr_exp2f(-10.0), 0.0009765625);
+        assert_eq!(cr_exp2f(128.0), 1.0 / 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where exp2f is an ulp off */
+        assert_eq!(cr_exp2f(0.027645266), 1.019347);
+        assert_eq!(cr_exp2f(0.028057555), 1.0196383);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_exp2f, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_exp2f, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_exp2f, reference, 1);
+    }
+}
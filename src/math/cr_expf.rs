@@ -0,0 +1,76 @@
+use super::cr_exp;
+use super::crf32::round_cr;
+use super::soft_float::Soft;
+use super::soft_math;
+
+/// Exponential, base *e*, correctly rounded (f32)
+///
+/// Calculates Euler's number raised to `x`, rounded to nearest. Unlike
+/// [`expf`](fn.expf.html), the result is always the float nearest to the
+/// exact value.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_expf(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_exp(x), || soft_math::exp(Soft::from_f64(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::exp;
+    use super::cr_expf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 10] = [
+        (0x377eff81, 0x3f800080), (0x383a3ef1, 0x3f800175), (0x38e69cc1, 0x3f80039a),
+        (0x39c6be5b, 0x3f800c6d), (0x4001b249, 0x40f2cd14), (0x40315b33, 0x417fa47d),
+        (0xb3000000, 0x3f800000), (0xbae0e25c, 0x3f7f8fa7), (0xbbf0edf1, 0x3f7e1fe9),
+        (0xc16912cd, 0x34fd331b),
+    ];
+
+    /* exp in double precision, evaluated independently of cr_expf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(exp(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_expf(f32::NAN).is_nan());
+        assert_eq!(cr_expf(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_expf(-1.0 / 0.0).to_bits(), (0.0_f32).to_bits());
+        assert_eq!(cr_expf(0.0), 1.0);
+        assert_eq!(cr_expf(89.0), 1.0 / 0.0);
+        assert_eq!(cr_expf(-104.0).to_bits(), (0.0_f32).to_bits());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where expf is an ulp off */
+        assert_eq!(cr_expf(1.3291805e-5), 1.0000134);
+        assert_eq!(cr_expf(2.1397927e-5), 1.0000215);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_expf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_expf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_expf, reference, 1);
+    }
+}
@@ -0,0 +1,60 @@
+use super::double_double::round;
+use super::k_crexp::k_crexpm1;
+use super::soft_math;
+
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 5.421010862427522e-20; /* 0x1p-64 */
+
+/// Exponential, base *e*, of x-1, correctly rounded (f64)
+///
+/// Calculates the exponential of `x` and subtracts 1, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_expm1(x: f64) -> f64 {
+    let x1p_54 = f64::from_bits(0x3c90000000000000); // 0x1p-54 === 2 ^ -54
+
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.79 {
+        return x * f64::MAX;
+    }
+    if x < -40.0 {
+        /* exp(x) is far below half an ulp of -1 */
+        return -1.0;
+    }
+    if x.abs() < x1p_54 {
+        /* expm1(x) = x + x^2/2 + ..., within half an ulp of x */
+        return x;
+    }
+    if x < 709.0 {
+        let (h, l) = k_crexpm1(x);
+        if let Some(y) = round(h, l, EPS) {
+            return y;
+        }
+    }
+    soft_math::expm1(x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_expm1;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_expm1(f64::NAN).is_nan());
+        assert_eq!(cr_expm1(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_expm1(-1.0 / 0.0), -1.0);
+        assert_eq!(cr_expm1(-0.0).to_bits(), 0x8000000000000000);
+        assert_eq!(cr_expm1(5e-324), 5e-324);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* e - 1 = 1.71828182845904523536... */
+        assert_eq!(cr_expm1(1.0).to_bits(), 0x3ffb7e151628aed3);
+        assert_eq!(cr_expm1(1e-10), 1.00000000005e-10);
+        assert_eq!(cr_expm1(-1e-5), -9.999950000166666e-06);
+        assert_eq!(cr_expm1(709.5), 1.3549863193146328e308);
+    }
+}
@@ -0,0 +1,74 @@
+use super::cr_expm1;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// Exponential, base *e*, of x-1, correctly rounded (f32)
+///
+/// Calculates the exponential of `x` and subtracts 1, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_expm1f(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_expm1(x), || soft_math::expm1(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::expm1;
+    use super::cr_expm1f;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 13] = [
+        (0x33b504f3, 0x33b504f3), (0x34ca62c1, 0x34ca62c3), (0x36322b1b, 0x36322b2a),
+        (0x37b61385, 0x37b61407), (0x3a254e7a, 0x3a255bd3), (0x3b643f1b, 0x3b64a4fa),
+        (0x3dc252dd, 0x3dcbd76b), (0x4001b249, 0x40d2cd14), (0x40315b33, 0x416fa47d),
+        (0xb3800000, 0xb3800000), (0xb675cbfc, 0xb675cbdf), (0xbb7b3b6c, 0xbb7ac04e),
+        (0xc169ee19, 0xbf7ffff8),
+    ];
+
+    /* expm1 in double precision, evaluated independently of cr_expm1f */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(expm1(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_expm1f(f32::NAN).is_nan());
+        assert_eq!(cr_expm1f(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_expm1f(-1.0 / 0.0), -1.0);
+        assert_eq!(cr_expm1f(-0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(cr_expm1f(-20.0), -1.0);
+        assert_eq!(cr_expm1f(89.0), 1.0 / 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where expm1f is an ulp off */
+        assert_eq!(cr_expm1f(0.001763143), 0.0017646983);
+        assert_eq!(cr_expm1f(0.0029312335), 0.0029355336);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_expm1f, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_expm1f, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_expm1f, reference, 1);
+    }
+}
@@ -0,0 +1,102 @@
+use super::crf32::round_approx;
+use super::hypot;
+use super::soft_math;
+
+/* the relative error of hypot, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// The length of the hypotenuse, correctly rounded (f32)
+///
+/// Calculates `sqrt(x*x + y*y)` without undue overflow or underflow,
+/// rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_hypotf(x: f32, y: f32) -> f32 {
+    let (x, y) = (x as f64, y as f64);
+    round_approx(hypot(x, y), EPS, || soft_math::hypot(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check2, check2_sweep, nearest};
+    use super::super::soft_float::{add, cmp, mul, Soft};
+    use super::super::sqrt;
+    use super::cr_hypotf;
+
+    /* the float nearest to sqrt(x^2 + y^2), found by comparing the exact
+     * sum with the squares of the halfway points around its square root */
+    fn reference(x: f32, y: f32) -> Option<f32> {
+        let (x, y) = (x as f64, y as f64);
+        if !x.is_finite() || !y.is_finite() {
+            return None;
+        }
+        if x == 0.0 && y == 0.0 {
+            return Some(0.0);
+        }
+        let (a, b) = (Soft::from_f64(x), Soft::from_f64(y));
+        let s = add(mul(a, a), mul(b, b));
+        let r = nearest((sqrt(x * x + y * y) as f32).min(f32::MAX), |m| {
+            let m = Soft::from_f64(m);
+            cmp(s, mul(m, m)).unwrap()
+        });
+        Some(r)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_hypotf(f32::NAN, 1.0).is_nan());
+        assert_eq!(cr_hypotf(f32::NAN, -1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_hypotf(-0.0, 0.0), 0.0);
+        assert_eq!(cr_hypotf(-3.0, 4.0), 5.0);
+        assert_eq!(cr_hypotf(3e38, 3e38), 1.0 / 0.0);
+        assert_eq!(cr_hypotf(1e-45, 1e-45), 1e-45);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_hypotf(0.9772651, 0.8482821), 1.2940748);
+        assert_eq!(cr_hypotf(0.08489776, 2.9353883), 2.9366157);
+    }
+
+    #[test]
+    fn pythagorean_triples() {
+        /* exact results, and results exactly halfway between two floats
+         * from triples (a, b, c) with odd c between 2^24 and 2^25, scaled
+         * by powers of 2 */
+        let cases = [
+            (3.0, 4.0, 5.0),
+            (20.0, 21.0, 29.0),
+            (119.0, 120.0, 169.0),
+            (4059.0, 4060.0, 5741.0),
+            (388131.0, 16777180.0, 16781668.0),
+            (14836401.0, 9402400.0, 17564848.0),
+            (16766505.0, 16772272.0, 23715496.0),
+        ];
+        for &(a, b, c) in cases.iter() {
+            for &e in [0, -100, 100, -126].iter() {
+                let s = f32::from_bits(((127 + e) << 23) as u32);
+                let (a, b, c): (f32, f32, f32) = (a * s, b * s, c * s);
+                assert_eq!(cr_hypotf(a, b), c, "{:?}, {:?}", a, b);
+                assert_eq!(cr_hypotf(-b, a), c, "{:?}, {:?}", b, a);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_second() {
+        let ys = [1.0, -3.0, 0.75, 1e-45, 1e-20, 7.5e18, 3e38];
+        check2_sweep(cr_hypotf, reference, &ys, 65_537);
+    }
+
+    #[test]
+    fn sampled() {
+        check2(cr_hypotf, reference, 4096);
+    }
+
+    /* random pairs, as all of them would take far too long */
+    #[test]
+    #[ignore]
+    fn random() {
+        check2(cr_hypotf, reference, 1 << 28);
+    }
+}
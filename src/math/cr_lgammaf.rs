@@ -0,0 +1,171 @@
+use super::crf32::round_approx;
+use super::lgamma;
+use super::soft_math;
+
+/* the relative error of lgamma, with a margin, and for negative x, where
+ * it subtracts terms of up to 2^7 times a result of magnitude at least 1 */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+const EPS_NEG: f64 = 3.637978807091713e-12; /* 0x1p-38 */
+
+/// The logarithm of the absolute value of the gamma function, correctly
+/// rounded (f32)
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_lgammaf(x: f32) -> f32 {
+    let x = x as f64;
+    let y = lgamma(x);
+    if x < 0.0 && y.abs() < 1.0 {
+        /* near the zeros of lgamma, where the cancellation is worse */
+        return soft_math::lgamma(x).to_f32();
+    }
+    let eps = if x < 0.0 { EPS_NEG } else { EPS };
+    round_approx(y, eps, || soft_math::lgamma(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::{fabs, lgamma_r, log};
+    use super::cr_lgammaf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+    /* the 40 floats nearest to halfway between two floats in a comparison
+     * of all of them with MPFR, with their correctly rounded results */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 40] = [
+        (0x064cb44b, 0x429e7c1a), (0x07c060fa, 0x429a733a), (0x0dc8bba4, 0x4289bac4),
+        (0x0f61ff63, 0x42855565), (0x111c87f8, 0x428085df), (0x145cb6d4, 0x426f092f),
+        (0x16c5ee7a, 0x42619bd4), (0x1a8446cb, 0x424d0a45), (0x1f116ab8, 0x4233b53e),
+        (0x2423c085, 0x421781d1), (0x28e3fa26, 0x41fa75df), (0x29bd4f07, 0x41f0db65),
+        (0x2caa0a85, 0x41d071d6), (0x3b7c53aa, 0x40b1d661), (0x3bd9a107, 0x40a056fc),
+        (0x42bc1c3d, 0x43a5fbed), (0x5ba4c7ca, 0x5e4407fb), (0x5dc98cd3, 0x608140c0),
+        (0x5e961867, 0x6145a15e), (0x65fca09f, 0x68cead5a), (0x67b98485, 0x6a9eea75),
+        (0x6b9ce710, 0x6e93967c), (0x7500b6fc, 0x78132970), (0x77ac5674, 0x7acf27b3),
+        (0x7b54ff19, 0x7e8870c3), (0x864cb44b, 0x429e7c1a), (0x87c060fa, 0x429a733a),
+        (0x8dc8bba4, 0x4289bac4), (0x8f61ff63, 0x42855565), (0x911c87f8, 0x428085df),
+        (0x945cb6d4, 0x426f092f), (0x96c5ee7a, 0x42619bd4), (0x9a8446cb, 0x424d0a45),
+        (0x9f116ab8, 0x4233b53e), (0xa423c085, 0x421781d1), (0xb0d6f2ca, 0x41a23559),
+        (0xb90f6e0d, 0x410e5ad5), (0xc18458a0, 0xc1f865f3), (0xc33807b8, 0xc441eead),
+        (0xc36c073c, 0xc483b679),
+    ];
+
+    /* the 6 floats nearest to halfway between two floats within 131072
+     * floats of each of the zeros at 1 and 2, and the float nearest to
+     * halfway within 16384 floats of each zero -9 < x < -2 and the float
+     * nearest to that zero, in a comparison with MPFR 4.2.0, with their
+     * correctly rounded results */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const ZERO_CASES: [(u32, u32); 37] = [
+        (0x3f7e646c, 0x3b6fb3a8), (0x3f7e7248, 0x3b678f7e), (0x3f7e9cb3, 0x3b4eac74),
+        (0x3f7f4de8, 0x3ace64e8), (0x3f810397, 0xbb942788), (0x3f813207, 0xbbae4dc3),
+        (0x3ffe472e, 0xbbb87447), (0x3ffeb1b1, 0xbb8c3cfd), (0x3fffbdae, 0xba5ff794),
+        (0x4000609c, 0x3b241d15), (0x4001363b, 0x3c050c20), (0x4001ee60, 0x3c55cb59),
+        (0xc01d3fe5, 0xb3f26792), (0xc01d7c17, 0xbbb45245), (0xc02f9dbc, 0xbbe2a566),
+        (0xc02fda08, 0xb41ad05c), (0xc0490df2, 0x3c871f07), (0xc049306e, 0xb4528315),
+        (0xc07ce8ff, 0xbd916a96), (0xc07d238b, 0x35eea5aa), (0xc0812410, 0x3dd54ae6),
+        (0xc0814274, 0xb64cf2d6), (0xc09fa652, 0xbe8206f0), (0xc09fbabc, 0x37222e78),
+        (0xc0a04353, 0xb7789067), (0xc0a07a65, 0xbf1be592), (0xc0bfe65f, 0xbf4e6218),
+        (0xc0bff498, 0x38e9761e), (0xc0c00b59, 0x387a77d6), (0xc0dffe60, 0x3a28d663),
+        (0xc0e001a0, 0xb9232834), (0xc0e02ba6, 0xc0534565), (0xc0ffffcc, 0x399bd830),
+        (0xc100001a, 0x39486027), (0xc1001324, 0xc0a7f4af), (0xc10ffffd, 0xbd198ed1),
+        (0xc11034d9, 0xc107af28),
+    ];
+
+    /* 96 floats spread evenly over the bit patterns of all nonzero finite
+     * floats, with their correctly rounded results from MPFR 4.2.0, as a
+     * reference independent of the double function that is rounded */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const SAMPLES: [(u32, u32); 96] = [
+        (0x01540000, 0x42ac4716), (0x03fbffff, 0x42a50022), (0x06a3fffe, 0x429d8ab9),
+        (0x094bfffd, 0x42962c85), (0x0bf3fffc, 0x428ee264), (0x0e9bfffb, 0x42877611),
+        (0x1143fffa, 0x428012be), (0x13ebfff9, 0x42718a65), (0x1693fff8, 0x4262c584),
+        (0x193bfff7, 0x4253f3a2), (0x1be3fff6, 0x4245512f), (0x1e8bfff5, 0x4236a1e6),
+        (0x2133fff4, 0x4227c3a3), (0x23dbfff3, 0x4219193d), (0x2683fff2, 0x420a81a1),
+        (0x292bfff1, 0x41f72b56), (0x2bd3fff0, 0x41d9c54a), (0x2e7bffef, 0x41bca97b),
+        (0x3123ffee, 0x419ed3d5), (0x33cbffed, 0x41815b05), (0x3673ffec, 0x414864ff),
+        (0x391bffeb, 0x410d0210), (0x3bc3ffea, 0x40a3b395), (0x3e6bffe9, 0x3fafe09e),
+        (0x4113ffe8, 0x41324b2a), (0x43bbffe7, 0x44e76f36), (0x4663ffe6, 0x47f4c1a8),
+        (0x490bffe5, 0x4ad689ee), (0x4bb3ffe4, 0x4db3bc16), (0x4e5bffe3, 0x50870b5f),
+        (0x5103ffe2, 0x53402640), (0x53abffe1, 0x56113d46), (0x5653ffe0, 0x58cb5c99),
+        (0x58fbffdf, 0x5b87315d), (0x5ba3ffde, 0x5e4313e9), (0x5e4bffdd, 0x61051255),
+        (0x60f3ffdc, 0x63ad0f52), (0x639bffdb, 0x666f6218), (0x6643ffda, 0x6921b1d6),
+        (0x68ebffd9, 0x6bd0288a), (0x6b93ffd8, 0x6e8b13fe), (0x6e3bffd7, 0x713b8cdb),
+        (0x70e3ffd6, 0x73f07d0f), (0x738bffd5, 0x769bb2e3), (0x7633ffd4, 0x7952a374),
+        (0x78dbffd3, 0x7c070675), (0x7b83ffd2, 0x7ea98dd2), (0x7e2bffd1, 0x7f800000),
+        (0x8153ffd1, 0x42ac4717), (0x83fbffd0, 0x42a50023), (0x86a3ffcf, 0x429d8aba),
+        (0x894bffce, 0x42962c86), (0x8bf3ffcd, 0x428ee264), (0x8e9bffcc, 0x42877612),
+        (0x9143ffcb, 0x428012be), (0x93ebffca, 0x42718a66), (0x9693ffc9, 0x4262c586),
+        (0x993bffc8, 0x4253f3a3), (0x9be3ffc7, 0x42455130), (0x9e8bffc6, 0x4236a1e7),
+        (0xa133ffc5, 0x4227c3a4), (0xa3dbffc4, 0x4219193e), (0xa683ffc3, 0x420a81a2),
+        (0xa92bffc2, 0x41f72b58), (0xabd3ffc1, 0x41d9c54c), (0xae7bffc0, 0x41bca97c),
+        (0xb123ffbf, 0x419ed3d7), (0xb3cbffbe, 0x41815b07), (0xb673ffbd, 0x41486507),
+        (0xb91bffbc, 0x410d02c9), (0xbbc3ffbb, 0x40a3ec2e), (0xbe6bffba, 0x3fd33993),
+        (0xc113ffb9, 0xc13e058b), (0xc3bbffb8, 0xc4e768d4), (0xc663ffb7, 0xc7f4c4e8),
+        (0xc90bffb6, 0xcad689b9), (0xcbb3ffb5, 0x7f800000), (0xce5bffb4, 0x7f800000),
+        (0xd103ffb3, 0x7f800000), (0xd3abffb2, 0x7f800000), (0xd653ffb1, 0x7f800000),
+        (0xd8fbffb0, 0x7f800000), (0xdba3ffaf, 0x7f800000), (0xde4bffae, 0x7f800000),
+        (0xe0f3ffad, 0x7f800000), (0xe39bffac, 0x7f800000), (0xe643ffab, 0x7f800000),
+        (0xe8ebffaa, 0x7f800000), (0xeb93ffa9, 0x7f800000), (0xee3bffa8, 0x7f800000),
+        (0xf0e3ffa7, 0x7f800000), (0xf38bffa6, 0x7f800000), (0xf633ffa5, 0x7f800000),
+        (0xf8dbffa4, 0x7f800000), (0xfb83ffa3, 0x7f800000), (0xfe2bffa2, 0x7f800000),
+    ];
+
+    /* lgamma in double precision, evaluated independently of cr_lgammaf,
+     * except for negative x where its reflection formula cancels by more
+     * than ERR allows for */
+    fn reference(x: f32) -> Option<f32> {
+        let x = x as f64;
+        let y = lgamma_r(x).0;
+        if x < 0.0 && 256.0 * fabs(y) < fabs(x) * (fabs(log(fabs(x))) + 1.0) + 1.0 {
+            return None;
+        }
+        rounded(y, ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_lgammaf(f32::NAN).is_nan());
+        assert_eq!(cr_lgammaf(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_lgammaf(-1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_lgammaf(0.0), 1.0 / 0.0);
+        assert_eq!(cr_lgammaf(-3.0), 1.0 / 0.0);
+        assert_eq!(cr_lgammaf(1.0), 0.0);
+        assert_eq!(cr_lgammaf(2.0), 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where lgammaf is an ulp off */
+        assert_eq!(cr_lgammaf(1.3389083e-38), 87.20638);
+        assert_eq!(cr_lgammaf(1.4549355e-38), 87.12327);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_lgammaf, &HARD_CASES);
+    }
+
+    #[test]
+    fn zero_cases() {
+        check_cases(cr_lgammaf, &ZERO_CASES);
+    }
+
+    #[test]
+    fn samples() {
+        check_cases(cr_lgammaf, &SAMPLES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_lgammaf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 13 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_lgammaf, reference, 1);
+    }
+}
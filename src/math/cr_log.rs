@@ -0,0 +1,57 @@
+use super::double_double::round;
+use super::k_crlog::k_crlog;
+use super::soft_math;
+
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 5.421010862427522e-20; /* 0x1p-64 */
+
+/// The natural logarithm of `x`, correctly rounded (f64).
+///
+/// Unlike [`log`](fn.log.html), the result is always the double nearest to
+/// the exact value.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_log(x: f64) -> f64 {
+    if x.is_nan() || x == 1.0 / 0.0 {
+        return x;
+    }
+    if x == 0.0 {
+        return -1.0 / (x * x); /* log(+-0)=-inf */
+    }
+    if x < 0.0 {
+        return (x - x) / 0.0; /* log(-#) = NaN */
+    }
+    if x == 1.0 {
+        return 0.0;
+    }
+    let (h, l) = k_crlog(x);
+    if let Some(y) = round(h, l, EPS) {
+        return y;
+    }
+    soft_math::log(x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_log;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_log(f64::NAN).is_nan());
+        assert!(cr_log(-1.0).is_nan());
+        assert_eq!(cr_log(0.0), -1.0 / 0.0);
+        assert_eq!(cr_log(-0.0), -1.0 / 0.0);
+        assert_eq!(cr_log(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_log(1.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* ln2 = 0.69314718055994530942... */
+        assert_eq!(cr_log(2.0).to_bits(), 0x3fe62e42fefa39ef);
+        assert_eq!(cr_log(5e-324), -744.4400719213812);
+        assert_eq!(cr_log(f64::MAX), 709.782712893384);
+        assert_eq!(cr_log(1.0 + f64::EPSILON), 2.2204460492503128e-16);
+        assert_eq!(cr_log(0.1), -2.3025850929940455);
+    }
+}
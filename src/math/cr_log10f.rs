@@ -0,0 +1,89 @@
+use super::crf32::round_approx;
+use super::log10;
+use super::soft_math;
+
+/* the relative error of log10, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// The base 10 logarithm of `x`, correctly rounded (f32).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_log10f(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(log10(x), EPS, || soft_math::log10(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cr_log2;
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::cr_log10f;
+    use core::f64::consts::LOG10_2;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 52] = [
+        (0x0225f259, 0xc213a7de), (0x058ecd6a, 0xc20b7ce1), (0x0a4d4ce8, 0xc2000527),
+        (0x0ab667dd, 0xc1fe0af5), (0x0cb01343, 0xc1f48857), (0x0e10c607, 0xc1edfce8),
+        (0x0efeee7a, 0xc1e99d23), (0x10b88aec, 0xc1e11a7d), (0x120b93dc, 0xc1dad957),
+        (0x1266ada7, 0xc1d91a7d), (0x13ae78d3, 0xc1d2d957), (0x13b57a47, 0xc1d2b653),
+        (0x15964dc5, 0xc1c9bbed), (0x1944e975, 0xc1b7f01f), (0x1eac122f, 0xc19dea75),
+        (0x214a9d50, 0xc1914e94), (0x260b1d0e, 0xc1750fdb), (0x262ca79a, 0xc1738f9c),
+        (0x2846c871, 0xc15f50dc), (0x2b1b73f9, 0xc144201d), (0x2bb4e779, 0xc13e4161),
+        (0x2f149212, 0xc11de885), (0x33b5ebfc, 0xc0e24e31), (0x356366fb, 0xc0c24e31),
+        (0x3c695cc0, 0xbfec567c), (0x3f5fde1b, 0xbd6e9637), (0x40d6b904, 0x3f53a473),
+        (0x427a28e9, 0x3fe5e87d), (0x43079cce, 0x40087799), (0x45bdedc8, 0x407228d0),
+        (0x499271d9, 0x40c287b1), (0x4dff4ddc, 0x410ba8b4), (0x56f8c876, 0x41622d04),
+        (0x5735f401, 0x4164d18d), (0x5d610fe9, 0x41900c01), (0x5f3315a3, 0x4198e2cb),
+        (0x604df02c, 0x419e3014), (0x610567e4, 0x41a17eec), (0x62a6c1dd, 0x41a97eec),
+        (0x6307980c, 0x41ab2f6e), (0x63bf4495, 0x41aec9ea), (0x63ce2f36, 0x41af0cb5),
+        (0x64a97e0f, 0x41b32f6e), (0x65716a23, 0x41b6d28a), (0x65903d25, 0x41b770f4),
+        (0x6a581bf5, 0x41ce8525), (0x6f592c3c, 0x41e69e9c), (0x6ff93d5b, 0x41e9819d),
+        (0x711016a5, 0x41eed3bd), (0x721feeb7, 0x41f4018d), (0x73cb20d6, 0x41fc0fba),
+        (0x7f6362e7, 0x4219ebe5),
+    ];
+
+    /* log10(x) = log2(x)*log10(2) */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(cr_log2(x as f64) * LOG10_2, ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_log10f(f32::NAN).is_nan());
+        assert_eq!(cr_log10f(1.0 / 0.0), 1.0 / 0.0);
+        assert!(cr_log10f(-1.0).is_nan());
+        assert_eq!(cr_log10f(0.0), -1.0 / 0.0);
+        assert_eq!(cr_log10f(1.0), 0.0);
+        assert_eq!(cr_log10f(1e10), 10.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where log10f is an ulp off */
+        assert_eq!(cr_log10f(9.931242e-39), -38.002995);
+        assert_eq!(cr_log10f(3.8627866e-38), -37.413097);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_log10f, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_log10f, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 6 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_log10f, reference, 1);
+    }
+}
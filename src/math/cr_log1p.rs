@@ -0,0 +1,61 @@
+use super::double_double::{add, div, fast_two_sum, round, two_sum};
+use super::k_crlog::k_crlog;
+use super::soft_math;
+
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 5.421010862427522e-20; /* 0x1p-64 */
+
+/// The natural logarithm of 1+`x`, correctly rounded (f64).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_log1p(x: f64) -> f64 {
+    let x1p_54 = f64::from_bits(0x3c90000000000000); // 0x1p-54 === 2 ^ -54
+
+    if x.is_nan() || x == 1.0 / 0.0 {
+        return x;
+    }
+    if x == -1.0 {
+        return -1.0 / (x * x - 1.0); /* log1p(-1) = -inf */
+    }
+    if x < -1.0 {
+        return (x - x) / 0.0; /* log1p(x<-1) = NaN */
+    }
+    if x.abs() < x1p_54 {
+        /* log1p(x) = x - x^2/2 + ..., within half an ulp of x */
+        return x;
+    }
+    /* 1 + x = a + b exactly, log(a + b) = log(a) + log(1 + b/a), where
+     * |b/a| <= 2^-53 needs its square but not its cube */
+    let (a, b) = two_sum(1.0, x);
+    let (uh, ul) = div((b, 0.0), (a, 0.0));
+    let (h, l) = add(k_crlog(a), fast_two_sum(uh, ul - 0.5 * uh * uh));
+    if let Some(y) = round(h, l, EPS) {
+        return y;
+    }
+    soft_math::log1p(x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_log1p;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_log1p(f64::NAN).is_nan());
+        assert!(cr_log1p(-2.0).is_nan());
+        assert_eq!(cr_log1p(-1.0), -1.0 / 0.0);
+        assert_eq!(cr_log1p(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_log1p(-0.0).to_bits(), 0x8000000000000000);
+        assert_eq!(cr_log1p(5e-324), 5e-324);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* ln2 = 0.69314718055994530942... */
+        assert_eq!(cr_log1p(1.0).to_bits(), 0x3fe62e42fefa39ef);
+        assert_eq!(cr_log1p(1e-10), 9.999999999500001e-11);
+        assert_eq!(cr_log1p(-0.5), -0.6931471805599453);
+        assert_eq!(cr_log1p(1e300), 690.7755278982137);
+        assert_eq!(cr_log1p(3e-9), 2.9999999955e-09);
+    }
+}
@@ -0,0 +1,81 @@
+use super::cr_log1p;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// The natural logarithm of 1+`x`, correctly rounded (f32).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_log1pf(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_log1p(x), || soft_math::log1p(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::log1p;
+    use super::cr_log1pf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 43] = [
+        (0x35400003, 0x353fffff), (0x36dedace, 0x36deda9d), (0x3710001b, 0x370ffff3),
+        (0x3770004b, 0x376fffdb), (0x37c6e0e0, 0x37c6e046), (0x3cf58230, 0x3cf1e6ce),
+        (0x3ddbfec3, 0x3dd0f671), (0x3ebe9143, 0x3ea20160), (0x3efd81ad, 0x3ecdeee1),
+        (0x41078feb, 0x400fe5e7), (0x44bc2360, 0x40ea272e), (0x464d572b, 0x4117bcf1),
+        (0x4665a5a6, 0x41198725), (0x46ca6a75, 0x41229929), (0x50da4bf6, 0x41c0ce7f),
+        (0x542dfac1, 0x41e5ceba), (0x55185f82, 0x41efd648), (0x5588e13b, 0x41f4863e),
+        (0x5d800341, 0x42265b10), (0x5d8b2d5b, 0x4226b0b1), (0x5ee8984e, 0x422e4a21),
+        (0x5f64c24a, 0x4230fee2), (0x62b467ba, 0x42437434), (0x63b134d9, 0x4248ed72),
+        (0x64e27fa3, 0x424f7453), (0x65d890d3, 0x4254d1f9), (0x66a8c860, 0x42595e46),
+        (0x66abbd63, 0x4259700e), (0x6914cb96, 0x4266ba1e), (0x6d1f23eb, 0x427d2d35),
+        (0x6d6c9d3a, 0x427ec360), (0x6f31a8ec, 0x42845a89), (0x7405dee8, 0x4291a68d),
+        (0x79e7ec37, 0x42a1ffb7), (0x7d98b8f4, 0x42ac40f2), (0xb3b504f3, 0xb3b504f4),
+        (0xb53ffffd, 0xb5400001), (0xb619776c, 0xb6197777), (0xb70fffe5, 0xb710000d),
+        (0xb76fffb5, 0xb7700025), (0xb7c6e012, 0xb7c6e0ac), (0xba800b50, 0xba801b55),
+        (0xbb0ec8c4, 0xbb0ef0a5),
+    ];
+
+    /* log1p in double precision, evaluated independently of cr_log1pf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(log1p(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_log1pf(f32::NAN).is_nan());
+        assert_eq!(cr_log1pf(1.0 / 0.0), 1.0 / 0.0);
+        assert!(cr_log1pf(-2.0).is_nan());
+        assert_eq!(cr_log1pf(-1.0), -1.0 / 0.0);
+        assert_eq!(cr_log1pf(-0.0).to_bits(), (-0.0_f32).to_bits());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where log1pf is an ulp off */
+        assert_eq!(cr_log1pf(4.2862943e-5), 4.2862022e-5);
+        assert_eq!(cr_log1pf(0.0018433103), 0.0018416134);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_log1pf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_log1pf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_log1pf, reference, 1);
+    }
+}
@@ -0,0 +1,55 @@
+use super::double_double::{mul, round};
+use super::k_crlog::k_crlog;
+use super::soft_math;
+
+/* 1/ln2 as a double-double */
+const INVLN2_HI: f64 = 1.4426950408889634; /* 0x3ff71547, 0x652b82fe */
+const INVLN2_LO: f64 = 2.0355273740931033e-17; /* 0x3c7777d0, 0xffda0d24 */
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 5.421010862427522e-20; /* 0x1p-64 */
+
+/// The base 2 logarithm of `x`, correctly rounded (f64).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_log2(x: f64) -> f64 {
+    if x.is_nan() || x == 1.0 / 0.0 {
+        return x;
+    }
+    if x == 0.0 {
+        return -1.0 / (x * x); /* log(+-0)=-inf */
+    }
+    if x < 0.0 {
+        return (x - x) / 0.0; /* log(-#) = NaN */
+    }
+    if x == 1.0 {
+        return 0.0;
+    }
+    let (h, l) = mul(k_crlog(x), (INVLN2_HI, INVLN2_LO));
+    if let Some(y) = round(h, l, EPS) {
+        return y;
+    }
+    soft_math::log2(x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_log2;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_log2(f64::NAN).is_nan());
+        assert!(cr_log2(-1.0).is_nan());
+        assert_eq!(cr_log2(0.0), -1.0 / 0.0);
+        assert_eq!(cr_log2(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_log2(1.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_log2(8.0), 3.0);
+        assert_eq!(cr_log2(5e-324), -1074.0);
+        assert_eq!(cr_log2(0.5), -1.0);
+        assert_eq!(cr_log2(3.0), 1.584962500721156);
+        assert_eq!(cr_log2(10.0), 3.321928094887362);
+    }
+}
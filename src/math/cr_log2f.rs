@@ -0,0 +1,118 @@
+use super::cr_log2;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// The base 2 logarithm of `x`, correctly rounded (f32).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_log2f(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_log2(x), || soft_math::log2(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::log2;
+    use super::cr_log2f;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 152] = [
+        (0x002452a4, 0xc2ffa268), (0x0048a548, 0xc2fda268), (0x00914a90, 0xc2fba268),
+        (0x01114a90, 0xc2f9a268), (0x01914a90, 0xc2f7a268), (0x02114a90, 0xc2f5a268),
+        (0x02914a90, 0xc2f3a268), (0x03114a90, 0xc2f1a268), (0x03914a90, 0xc2efa268),
+        (0x04114a90, 0xc2eda268), (0x04914a90, 0xc2eba268), (0x05114a90, 0xc2e9a268),
+        (0x05914a90, 0xc2e7a268), (0x06114a90, 0xc2e5a268), (0x06914a90, 0xc2e3a268),
+        (0x07114a90, 0xc2e1a268), (0x07914a90, 0xc2dfa268), (0x08114a90, 0xc2dda268),
+        (0x08914a90, 0xc2dba268), (0x09114a90, 0xc2d9a268), (0x09914a90, 0xc2d7a268),
+        (0x0a114a90, 0xc2d5a268), (0x0a914a90, 0xc2d3a268), (0x0b114a90, 0xc2d1a268),
+        (0x0b914a90, 0xc2cfa268), (0x0c114a90, 0xc2cda268), (0x0c914a90, 0xc2cba268),
+        (0x0d114a90, 0xc2c9a268), (0x0d914a90, 0xc2c7a268), (0x0e114a90, 0xc2c5a268),
+        (0x0e914a90, 0xc2c3a268), (0x0f114a90, 0xc2c1a268), (0x0f914a90, 0xc2bfa268),
+        (0x10114a90, 0xc2bda268), (0x10914a90, 0xc2bba268), (0x11114a90, 0xc2b9a268),
+        (0x11914a90, 0xc2b7a268), (0x12114a90, 0xc2b5a268), (0x12914a90, 0xc2b3a268),
+        (0x13114a90, 0xc2b1a268), (0x13914a90, 0xc2afa268), (0x14114a90, 0xc2ada268),
+        (0x14914a90, 0xc2aba268), (0x15114a90, 0xc2a9a268), (0x15914a90, 0xc2a7a268),
+        (0x16114a90, 0xc2a5a268), (0x16914a90, 0xc2a3a268), (0x17114a90, 0xc2a1a268),
+        (0x17914a90, 0xc29fa268), (0x18114a90, 0xc29da268), (0x18914a90, 0xc29ba268),
+        (0x19114a90, 0xc299a268), (0x19914a90, 0xc297a268), (0x1a114a90, 0xc295a268),
+        (0x1a914a90, 0xc293a268), (0x1b114a90, 0xc291a268), (0x1b914a90, 0xc28fa268),
+        (0x1c114a90, 0xc28da268), (0x1c914a90, 0xc28ba268), (0x1d114a90, 0xc289a268),
+        (0x1d914a90, 0xc287a268), (0x1e114a90, 0xc285a268), (0x1e914a90, 0xc283a268),
+        (0x1f114a90, 0xc281a268), (0x2fd54996, 0xc1fa1b55), (0x30554996, 0xc1f21b55),
+        (0x30d54996, 0xc1ea1b55), (0x31554996, 0xc1e21b55), (0x31d54996, 0xc1da1b55),
+        (0x32554996, 0xc1d21b55), (0x32d54996, 0xc1ca1b55), (0x33554996, 0xc1c21b55),
+        (0x33d54996, 0xc1ba1b55), (0x34554996, 0xc1b21b55), (0x34d54996, 0xc1aa1b55),
+        (0x3ea07ab9, 0xbfd63da2), (0x40207ab9, 0x3fa9c25e), (0x4a554996, 0x41ade4ab),
+        (0x4ad54996, 0x41b5e4ab), (0x4b554996, 0x41bde4ab), (0x4bd54996, 0x41c5e4ab),
+        (0x4c554996, 0x41cde4ab), (0x4cd54996, 0x41d5e4ab), (0x4d554996, 0x41dde4ab),
+        (0x4dd54996, 0x41e5e4ab), (0x4e554996, 0x41ede4ab), (0x4ed54996, 0x41f5e4ab),
+        (0x4f554996, 0x41fde4ab), (0x5f914a90, 0x42805d98), (0x60114a90, 0x42825d98),
+        (0x60914a90, 0x42845d98), (0x61114a90, 0x42865d98), (0x61914a90, 0x42885d98),
+        (0x62114a90, 0x428a5d98), (0x62914a90, 0x428c5d98), (0x63114a90, 0x428e5d98),
+        (0x63914a90, 0x42905d98), (0x64114a90, 0x42925d98), (0x64914a90, 0x42945d98),
+        (0x65114a90, 0x42965d98), (0x65914a90, 0x42985d98), (0x66114a90, 0x429a5d98),
+        (0x66914a90, 0x429c5d98), (0x67114a90, 0x429e5d98), (0x67914a90, 0x42a05d98),
+        (0x68114a90, 0x42a25d98), (0x68914a90, 0x42a45d98), (0x69114a90, 0x42a65d98),
+        (0x69914a90, 0x42a85d98), (0x6a114a90, 0x42aa5d98), (0x6a914a90, 0x42ac5d98),
+        (0x6b114a90, 0x42ae5d98), (0x6b914a90, 0x42b05d98), (0x6c114a90, 0x42b25d98),
+        (0x6c914a90, 0x42b45d98), (0x6d114a90, 0x42b65d98), (0x6d914a90, 0x42b85d98),
+        (0x6e114a90, 0x42ba5d98), (0x6e914a90, 0x42bc5d98), (0x6f114a90, 0x42be5d98),
+        (0x6f914a90, 0x42c05d98), (0x70114a90, 0x42c25d98), (0x70914a90, 0x42c45d98),
+        (0x71114a90, 0x42c65d98), (0x71914a90, 0x42c85d98), (0x72114a90, 0x42ca5d98),
+        (0x72914a90, 0x42cc5d98), (0x73114a90, 0x42ce5d98), (0x73914a90, 0x42d05d98),
+        (0x74114a90, 0x42d25d98), (0x74914a90, 0x42d45d98), (0x75114a90, 0x42d65d98),
+        (0x75914a90, 0x42d85d98), (0x76114a90, 0x42da5d98), (0x76914a90, 0x42dc5d98),
+        (0x77114a90, 0x42de5d98), (0x77914a90, 0x42e05d98), (0x78114a90, 0x42e25d98),
+        (0x78914a90, 0x42e45d98), (0x79114a90, 0x42e65d98), (0x79914a90, 0x42e85d98),
+        (0x7a114a90, 0x42ea5d98), (0x7a914a90, 0x42ec5d98), (0x7b114a90, 0x42ee5d98),
+        (0x7b914a90, 0x42f05d98), (0x7c114a90, 0x42f25d98), (0x7c914a90, 0x42f45d98),
+        (0x7d114a90, 0x42f65d98), (0x7d914a90, 0x42f85d98), (0x7e114a90, 0x42fa5d98),
+        (0x7e914a90, 0x42fc5d98), (0x7f114a90, 0x42fe5d98),
+    ];
+
+    /* log2 in double precision, evaluated independently of cr_log2f */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(log2(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_log2f(f32::NAN).is_nan());
+        assert_eq!(cr_log2f(1.0 / 0.0), 1.0 / 0.0);
+        assert!(cr_log2f(-1.0).is_nan());
+        assert_eq!(cr_log2f(-0.0), -1.0 / 0.0);
+        assert_eq!(cr_log2f(1e-45), -149.0);
+        assert_eq!(cr_log2f(1024.0), 10.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where log2f is an ulp off */
+        assert_eq!(cr_log2f(1.3274205e-38), -125.82464);
+        assert_eq!(cr_log2f(2.0258814e-38), -125.214714);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_log2f, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_log2f, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 3 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_log2f, reference, 1);
+    }
+}
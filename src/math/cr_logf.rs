@@ -0,0 +1,91 @@
+use super::cr_log;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// The natural logarithm of `x`, correctly rounded (f32).
+///
+/// Unlike [`logf`](fn.logf.html), the result is always the float nearest
+/// to the exact value.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_logf(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_log(x), || soft_math::log(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::log;
+    use super::cr_logf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 64] = [
+        (0x022ae487, 0xc2a9efaa), (0x064cb44b, 0xc29e7c1a), (0x07c060fa, 0xc29a733a),
+        (0x0dc8bba4, 0xc289bac4), (0x0f61ff63, 0xc2855565), (0x111c87f8, 0xc28085df),
+        (0x11b257e3, 0xc27dc05e), (0x13fcd84a, 0xc27143cc), (0x145cb6d4, 0xc26f092f),
+        (0x14907055, 0xc26df596), (0x158772eb, 0xc268abd4), (0x16c5ee7a, 0xc2619bd4),
+        (0x18b06f2a, 0xc256fa6f), (0x1a8446cb, 0xc24d0a45), (0x1aac80dc, 0xc24bfa60),
+        (0x1b81ab52, 0xc2479316), (0x1dc9e7c1, 0xc23ab685), (0x1f116ab8, 0xc233b53e),
+        (0x22925ad4, 0xc220462d), (0x22f6d580, 0xc21e2ef4), (0x2423c085, 0xc21781d1),
+        (0x25be734f, 0xc20e95ce), (0x25f12e61, 0xc20da3fb), (0x2758eec4, 0xc205bf23),
+        (0x28e3fa26, 0xc1fa75e0), (0x29e6126b, 0xc1ef4c02), (0x29fd22f8, 0xc1ee8859),
+        (0x2c4c24b7, 0xc1d48710), (0x2e492984, 0xc1be76ee), (0x3418949b, 0xc17c447f),
+        (0x38dcbe38, 0xc1128ba4), (0x39c31348, 0xc0fcaf65), (0x39c7b1f9, 0xc0fbefa7),
+        (0x3bf86ef0, 0xc09c399e), (0x3c413d3a, 0xc08e158f), (0x3e2b3421, 0xbfe4f170),
+        (0x41178feb, 0x400fe5e7), (0x44bc4360, 0x40ea272e), (0x464d5b2b, 0x4117bcf1),
+        (0x4665a9a6, 0x41198725), (0x46ca6c75, 0x41229929), (0x4c5d65a5, 0x418f034b),
+        (0x4d604ebe, 0x419a352c), (0x4e85f412, 0x41a6b811), (0x4fe4ab24, 0x41b6166f),
+        (0x526a304f, 0x41d2011d), (0x54af989d, 0x41eb6d3c), (0x5d800341, 0x42265b10),
+        (0x5d8b2d5b, 0x4226b0b1), (0x5ee8984e, 0x422e4a21), (0x5f64c24a, 0x4230fee2),
+        (0x62b467ba, 0x42437434), (0x63b134d9, 0x4248ed72), (0x64e27fa3, 0x424f7453),
+        (0x65d890d3, 0x4254d1f9), (0x66a8c860, 0x42595e46), (0x66abbd63, 0x4259700e),
+        (0x6914cb96, 0x4266ba1e), (0x6d1f23eb, 0x427d2d35), (0x6d6c9d3a, 0x427ec360),
+        (0x6f31a8ec, 0x42845a89), (0x7405dee8, 0x4291a68d), (0x79e7ec37, 0x42a1ffb7),
+        (0x7d98b8f4, 0x42ac40f2),
+    ];
+
+    /* log in double precision, evaluated independently of cr_logf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(log(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_logf(f32::NAN).is_nan());
+        assert_eq!(cr_logf(1.0 / 0.0), 1.0 / 0.0);
+        assert!(cr_logf(-1.0).is_nan());
+        assert_eq!(cr_logf(0.0), -1.0 / 0.0);
+        assert_eq!(cr_logf(1.0), 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where logf is an ulp off */
+        assert_eq!(cr_logf(1.3389083e-38), -87.20638);
+        assert_eq!(cr_logf(1.4549355e-38), -87.12327);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_logf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_logf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_logf, reference, 1);
+    }
+}
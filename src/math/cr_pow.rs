@@ -0,0 +1,94 @@
+use super::double_double::{add, mul, mul_f64, round};
+use super::k_crexp::k_crexp;
+use super::k_crlog::k_crlog;
+use super::soft_float::Soft;
+use super::soft_math;
+use super::{pow, scalbn, trunc};
+
+/* the relative error of the fast path is below EPS0 + |y*log(x)|*EPS1 */
+const EPS0: f64 = 2.117582368135751e-22; /* 0x1p-72 */
+const EPS1: f64 = 1.3552527156068805e-20; /* 0x1p-66 */
+
+/// Returns `x` to the power of `y`, correctly rounded (f64).
+///
+/// Unlike [`pow`](fn.pow.html), the result is always the double nearest to
+/// the exact value. Special cases are those of `pow`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_pow(x: f64, y: f64) -> f64 {
+    let x1p53 = f64::from_bits(0x4340000000000000); // 0x1p53 === 2 ^ 53
+    let x1p64 = f64::from_bits(0x43f0000000000000); // 0x1p64 === 2 ^ 64
+
+    if y == 0.0 || !x.is_finite() || !y.is_finite() || x == 0.0 || x.abs() == 1.0 {
+        return pow(x, y);
+    }
+    let yint = y == trunc(y);
+    if (x < 0.0 && !yint) || y.abs() >= x1p64 {
+        /* NaN, or certain overflow or underflow */
+        return pow(x, y);
+    }
+    let neg = x < 0.0 && y.abs() < x1p53 && (y.abs() as u64) & 1 == 1;
+    let a = x.abs();
+
+    /* t = y*log(a) */
+    let (th, tl) = mul_f64(k_crlog(a), y);
+    if !(-746.0..=710.0).contains(&th) {
+        return pow(x, y);
+    }
+    let r = if (-708.0..709.0).contains(&th) {
+        let (m, t, p) = k_crexp(th, tl);
+        let (h, l) = add(t, mul(t, p));
+        round(h, l, EPS0 + th.abs() * EPS1).map(|r| scalbn(r, m))
+    } else {
+        None
+    };
+    let r = r
+        .or_else(|| soft_math::exact_pow(a, y).map(Soft::to_f64))
+        .unwrap_or_else(|| soft_math::pow(a, y).to_f64());
+    if neg {
+        -r
+    } else {
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_pow;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(cr_pow(f64::NAN, 0.0), 1.0);
+        assert_eq!(cr_pow(1.0, f64::NAN), 1.0);
+        assert!(cr_pow(-2.0, 0.5).is_nan());
+        assert_eq!(cr_pow(-1.0, 1.0 / 0.0), 1.0);
+        assert_eq!(cr_pow(0.0, -1.0), 1.0 / 0.0);
+        assert_eq!(cr_pow(-0.0, -3.0), -1.0 / 0.0);
+        assert_eq!(cr_pow(2.0, 1e20), 1.0 / 0.0);
+        assert_eq!(cr_pow(-2.0, -1e20), 0.0);
+    }
+
+    #[test]
+    fn exact_results() {
+        assert_eq!(cr_pow(2.0, -1074.0), 5e-324);
+        assert_eq!(cr_pow(-3.0, 3.0), -27.0);
+        assert_eq!(cr_pow(6.25, 1.5), 15.625);
+        assert_eq!(cr_pow(4096.0, 1.0 / 12.0), 2.0);
+        /* midpoints between two doubles, which round to even */
+        assert_eq!(cr_pow(2.0, -1075.0), 0.0);
+        assert_eq!(cr_pow(0.5, 1075.0), 0.0);
+        assert_eq!(cr_pow(134217727.0, 2.0), 18014398241046528.0);
+        /* 3^5*2^-1075 */
+        assert_eq!(cr_pow(5.697340647455879e-65, 5.0), 6.03e-322);
+        assert_eq!(cr_pow(4503599493152769.0, 0.5), 67108863.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_pow(2.0, 0.5).to_bits(), 0x3ff6a09e667f3bcd);
+        assert_eq!(cr_pow(10.0, -2.0), 0.01);
+        assert_eq!(cr_pow(1.0000001, 1e9), 2.6881038582144647e43);
+        assert_eq!(cr_pow(0.5, 1074.5), 5e-324);
+        assert_eq!(cr_pow(1.5, 1750.5), 1.769086127627106e308);
+    }
+}
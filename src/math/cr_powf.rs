@@ -0,0 +1,118 @@
+use super::cr_pow;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// Returns `x` to the power of `y`, correctly rounded (f32).
+///
+/// Unlike [`powf`](fn.powf.html), the result is always the float nearest
+/// to the exact value. Special cases are those of `powf`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_powf(x: f32, y: f32) -> f32 {
+    let (x, y) = (x as f64, y as f64);
+    round_cr(cr_pow(x, y), || {
+        /* x is finite and nonzero, and if negative, y is an integer */
+        let a = x.abs();
+        let r = soft_math::exact_pow(a, y).unwrap_or_else(|| soft_math::pow(a, y));
+        if x < 0.0 && y % 2.0 != 0.0 {
+            r.neg()
+        } else {
+            r
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check2, check2_sweep, rounded};
+    use super::super::pow;
+    use super::cr_powf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* pow in double precision, evaluated independently of cr_powf */
+    fn reference(x: f32, y: f32) -> Option<f32> {
+        rounded(pow(x as f64, y as f64), ERR)
+    }
+
+    /* exponents whose powers are often exact, or of extreme range */
+    const EXPONENTS: [f32; 12] = [
+        2.0, 3.0, 0.5, -1.0, -0.5, 1.5, -2.0, 0.33333334, 0.1, 7.0, 24.5, -127.25,
+    ];
+    /* bases, among them some near 1 whose powers stay in range longest */
+    const BASES: [f32; 10] = [
+        2.0, 10.0, 0.5, -3.0, 1.0000001, 0.99999994, 1.5, 17.25, 1e-30, 3e38,
+    ];
+
+    fn swapped(y: f32, x: f32) -> f32 {
+        cr_powf(x, y)
+    }
+
+    fn reference_swapped(y: f32, x: f32) -> Option<f32> {
+        reference(x, y)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_powf(f32::NAN, 1.0).is_nan());
+        assert_eq!(cr_powf(f32::NAN, 0.0), 1.0);
+        assert_eq!(cr_powf(1.0, f32::NAN), 1.0);
+        assert!(cr_powf(-2.0, 0.5).is_nan());
+        assert_eq!(cr_powf(-2.0, 3.0), -8.0);
+        assert_eq!(cr_powf(0.0, -1.0), 1.0 / 0.0);
+        assert_eq!(cr_powf(-0.0, -3.0), -1.0 / 0.0);
+        assert_eq!(cr_powf(-1.0, 1.0 / 0.0), 1.0);
+        assert_eq!(cr_powf(0.5, -1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_powf(10.0, 39.0), 1.0 / 0.0);
+        assert_eq!(cr_powf(10.0, -46.0), 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_powf(1.4270424, -24.043798), 0.00019353126);
+        assert_eq!(cr_powf(1.1172311, -38.549164), 0.0139356265);
+    }
+
+    #[test]
+    fn exact_results() {
+        /* 4097^2 = 16785409 and 257^3 = 16974593 are halfway between two
+         * floats */
+        assert_eq!(cr_powf(4097.0, 2.0), 16785408.0);
+        assert_eq!(cr_powf(-257.0, 3.0), -16974592.0);
+        assert_eq!(cr_powf(16785409.0, 0.5), 4097.0);
+        assert_eq!(cr_powf(2.0, -149.0), 1e-45);
+        assert_eq!(cr_powf(3.0, 5.0), 243.0);
+    }
+
+    #[test]
+    fn sampled() {
+        check2(cr_powf, reference, 4096);
+    }
+
+    #[test]
+    fn fixed_exponents() {
+        check2_sweep(cr_powf, reference, &EXPONENTS, 65_537);
+    }
+
+    #[test]
+    fn fixed_bases() {
+        check2_sweep(swapped, reference_swapped, &BASES, 65_537);
+    }
+
+    /* all 2^32 floats against each exponent and each base, about forty
+     * minutes for each of them in release mode */
+    #[test]
+    #[ignore]
+    fn fixed_exhaustive() {
+        check2_sweep(cr_powf, reference, &EXPONENTS, 1);
+        check2_sweep(swapped, reference_swapped, &BASES, 1);
+    }
+
+    /* random pairs, as all of them would take far too long */
+    #[test]
+    #[ignore]
+    fn random() {
+        check2(cr_powf, reference, 1 << 28);
+    }
+}
@@ -0,0 +1,61 @@
+use super::double_double::round;
+use super::k_crsin::k_crsincos;
+use super::rem_pio2_cr::rem_pio2_cr_dd;
+use super::soft_math;
+
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 1.0842021724855044e-19; /* 0x1p-63 */
+
+/// The sine of `x`, correctly rounded (f64).
+///
+/// `x` is specified in radians. Unlike [`sin`](fn.sin.html), the result is
+/// always the double nearest to the exact value.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_sin(x: f64) -> f64 {
+    let x1p_26 = f64::from_bits(0x3e50000000000000); // 0x1p-26 === 2 ^ -26
+
+    if x.is_nan() || x.is_infinite() {
+        return x - x;
+    }
+    if x.abs() < x1p_26 {
+        /* sin(x) = x - x^3/6 + ..., within half an ulp of x */
+        return x;
+    }
+    let (n, rh, rl) = rem_pio2_cr_dd(x);
+    let (s, c) = k_crsincos(rh, rl);
+    let (h, l) = match n {
+        0 => s,
+        1 => c,
+        2 => (-s.0, -s.1),
+        _ => (-c.0, -c.1),
+    };
+    if let Some(y) = round(h, l, EPS) {
+        return y;
+    }
+    soft_math::sin_cos(x).0.to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_sin;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_sin(f64::NAN).is_nan());
+        assert!(cr_sin(1.0 / 0.0).is_nan());
+        assert!(cr_sin(-1.0 / 0.0).is_nan());
+        assert_eq!(cr_sin(-0.0).to_bits(), 0x8000000000000000);
+        assert_eq!(cr_sin(5e-324), 5e-324);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_sin(1.0), 0.8414709848078965);
+        assert_eq!(cr_sin(-3.0), -0.1411200080598672);
+        assert_eq!(cr_sin(1e22), -0.8522008497671888);
+        assert_eq!(cr_sin(f64::MAX), 0.004961954789184062);
+        /* the double closest to a multiple of pi/2 */
+        assert_eq!(cr_sin(f64::from_bits(0x7506ac5b262ca1ff)), 1.0);
+    }
+}
@@ -0,0 +1,94 @@
+use super::cr_sin;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// The sine of `x`, correctly rounded (f32).
+///
+/// `x` is specified in radians. Unlike [`sinf`](fn.sinf.html), the result
+/// is always the float nearest to the exact value.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_sinf(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_sin(x), || soft_math::sin_cos(x).0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::sin;
+    use super::cr_sinf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 80] = [
+        (0x3dcf5597, 0x3dcefaf9), (0x3ef3830f, 0x3eea6f45), (0x42d44528, 0xbf20c9a7),
+        (0x4371ade3, 0x3e62da56), (0x45a8abb3, 0x3e51ade2), (0x46199998, 0xbeb1fa5d),
+        (0x4967cb9b, 0x3ec9df85), (0x4a987933, 0xbf70f319), (0x4aa5a796, 0x3f7ff108),
+        (0x4df947f3, 0xbf1f1f91), (0x4ecd11c7, 0x3f37516c), (0x4f45dcab, 0x3f3539dd),
+        (0x4fb56937, 0xbf7ffcb7), (0x521945ed, 0xbd933a00), (0x545bb734, 0xbc6fce9e),
+        (0x55cafb2a, 0xbf7e7a17), (0x58dfb085, 0xbf5585ae), (0x5a935f4c, 0x3f5f0fa8),
+        (0x5cdaa4f7, 0x3f7108db), (0x5dadd689, 0xbf74fc9e), (0x5de78921, 0xbe4a4b3a),
+        (0x5f208d82, 0x3f2c7688), (0x616d8730, 0x3f67ef01), (0x61dfc847, 0xbf0094df),
+        (0x6446cec0, 0xbf6995bd), (0x653cee8f, 0x3f30df39), (0x67051b8d, 0xbe7ccf49),
+        (0x67a9242b, 0xbf7fab81), (0x6a3f60ff, 0xbf79ee5f), (0x6d734599, 0xbf5b5135),
+        (0x6dcea82e, 0x3f40f5e1), (0x6e649053, 0xbedf21a7), (0x73243f06, 0x3e943a84),
+        (0x742c68ce, 0xbf787516), (0x7970a79d, 0x3f4207c3), (0x79d1f6d3, 0xbf7a88ee),
+        (0x7a5aacdb, 0x3eda83bb), (0x7a817b08, 0x3e1b493e), (0x7c2e964a, 0xbef4a5ec),
+        (0x7f2a2ca7, 0x3efdc03d), (0xbdcf5597, 0xbdcefaf9), (0xbef3830f, 0xbeea6f45),
+        (0xc2d44528, 0x3f20c9a7), (0xc371ade3, 0xbe62da56), (0xc5a8abb3, 0xbe51ade2),
+        (0xc6199998, 0x3eb1fa5d), (0xc967cb9b, 0xbec9df85), (0xca987933, 0x3f70f319),
+        (0xcaa5a796, 0xbf7ff108), (0xcdf947f3, 0x3f1f1f91), (0xcecd11c7, 0xbf37516c),
+        (0xcf45dcab, 0xbf3539dd), (0xcfb56937, 0x3f7ffcb7), (0xd21945ed, 0x3d933a00),
+        (0xd45bb734, 0x3c6fce9e), (0xd5cafb2a, 0x3f7e7a17), (0xd8dfb085, 0x3f5585ae),
+        (0xda935f4c, 0xbf5f0fa8), (0xdcdaa4f7, 0xbf7108db), (0xddadd689, 0x3f74fc9e),
+        (0xdde78921, 0x3e4a4b3a), (0xdf208d82, 0xbf2c7688), (0xe16d8730, 0xbf67ef01),
+        (0xe1dfc847, 0x3f0094df), (0xe446cec0, 0x3f6995bd), (0xe53cee8f, 0xbf30df39),
+        (0xe7051b8d, 0x3e7ccf49), (0xe7a9242b, 0x3f7fab81), (0xea3f60ff, 0x3f79ee5f),
+        (0xed734599, 0x3f5b5135), (0xedcea82e, 0xbf40f5e1), (0xee649053, 0x3edf21a7),
+        (0xf3243f06, 0xbe943a84), (0xf42c68ce, 0x3f787516), (0xf970a79d, 0xbf4207c3),
+        (0xf9d1f6d3, 0x3f7a88ee), (0xfa5aacdb, 0xbeda83bb), (0xfa817b08, 0xbe1b493e),
+        (0xfc2e964a, 0x3ef4a5ec), (0xff2a2ca7, 0xbefdc03d),
+    ];
+
+    /* sin in double precision, evaluated independently of cr_sinf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(sin(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_sinf(f32::NAN).is_nan());
+        assert!(cr_sinf(1.0 / 0.0).is_nan());
+        assert_eq!(cr_sinf(-0.0).to_bits(), (-0.0_f32).to_bits());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where sinf is an ulp off */
+        assert_eq!(cr_sinf(0.597694), 0.5627377);
+        assert_eq!(cr_sinf(0.83370656), 0.7404278);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_sinf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_sinf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 11 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_sinf, reference, 1);
+    }
+}
@@ -0,0 +1,59 @@
+use super::double_double::{add, add_f64, div, round};
+use super::k_crexp::k_crexpm1;
+use super::soft_math;
+
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 1.0842021724855044e-19; /* 0x1p-63 */
+
+/// Hyperbolic sine, correctly rounded (f64)
+///
+/// The hyperbolic sine of `x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_sinh(x: f64) -> f64 {
+    let x1p_26 = f64::from_bits(0x3e50000000000000); // 0x1p-26 === 2 ^ -26
+
+    if x.is_nan() || x.is_infinite() {
+        return x;
+    }
+    let a = x.abs();
+    if a < x1p_26 {
+        /* sinh(x) = x + x^3/6 + ..., within half an ulp of x */
+        return x;
+    }
+    if a > 710.5 {
+        return x * f64::MAX;
+    }
+    if a < 709.0 {
+        /* sinh(a) = (e + e/(e + 1))/2, with e = expm1(a) */
+        let e = k_crexpm1(a);
+        let (h, l) = add(e, div(e, add_f64(e, 1.0)));
+        if let Some(y) = round(h, l, EPS) {
+            return if x < 0.0 { -0.5 * y } else { 0.5 * y };
+        }
+    }
+    soft_math::sinh(x).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_sinh;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_sinh(f64::NAN).is_nan());
+        assert_eq!(cr_sinh(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_sinh(-1.0 / 0.0), -1.0 / 0.0);
+        assert_eq!(cr_sinh(-0.0).to_bits(), 0x8000000000000000);
+        assert_eq!(cr_sinh(711.0), 1.0 / 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_sinh(1.0), 1.1752011936438014);
+        assert_eq!(cr_sinh(-0.25), -0.2526123168081683);
+        assert_eq!(cr_sinh(1e-5), 1.0000000000166668e-05);
+        assert_eq!(cr_sinh(710.0), 1.1169973830808555e308);
+        assert_eq!(cr_sinh(-40.0), -1.1769263341851e17);
+    }
+}
@@ -0,0 +1,70 @@
+use super::cr_sinh;
+use super::crf32::round_cr;
+use super::soft_math;
+
+/// Hyperbolic sine, correctly rounded (f32)
+///
+/// The hyperbolic sine of `x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_sinhf(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_sinh(x), || soft_math::sinh(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::sinh;
+    use super::cr_sinhf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 6] = [
+        (0x3a1285ff, 0x3a1285ff), (0x3b36aa1f, 0x3b36aa2f), (0x3f7df258, 0x3f94d8aa),
+        (0xba1285ff, 0xba1285ff), (0xbb36aa1f, 0xbb36aa2f), (0xbf7df258, 0xbf94d8aa),
+    ];
+
+    /* sinh in double precision, evaluated independently of cr_sinhf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(sinh(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_sinhf(f32::NAN).is_nan());
+        assert_eq!(cr_sinhf(1.0 / 0.0), 1.0 / 0.0);
+        assert_eq!(cr_sinhf(-1.0 / 0.0), -1.0 / 0.0);
+        assert_eq!(cr_sinhf(-0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(cr_sinhf(-90.0), -1.0 / 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where sinhf is an ulp off */
+        assert_eq!(cr_sinhf(0.00024561677), 0.00024561677);
+        assert_eq!(cr_sinhf(0.00024573607), 0.00024573607);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_sinhf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_sinhf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 2 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_sinhf, reference, 1);
+    }
+}
@@ -0,0 +1,65 @@
+use super::double_double::{div, round};
+use super::k_crsin::k_crsincos;
+use super::rem_pio2_cr::rem_pio2_cr_dd;
+use super::soft_float::div as soft_div;
+use super::soft_math;
+
+/* the relative error of the fast path, with a margin */
+const EPS: f64 = 1.0842021724855044e-19; /* 0x1p-63 */
+
+/// The tangent of `x`, correctly rounded (f64).
+///
+/// `x` is specified in radians.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_tan(x: f64) -> f64 {
+    let x1p_27 = f64::from_bits(0x3e40000000000000); // 0x1p-27 === 2 ^ -27
+
+    if x.is_nan() || x.is_infinite() {
+        return x - x;
+    }
+    if x.abs() < x1p_27 {
+        /* tan(x) = x + x^3/3 + ..., within half an ulp of x */
+        return x;
+    }
+    let (n, rh, rl) = rem_pio2_cr_dd(x);
+    let (s, c) = k_crsincos(rh, rl);
+    /* tan(r + pi/2) = -cos(r)/sin(r) */
+    let (h, l) = if n & 1 == 0 {
+        div(s, c)
+    } else {
+        div((-c.0, -c.1), s)
+    };
+    if let Some(y) = round(h, l, EPS) {
+        return y;
+    }
+    let (s, c) = soft_math::sin_cos(x);
+    soft_div(s, c).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cr_tan;
+
+    #[test]
+    fn special_values() {
+        assert!(cr_tan(f64::NAN).is_nan());
+        assert!(cr_tan(1.0 / 0.0).is_nan());
+        assert!(cr_tan(-1.0 / 0.0).is_nan());
+        assert_eq!(cr_tan(-0.0).to_bits(), 0x8000000000000000);
+        assert_eq!(cr_tan(5e-324), 5e-324);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_tan(1.0), 1.5574077246549023);
+        assert_eq!(cr_tan(-3.0), 0.1425465430742778);
+        assert_eq!(cr_tan(1e22), -1.6287782256068988);
+        assert_eq!(cr_tan(f64::MAX), -0.004962015874444895);
+        /* the double closest to a multiple of pi/2 */
+        assert_eq!(
+            cr_tan(f64::from_bits(0x7506ac5b262ca1ff)),
+            -2.133485385753704e18
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use super::cr_tan;
+use super::crf32::round_cr;
+use super::soft_float::div;
+use super::soft_math;
+
+/// The tangent of `x`, correctly rounded (f32).
+///
+/// `x` is specified in radians.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_tanf(x: f32) -> f32 {
+    let x = x as f64;
+    round_cr(cr_tan(x), || {
+        let (s, c) = soft_math::sin_cos(x);
+        div(s, c)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::tan;
+    use super::cr_tanf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 42] = [
+        (0x39b89ba2, 0x39b89ba2), (0x3e19205e, 0x3e1a4733), (0x408174dd, 0x3fa29b31),
+        (0x40e67f59, 0x3fa80c88), (0x43bf198b, 0xbfec8514), (0x451e0885, 0xbef714d6),
+        (0x453c5846, 0x3f64fa0e), (0x4ece8446, 0xbfbd4d2f), (0x575852f8, 0xbfc0e1da),
+        (0x5be68bcd, 0xbff16157), (0x5d5873ae, 0x3ea6bfed), (0x5dc574a6, 0x3f2e6c11),
+        (0x5ffd33a4, 0x3fd06c8c), (0x613d28d9, 0x3ec05657), (0x68058f5d, 0x3e9076c9),
+        (0x76eb0bdc, 0x4019eb75), (0x7714b423, 0x3fe83698), (0x77cda26b, 0x3f882b34),
+        (0x79c42c65, 0x3fa2e336), (0x7a98d2c2, 0x3f4aef73), (0x7dae7426, 0x3f4ac6db),
+        (0xb9b89ba2, 0xb9b89ba2), (0xbe19205e, 0xbe1a4733), (0xc08174dd, 0xbfa29b31),
+        (0xc0e67f59, 0xbfa80c88), (0xc3bf198b, 0x3fec8514), (0xc51e0885, 0x3ef714d6),
+        (0xc53c5846, 0xbf64fa0e), (0xcece8446, 0x3fbd4d2f), (0xd75852f8, 0x3fc0e1da),
+        (0xdbe68bcd, 0x3ff16157), (0xdd5873ae, 0xbea6bfed), (0xddc574a6, 0xbf2e6c11),
+        (0xdffd33a4, 0xbfd06c8c), (0xe13d28d9, 0xbec05657), (0xe8058f5d, 0xbe9076c9),
+        (0xf6eb0bdc, 0xc019eb75), (0xf714b423, 0xbfe83698), (0xf7cda26b, 0xbf882b34),
+        (0xf9c42c65, 0xbfa2e336), (0xfa98d2c2, 0xbf4aef73), (0xfdae7426, 0xbf4ac6db),
+    ];
+
+    /* tan in double precision, evaluated independently of cr_tanf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(tan(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_tanf(f32::NAN).is_nan());
+        assert!(cr_tanf(1.0 / 0.0).is_nan());
+        assert_eq!(cr_tanf(-0.0).to_bits(), (-0.0_f32).to_bits());
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where tanf is an ulp off */
+        assert_eq!(cr_tanf(0.0051800883), 0.005180135);
+        assert_eq!(cr_tanf(0.0058252444), 0.0058253105);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_tanf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_tanf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 12 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_tanf, reference, 1);
+    }
+}
@@ -0,0 +1,80 @@
+use super::crf32::round_approx;
+use super::soft_math;
+use super::tanh;
+
+/* the relative error of tanh, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// Hyperbolic tangent, correctly rounded (f32)
+///
+/// Computes the hyperbolic tangent of `x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_tanhf(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(tanh(x), EPS, || soft_math::tanh(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::{copysign, cr_expm1, fabs};
+    use super::cr_tanhf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 8.881784197001252e-16; /* 0x1p-50 */
+
+    /* the floats for which the reference does not decide the rounding in a
+     * comparison of all floats, with their correctly rounded results
+     * from MPFR */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 2] = [
+        (0x3ac37de2, 0x3ac37dd9), (0xbac37de2, 0xbac37dd9),
+    ];
+
+    /* tanh(|x|) = t/(t+2) with t = expm1(2|x|), which rounds to 1 above 9 */
+    fn reference(x: f32) -> Option<f32> {
+        let x = x as f64;
+        let a = fabs(x);
+        let y = if a > 20.0 {
+            1.0
+        } else {
+            let t = cr_expm1(2.0 * a);
+            t / (t + 2.0)
+        };
+        rounded(copysign(y, x), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_tanhf(f32::NAN).is_nan());
+        assert_eq!(cr_tanhf(1.0 / 0.0), 1.0);
+        assert_eq!(cr_tanhf(-1.0 / 0.0), -1.0);
+        assert_eq!(cr_tanhf(-0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(cr_tanhf(10.0), 1.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        /* where tanhf is an ulp off */
+        assert_eq!(cr_tanhf(2.9815254e-8), 2.9815254e-8);
+        assert_eq!(cr_tanhf(2.9829817e-8), 2.9829817e-8);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_tanhf, &HARD_CASES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_tanhf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 5 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_tanhf, reference, 1);
+    }
+}
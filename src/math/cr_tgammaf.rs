@@ -0,0 +1,163 @@
+use super::crf32::round_approx;
+use super::soft_math;
+use super::tgamma;
+
+/* the relative error of tgamma, with a margin */
+const EPS: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+/// The gamma function, correctly rounded (f32)
+///
+/// Computes the gamma function of `x`, rounded to nearest.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cr_tgammaf(x: f32) -> f32 {
+    let x = x as f64;
+    round_approx(tgamma(x), EPS, || soft_math::tgamma(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crf32::{check, check_cases, rounded};
+    use super::super::tgamma;
+    use super::cr_tgammaf;
+
+    /* the relative error of the reference, with a margin */
+    const ERR: f64 = 2.842170943040401e-14; /* 0x1p-45 */
+
+    /* the 40 floats nearest to halfway between two floats in a comparison
+     * of all of them with MPFR, with their correctly rounded results */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const HARD_CASES: [(u32, u32); 40] = [
+        (0x27b84a93, 0x5731ce33), (0x27c23dc3, 0x5728b28b), (0x27d0ac19, 0x571d07ec),
+        (0x27d0dd0d, 0x571ce31e), (0x27d25d25, 0x571bc4aa), (0x27d43d43, 0x571a644b),
+        (0x27dc23dd, 0x5714d9c6), (0x27de86a9, 0x57134133), (0x27e05475, 0x57121211),
+        (0x27e1368b, 0x57117f6e), (0x27e25473, 0x5710c7a2), (0x27ec7ec7, 0x570a8e84),
+        (0x27ee4bc5, 0x57098279), (0x27f00ff1, 0x57087f77), (0x27f02a3b, 0x57087086),
+        (0x27f0ff0f, 0x5707f808), (0x27fc3237, 0x5701ee3c), (0x27fe01ff, 0x57010100),
+        (0x27ffffff, 0x57000000), (0x297ffffd, 0x55800001), (0x29de9bd0, 0x55133335),
+        (0x2dee534e, 0x51097e20), (0x30c67ca9, 0x4e2516c5), (0x371fe89a, 0x47ccea7b),
+        (0x3bec3a79, 0x430a24a0), (0x3c7bb570, 0x42810ec8), (0x3e6fcd20, 0x4078a4d0),
+        (0x40d0ed6a, 0x4397a15c), (0x41972184, 0x59849379), (0x41e886d1, 0x709989b5),
+        (0xa7c100c1, 0xd729c7a0), (0xa96401c8, 0xd58fb705), (0xaa718c03, 0xd487a8b0),
+        (0xb2b278ac, 0xcc379a8a), (0xb2cbe92d, 0xcc20b29c), (0xb4cec13b, 0xca1e7cbf),
+        (0xb50b4c06, 0xc9eb3d0e), (0xb6072a66, 0xc8f26dc5), (0xba268ee2, 0xc4c4cef0),
+        (0xbd745227, 0xc18b3c6a),
+    ];
+
+    /* the float nearest to halfway between two floats within 8192 floats of
+     * each pole -40 <= x <= -1, and the neighbours of the poles at -1, -2,
+     * -3, -10, -20, -30 and -40, in a comparison with MPFR 4.2.0, with their
+     * correctly rounded results */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const POLE_CASES: [(u32, u32); 54] = [
+        (0xbf7fffff, 0xcb800000), (0xbf800001, 0x4affffff), (0xbf80168a, 0x44b5ad1d),
+        (0xbfffffff, 0x4a800001), (0xc0000001, 0xc9fffffc), (0xc0000103, 0xc5fd0535),
+        (0xc03fffff, 0xc92aaaae), (0xc0400001, 0x492aaaa7), (0xc0400639, 0x43db4f05),
+        (0xc07fead5, 0x42014064), (0xc0a000b6, 0x42c004b9), (0xc0bff8d6, 0x3fcb9ea6),
+        (0xc0dff49b, 0xbe127b2e), (0xc0ffecff, 0x3c3009c5), (0xc10fe39d, 0xb9d3c4d8),
+        (0xc11ff85b, 0x391b8237), (0xc11fffff, 0x3e93f293), (0xc1200001, 0xbe93f268),
+        (0xc12fed48, 0xb6ba02f2), (0xc140138c, 0xb4e80ecd), (0xc14fe350, 0xb2c8994b),
+        (0xc1601b6a, 0xb0e76404), (0xc1701afc, 0x2efab4e5), (0xc17fe1cd, 0x2ce8de26),
+        (0xc1881fa3, 0x2a44202b), (0xc1901508, 0xa884f9f1), (0xc197fee0, 0xa88703cb),
+        (0xc19fffff, 0x2a72a1b9), (0xc1a00001, 0xaa72a102), (0xc1a015f0, 0xa42b5bee),
+        (0xc1a80cf2, 0x22601bd8), (0xc1affc82, 0x211ad0ab), (0xc1b7e15d, 0x9d4ce658),
+        (0xc1c012fe, 0x9b4bff9b), (0xc1c81d19, 0x18a7b34e), (0xc1d00ecb, 0x96cf87c4),
+        (0xc1d7f412, 0x949f30a9), (0xc1dfe9cf, 0x11c6fc45), (0xc1e7f6b8, 0x90008145),
+        (0xc1efffff, 0x131c99a5), (0xc1f00001, 0x931c9920), (0xc1f00efe, 0x8d2302c7),
+        (0xc1f7ec0b, 0x8a860d06), (0xc1fffe6b, 0x09cce8b2), (0xc2041ce9, 0x049d5562),
+        (0xc2080526, 0x83613575), (0xc20bf9d4, 0x80b2a707), (0xc21019ec, 0x80010e8b),
+        (0xc213edc9, 0x80000c27), (0xc21809e2, 0x80000088), (0xc21be5ba, 0x80000002),
+        (0xc21ffe33, 0x00000001), (0xc21fffff, 0x000000e5), (0xc2200001, 0x800000e5),
+    ];
+
+    /* 96 floats spread evenly over the bit patterns of -42 < x < 35.1,
+     * where gamma neither overflows nor underflows, with their correctly
+     * rounded results from MPFR 4.2.0, as a reference independent of the
+     * double function that is rounded */
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const SAMPLES: [(u32, u32); 96] = [
+        (0x00b045de, 0x7e39e4c0), (0x0210d199, 0x7ce244e1), (0x03715d54, 0x7b87c2ee),
+        (0x04d1e90f, 0x7a1c1ace), (0x063274ca, 0x78b79e89), (0x07930085, 0x775ee893),
+        (0x08f38c40, 0x76068b5e), (0x0a5417fb, 0x749a7f6e), (0x0bb4a3b6, 0x73356665),
+        (0x0d152f71, 0x71dba573), (0x0e75bb2c, 0x70855958), (0x0fd646e7, 0x6f18ec70),
+        (0x1136d2a2, 0x6db33bd2), (0x12975e5d, 0x6c587a6a), (0x13f7ea18, 0x6b042cb5),
+        (0x155875d3, 0x69976193), (0x16b9018e, 0x68311e57), (0x18198d49, 0x66d56671),
+        (0x197a1904, 0x65830552), (0x1adaa4bf, 0x6415de98), (0x1c3b307a, 0x62af0d7d),
+        (0x1d9bbc35, 0x61526891), (0x1efc47f0, 0x6001e30c), (0x205cd3ab, 0x5e946345),
+        (0x21bd5f66, 0x5d2d08d4), (0x231deb21, 0x5bcf7fde), (0x247e76dc, 0x5a80c5c2),
+        (0x25df0297, 0x5912ef5f), (0x273f8e52, 0x57ab0ff3), (0x28a01a0d, 0x564cab7a),
+        (0x2a00a5c8, 0x54feb61b), (0x2b613183, 0x539182af), (0x2cc1bd3e, 0x52292273),
+        (0x2e2248f9, 0x50c9ea94), (0x2f82d4b4, 0x4f7a75f5), (0x30e3606f, 0x4e101d00),
+        (0x3243ec2a, 0x4ca73ff2), (0x33a477e5, 0x4b473c64), (0x350503a0, 0x49f65982),
+        (0x36658f5b, 0x488ebe0c), (0x37c61b16, 0x47256781), (0x3926a6d1, 0x45c49b93),
+        (0x3a87328c, 0x44723a36), (0x3be7be47, 0x430cd3dc), (0x3d484a02, 0x419f5b31),
+        (0x3ea8d5bd, 0x402d6533), (0x40096178, 0x3f891a14), (0x4169ed33, 0x50ec8957),
+        (0x80be1289, 0xfe2c65c0), (0x821e9e44, 0xfcce9586), (0x837f29ff, 0xfb806b5a),
+        (0x84dfb5ba, 0xfa1279b6), (0x86404175, 0xf8aa708f), (0x87a0cd30, 0xf74bc778),
+        (0x890158eb, 0xf5fd555a), (0x8a61e4a6, 0xf4910f4a), (0x8bc27061, 0xf32886a0),
+        (0x8d22fc1c, 0xf1c90ca7), (0x8e8387d7, 0xf07920d8), (0x8fe41392, 0xef0fabcf),
+        (0x91449f4d, 0xeda6a792), (0x92a52b08, 0xec46644f), (0x9405b6c3, 0xeaf50f7e),
+        (0x9566427e, 0xe98e4f11), (0x96c6ce39, 0xe824d30a), (0x982759f4, 0xe6c3cdb8),
+        (0x9987e5af, 0xe5711f99), (0x9ae8716a, 0xe40cf8e1), (0x9c48fd25, 0xe2a308af),
+        (0x9da988e0, 0xe1414831), (0x9f0a149b, 0xdfed4f95), (0xa06aa056, 0xde8ba90f),
+        (0xa1cb2c11, 0xdd21482f), (0xa32bb7cc, 0xdbbed315), (0xa48c4387, 0xda699df2),
+        (0xa5eccf42, 0xd90a5f6e), (0xa74d5afd, 0xd79f9137), (0xa8ade6b8, 0xd63c6dc5),
+        (0xaa0e7273, 0xd4e6094d), (0xab6efe2e, 0xd3891bd3), (0xaccf89e9, 0xd21de37c),
+        (0xae3015a4, 0xd0ba17aa), (0xaf90a15f, 0xcf629054), (0xb0f12d1a, 0xce07de13),
+        (0xb251b8d5, 0xcc9c3eb4), (0xb3b24490, 0xcb37d036), (0xb512d04b, 0xc9df31d0),
+        (0xb6735c06, 0xc886a61a), (0xb7d3e7c1, 0xc71aa32b), (0xb934737c, 0xc5b59b7e),
+        (0xba94ff37, 0xc45c118e), (0xbbf58af2, 0xc3060936), (0xbd5616ad, 0xc19e1c46),
+        (0xbeb6a268, 0xc07ac539), (0xc0172e23, 0xbf9ab0d2), (0xc177b9de, 0x2b32ec39),
+    ];
+
+    /* tgamma in double precision, evaluated independently of cr_tgammaf */
+    fn reference(x: f32) -> Option<f32> {
+        rounded(tgamma(x as f64), ERR)
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cr_tgammaf(f32::NAN).is_nan());
+        assert_eq!(cr_tgammaf(1.0 / 0.0), 1.0 / 0.0);
+        assert!(cr_tgammaf(-1.0 / 0.0).is_nan());
+        assert_eq!(cr_tgammaf(0.0), 1.0 / 0.0);
+        assert_eq!(cr_tgammaf(-0.0), -1.0 / 0.0);
+        assert!(cr_tgammaf(-3.0).is_nan());
+        assert_eq!(cr_tgammaf(5.0), 24.0);
+        assert_eq!(cr_tgammaf(36.0), 1.0 / 0.0);
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(cr_tgammaf(0.5), 1.7724539);
+        assert_eq!(cr_tgammaf(-2.5), -0.94530874);
+        assert_eq!(cr_tgammaf(10.1), 454761.16);
+    }
+
+    #[test]
+    fn hard_cases() {
+        check_cases(cr_tgammaf, &HARD_CASES);
+    }
+
+    #[test]
+    fn pole_cases() {
+        check_cases(cr_tgammaf, &POLE_CASES);
+    }
+
+    #[test]
+    fn samples() {
+        check_cases(cr_tgammaf, &SAMPLES);
+    }
+
+    #[test]
+    fn sampled() {
+        check(cr_tgammaf, reference, 1_000_003);
+    }
+
+    /* all 2^32 floats, about 13 minutes in release mode */
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        check(cr_tgammaf, reference, 1);
+    }
+}
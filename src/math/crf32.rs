@@ -0,0 +1,218 @@
+// Rounding of double precision values for the correctly rounded f32
+// functions
+//
+// These evaluate their function in double precision and round the result
+// to f32. A double that is itself correctly rounded rounds to the
+// correctly rounded float unless it lies exactly halfway between two
+// floats, where the exact value may be on either side of it. Any other
+// double rounds correctly if the interval its error bound allows holds no
+// halfway point. In the remaining hard cases the function is evaluated
+// again with soft_math, whose error is far below the distance between a
+// halfway point and the value of the function at any float that is not
+// exactly halfway.
+//
+// The tests compare each function with a reference within a bound on the
+// error of the reference. A function that rounds a correctly rounded
+// double function, such as cr_expf, is compared with the double function
+// of the rest of the library, and one that rounds a function of the
+// library, such as cr_acosf, with an expression in the correctly rounded
+// double functions. cbrtf and hypotf are compared exactly with the halfway
+// points. cr_erff, cr_erfcf, cr_tgammaf and cr_lgammaf have no second
+// double function to compare with, so their references are the double
+// function they round, which tests the rounding but not the bound on the
+// error of that function, and the samples tests check that bound on floats
+// spread over their domains against correctly rounded results from MPFR,
+// as do further tables of the floats nearest to halfway where the double
+// functions are hardest: the middle range of erf, the tail of erfc, the
+// poles of tgamma and the zeros of lgamma. Where the bound of a reference
+// does not decide the rounding it is skipped, and the floats for which
+// that happens, or which are nearest to halfway, are kept with their
+// correctly rounded results from MPFR in the hard_cases tests. The sampled
+// tests take every 1000003rd float. The ignored exhaustive tests take all
+// of them, at between 2 and 30 minutes for each function in release mode,
+// as noted at each test:
+//
+//     cargo test --release --lib cr_expf -- --ignored
+//
+// The exhaustive job of azure-pipelines.yml runs all of them nightly.
+//
+// The functions of two arguments are tested along lines of floats with one
+// argument fixed, and on random pairs.
+//
+// There are no correctly rounded j0f, j1f, y0f, y1f, jnf and ynf. Near the
+// zeros of the Bessel functions their relative error is unbounded, so
+// rounding them correctly takes expansions about each zero, and the
+// double functions are no reference there either.
+
+use super::soft_float::Soft;
+#[cfg(test)]
+use core::cmp::Ordering;
+
+/// `y` rounded to f32, where `y` is the correctly rounded double of the
+/// exact value, which `accurate` evaluates if `y` is halfway between two
+/// floats.
+#[inline]
+pub(crate) fn round_cr<F: FnOnce() -> Soft>(y: f64, accurate: F) -> f32 {
+    let i = y.to_bits();
+    if y == 0.0 || !y.is_finite() || f64::from_bits(i - 1) as f32 == f64::from_bits(i + 1) as f32 {
+        y as f32
+    } else {
+        accurate().to_f32()
+    }
+}
+
+/// `y` rounded to f32, where `y` is within `eps` relative of the exact
+/// value, which `accurate` evaluates if that does not determine the
+/// result.
+#[inline]
+pub(crate) fn round_approx<F: FnOnce() -> Soft>(y: f64, eps: f64, accurate: F) -> f32 {
+    let d = y.abs() * eps;
+    if !y.is_finite() || (y - d) as f32 == (y + d) as f32 {
+        y as f32
+    } else {
+        accurate().to_f32()
+    }
+}
+
+/// `y` rounded to f32, if every value within `err` relative of `y`, and
+/// one ulp of `y` more, rounds to the same float. Test references use this
+/// with the error bound of their own evaluation, which makes a double that
+/// is halfway between two floats a hard case even with `err` zero.
+#[cfg(test)]
+pub(crate) fn rounded(y: f64, err: f64) -> Option<f32> {
+    let d = y.abs() * (err + f64::EPSILON);
+    if y == 0.0 || !y.is_finite() || (y - d) as f32 == (y + d) as f32 {
+        Some(y as f32)
+    } else {
+        None
+    }
+}
+
+/// The float nearest to a positive exact value, given `r`, a float at most
+/// one float away from it, and `cmp`, which compares the exact value with
+/// a double exactly. Ties go to the even float.
+#[cfg(test)]
+pub(crate) fn nearest(r: f32, cmp: impl Fn(f64) -> Ordering) -> f32 {
+    let (lo, hi) = (
+        f32::from_bits(r.to_bits() - 1),
+        f32::from_bits(r.to_bits() + 1),
+    );
+    let (a, b) = (r as f64, lo as f64);
+    /* the halfway points below and above r, where above the largest
+     * float the gap is that below it */
+    let below = (a + b) / 2.0;
+    let above = a + if hi.is_finite() {
+        (hi as f64 - a) / 2.0
+    } else {
+        (a - b) / 2.0
+    };
+    let even = |s: f32| if r.to_bits() & 1 == 0 { r } else { s };
+    match (cmp(below), cmp(above)) {
+        (Ordering::Less, _) => lo,
+        (Ordering::Equal, _) => even(lo),
+        (_, Ordering::Greater) => hi,
+        (_, Ordering::Equal) => even(hi),
+        _ => r,
+    }
+}
+
+/// Checks `f` on the inputs of `cases`, given as bits with the bits of
+/// their correctly rounded results.
+#[cfg(test)]
+pub(crate) fn check_cases(f: fn(f32) -> f32, cases: &[(u32, u32)]) {
+    for &(x, r) in cases.iter() {
+        let x = f32::from_bits(x);
+        assert_eq!(f(x).to_bits(), r, "{:?}", x);
+    }
+}
+
+/// Checks `f` against `reference` on every `step`-th f32, skipping those
+/// for which `reference` returns `None`.
+#[cfg(test)]
+pub(crate) fn check(f: fn(f32) -> f32, reference: fn(f32) -> Option<f32>, step: usize) {
+    for i in (0..=u32::MAX).step_by(step) {
+        let x = f32::from_bits(i);
+        if let Some(r) = reference(x) {
+            let y = f(x);
+            assert!(
+                y.to_bits() == r.to_bits() || y.is_nan() && r.is_nan(),
+                "{:?}: {:?} != {:?}",
+                x,
+                y,
+                r
+            );
+        }
+    }
+}
+
+/// Checks `f` against `reference` on `n` pseudorandom pairs of f32.
+#[cfg(test)]
+pub(crate) fn check2(f: fn(f32, f32) -> f32, reference: fn(f32, f32) -> Option<f32>, n: usize) {
+    let mut s: u64 = 0x9e3779b97f4a7c15;
+    for _ in 0..n {
+        s ^= s << 13;
+        s ^= s >> 7;
+        s ^= s << 17;
+        let (x, y) = (f32::from_bits(s as u32), f32::from_bits((s >> 32) as u32));
+        if let Some(r) = reference(x, y) {
+            let z = f(x, y);
+            assert!(
+                z.to_bits() == r.to_bits() || z.is_nan() && r.is_nan(),
+                "{:?}, {:?}: {:?} != {:?}",
+                x,
+                y,
+                z,
+                r
+            );
+        }
+    }
+}
+
+/// Checks `f` against `reference` with its first argument on every
+/// `step`-th f32 and its second on each of `second`.
+#[cfg(test)]
+pub(crate) fn check2_sweep(
+    f: fn(f32, f32) -> f32,
+    reference: fn(f32, f32) -> Option<f32>,
+    second: &[f32],
+    step: usize,
+) {
+    for &y in second.iter() {
+        for i in (0..=u32::MAX).step_by(step) {
+            let x = f32::from_bits(i);
+            if let Some(r) = reference(x, y) {
+                let z = f(x, y);
+                assert!(
+                    z.to_bits() == r.to_bits() || z.is_nan() && r.is_nan(),
+                    "{:?}, {:?}: {:?} != {:?}",
+                    x,
+                    y,
+                    z,
+                    r
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::soft_float::Soft;
+    use super::{round_approx, round_cr};
+
+    #[test]
+    fn rounding() {
+        let never = || -> Soft { panic!() };
+        assert_eq!(round_cr(1.0, never), 1.0);
+        assert_eq!(round_cr(-0.0, never).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(round_cr(1.0 / 0.0, never), 1.0 / 0.0);
+        assert!(round_cr(f64::NAN, never).is_nan());
+        assert_eq!(round_cr(1.0 + 1.5 * f64::EPSILON, never), 1.0);
+        assert_eq!(round_approx(1.0 + f64::EPSILON, 1e-10, never), 1.0);
+        assert_eq!(round_approx(-1e-300, 1e-10, never), -0.0);
+        /* 1 + 2^-24 is halfway between 1 and 1 + 2^-23 */
+        let half = Soft::from_f64(1.0 + 5.960464477539063e-8);
+        assert_eq!(round_cr(1.0 + 5.960464477539063e-8, || half), 1.0);
+        assert_eq!(round_approx(1.0 + 5.9604e-8, 1e-10, || half), 1.0);
+    }
+}
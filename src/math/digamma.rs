@@ -0,0 +1,144 @@
+/* The digamma function psi(x), the logarithmic derivative of gamma(x).
+ *
+ * For x >= 10 the asymptotic series
+ *
+ *   psi(x) = log(x) - 1/(2*x) - sum of B_2k/(2*k*x^2k)
+ *
+ * is used. Smaller x are brought into [1, 2) with the recurrence
+ * psi(x + 1) = psi(x) + 1/x, whose terms then do not cancel, and there psi
+ * is taken from its Taylor series at its zero x0, whose coefficients are
+ * (-1)^(k+1)*zeta(k + 1, x0), so that the result keeps its relative
+ * accuracy near x0. Negative x are reflected with
+ * psi(x) = psi(1 - x) - pi/tan(pi*x).
+ */
+
+use super::{floor, log, tanpi};
+
+const PI: f64 = 3.141592653589793238462643383279502884;
+
+/* the positive zero of psi, as X0_HI + X0_LO */
+const X0_HI: f64 = 1.4616321449683622e+00;
+const X0_LO: f64 = 9.5499954299656970e-17;
+
+/* (-1)^(k+1)*zeta(k + 1, x0), k = 1, 2, ..., 40 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const TAYLOR: [f64; 40] = [
+    9.6767224544762120e-01, -4.4276316898359208e-01, 2.5849976095565103e-01,
+    -1.6394270544240652e-01, 1.0782405069126237e-01, -7.2199561256454714e-02,
+    4.8804288164143110e-02, -3.3161126474847362e-02, 2.2597648232218104e-02,
+    -1.5424765904948960e-02, 1.0538791616612175e-02, -7.2045343863568687e-03,
+    4.9267813957298533e-03, -3.3698016554393282e-03, 2.3051263267349280e-03,
+    -1.5769367714301972e-03, 1.0788252019162967e-03, -7.3807093899600515e-04,
+    5.0495326583460199e-04, -3.4546802510630769e-04, 2.3635601564027053e-04,
+    -1.6170622091974803e-04, 1.1063372768747410e-04, -7.5691795821950661e-05,
+    5.1785757952220809e-05, -3.5430070947659604e-05, 2.4240066118601318e-05,
+    -1.6584242271854135e-05, 1.1346384584663850e-05, -7.7628176684620943e-06,
+    5.3110609208898636e-06, -3.6336507898010456e-06, 2.4860227331295378e-06,
+    -1.7008538854332607e-06, 1.1636675363548843e-06, -7.9614254312419699e-07,
+    5.4469419306694457e-07, -3.7266161283438227e-07, 2.5496265520215542e-07,
+    -1.7443695117727745e-07,
+];
+
+/* B_2k/(2*k), k = 1, 2, ..., 8 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const ASYM: [f64; 8] = [
+    8.3333333333333333e-02,  /* 1/12 */
+    -8.3333333333333333e-03, /* -1/120 */
+    3.9682539682539683e-03,  /* 1/252 */
+    -4.1666666666666667e-03, /* -1/240 */
+    7.5757575757575758e-03,  /* 1/132 */
+    -2.1092796092796093e-02, /* -691/32760 */
+    8.3333333333333333e-02,  /* 1/12 */
+    -4.4325980392156863e-01, /* -3617/8160 */
+];
+
+/* psi(x) for x > 0 */
+#[inline]
+fn positive(mut x: f64) -> f64 {
+    if x >= 10.0 {
+        let z = 1.0 / (x * x);
+        let mut p = 0.0;
+        for &c in ASYM.iter().rev() {
+            p = p * z + c;
+        }
+        return log(x) - 0.5 / x - p * z;
+    }
+    let mut r = 0.0;
+    if x < 1.0 {
+        r = -1.0 / x;
+        x += 1.0;
+    }
+    /* x - 1 is exact for x >= 2 */
+    while x >= 2.0 {
+        x -= 1.0;
+        r += 1.0 / x;
+    }
+    /* x - X0_HI is exact for 1 <= x < 2 */
+    let t = (x - X0_HI) - X0_LO;
+    let mut p = 0.0;
+    for &c in TAYLOR.iter().rev() {
+        p = p * t + c;
+    }
+    p * t + r
+}
+
+/// Digamma function (f64)
+///
+/// Computes `psi(x)`, the derivative of `lgamma(x)`. It has poles at zero
+/// and the negative integers, where the result is an infinity at +0 and -0
+/// and NaN otherwise.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn digamma(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        return x;
+    }
+    if x == 0.0 {
+        return -1.0 / x;
+    }
+    if x > 0.0 {
+        return positive(x);
+    }
+    if x == f64::NEG_INFINITY || x == floor(x) {
+        return f64::NAN;
+    }
+    /* tanpi(x) is not 0 since x is not an integer */
+    positive(1.0 - x) - PI / tanpi(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testutil::close;
+    use super::digamma;
+
+    #[test]
+    fn special_values() {
+        assert!(digamma(f64::NAN).is_nan());
+        assert!(digamma(f64::NEG_INFINITY).is_nan());
+        assert!(digamma(-3.0).is_nan());
+        assert_eq!(digamma(f64::INFINITY), f64::INFINITY);
+        assert_eq!(digamma(0.0), f64::NEG_INFINITY);
+        assert_eq!(digamma(-0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn values() {
+        /* (x, psi(x)) */
+        let cases = [
+            (1.0, -0.5772156649015329),
+            (0.5, -1.9635100260214235),
+            (1e-10, -10000000000.577215),
+            (1.4616321449683622, -9.241265521729427e-17),
+            (1.5, 0.03648997397857652),
+            (3.7, 1.1671535393615113),
+            (100.0, 4.600161852738087),
+            (1e300, 690.7755278982137),
+            (-0.5, 0.03648997397857652),
+            (-2.3, 3.3173231575618227),
+            (-1e-10, 9999999999.422785),
+        ];
+        for &(x, v) in cases.iter() {
+            assert!(close(digamma(x), v, 4e-16), "digamma({})", x);
+        }
+    }
+}
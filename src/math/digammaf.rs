@@ -0,0 +1,11 @@
+use super::digamma::digamma;
+
+/// Digamma function (f32)
+///
+/// Computes `psi(x)`, the derivative of `lgammaf(x)`, by evaluating
+/// [`digamma`](fn.digamma.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn digammaf(x: f32) -> f32 {
+    digamma(x as f64) as f32
+}
@@ -0,0 +1,48 @@
+use super::soft_float::{self, Soft};
+use super::RoundingMode;
+
+/// Division with explicit rounding (f64)
+///
+/// Returns `x / y`, correctly rounded in the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn div_rm(x: f64, y: f64, rm: RoundingMode) -> f64 {
+    soft_float::div(Soft::from_f64(x), Soft::from_f64(y)).to_f64_rm(rm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{divf_rm, fma, nextafter};
+    use super::div_rm;
+    use super::RoundingMode::*;
+
+    #[test]
+    fn directed() {
+        for i in 0..20000u64 {
+            let h = i.wrapping_mul(0x9e3779b97f4a7c15);
+            let a = f64::from_bits(h & 0x800fffffffffffff | (0x3c0 + h % 127) << 52);
+            let b = f64::from_bits(h.rotate_left(29) & 0x800fffffffffffff | 0x3ff << 52);
+            /* the remainder a - q*b is exact, and a/b - q = r/b */
+            let q = a / b;
+            let r = fma(-q, b, a);
+            let above = (r > 0.0) == (b > 0.0) && r != 0.0;
+            let below = (r < 0.0) == (b > 0.0) && r != 0.0;
+            let up = if above { nextafter(q, 1.0 / 0.0) } else { q };
+            let down = if below { nextafter(q, -1.0 / 0.0) } else { q };
+            assert_eq!(div_rm(a, b, TowardPositive), up, "{:e} / {:e}", a, b);
+            assert_eq!(div_rm(a, b, TowardNegative), down, "{:e} / {:e}", a, b);
+        }
+        assert_eq!(
+            div_rm(1.0, 3.0, TowardNegative).to_bits(),
+            0x3fd5555555555555
+        );
+        assert_eq!(
+            div_rm(1.0, 3.0, TowardPositive).to_bits(),
+            0x3fd5555555555556
+        );
+        assert_eq!(divf_rm(1.0, 3.0, TowardZero).to_bits(), 0x3eaaaaaa);
+        assert_eq!(divf_rm(-1.0, 3.0, TowardNegative).to_bits(), 0xbeaaaaab);
+        assert_eq!(div_rm(1.0, -0.0, TowardZero), -1.0 / 0.0);
+        assert!(div_rm(0.0, 0.0, TowardZero).is_nan());
+    }
+}
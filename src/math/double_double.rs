@@ -0,0 +1,154 @@
+// Double-double arithmetic for the fast paths of the cr_* functions
+//
+// A value is carried as an unevaluated sum hi + lo of two f64, with
+// |lo| <= ulp(hi)/2. Sums and products of two f64 are formed exactly with
+// the algorithms of Knuth and Dekker, so that a double-double holds about
+// 106 bits. None of these functions is meant for operands close to the
+// overflow or underflow thresholds.
+
+/* 2^27 + 1, the splitting constant of Veltkamp */
+const SPLIT: f64 = 134217729.0;
+
+/// `(s, e)` with `s = a + b` rounded and `s + e = a + b` exactly, for
+/// `|a| >= |b|` (or `a` zero).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// `(s, e)` with `s = a + b` rounded and `s + e = a + b` exactly.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/* a = hi + lo with both halves holding at most 26 bits */
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLIT * a;
+    let hi = c - (c - a);
+    (hi, a - hi)
+}
+
+/// `(p, e)` with `p = a * b` rounded and `p + e = a * b` exactly.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    (p, ((ah * bh - p) + ah * bl + al * bh) + al * bl)
+}
+
+/// The sum of two double-doubles.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn add(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let (s, e) = two_sum(a.0, b.0);
+    fast_two_sum(s, e + (a.1 + b.1))
+}
+
+/// The sum of a double-double and a f64.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn add_f64(a: (f64, f64), b: f64) -> (f64, f64) {
+    let (s, e) = two_sum(a.0, b);
+    fast_two_sum(s, e + a.1)
+}
+
+/// The product of two double-doubles.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let (p, e) = two_prod(a.0, b.0);
+    fast_two_sum(p, e + (a.0 * b.1 + a.1 * b.0))
+}
+
+/// The product of a double-double and a f64.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn mul_f64(a: (f64, f64), b: f64) -> (f64, f64) {
+    let (p, e) = two_prod(a.0, b);
+    fast_two_sum(p, e + a.1 * b)
+}
+
+/// The quotient of two double-doubles.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn div(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let q = a.0 / b.0;
+    /* the remainder a - q*b, of which a.0 - q*b.0 is exact */
+    let (p, e) = two_prod(q, b.0);
+    let r = (((a.0 - p) - e) + a.1) - q * b.1;
+    fast_two_sum(q, r / b.0)
+}
+
+/// The square root of a positive double-double.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn sqrt(a: (f64, f64)) -> (f64, f64) {
+    let s = super::sqrt(a.0);
+    /* one Newton step, s + (a - s^2)/(2s), with a.0 - s^2 exact */
+    let (p, e) = two_prod(s, s);
+    fast_two_sum(s, (((a.0 - p) - e) + a.1) / (2.0 * s))
+}
+
+/// Rounds `hi + lo` to nearest if the exact value it approximates, known
+/// to within a relative error `eps`, is certain to round to the same f64.
+///
+/// `hi + lo` must be normalized and its magnitude well inside the range of
+/// normal numbers. `eps` must also cover the error of about `2^-105`
+/// relative committed by this test itself.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn round(hi: f64, lo: f64, eps: f64) -> Option<f64> {
+    let err = eps * hi.abs();
+    let a = hi + (lo - err);
+    let b = hi + (lo + err);
+    if a == b {
+        Some(a)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{div, mul, round, sqrt, two_prod, two_sum};
+
+    #[test]
+    fn exact_operations() {
+        let x1p_60 = f64::from_bits(0x3c30000000000000); // 0x1p-60 === 2 ^ -60
+        assert_eq!(two_sum(1.0, x1p_60), (1.0, x1p_60));
+        let a = 1.0 + f64::EPSILON;
+        assert_eq!(
+            two_prod(a, a),
+            (1.0 + 2.0 * f64::EPSILON, f64::EPSILON * f64::EPSILON)
+        );
+        /* 1/3 to about 106 bits */
+        let (q, r) = div((1.0, 0.0), (3.0, 0.0));
+        let (p, e) = mul((q, r), (3.0, 0.0));
+        assert_eq!(p, 1.0);
+        assert!(e.abs() < 1e-31);
+        /* sqrt(2) to about 106 bits */
+        let (s, t) = sqrt((2.0, 0.0));
+        let (p, e) = mul((s, t), (s, t));
+        assert_eq!(p, 2.0);
+        assert!(e.abs() < 1e-30);
+    }
+
+    #[test]
+    fn rounding_test() {
+        let x1p_60 = f64::from_bits(0x3c30000000000000); // 0x1p-60 === 2 ^ -60
+        let half_ulp = f64::EPSILON / 2.0;
+        assert_eq!(round(1.0, x1p_60, x1p_60), Some(1.0));
+        /* too close to the midpoint between 1 and 1 + 2^-52 */
+        assert_eq!(round(1.0, half_ulp - x1p_60, 2.0 * x1p_60), None);
+        assert_eq!(round(1.0, half_ulp - 4.0 * x1p_60, x1p_60), Some(1.0));
+    }
+}
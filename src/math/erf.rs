@@ -172,6 +172,7 @@ const SB5: f64 = 2.55305040643316442583e+03; /* 0x40A3F219, 0xCEDF3BE6 */
 const SB6: f64 = 4.74528541206955367215e+02; /* 0x407DA874, 0xE79FE763 */
 const SB7: f64 = -2.24409524465858183362e+01; /* 0xC03670E2, 0x42712D62 */
 
+#[inline]
 fn erfc1(x: f64) -> f64 {
     let s: f64;
     let p: f64;
@@ -184,6 +185,7 @@ fn erfc1(x: f64) -> f64 {
     1.0 - ERX - p / q
 }
 
+#[inline]
 fn erfc2(ix: u32, mut x: f64) -> f64 {
     let s: f64;
     let r: f64;
@@ -219,6 +221,7 @@ fn erfc2(ix: u32, mut x: f64) -> f64 {
 /// Calculates an approximation to the “error function”, which estimates
 /// the probability that an observation will fall within x standard
 /// deviations of the mean (assuming a normal distribution).
+#[inline]
 pub fn erf(x: f64) -> f64 {
     let r: f64;
     let s: f64;
@@ -268,6 +271,7 @@ pub fn erf(x: f64) -> f64 {
 /// Is `1 - erf(x)`. Is computed directly, so that you can use it to avoid
 /// the loss of precision that would result from subtracting
 /// large probabilities (on large `x`) from 1.
+#[inline]
 pub fn erfc(x: f64) -> f64 {
     let r: f64;
     let s: f64;
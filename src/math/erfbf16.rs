@@ -0,0 +1,33 @@
+use super::{bf16, erf};
+
+/// Error function (bf16)
+///
+/// Calculates the error function of `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn erfbf16(x: bf16) -> bf16 {
+    bf16::from_f64(erf(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bfloat16::{check, check_f32};
+    use super::super::{cr_erff, erff};
+    use super::{bf16, erfbf16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check_f32(x, erfbf16(x), erff(x.to_f32()), 1);
+        }
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check(x, erfbf16(x), cr_erff(x.to_f32()) as f64);
+        }
+    }
+}
@@ -83,6 +83,7 @@ const SB5: f32 = 2.5530502930e+03; /* 0x451f90ce */
 const SB6: f32 = 4.7452853394e+02; /* 0x43ed43a7 */
 const SB7: f32 = -2.2440952301e+01; /* 0xc1b38712 */
 
+#[inline]
 fn erfc1(x: f32) -> f32 {
     let s: f32;
     let p: f32;
@@ -94,6 +95,7 @@ fn erfc1(x: f32) -> f32 {
     return 1.0 - ERX - p / q;
 }
 
+#[inline]
 fn erfc2(mut ix: u32, mut x: f32) -> f32 {
     let s: f32;
     let r: f32;
@@ -130,6 +132,7 @@ fn erfc2(mut ix: u32, mut x: f32) -> f32 {
 /// Calculates an approximation to the “error function”, which estimates
 /// the probability that an observation will fall within x standard
 /// deviations of the mean (assuming a normal distribution).
+#[inline]
 pub fn erff(x: f32) -> f32 {
     let r: f32;
     let s: f32;
@@ -179,6 +182,7 @@ pub fn erff(x: f32) -> f32 {
 /// Is `1 - erf(x)`. Is computed directly, so that you can use it to avoid
 /// the loss of precision that would result from subtracting
 /// large probabilities (on large `x`) from 1.
+#[inline]
 pub fn erfcf(x: f32) -> f32 {
     let r: f32;
     let s: f32;
@@ -0,0 +1,46 @@
+use super::{erf, f16};
+
+/// Error function (f16)
+///
+/// Calculates an approximation to the “error function”, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn erff16(x: f16) -> f16 {
+    f16::from_f64(erf(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::float16::check;
+    use super::super::soft_math;
+    use super::{erff16, f16};
+
+    #[test]
+    fn values() {
+        assert_eq!(erff16(f16::from_bits(0x3800)).to_bits(), 0x382a); // 0.5
+        assert_eq!(erff16(f16::from_bits(0x3c00)).to_bits(), 0x3abe); // 1
+        assert_eq!(erff16(f16::from_bits(0xc000)).to_bits(), 0xbbf6); // -2
+        assert_eq!(erff16(f16::from_bits(0x4200)), f16::ONE); // 3
+        assert_eq!(erff16(-f16::INFINITY), -f16::ONE);
+    }
+
+    /* erf(x), evaluated to within 2^-122 and rounded to f64 */
+    fn reference(x: f16) -> f64 {
+        let x = x.to_f64();
+        if x.is_nan() || x == 0.0 {
+            x
+        } else if x.is_infinite() {
+            x.signum()
+        } else {
+            soft_math::erf(x).to_f64()
+        }
+    }
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = f16::from_bits(i);
+            check(x, erff16(x), reference(x));
+        }
+    }
+}
@@ -65,6 +65,7 @@
  *          if x < -745.133219101941108420 then exp(x) underflows
  */
 
+use super::fenv::{inexact, oflow};
 use super::scalbn;
 
 const HALF: [f64; 2] = [0.5, -0.5];
@@ -109,17 +110,22 @@ pub fn exp(mut x: f64) -> f64 {
         if x > 709.782712893383973096 {
             /* overflow if x!=inf */
             x *= x1p1023;
-            return x;
+            return if hx < 0x7ff00000 { oflow(x) } else { x };
         }
         if x < -708.39641853226410622 {
             /* underflow if x!=-inf */
-            force_eval!((-x1p_149 / x) as f32);
+            if hx < 0x7ff00000 {
+                force_eval!((-x1p_149 / x) as f32);
+            }
             if x < -745.13321910194110842 {
                 return 0.;
             }
         }
     }
 
+    /* exp(x) is inexact for finite x!=0 */
+    inexact(x != 0.0);
+
     /* argument reduction */
     if hx > 0x3fd62e42 {
         /* if |x| > 0.5 ln2 */
@@ -138,8 +144,6 @@ pub fn exp(mut x: f64) -> f64 {
         hi = x;
         lo = 0.;
     } else {
-        /* inexact if x!=0 */
-        force_eval!(x1p1023 + x);
         return 1. + x;
     }
 
@@ -153,3 +157,29 @@ pub fn exp(mut x: f64) -> f64 {
         scalbn(y, k)
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+    use super::exp;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f64::INFINITY, 0),
+            (f64::NEG_INFINITY, 0),
+            (f64::NAN, 0),
+            (710.0, FE_OVERFLOW | FE_INEXACT),
+            (-750.0, FE_UNDERFLOW | FE_INEXACT),
+            (-740.0, FE_UNDERFLOW | FE_INEXACT),
+            (1e-300, FE_INEXACT),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(exp, x), e, "exp({:?})", x);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::fenv::inexact;
 use super::{exp2, modf, pow};
 
 const LN10: f64 = 3.32192809488736234787031942948939;
@@ -6,16 +7,41 @@ const P10: &[f64] = &[
     1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
 ];
 
+#[inline]
 pub fn exp10(x: f64) -> f64 {
     let (mut y, n) = modf(x);
     let u: u64 = n.to_bits();
     /* fabs(n) < 16 without raising invalid on nan */
     if (u >> 52 & 0x7ff) < 0x3ff + 4 {
         if y == 0.0 {
-            return P10[((n as isize) + 15) as usize];
+            /* the negative powers of 10 are inexact */
+            inexact(n < 0.0);
+            return i!(P10, ((n as isize) + 15) as usize);
         }
         y = exp2(LN10 * y);
-        return y * P10[((n as isize) + 15) as usize];
+        return y * i!(P10, ((n as isize) + 15) as usize);
     }
     return pow(10.0, x);
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+    use super::exp10;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (2.0, 0),
+            (0.5, FE_INEXACT),
+            (400.0, FE_OVERFLOW | FE_INEXACT),
+            (-400.0, FE_UNDERFLOW | FE_INEXACT),
+            (f64::NEG_INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(exp10, x), e, "exp10({:?})", x);
+        }
+    }
+}
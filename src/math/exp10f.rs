@@ -1,3 +1,4 @@
+use super::fenv::{inexact, oflow, uflow};
 use super::{exp2, exp2f, modff};
 
 const LN10_F32: f32 = 3.32192809488736234787031942948939;
@@ -12,10 +13,44 @@ pub fn exp10f(x: f32) -> f32 {
     /* fabsf(n) < 8 without raising invalid on nan */
     if (u >> 23 & 0xff) < 0x7f + 3 {
         if y == 0.0 {
+            /* the negative powers of 10 are inexact */
+            inexact(n < 0.0);
             return P10[((n as isize) + 7) as usize];
         }
         y = exp2f(LN10_F32 * y);
         return y * P10[((n as isize) + 7) as usize];
     }
-    return exp2(LN10_F64 * (x as f64)) as f32;
+    let y = exp2(LN10_F64 * (x as f64)) as f32;
+    /* the conversion to float overflows or underflows for finite x */
+    if !x.is_finite() {
+        y
+    } else if y.is_infinite() {
+        oflow(y)
+    } else if y.abs() < f32::from_bits(0x00800000) {
+        uflow(y)
+    } else {
+        y
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+    use super::exp10f;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (2.0, 0),
+            (0.5, FE_INEXACT),
+            (40.0, FE_OVERFLOW | FE_INEXACT),
+            (-50.0, FE_UNDERFLOW | FE_INEXACT),
+            (f32::NEG_INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(exp10f, x), e, "exp10f({:?})", x);
+        }
+    }
 }
@@ -0,0 +1,80 @@
+use super::{exp10, expm1, with_set_low_word};
+
+const LN10_HI: f64 = 2.30258506536483764648e+00; /* 0x40026bb1, 0xb8000000 */
+const LN10_LO: f64 = 2.76292080375336171306e-08; /* 0x3e5daaa8, 0xac16ea57 */
+
+/// Exponential, base 10, of x, minus 1 (f64)
+///
+/// Calculates 10 raised to the power `x`, minus 1. The result is accurate
+/// even for values of `x` near zero, where using `exp10(x)-1` would lose many
+/// significant digits.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp10m1(x: f64) -> f64 {
+    let x1p100 = f64::from_bits(0x4630000000000000); // 0x1p100 === 2 ^ 100
+    let x1p_100 = f64::from_bits(0x39b0000000000000); // 0x1p-100 === 2 ^ -100
+
+    let ix = (x.to_bits() >> 32) as u32 & 0x7fffffff;
+
+    /* |x| >= 1 or nan: exp10(x)-1 loses at most one bit */
+    if ix >= 0x3ff00000 {
+        if x.is_nan() {
+            return x + x;
+        }
+        return exp10(x) - 1.0;
+    }
+
+    if x == 0.0 {
+        return x;
+    }
+    /* below 0x1p-969 the low products would be subnormal, so x is scaled
+     * by 2^100, which the result undoes exactly unless it is subnormal */
+    let (x1, scale) = if ix < 0x03600000 {
+        (x * x1p100, x1p_100)
+    } else {
+        (x, 1.0)
+    };
+
+    /* t + e = x*ln(10) in double-double, the high product is exact */
+    let xh = with_set_low_word(x1, 0);
+    let xl = x1 - xh;
+    let hi = xh * LN10_HI;
+    let lo = xl * LN10_HI + x1 * LN10_LO;
+    let t = hi + lo;
+    let e = lo - (t - hi);
+
+    /* |x| < 0x1p-54: exp10m1(x) rounds to x*ln(10) */
+    if ix < 0x3c900000 {
+        return (t + e) * scale;
+    }
+
+    /* expm1(t + e) ~= expm1(t) + e*exp(t) */
+    let y = expm1(t);
+    y + e * (1.0 + y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::exp10m1;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(exp10m1(0.0).to_bits(), 0);
+        assert_eq!(exp10m1(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(exp10m1(2.0), 99.0);
+        assert_eq!(exp10m1(-1.0), -0.9);
+        assert_eq!(exp10m1(f64::INFINITY), f64::INFINITY);
+        assert_eq!(exp10m1(f64::NEG_INFINITY), -1.0);
+        assert_eq!(exp10m1(400.0), f64::INFINITY);
+        assert!(exp10m1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn small_arguments() {
+        /* expm1(x*ln10) to 0.5 ulp */
+        assert_eq!(exp10m1(1e-10), 2.3025850932591407e-10);
+        assert_eq!(exp10m1(-3e-300), -6.907755278982137e-300);
+        assert_eq!(exp10m1(f64::from_bits(1)), f64::from_bits(2));
+    }
+}
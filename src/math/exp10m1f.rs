@@ -0,0 +1,38 @@
+use super::exp10m1;
+
+/// Exponential, base 10, of x, minus 1 (f32)
+///
+/// Calculates 10 raised to the power `x`, minus 1, by evaluating
+/// `exp10m1` in double precision. The result is accurate even for values of
+/// `x` near zero, where using `exp10f(x)-1` would lose many significant
+/// digits. It is within one ulp, but rounding the double result to f32 can
+/// miss the nearest float where the exact value is very close to halfway
+/// between two floats.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp10m1f(x: f32) -> f32 {
+    exp10m1(x as f64) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::exp10m1f;
+
+    #[test]
+    fn hard_cases() {
+        /* exact results within 2^-27 ulp of a halfway point, and one that
+         * expm1(x as f64 * LN_10) as f32 rounded the wrong way */
+        let cases = [
+            (0x417d7f60, 0x59c64405),
+            (0xb05acf04, 0xb0fbe9ac),
+            (0x2f2fc918, 0x2fca616d),
+            (0x38bbccef, 0x39583c52),
+            (0xbd652191, 0xbdf78399),
+            (0x4130c914, 0x51d08e2f),
+        ];
+        for &(x, r) in cases.iter() {
+            let y = exp10m1f(f32::from_bits(x)).to_bits();
+            assert_eq!(y, r, "exp10m1f({:#x})", x);
+        }
+    }
+}
@@ -24,6 +24,7 @@
 // OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
 // SUCH DAMAGE.
 
+use super::fenv::{inexact, oflow};
 use super::scalbn;
 
 const TBLSIZE: usize = 256;
@@ -349,7 +350,7 @@ pub fn exp2(mut x: f64) -> f64 {
             /* x >= 1024 or nan */
             /* overflow */
             x *= x1p1023;
-            return x;
+            return if ix < 0x7ff00000 { oflow(x) } else { x };
         }
         if ix >= 0x7ff00000 {
             /* -inf or -nan */
@@ -367,8 +368,11 @@ pub fn exp2(mut x: f64) -> f64 {
         }
     } else if ix < 0x3c900000 {
         /* |x| < 0x1p-54 */
+        inexact(x != 0.0);
         return 1.0 + x;
     }
+    /* exp2(x) is inexact unless x is an integer */
+    inexact(x as i32 as f64 != x);
 
     /* Reduce x, computing z, i0, and k. */
     let ui = f64::to_bits(x + redux);
@@ -393,3 +397,26 @@ fn i0_wrap_test() {
     let x = -3.0 / 256.0;
     assert_eq!(exp2(x), f64::from_bits(0x3fefbdba3692d514));
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+    use super::exp2;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (3.0, 0),
+            (0.5, FE_INEXACT),
+            (1024.0, FE_OVERFLOW | FE_INEXACT),
+            (-1080.0, FE_UNDERFLOW | FE_INEXACT),
+            (f64::INFINITY, 0),
+            (f64::NEG_INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(exp2, x), e, "exp2({:?})", x);
+        }
+    }
+}
@@ -24,6 +24,8 @@
 // OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
 // SUCH DAMAGE.
 
+use super::fenv::{inexact, oflow};
+
 const TBLSIZE: usize = 16;
 
 static EXP2FT: [u64; TBLSIZE] = [
@@ -99,11 +101,11 @@ pub fn exp2f(mut x: f32) -> f32 {
         if ui >= 0x43000000 && ui < 0x80000000 {
             /* x >= 128 */
             x *= x1p127;
-            return x;
+            return if ix < 0x7f800000 { oflow(x) } else { x };
         }
         if ui >= 0x80000000 {
             /* x < -126 */
-            if ui >= 0xc3160000 || (ui & 0x0000ffff != 0) {
+            if ix < 0x7f800000 && (ui >= 0xc3160000 || (ui & 0x0000ffff != 0)) {
                 force_eval!(f32::from_bits(0x80000001) / x);
             }
             if ui >= 0xc3160000 {
@@ -113,8 +115,11 @@ pub fn exp2f(mut x: f32) -> f32 {
         }
     } else if ix <= 0x33000000 {
         /* |x| <= 0x1p-25 */
+        inexact(x != 0.0);
         return 1.0 + x;
     }
+    /* exp2f(x) is inexact unless x is an integer */
+    inexact(x as i32 as f32 != x);
 
     /* Reduce x, computing z, i0, and k. */
     let ui = f32::to_bits(x + redux);
@@ -134,3 +139,26 @@ pub fn exp2f(mut x: f32) -> f32 {
     /* Scale by 2**k */
     (r * ukf) as f32
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+    use super::exp2f;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (3.0, 0),
+            (0.5, FE_INEXACT),
+            (128.0, FE_OVERFLOW | FE_INEXACT),
+            (-160.0, FE_UNDERFLOW | FE_INEXACT),
+            (f32::INFINITY, 0),
+            (f32::NEG_INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(exp2f, x), e, "exp2f({:?})", x);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use super::{exp2, expm1, with_set_low_word};
+
+const LN2_HI: f64 = 6.93147175014019012451e-01; /* 0x3fe62e42, 0xfc000000 */
+const LN2_LO: f64 = 5.54592629696606051924e-09; /* 0x3e37d1cf, 0x79abc9e4 */
+
+/// Exponential, base 2, of x, minus 1 (f64)
+///
+/// Calculates 2 raised to the power `x`, minus 1. The result is accurate
+/// even for values of `x` near zero, where using `exp2(x)-1` would lose many
+/// significant digits.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2m1(x: f64) -> f64 {
+    let x1p100 = f64::from_bits(0x4630000000000000); // 0x1p100 === 2 ^ 100
+    let x1p_100 = f64::from_bits(0x39b0000000000000); // 0x1p-100 === 2 ^ -100
+
+    let ix = (x.to_bits() >> 32) as u32 & 0x7fffffff;
+
+    /* |x| >= 1 or nan: exp2(x)-1 loses at most one bit */
+    if ix >= 0x3ff00000 {
+        if x.is_nan() {
+            return x + x;
+        }
+        return exp2(x) - 1.0;
+    }
+
+    if x == 0.0 {
+        return x;
+    }
+    /* below 0x1p-969 the low products would be subnormal, so x is scaled
+     * by 2^100, which the result undoes exactly unless it is subnormal */
+    let (x1, scale) = if ix < 0x03600000 {
+        (x * x1p100, x1p_100)
+    } else {
+        (x, 1.0)
+    };
+
+    /* t + e = x*ln(2) in double-double, the high product is exact */
+    let xh = with_set_low_word(x1, 0);
+    let xl = x1 - xh;
+    let hi = xh * LN2_HI;
+    let lo = xl * LN2_HI + x1 * LN2_LO;
+    let t = hi + lo;
+    let e = lo - (t - hi);
+
+    /* |x| < 0x1p-54: exp2m1(x) rounds to x*ln(2) */
+    if ix < 0x3c900000 {
+        return (t + e) * scale;
+    }
+
+    /* expm1(t + e) ~= expm1(t) + e*exp(t) */
+    let y = expm1(t);
+    y + e * (1.0 + y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::exp2m1;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(exp2m1(0.0).to_bits(), 0);
+        assert_eq!(exp2m1(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(exp2m1(1.0), 1.0);
+        assert_eq!(exp2m1(-1.0), -0.5);
+        assert_eq!(exp2m1(10.0), 1023.0);
+        assert_eq!(exp2m1(f64::INFINITY), f64::INFINITY);
+        assert_eq!(exp2m1(f64::NEG_INFINITY), -1.0);
+        assert_eq!(exp2m1(1024.0), f64::INFINITY);
+        assert!(exp2m1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn small_arguments() {
+        /* expm1(x*ln2) to 0.5 ulp */
+        assert_eq!(exp2m1(1e-10), 6.931471805839679e-11);
+        assert_eq!(exp2m1(-3e-300), -2.079441541679836e-300);
+        assert_eq!(exp2m1(f64::from_bits(1)), f64::from_bits(1));
+        assert_eq!(exp2m1(f64::from_bits(3)), f64::from_bits(2));
+    }
+}
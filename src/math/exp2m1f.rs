@@ -0,0 +1,35 @@
+use super::exp2m1;
+
+/// Exponential, base 2, of x, minus 1 (f32)
+///
+/// Calculates 2 raised to the power `x`, minus 1, by evaluating `exp2m1`
+/// in double precision. The result is accurate even for values of `x` near
+/// zero, where using `exp2f(x)-1` would lose many significant digits. As the
+/// double result is rounded again to f32, it is within one ulp but not
+/// always the nearest float.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2m1f(x: f32) -> f32 {
+    exp2m1(x as f64) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::exp2m1f;
+
+    #[test]
+    fn hard_cases() {
+        /* exact results within 2^-27 ulp of a halfway point */
+        let cases = [
+            (0x284ff562, 0x28102558),
+            (0x360a69b8, 0x35bfe19a),
+            (0xacce1f0f, 0xac8edf56),
+            (0xae9abff7, 0xae568764),
+            (0x3078d80d, 0x302c7c49),
+        ];
+        for &(x, r) in cases.iter() {
+            let y = exp2m1f(f32::from_bits(x)).to_bits();
+            assert_eq!(y, r, "exp2m1f({:#x})", x);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::{bf16, exp};
+
+/// Exponential, base *e* (bf16)
+///
+/// Calculates *e* raised to the power `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expbf16(x: bf16) -> bf16 {
+    bf16::from_f64(exp(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bfloat16::{check, check_f32};
+    use super::super::{cr_exp, expf};
+    use super::{bf16, expbf16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check_f32(x, expbf16(x), expf(x.to_f32()), 1);
+        }
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check(x, expbf16(x), cr_exp(x.to_f64()));
+        }
+    }
+}
@@ -13,6 +13,7 @@
  * ====================================================
  */
 
+use super::fenv::{inexact, oflow};
 use super::scalbnf;
 
 const HALF: [f32; 2] = [0.5, -0.5];
@@ -51,11 +52,13 @@ pub fn expf(mut x: f32) -> f32 {
             /* x >= 88.722839f */
             /* overflow */
             x *= x1p127;
-            return x;
+            return if hx < 0x7f800000 { oflow(x) } else { x };
         }
         if signb {
-            /* underflow */
-            force_eval!(-x1p_126 / x);
+            /* underflow if x!=-inf */
+            if hx < 0x7f800000 {
+                force_eval!(-x1p_126 / x);
+            }
             if hx >= 0x42cff1b5 {
                 /* x <= -103.972084f */
                 return 0.;
@@ -63,6 +66,9 @@ pub fn expf(mut x: f32) -> f32 {
         }
     }
 
+    /* expf(x) is inexact for finite x!=0 */
+    inexact(x != 0.0);
+
     /* argument reduction */
     let k: i32;
     let hi: f32;
@@ -85,8 +91,6 @@ pub fn expf(mut x: f32) -> f32 {
         hi = x;
         lo = 0.;
     } else {
-        /* raise inexact */
-        force_eval!(x1p127 + x);
         return 1. + x;
     }
 
@@ -100,3 +104,29 @@ pub fn expf(mut x: f32) -> f32 {
         scalbnf(y, k)
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+    use super::expf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f32::INFINITY, 0),
+            (f32::NEG_INFINITY, 0),
+            (f32::NAN, 0),
+            (89.0, FE_OVERFLOW | FE_INEXACT),
+            (-104.0, FE_UNDERFLOW | FE_INEXACT),
+            (-100.0, FE_UNDERFLOW | FE_INEXACT),
+            (1e-30, FE_INEXACT),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(expf, x), e, "expf({:?})", x);
+        }
+    }
+}
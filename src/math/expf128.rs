@@ -0,0 +1,146 @@
+/* expf128(x)
+ * Returns the exponential of x.
+ *
+ * Method
+ *   1. Argument reduction:
+ *      Given x, find r and integer k such that
+ *
+ *               x = k*ln2 + r,  |r| <= 0.5*ln2.
+ *
+ *      ln2 is split into ln2_hi + ln2_lo, where ln2_hi has 98
+ *      significant bits so that k*ln2_hi is exact, and r is kept as
+ *      r_hi + r_lo.
+ *
+ *   2. Approximation of exp(r) by the Taylor series
+ *          exp(r) = 1 + r + r^2*P(r),
+ *          P(r) = 1/2! + r/3! + ... + r^23/25!,
+ *      whose truncation error on [-0.34658,0.34658] is below 2^-120.
+ *      The low part of r is added as exp(r_hi + r_lo) ~ exp(r_hi) + r_lo,
+ *      and 1 + r_hi is split into an exact sum before the final addition.
+ *
+ *   3. Scale back to obtain exp(x):
+ *         exp(x) = 2^k * exp(r)
+ *
+ * Special cases:
+ *      exp(INF) is INF, exp(NaN) is NaN;
+ *      exp(-INF) is 0, and
+ *      for finite argument, only exp(0)=1 is exact.
+ *
+ * Accuracy:
+ *      the error is below 0.6 ulp, except for subnormal results, where
+ *      it is below 1 ulp.
+ *
+ * Misc. info.
+ *      For IEEE quad
+ *          if x >  1.1356523406294143949491931077970765e+04 then exp(x) overflows
+ *          if x < -1.1433462743336297878837243843452623e+04 then exp(x) underflows
+ */
+
+use super::{f128, rintf128, scalbnf128};
+
+const LN2_HI: f128 = f128::from_bits(0x3ffe62e42fefa39ef35793c767300000); /* 0.693147180559945309417232121457981864 */
+const LN2_LO: f128 = f128::from_bits(0x3f98f97b57a079a193394c5b16c5068c); /* 1.94704509238074995158795957333327386e-31 */
+const INVLN2: f128 = f128::from_bits(0x3fff71547652b82fe1777d0ffda0d23a); /* 1.44269504088896340735992468100189204 */
+const O_THRESHOLD: f128 = f128::from_bits(0x400c62e42fefa39ef35793c7673007e6); /* 11356.523406294143949491931077970765 */
+const U_THRESHOLD: f128 = f128::from_bits(0xc00c654bb3b2c73ebb059fabb506ff34); /* -11433.4627433362978788372438434526231 */
+const P: [f128; 24] = [
+    f128::from_bits(0x3ffe0000000000000000000000000000), /* 1/2! */
+    f128::from_bits(0x3ffc5555555555555555555555555555), /* 1/3! */
+    f128::from_bits(0x3ffa5555555555555555555555555555), /* 1/4! */
+    f128::from_bits(0x3ff81111111111111111111111111111), /* 1/5! */
+    f128::from_bits(0x3ff56c16c16c16c16c16c16c16c16c17), /* 1/6! */
+    f128::from_bits(0x3ff2a01a01a01a01a01a01a01a01a01a), /* 1/7! */
+    f128::from_bits(0x3fefa01a01a01a01a01a01a01a01a01a), /* 1/8! */
+    f128::from_bits(0x3fec71de3a556c7338faac1c88e50017), /* 1/9! */
+    f128::from_bits(0x3fe927e4fb7789f5c72ef016d3ea6679), /* 1/10! */
+    f128::from_bits(0x3fe5ae64567f544e38fe747e4b837dc7), /* 1/11! */
+    f128::from_bits(0x3fe21eed8eff8d897b544da987acfe85), /* 1/12! */
+    f128::from_bits(0x3fde6124613a86d097ca38331d23af68), /* 1/13! */
+    f128::from_bits(0x3fda93974a8c07c9d20badf145dfa3e5), /* 1/14! */
+    f128::from_bits(0x3fd6ae7f3e733b81f11d8656b0ee8cb0), /* 1/15! */
+    f128::from_bits(0x3fd2ae7f3e733b81f11d8656b0ee8cb0), /* 1/16! */
+    f128::from_bits(0x3fce952c77030ad4a6b2605197771b00), /* 1/17! */
+    f128::from_bits(0x3fca6827863b97d977bb004886a2c2ab), /* 1/18! */
+    f128::from_bits(0x3fc62f49b4681415724ca1ec3b7b9675), /* 1/19! */
+    f128::from_bits(0x3fc1e542ba402022507a9cad2bf8f0bb), /* 1/20! */
+    f128::from_bits(0x3fbd71b8ef6dcf5718bef146fcee6e45), /* 1/21! */
+    f128::from_bits(0x3fb90ce396db7f8529450c90b7f338ec), /* 1/22! */
+    f128::from_bits(0x3fb4761b413163819d97b8704dd7f628), /* 1/23! */
+    f128::from_bits(0x3faff2cf01972f577cca4b4067ca9d8a), /* 1/24! */
+    f128::from_bits(0x3fab3f3ccdd165fa8d4e44a419776f11), /* 1/25! */
+];
+
+/* exp(x + xtail) for finite x with |xtail| <= ulp(x), where the result
+ * neither overflows nor underflows to zero */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_expf128(x: f128, xtail: f128) -> f128 {
+    /* reduce x into [-0.5*ln2, 0.5*ln2] */
+    let kf = rintf128(x * INVLN2);
+    let k = kf.to_f64() as i32;
+    let hi = x - kf * LN2_HI;
+    let lo = kf * LN2_LO - xtail;
+    let r = hi - lo;
+    let rlo = (hi - r) - lo;
+
+    let mut q = P[23];
+    for &c in P[..23].iter().rev() {
+        q = q * r + c;
+    }
+    /* 1 + r is formed exactly as hi + lo before adding the rest */
+    let hi = f128::ONE + r;
+    let lo = r - (hi - f128::ONE);
+    scalbnf128(hi + (lo + (r * r * q + rlo)), k)
+}
+
+/// Exponential, base *e* (f128)
+///
+/// Calculate the exponential of `x`, that is, *e* raised to the power `x`
+/// (where *e* is the base of the natural system of logarithms, approximately 2.71828).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expf128(x: f128) -> f128 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > O_THRESHOLD {
+        return f128::INFINITY;
+    }
+    if x < U_THRESHOLD {
+        return f128::ZERO;
+    }
+    k_expf128(x, f128::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expf128, f128};
+
+    #[test]
+    fn special_values() {
+        assert!(expf128(f128::NAN).is_nan());
+        assert_eq!(expf128(f128::INFINITY), f128::INFINITY);
+        assert_eq!(expf128(f128::NEG_INFINITY).to_bits(), 0);
+        assert_eq!(expf128(f128::ZERO), f128::ONE);
+        assert_eq!(expf128(-f128::ZERO), f128::ONE);
+        assert_eq!(expf128(f128::from(11357.0)), f128::INFINITY);
+        assert_eq!(expf128(f128::from(-11434.0)).to_bits(), 0);
+    }
+
+    #[test]
+    fn values() {
+        /* correctly rounded results */
+        let cases = [
+            (1.0, 0x40005bf0a8b1457695355fb8ac404e7a),
+            (-1.0, 0x3ffd78b56362cef37c6aeb7b1e0a4154),
+            (0.125, 0x3fff2216045b6f5ccf9ced688384e06c),
+            (100.0, 0x408f3494a9b171bf4acc225093322428),
+            (-11400.0, 0x000000000000000000009b0b43706715),
+            (11356.0, 0x7ffe2f5c036bda9471f881247e0e3df7),
+        ];
+        for &(x, r) in cases.iter() {
+            let y = expf128(f128::from(x)).to_bits();
+            assert_eq!(y, r, "exp({})", x);
+        }
+    }
+}
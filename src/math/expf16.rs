@@ -0,0 +1,42 @@
+use super::{exp, f16};
+
+/// Exponential, base *e* (f16)
+///
+/// Calculates *e* raised to the power `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expf16(x: f16) -> f16 {
+    f16::from_f64(exp(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cr_exp;
+    use super::super::float16::check;
+    use super::{expf16, f16};
+
+    #[test]
+    fn values() {
+        let cases = [
+            (0x3c00, 0x4170), // 1
+            (0xbc00, 0x35e3), // -1
+            (0x4980, 0x7b4f), // 11
+            (0x4a00, 0x7c00), // 12 overflows
+            (0xcc40, 0x0001), // -17 is the smallest subnormal
+            (0x0000, 0x3c00),
+            (0xfc00, 0x0000),
+        ];
+        for &(x, r) in cases.iter() {
+            assert_eq!(expf16(f16::from_bits(x)).to_bits(), r, "exp({:#x})", x);
+        }
+        assert!(expf16(f16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = f16::from_bits(i);
+            check(x, expf16(x), cr_exp(x.to_f64()));
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/* expf80(x)
+ * Returns the exponential of x.
+ *
+ * Method
+ *      x is converted exactly to f128, see expf128, and the result is
+ *      rounded to f80. The f128 result has a relative error of about
+ *      2^-113, far below half an f80 ulp (2^-64), so the final rounding
+ *      is correct in all but very rare cases. Both formats have the same
+ *      exponent range, so overflow and underflow happen for the same x.
+ */
+
+use super::{expf128, f128, f80};
+
+/// Exponential, base *e* (f80)
+///
+/// Calculate the exponential of `x`, that is, *e* raised to the power `x`
+/// (where *e* is the base of the natural system of logarithms, approximately 2.71828).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expf80(x: f80) -> f80 {
+    f80::from_f128(expf128(f128::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expf80, f80};
+
+    #[test]
+    fn values() {
+        assert_eq!(expf80(f80::ZERO), f80::ONE);
+        assert_eq!(expf80(f80::INFINITY), f80::INFINITY);
+        assert_eq!(expf80(f80::NEG_INFINITY).to_bits(), 0);
+        assert!(expf80(f80::NAN).is_nan());
+        /* correctly rounded results */
+        let cases = [
+            (1.0, 0x4000adf85458a2bb4a9b),
+            (-1.0, 0x3ffdbc5ab1b16779be35),
+            (100.0, 0x408f9a4a54d8b8dfa566),
+            (11356.0, 0x7ffe97ae01b5ed4a38fc),
+            (-11390.0, 0x00000000000000001a0e),
+        ];
+        for &(x, r) in cases.iter() {
+            assert_eq!(expf80(f80::from(x)).to_bits(), r, "exp({})", x);
+        }
+    }
+}
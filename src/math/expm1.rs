@@ -10,6 +10,7 @@
  * ====================================================
  */
 
+use super::fenv::{inexact, oflow};
 use core::f64;
 
 const O_THRESHOLD: f64 = 7.09782712893383973096e+02; /* 0x40862E42, 0xFEFA39EF */
@@ -51,13 +52,16 @@ pub fn expm1(mut x: f64) -> f64 {
             return x;
         }
         if sign != 0 {
+            inexact(hx < 0x7ff00000);
             return -1.0;
         }
         if x > O_THRESHOLD {
             x *= f64::from_bits(0x7fe0000000000000);
-            return x;
+            return if hx < 0x7ff00000 { oflow(x) } else { x };
         }
     }
+    /* expm1(x) is inexact for finite x!=0 */
+    inexact(x != 0.0);
 
     /* argument reduction */
     if hx > 0x3fd62e42 {
@@ -83,7 +87,7 @@ pub fn expm1(mut x: f64) -> f64 {
         c = (hi - x) - lo;
     } else if hx < 0x3c900000 {
         /* |x| < 2**-54, return x */
-        if hx < 0x00100000 {
+        if hx < 0x00100000 && x != 0.0 {
             force_eval!(x);
         }
         return x;
@@ -143,3 +147,26 @@ mod tests {
         assert_eq!(super::expm1(1.1), 2.0041660239464334);
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW};
+    use super::expm1;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (1000.0, FE_OVERFLOW | FE_INEXACT),
+            (-1000.0, FE_INEXACT),
+            (f64::NEG_INFINITY, 0),
+            (f64::INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(expm1, x), e, "expm1({:?})", x);
+        }
+    }
+}
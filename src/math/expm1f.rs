@@ -13,6 +13,8 @@
  * ====================================================
  */
 
+use super::fenv::{inexact, oflow};
+
 const O_THRESHOLD: f32 = 8.8721679688e+01; /* 0x42b17180 */
 const LN2_HI: f32 = 6.9313812256e-01; /* 0x3f317180 */
 const LN2_LO: f32 = 9.0580006145e-06; /* 0x3717f7d1 */
@@ -49,13 +51,16 @@ pub fn expm1f(mut x: f32) -> f32 {
             return x;
         }
         if sign {
+            inexact(hx < 0x7f800000);
             return -1.;
         }
         if x > O_THRESHOLD {
             x *= x1p127;
-            return x;
+            return if hx < 0x7f800000 { oflow(x) } else { x };
         }
     }
+    /* expm1f(x) is inexact for finite x!=0 */
+    inexact(x != 0.0);
 
     let k: i32;
     let hi: f32;
@@ -85,7 +90,7 @@ pub fn expm1f(mut x: f32) -> f32 {
         c = (hi - x) - lo;
     } else if hx < 0x33000000 {
         /* when |x|<2**-25, return x */
-        if hx < 0x00800000 {
+        if hx < 0x00800000 && x != 0.0 {
             force_eval!(x * x);
         }
         return x;
@@ -133,3 +138,26 @@ pub fn expm1f(mut x: f32) -> f32 {
         (x - (e + uf) + 1.) * twopk
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW};
+    use super::expm1f;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (100.0, FE_OVERFLOW | FE_INEXACT),
+            (-100.0, FE_INEXACT),
+            (f32::NEG_INFINITY, 0),
+            (f32::INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(expm1f, x), e, "expm1f({:?})", x);
+        }
+    }
+}
@@ -1,33 +1,226 @@
 // src: musl/src/fenv/fenv.c
-/* Dummy functions for archs lacking fenv implementation */
+/* Floating-point environment
+ *
+ * By default these are the dummy functions for archs lacking fenv
+ * implementation: no exception is ever reported and the exception macros
+ * are 0.
+ *
+ * With the `soft-fenv` feature, which links `std`, the exception flags are
+ * kept in software, separately for each thread as in C. They are raised by
+ * the functions of this crate where musl forces the evaluation of an
+ * expression for its side effect (see `force_eval!`). The elementary
+ * functions
+ *      exp, exp2, exp10, expm1, log, log2, log10, log1p, pow, sqrt,
+ *      sin, cos, tan, sincos, asin, acos, atan, sinh, cosh, tanh,
+ *      asinh, acosh, atanh
+ * and their f32 versions also raise them explicitly: invalid, divbyzero,
+ * overflow and underflow on their special-value paths, and inexact for
+ * every inexact result. Ordinary arithmetic does not raise them, so the
+ * other functions report only what their `force_eval!` records.
+ *
+ * Only rounding to nearest is supported in either case.
+ */
 
+#[cfg(feature = "soft-fenv")]
+use core::cell::Cell;
+
+#[cfg(feature = "soft-fenv")]
+pub const FE_INVALID: i32 = 1;
+#[cfg(feature = "soft-fenv")]
+pub const FE_DIVBYZERO: i32 = 4;
+#[cfg(feature = "soft-fenv")]
+pub const FE_OVERFLOW: i32 = 8;
+#[cfg(feature = "soft-fenv")]
+pub const FE_UNDERFLOW: i32 = 16;
+#[cfg(feature = "soft-fenv")]
+pub const FE_INEXACT: i32 = 32;
+
+#[cfg(not(feature = "soft-fenv"))]
+pub const FE_INVALID: i32 = 0;
+#[cfg(not(feature = "soft-fenv"))]
+pub const FE_DIVBYZERO: i32 = 0;
+#[cfg(not(feature = "soft-fenv"))]
+pub const FE_OVERFLOW: i32 = 0;
+#[cfg(not(feature = "soft-fenv"))]
 pub const FE_UNDERFLOW: i32 = 0;
+#[cfg(not(feature = "soft-fenv"))]
 pub const FE_INEXACT: i32 = 0;
 
+pub const FE_ALL_EXCEPT: i32 = FE_INVALID | FE_DIVBYZERO | FE_OVERFLOW | FE_UNDERFLOW | FE_INEXACT;
+
 pub const FE_TONEAREST: i32 = 0;
-pub const FE_TOWARDZERO: i32 = 0;
+pub const FE_DOWNWARD: i32 = 0x400;
+pub const FE_UPWARD: i32 = 0x800;
+pub const FE_TOWARDZERO: i32 = 0xc00;
+
+#[cfg(feature = "soft-fenv")]
+std::thread_local! {
+    static EXCEPT: Cell<i32> = const { Cell::new(0) };
+}
 
+/// Clears the exception flags in `mask`.
 #[inline]
-pub fn feclearexcept(_mask: i32) -> i32 {
+pub fn feclearexcept(mask: i32) -> i32 {
+    /* try_with, as with can panic, though not for a Cell */
+    #[cfg(feature = "soft-fenv")]
+    let _ = EXCEPT.try_with(|e| e.set(e.get() & !(mask & FE_ALL_EXCEPT)));
+    let _ = mask;
     0
 }
 
+/// Raises the exceptions in `mask`.
 #[inline]
-pub fn feraiseexcept(_mask: i32) -> i32 {
+pub fn feraiseexcept(mask: i32) -> i32 {
+    #[cfg(feature = "soft-fenv")]
+    let _ = EXCEPT.try_with(|e| e.set(e.get() | mask & FE_ALL_EXCEPT));
+    let _ = mask;
     0
 }
 
+/// Returns the exception flags in `mask` that are currently set.
 #[inline]
-pub fn fetestexcept(_mask: i32) -> i32 {
-    0
+pub fn fetestexcept(mask: i32) -> i32 {
+    #[cfg(feature = "soft-fenv")]
+    return EXCEPT.try_with(|e| e.get()).unwrap_or(0) & mask & FE_ALL_EXCEPT;
+    #[cfg(not(feature = "soft-fenv"))]
+    {
+        let _ = mask;
+        0
+    }
 }
 
+/// Returns the rounding mode, which is always `FE_TONEAREST` as only
+/// rounding to nearest is supported.
 #[inline]
 pub fn fegetround() -> i32 {
     FE_TONEAREST
 }
 
+/// Sets the rounding mode to `r`, and returns 0 if it could. Only rounding
+/// to nearest is supported, so this fails and returns -1 for the other
+/// modes, as musl does on archs lacking a fenv implementation.
 #[inline]
-pub fn fesetround(_r: i32) -> i32 {
-    0
+pub fn fesetround(r: i32) -> i32 {
+    if r == FE_TONEAREST {
+        0
+    } else {
+        -1
+    }
+}
+
+/* musl computes the special results of a function with arithmetic that
+ * raises the corresponding exceptions, which the software fenv does not
+ * see: these return such a result x and raise them explicitly. */
+#[inline]
+pub(crate) fn oflow<T>(x: T) -> T {
+    feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+    x
+}
+
+#[inline]
+pub(crate) fn uflow<T>(x: T) -> T {
+    feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+    x
+}
+
+#[inline]
+pub(crate) fn divzero<T>(x: T) -> T {
+    feraiseexcept(FE_DIVBYZERO);
+    x
+}
+
+#[inline]
+pub(crate) fn invalid<T>(x: T) -> T {
+    feraiseexcept(FE_INVALID);
+    x
+}
+
+/* Raises inexact if the result of the function is not exact, which for
+ * the elementary functions holds at all but a few arguments. */
+#[inline]
+pub(crate) fn inexact(inexact: bool) {
+    if inexact {
+        feraiseexcept(FE_INEXACT);
+    }
+}
+
+/* The exceptions raised by an operation with the result x: invalid for a
+ * NaN, overflow for an infinity and underflow for a zero or subnormal, all
+ * but invalid being inexact. This is what force_eval! records. */
+#[cfg(feature = "soft-fenv")]
+pub(crate) trait Exceptions {
+    fn exceptions(self) -> i32;
+}
+
+#[cfg(feature = "soft-fenv")]
+impl Exceptions for f64 {
+    #[inline]
+    fn exceptions(self) -> i32 {
+        let e = self.to_bits() >> 52 & 0x7ff;
+        if self.is_nan() {
+            FE_INVALID
+        } else if e == 0x7ff {
+            FE_OVERFLOW | FE_INEXACT
+        } else if e == 0 {
+            FE_UNDERFLOW | FE_INEXACT
+        } else {
+            FE_INEXACT
+        }
+    }
+}
+
+#[cfg(feature = "soft-fenv")]
+impl Exceptions for f32 {
+    #[inline]
+    fn exceptions(self) -> i32 {
+        let e = self.to_bits() >> 23 & 0xff;
+        if self.is_nan() {
+            FE_INVALID
+        } else if e == 0xff {
+            FE_OVERFLOW | FE_INEXACT
+        } else if e == 0 {
+            FE_UNDERFLOW | FE_INEXACT
+        } else {
+            FE_INEXACT
+        }
+    }
+}
+
+#[cfg(feature = "soft-fenv")]
+#[inline]
+pub(crate) fn force_eval<T: Exceptions>(x: T) {
+    feraiseexcept(x.exceptions());
+}
+
+/* the exceptions raised by f(x), which the flags of the test's own
+ * thread record alone */
+#[cfg(all(test, feature = "soft-fenv"))]
+pub(crate) fn raised<T, R>(f: fn(T) -> R, x: T) -> i32 {
+    feclearexcept(FE_ALL_EXCEPT);
+    let _ = f(x);
+    fetestexcept(FE_ALL_EXCEPT)
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod tests {
+    use super::super::*;
+    use super::{fegetround, fesetround, FE_TONEAREST, FE_TOWARDZERO, FE_UPWARD};
+
+    #[test]
+    fn flags() {
+        feclearexcept(FE_ALL_EXCEPT);
+        feraiseexcept(FE_OVERFLOW | FE_INVALID);
+        assert_eq!(fetestexcept(FE_ALL_EXCEPT), FE_OVERFLOW | FE_INVALID);
+        assert_eq!(fetestexcept(0), 0);
+        feclearexcept(FE_INVALID);
+        assert_eq!(fetestexcept(FE_ALL_EXCEPT), FE_OVERFLOW);
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(fesetround(FE_TONEAREST), 0);
+        assert_eq!(fesetround(FE_UPWARD), -1);
+        assert_eq!(fesetround(FE_TOWARDZERO), -1);
+        assert_eq!(fegetround(), FE_TONEAREST);
+    }
 }
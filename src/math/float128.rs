@@ -0,0 +1,266 @@
+use super::soft_float::{self, Soft};
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+const MANT_MASK: u128 = (1 << 112) - 1;
+
+/// Quadruple precision (IEEE 754 binary128) floating-point number
+///
+/// A software stand-in for the `f128` primitive, which is not yet
+/// available on stable. It stores the bit pattern of the number and
+/// implements the arithmetic operators, comparisons and conversions in
+/// software, correctly rounded to nearest.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct f128(u128);
+
+impl f128 {
+    /// Positive zero.
+    pub const ZERO: f128 = f128(0);
+    /// The value 1.
+    pub const ONE: f128 = f128(0x3fff << 112);
+    /// Quiet NaN.
+    pub const NAN: f128 = f128(0x7fff8 << 108);
+    /// Positive infinity.
+    pub const INFINITY: f128 = f128(0x7fff << 112);
+    /// Negative infinity.
+    pub const NEG_INFINITY: f128 = f128(0xffff << 112);
+    /// Largest finite value.
+    pub const MAX: f128 = f128(0x7ffe << 112 | MANT_MASK);
+    /// Smallest positive normal value.
+    pub const MIN_POSITIVE: f128 = f128(1 << 112);
+    /// Difference between 1 and the next larger value.
+    pub const EPSILON: f128 = f128((0x3fff - 112) << 112);
+
+    #[inline]
+    pub const fn from_bits(v: u128) -> f128 {
+        f128(v)
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// Converts to `f64`, rounding to nearest.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn to_f64(self) -> f64 {
+        self.unpack().to_f64()
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_nan(self) -> bool {
+        self.0 & !(1 << 127) > 0x7fff << 112
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_infinite(self) -> bool {
+        self.0 & !(1 << 127) == 0x7fff << 112
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_finite(self) -> bool {
+        self.0 & !(1 << 127) < 0x7fff << 112
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_sign_negative(self) -> bool {
+        self.0 >> 127 != 0
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn unpack(self) -> Soft {
+        let s = self.is_sign_negative();
+        let e = (self.0 >> 112) as i32 & 0x7fff;
+        let m = self.0 & MANT_MASK;
+        if e == 0x7fff {
+            if m == 0 {
+                Soft::Inf(s)
+            } else {
+                Soft::Nan
+            }
+        } else if e == 0 {
+            Soft::from_parts(s, -16494, m)
+        } else {
+            Soft::from_parts(s, e - 16495, m | 1 << 112)
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn pack(x: Soft) -> f128 {
+        match x.round(113, -16382, 16383) {
+            Soft::Nan => f128::NAN,
+            Soft::Inf(s) => f128((s as u128) << 127 | f128::INFINITY.0),
+            Soft::Zero(s) => f128((s as u128) << 127),
+            Soft::Num(s, e, m) => {
+                let ix = if e < -16382 {
+                    m >> (15 - 16382 - e)
+                } else {
+                    ((e + 16383) as u128) << 112 | (m >> 15) & MANT_MASK
+                };
+                f128((s as u128) << 127 | ix)
+            }
+        }
+    }
+}
+
+impl From<f64> for f128 {
+    #[inline]
+    fn from(x: f64) -> f128 {
+        f128::pack(Soft::from_f64(x))
+    }
+}
+
+impl From<f32> for f128 {
+    #[inline]
+    fn from(x: f32) -> f128 {
+        f128::from(x as f64)
+    }
+}
+
+impl From<i32> for f128 {
+    #[inline]
+    fn from(x: i32) -> f128 {
+        f128::from(x as f64)
+    }
+}
+
+impl From<i64> for f128 {
+    #[inline]
+    fn from(x: i64) -> f128 {
+        let m = if x < 0 { -(x as i128) } else { x as i128 };
+        f128::pack(Soft::from_parts(x < 0, 0, m as u128))
+    }
+}
+
+impl Add for f128 {
+    type Output = f128;
+
+    #[inline]
+    fn add(self, rhs: f128) -> f128 {
+        f128::pack(soft_float::add(self.unpack(), rhs.unpack()))
+    }
+}
+
+impl Sub for f128 {
+    type Output = f128;
+
+    #[inline]
+    fn sub(self, rhs: f128) -> f128 {
+        f128::pack(soft_float::sub(self.unpack(), rhs.unpack()))
+    }
+}
+
+impl Mul for f128 {
+    type Output = f128;
+
+    #[inline]
+    fn mul(self, rhs: f128) -> f128 {
+        f128::pack(soft_float::mul(self.unpack(), rhs.unpack()))
+    }
+}
+
+impl Div for f128 {
+    type Output = f128;
+
+    #[inline]
+    fn div(self, rhs: f128) -> f128 {
+        f128::pack(soft_float::div(self.unpack(), rhs.unpack()))
+    }
+}
+
+impl Neg for f128 {
+    type Output = f128;
+
+    #[inline]
+    fn neg(self) -> f128 {
+        f128(self.0 ^ 1 << 127)
+    }
+}
+
+impl PartialEq for f128 {
+    #[inline]
+    fn eq(&self, other: &f128) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for f128 {
+    #[inline]
+    fn partial_cmp(&self, other: &f128) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        /* map the sign-magnitude encoding to a monotonic integer */
+        let key = |x: u128| {
+            if x >> 127 != 0 {
+                -((x & !(1 << 127)) as i128)
+            } else {
+                x as i128
+            }
+        };
+        Some(key(self.0).cmp(&key(other.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::f128;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(f128::from(1.0).to_bits(), f128::ONE.to_bits());
+        assert_eq!(
+            f128::from(0.1).to_bits(),
+            0x3ffb999999999999a000000000000000
+        );
+        assert_eq!(f128::from(-2i64).to_bits(), 0xc000 << 112);
+        assert_eq!(f128::from(5e-324).to_f64(), 5e-324);
+        assert_eq!(f128::MAX.to_f64(), 1.0 / 0.0);
+        assert_eq!(f128::MIN_POSITIVE.to_f64(), 0.0);
+        assert!(f128::from(0.0 / 0.0).is_nan());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let one = f128::ONE;
+        let three = f128::from(3.0);
+        let ten = f128::from(10.0);
+        assert_eq!((one / three).to_bits(), 0x3ffd5555555555555555555555555555);
+        assert_eq!((one / ten).to_bits(), 0x3ffb999999999999999999999999999a);
+        assert_eq!((one + f128::EPSILON) - one, f128::EPSILON);
+        /* ties round to even */
+        assert_eq!(one + f128::EPSILON * f128::from(0.5), one);
+        assert_eq!(f128::MAX + f128::MAX, f128::INFINITY);
+        assert_eq!(
+            (f128::MIN_POSITIVE * f128::EPSILON).to_bits(),
+            1,
+            "smallest subnormal"
+        );
+        assert!((f128::INFINITY - f128::INFINITY).is_nan());
+        assert_eq!((one - one).to_bits(), 0);
+    }
+
+    #[test]
+    fn comparisons() {
+        assert!(f128::NAN != f128::NAN);
+        assert!(f128::ZERO == -f128::ZERO);
+        assert!(-f128::ONE < f128::ZERO);
+        assert!(f128::MAX < f128::INFINITY);
+        assert!(f128::from(-3.0) < f128::from(-2.0));
+    }
+}
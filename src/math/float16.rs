@@ -0,0 +1,300 @@
+use super::soft_float::Soft;
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Half precision (IEEE 754 binary16) floating-point number
+///
+/// A software stand-in for the `f16` primitive, which is not yet
+/// available on stable. It stores the bit pattern of the number; the
+/// arithmetic operators are evaluated in `f64`, where sums, differences
+/// and products of two `f16` values are exact, and rounded once to
+/// nearest.
+///
+/// The `f16` math functions work the same way: they evaluate the `f64`
+/// function and round its result once. Their `exhaustive` tests compare
+/// every `f16` input with a correctly rounded reference, which shows that
+/// this is correctly rounded.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct f16(u16);
+
+impl f16 {
+    /// Positive zero.
+    pub const ZERO: f16 = f16(0);
+    /// The value 1.
+    pub const ONE: f16 = f16(0x3c00);
+    /// Quiet NaN.
+    pub const NAN: f16 = f16(0x7e00);
+    /// Positive infinity.
+    pub const INFINITY: f16 = f16(0x7c00);
+    /// Negative infinity.
+    pub const NEG_INFINITY: f16 = f16(0xfc00);
+    /// Largest finite value.
+    pub const MAX: f16 = f16(0x7bff);
+    /// Smallest positive normal value.
+    pub const MIN_POSITIVE: f16 = f16(0x0400);
+    /// Difference between 1 and the next larger value.
+    pub const EPSILON: f16 = f16(0x1400);
+
+    #[inline]
+    pub const fn from_bits(v: u16) -> f16 {
+        f16(v)
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts from `f64`, rounding to nearest.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn from_f64(x: f64) -> f16 {
+        match Soft::from_f64(x).round(11, -14, 15) {
+            Soft::Nan => f16::NAN,
+            Soft::Inf(s) => f16((s as u16) << 15 | f16::INFINITY.0),
+            Soft::Zero(s) => f16((s as u16) << 15),
+            Soft::Num(s, e, m) => {
+                let ix = if e < -14 {
+                    (m >> (103 - e)) as u16
+                } else {
+                    ((e + 15) as u16) << 10 | (m >> 117) as u16 & 0x3ff
+                };
+                f16((s as u16) << 15 | ix)
+            }
+        }
+    }
+
+    /// Converts from `f32`, rounding to nearest.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn from_f32(x: f32) -> f16 {
+        f16::from_f64(x as f64)
+    }
+
+    /// Converts to `f64`, which is exact.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn to_f64(self) -> f64 {
+        let s = self.0 >> 15 != 0;
+        let e = (self.0 >> 10) as i32 & 0x1f;
+        let m = (self.0 & 0x3ff) as u128;
+        let x = if e == 0x1f {
+            if m == 0 {
+                Soft::Inf(s)
+            } else {
+                Soft::Nan
+            }
+        } else if e == 0 {
+            Soft::from_parts(s, -24, m)
+        } else {
+            Soft::from_parts(s, e - 25, m | 1 << 10)
+        };
+        x.to_f64()
+    }
+
+    /// Converts to `f32`, which is exact.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7c00
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_infinite(self) -> bool {
+        self.0 & 0x7fff == 0x7c00
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_finite(self) -> bool {
+        self.0 & 0x7fff < 0x7c00
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_sign_negative(self) -> bool {
+        self.0 >> 15 != 0
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+}
+
+impl From<f16> for f32 {
+    #[inline]
+    fn from(x: f16) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl From<f16> for f64 {
+    #[inline]
+    fn from(x: f16) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl From<i8> for f16 {
+    #[inline]
+    fn from(x: i8) -> f16 {
+        f16::from_f64(x as f64)
+    }
+}
+
+impl From<u8> for f16 {
+    #[inline]
+    fn from(x: u8) -> f16 {
+        f16::from_f64(x as f64)
+    }
+}
+
+impl Add for f16 {
+    type Output = f16;
+
+    #[inline]
+    fn add(self, rhs: f16) -> f16 {
+        f16::from_f64(self.to_f64() + rhs.to_f64())
+    }
+}
+
+impl Sub for f16 {
+    type Output = f16;
+
+    #[inline]
+    fn sub(self, rhs: f16) -> f16 {
+        f16::from_f64(self.to_f64() - rhs.to_f64())
+    }
+}
+
+impl Mul for f16 {
+    type Output = f16;
+
+    #[inline]
+    fn mul(self, rhs: f16) -> f16 {
+        f16::from_f64(self.to_f64() * rhs.to_f64())
+    }
+}
+
+impl Div for f16 {
+    type Output = f16;
+
+    /* the f64 quotient has more than 2*11+2 bits, so rounding it again
+     * gives the correctly rounded result */
+    #[inline]
+    fn div(self, rhs: f16) -> f16 {
+        f16::from_f64(self.to_f64() / rhs.to_f64())
+    }
+}
+
+impl Neg for f16 {
+    type Output = f16;
+
+    #[inline]
+    fn neg(self) -> f16 {
+        f16(self.0 ^ 1 << 15)
+    }
+}
+
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &f16) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &f16) -> Option<Ordering> {
+        self.to_f64().partial_cmp(&other.to_f64())
+    }
+}
+
+/// Checks that `r`, the result of an `f16` function at `x`, is `y` rounded
+/// to nearest, where `y` is the function's correctly rounded `f64` (or
+/// `f32`) value. The exact value rounds the same way unless `y` lies
+/// exactly halfway between two `f16` values, which is asserted not to
+/// happen.
+#[cfg(test)]
+pub(crate) fn check(x: f16, r: f16, y: f64) {
+    if y.is_nan() {
+        assert!(r.is_nan(), "{:?} -> {:?}, expected NaN", x, r);
+        return;
+    }
+    let a = f16::from_f64(y.abs());
+    if y.is_finite() && a.to_f64() != y.abs() {
+        /* the f16 on the other side of |y|, with 2^16 standing in for
+         * infinity */
+        let b = if a.to_f64() > y.abs() {
+            a.0 - 1
+        } else {
+            a.0 + 1
+        };
+        let value = |i: u16| {
+            if i == 0x7c00 {
+                65536.0
+            } else {
+                f16(i).to_f64()
+            }
+        };
+        assert!(
+            value(a.0) + value(b) != 2.0 * y.abs(),
+            "{:?}: {:e} is halfway",
+            x,
+            y
+        );
+    }
+    assert_eq!(
+        r.to_bits(),
+        f16::from_f64(y).to_bits(),
+        "{:?} -> {:?}, expected {:e}",
+        x,
+        r,
+        y
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::f16;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(f16::from_f64(1.0).to_bits(), f16::ONE.to_bits());
+        assert_eq!(f16::from_f64(0.1).to_bits(), 0x2e66);
+        assert_eq!(f16::from_f64(65504.0).to_bits(), f16::MAX.to_bits());
+        /* 65520 is halfway between MAX and the next power of two */
+        assert_eq!(f16::from_f64(65519.99).to_bits(), f16::MAX.to_bits());
+        assert_eq!(f16::from_f64(65520.0), f16::INFINITY);
+        assert_eq!(f16::from_f64(5.960464477539063e-8).to_bits(), 1);
+        assert_eq!(f16::from_f64(2.9802322387695312e-8).to_bits(), 0);
+        assert_eq!(f16::from_f64(2.9802322387695316e-8).to_bits(), 1);
+        assert_eq!(f16::from_f64(-0.0).to_bits(), 0x8000);
+        assert!(f16::from_f64(0.0 / 0.0).is_nan());
+        assert_eq!(f16::from_bits(1).to_f64(), 5.960464477539063e-8);
+        assert_eq!(f16::MIN_POSITIVE.to_f32(), 6.1035156e-5);
+        assert_eq!(f16::EPSILON.to_f64(), 0.0009765625);
+    }
+
+    #[test]
+    fn round_trip() {
+        for i in 0..=0xffff {
+            let x = f16::from_bits(i);
+            if !x.is_nan() {
+                assert_eq!(f16::from_f64(x.to_f64()).to_bits(), i);
+                assert_eq!(f16::from_f32(x.to_f32()).to_bits(), i);
+            }
+        }
+    }
+}
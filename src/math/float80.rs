@@ -0,0 +1,278 @@
+use super::f128;
+use super::soft_float::{self, Soft};
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+const INT_BIT: u128 = 1 << 63;
+
+/// x87 extended precision floating-point number
+///
+/// The 80-bit format of the x87 FPU: a sign bit, a 15-bit exponent and a
+/// 64-bit significand with an explicit integer bit. The bit pattern is
+/// kept in the low 80 bits of a `u128`, in the order sign, exponent,
+/// significand, so `to_bits` matches the memory layout of an x86
+/// `long double`. Arithmetic is done in software and correctly rounded
+/// to nearest.
+///
+/// Like the x87 FPU, encodings with an exponent other than zero and a
+/// clear integer bit (unnormals, pseudo-infinities and pseudo-NaNs) are
+/// invalid and read as NaN, while pseudo-denormals are read by their
+/// value.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct f80(u128);
+
+impl f80 {
+    /// Positive zero.
+    pub const ZERO: f80 = f80(0);
+    /// The value 1.
+    pub const ONE: f80 = f80(0x3fff << 64 | INT_BIT);
+    /// Quiet NaN.
+    pub const NAN: f80 = f80(0x7fff << 64 | 0xc000000000000000);
+    /// Positive infinity.
+    pub const INFINITY: f80 = f80(0x7fff << 64 | INT_BIT);
+    /// Negative infinity.
+    pub const NEG_INFINITY: f80 = f80(0xffff << 64 | INT_BIT);
+    /// Largest finite value.
+    pub const MAX: f80 = f80(0x7ffe << 64 | 0xffffffffffffffff);
+    /// Smallest positive normal value.
+    pub const MIN_POSITIVE: f80 = f80(1 << 64 | INT_BIT);
+    /// Difference between 1 and the next larger value.
+    pub const EPSILON: f80 = f80((0x3fff - 63) << 64 | INT_BIT);
+
+    /// Creates a value from the low 80 bits of `v`.
+    #[inline]
+    pub const fn from_bits(v: u128) -> f80 {
+        f80(v & ((1 << 80) - 1))
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// Converts from `f128`, rounding to nearest.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn from_f128(x: f128) -> f80 {
+        f80::pack(x.unpack())
+    }
+
+    /// Converts to `f64`, rounding to nearest.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn to_f64(self) -> f64 {
+        self.unpack().to_f64()
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_nan(self) -> bool {
+        self.unpack() == Soft::Nan
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_infinite(self) -> bool {
+        self.0 & !(1 << 79) == f80::INFINITY.0
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_finite(self) -> bool {
+        !self.is_nan() && !self.is_infinite()
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_sign_negative(self) -> bool {
+        self.0 >> 79 != 0
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn unpack(self) -> Soft {
+        let s = self.is_sign_negative();
+        let e = (self.0 >> 64) as i32 & 0x7fff;
+        let m = self.0 & 0xffffffffffffffff;
+        if e == 0 {
+            Soft::from_parts(s, -16445, m)
+        } else if m & INT_BIT == 0 {
+            Soft::Nan
+        } else if e == 0x7fff {
+            if m == INT_BIT {
+                Soft::Inf(s)
+            } else {
+                Soft::Nan
+            }
+        } else {
+            Soft::from_parts(s, e - 16446, m)
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn pack(x: Soft) -> f80 {
+        match x.round(64, -16382, 16383) {
+            Soft::Nan => f80::NAN,
+            Soft::Inf(s) => f80((s as u128) << 79 | f80::INFINITY.0),
+            Soft::Zero(s) => f80((s as u128) << 79),
+            Soft::Num(s, e, m) => {
+                let ix = if e < -16382 {
+                    m >> (-16318 - e)
+                } else {
+                    ((e + 16383) as u128) << 64 | m >> 64
+                };
+                f80((s as u128) << 79 | ix)
+            }
+        }
+    }
+}
+
+impl From<f80> for f128 {
+    #[inline]
+    fn from(x: f80) -> f128 {
+        f128::pack(x.unpack())
+    }
+}
+
+impl From<f64> for f80 {
+    #[inline]
+    fn from(x: f64) -> f80 {
+        f80::pack(Soft::from_f64(x))
+    }
+}
+
+impl From<f32> for f80 {
+    #[inline]
+    fn from(x: f32) -> f80 {
+        f80::from(x as f64)
+    }
+}
+
+impl From<i32> for f80 {
+    #[inline]
+    fn from(x: i32) -> f80 {
+        f80::from(x as f64)
+    }
+}
+
+impl From<i64> for f80 {
+    #[inline]
+    fn from(x: i64) -> f80 {
+        let m = if x < 0 { -(x as i128) } else { x as i128 };
+        f80::pack(Soft::from_parts(x < 0, 0, m as u128))
+    }
+}
+
+impl Add for f80 {
+    type Output = f80;
+
+    #[inline]
+    fn add(self, rhs: f80) -> f80 {
+        f80::pack(soft_float::add(self.unpack(), rhs.unpack()))
+    }
+}
+
+impl Sub for f80 {
+    type Output = f80;
+
+    #[inline]
+    fn sub(self, rhs: f80) -> f80 {
+        f80::pack(soft_float::sub(self.unpack(), rhs.unpack()))
+    }
+}
+
+impl Mul for f80 {
+    type Output = f80;
+
+    #[inline]
+    fn mul(self, rhs: f80) -> f80 {
+        f80::pack(soft_float::mul(self.unpack(), rhs.unpack()))
+    }
+}
+
+impl Div for f80 {
+    type Output = f80;
+
+    #[inline]
+    fn div(self, rhs: f80) -> f80 {
+        f80::pack(soft_float::div(self.unpack(), rhs.unpack()))
+    }
+}
+
+impl Neg for f80 {
+    type Output = f80;
+
+    #[inline]
+    fn neg(self) -> f80 {
+        f80(self.0 ^ 1 << 79)
+    }
+}
+
+impl PartialEq for f80 {
+    #[inline]
+    fn eq(&self, other: &f80) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for f80 {
+    #[inline]
+    fn partial_cmp(&self, other: &f80) -> Option<Ordering> {
+        soft_float::cmp(self.unpack(), other.unpack())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{f128, f80};
+
+    #[test]
+    fn encodings() {
+        assert_eq!(f80::from(1.0).to_bits(), 0x3fff8000000000000000);
+        assert_eq!(f80::from(-2i64).to_bits(), 0xc0008000000000000000);
+        assert_eq!(f80::from(0.1).to_bits(), 0x3ffbccccccccccccd000);
+        assert_eq!(
+            f80::from(i64::max_value()).to_bits(),
+            0x403dfffffffffffffffe
+        );
+        /* pseudo-denormal and unnormal */
+        assert_eq!(f80::from_bits(0x00008000000000000000), f80::MIN_POSITIVE);
+        assert!(f80::from_bits(0x3fff4000000000000000).is_nan());
+        assert!(f80::from_bits(0x7fff0000000000000000).is_nan());
+        assert!(f80::INFINITY.is_infinite());
+        assert_eq!(f80::MAX.to_f64(), 1.0 / 0.0);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let one = f80::ONE;
+        let three = f80::from(3.0);
+        assert_eq!((one / three).to_bits(), 0x3ffdaaaaaaaaaaaaaaab);
+        assert_eq!((one + f80::EPSILON) - one, f80::EPSILON);
+        /* ties round to even */
+        assert_eq!(one + f80::EPSILON * f80::from(0.5), one);
+        assert_eq!(f80::MAX + f80::MAX, f80::INFINITY);
+        assert_eq!((f80::MIN_POSITIVE * f80::EPSILON).to_bits(), 1);
+        assert!((f80::INFINITY - f80::INFINITY).is_nan());
+        assert!(-f80::ONE < f80::ZERO);
+        assert!(f80::ZERO == -f80::ZERO);
+    }
+
+    #[test]
+    fn f128_conversions() {
+        let third = f128::ONE / f128::from(3.0);
+        assert_eq!(f80::from_f128(third).to_bits(), 0x3ffdaaaaaaaaaaaaaaab);
+        let x = f80::from_bits(0x00000000000000000001);
+        assert_eq!(f80::from_f128(f128::from(x)).to_bits(), 1);
+        assert_eq!(f80::from_f128(f128::MAX), f80::INFINITY);
+    }
+}
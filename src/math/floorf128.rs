@@ -0,0 +1,30 @@
+use super::f128;
+
+/// Floor (f128)
+///
+/// Finds the nearest integer less than or equal to `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn floorf128(x: f128) -> f128 {
+    let toint = f128::from_bits(0x406f << 112); // 0x1p112 === 2 ^ 112
+    let s = x.is_sign_negative();
+    let e = (x.to_bits() >> 112) as i32 & 0x7fff;
+
+    if e >= 0x3fff + 112 || x == f128::ZERO {
+        return x;
+    }
+    /* y = int(x) - x, where int(x) is an integer neighbor of x */
+    let y = if s {
+        x - toint + toint - x
+    } else {
+        x + toint - toint - x
+    };
+    if e < 0x3fff {
+        return if s { -f128::ONE } else { f128::ZERO };
+    }
+    if y > f128::ZERO {
+        x + y - f128::ONE
+    } else {
+        x + y
+    }
+}
@@ -0,0 +1,10 @@
+use super::{f16, floor};
+
+/// Floor (f16)
+///
+/// Finds the nearest integer less than or equal to `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn floorf16(x: f16) -> f16 {
+    f16::from_f64(floor(x.to_f64()))
+}
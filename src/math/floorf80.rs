@@ -0,0 +1,11 @@
+use super::{f128, f80, floorf128};
+
+/// Floor (f80)
+///
+/// Finds the nearest integer less than or equal to `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn floorf80(x: f80) -> f80 {
+    /* floor(x) is an f80 value, so the conversions are exact */
+    f80::from_f128(floorf128(f128::from(x)))
+}
@@ -126,11 +126,11 @@ pub fn fma(x: f64, y: f64, z: f64) -> f64 {
     } else {
         /* r -= z */
         let t = rlo;
-        rlo -= zlo;
-        rhi = rhi - zhi - (t < rlo) as u64;
+        rlo = rlo.wrapping_sub(zlo);
+        rhi = rhi.wrapping_sub(zhi).wrapping_sub((t < rlo) as u64);
         if (rhi >> 63) != 0 {
-            rlo = (-(rlo as i64)) as u64;
-            rhi = (-(rhi as i64)) as u64 - (rlo != 0) as u64;
+            rlo = rlo.wrapping_neg();
+            rhi = rhi.wrapping_neg().wrapping_sub((rlo != 0) as u64);
             sign = (sign == 0) as i32;
         }
         nonzero = (rhi != 0) as i32;
@@ -205,3 +205,18 @@ pub fn fma(x: f64, y: f64, z: f64) -> f64 {
     }
     scalbn(r, e)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::fma;
+    use core::f64;
+
+    #[test]
+    fn addend_larger_than_product() {
+        /* r -= z borrows, which overflowed in debug builds */
+        assert_eq!(fma(1.0, 1.0, -3.0), -2.0);
+        assert_eq!(fma(2.0, 3.0, -7.0), -1.0);
+        assert_eq!(fma(-1.0, 1.0, 1.5), 0.5);
+        assert_eq!(fma(1.0, 1.0, -1.0 - f64::EPSILON), -f64::EPSILON);
+    }
+}
@@ -0,0 +1,57 @@
+use super::soft_float::{self, Soft};
+use super::RoundingMode;
+
+/// Floating multiply add with explicit rounding (f64)
+///
+/// Computes `(x*y)+z` to infinite precision and rounds it once in the direction
+/// `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fma_rm(x: f64, y: f64, z: f64, rm: RoundingMode) -> f64 {
+    soft_float::fma_rm(Soft::from_f64(x), Soft::from_f64(y), Soft::from_f64(z), rm).to_f64_rm(rm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fmaf_rm;
+    use super::fma_rm;
+    use super::RoundingMode::*;
+    use core::f64;
+
+    #[test]
+    fn directed() {
+        let eps = f64::EPSILON;
+        let tiny = 8.673617379884035e-19; /* 2^-60 */
+        assert_eq!(fma_rm(1.0, 1.0, tiny, TowardPositive), 1.0 + eps);
+        assert_eq!(fma_rm(1.0, 1.0, tiny, TowardNegative), 1.0);
+        assert_eq!(fma_rm(1.0, 1.0, tiny, TiesToEven), 1.0);
+        assert_eq!(fma_rm(-1.0, 1.0, -tiny, TowardNegative), -1.0 - eps);
+        assert_eq!(fma_rm(-1.0, 1.0, -tiny, TowardZero), -1.0);
+        /* 1 + 2^-53 is halfway between 1 and 1 + eps */
+        assert_eq!(fma_rm(1.0, 1.0, eps / 2.0, TiesToEven), 1.0);
+        assert_eq!(fma_rm(1.0, 1.0, eps / 2.0, TiesToAway), 1.0 + eps);
+        assert_eq!(fmaf_rm(1.0, 1.0, 1e-10, TowardPositive), 1.0000001);
+        assert_eq!(fmaf_rm(3.0, 1.0 / 3.0, -1.0, TowardNegative), 2.9802322e-8);
+    }
+
+    #[test]
+    fn zeros_and_limits() {
+        assert_eq!(fma_rm(2.0, 3.0, -6.0, TowardNegative).to_bits(), 1 << 63);
+        assert_eq!(fma_rm(2.0, 3.0, -6.0, TowardPositive).to_bits(), 0);
+        assert_eq!(fma_rm(0.0, 1.0, -0.0, TowardNegative).to_bits(), 1 << 63);
+        assert_eq!(fma_rm(-0.0, 1.0, -0.0, TowardPositive).to_bits(), 1 << 63);
+        /* the product is not rounded */
+        assert_eq!(fma_rm(f64::MAX, 2.0, -f64::MAX, TowardZero), f64::MAX);
+        assert_eq!(fma_rm(f64::MAX, f64::MAX, 0.0, TowardZero), f64::MAX);
+        assert_eq!(fma_rm(f64::MAX, f64::MAX, 0.0, TowardPositive), 1.0 / 0.0);
+        assert_eq!(fma_rm(-f64::MAX, f64::MAX, 0.0, TowardPositive), -f64::MAX);
+        let x = f64::MIN_POSITIVE;
+        assert_eq!(fma_rm(x, 1e-20, 0.0, TowardPositive).to_bits(), 1);
+        assert_eq!(fma_rm(x, 1e-20, 0.0, TowardNegative).to_bits(), 0);
+        assert_eq!(
+            fma_rm(-x, 1e-20, 0.0, TowardNegative).to_bits(),
+            1 << 63 | 1
+        );
+        assert!(fma_rm(1.0 / 0.0, 0.0, 1.0, TowardZero).is_nan());
+    }
+}
@@ -0,0 +1,13 @@
+use super::f128;
+use super::soft_float;
+
+/// Floating multiply add (f128)
+///
+/// Computes `(x*y)+z`, rounded as one ternary operation:
+/// Computes the value (as if) to infinite precision and rounds once to the result format,
+/// to nearest with ties to even.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaf128(x: f128, y: f128, z: f128) -> f128 {
+    f128::pack(soft_float::fma(x.unpack(), y.unpack(), z.unpack()))
+}
@@ -0,0 +1,13 @@
+use super::f80;
+use super::soft_float;
+
+/// Floating multiply add (f80)
+///
+/// Computes `(x*y)+z`, rounded as one ternary operation:
+/// Computes the value (as if) to infinite precision and rounds once to the result format,
+/// to nearest with ties to even.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaf80(x: f80, y: f80, z: f80) -> f80 {
+    f80::pack(soft_float::fma(x.unpack(), y.unpack(), z.unpack()))
+}
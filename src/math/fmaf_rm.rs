@@ -0,0 +1,18 @@
+use super::soft_float::{self, Soft};
+use super::RoundingMode;
+
+/// Floating multiply add with explicit rounding (f32)
+///
+/// Computes `(x*y)+z` to infinite precision and rounds it once in the direction
+/// `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaf_rm(x: f32, y: f32, z: f32, rm: RoundingMode) -> f32 {
+    soft_float::fma_rm(
+        Soft::from_f64(x as f64),
+        Soft::from_f64(y as f64),
+        Soft::from_f64(z as f64),
+        rm,
+    )
+    .to_f32_rm(rm)
+}
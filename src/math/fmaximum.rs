@@ -0,0 +1,49 @@
+/// Maximum (f64)
+///
+/// Returns the larger of `x` and `y`, treating -0 as less than +0. If either
+/// argument is a NaN, a quiet NaN is returned.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    // -0 is less than +0
+    if y > x || (y == x && x.is_sign_negative()) {
+        y
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum;
+    use core::f64;
+
+    const SNAN: u64 = 0x7ff0_0000_0000_0001;
+    const QUIET: u64 = 0x0008_0000_0000_0000;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fmaximum(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(fmaximum(0.0, -0.0).to_bits(), 0);
+        assert_eq!(fmaximum(-0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f64::from_bits(SNAN);
+        assert!(fmaximum(f64::NAN, 1.0).is_nan());
+        assert!(fmaximum(f64::INFINITY, f64::NAN).is_nan());
+        assert_ne!(fmaximum(snan, 1.0).to_bits() & QUIET, 0);
+        assert_ne!(fmaximum(1.0, snan).to_bits() & QUIET, 0);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fmaximum(1.0, 2.0), 2.0);
+        assert_eq!(fmaximum(-1.0, -2.0), -1.0);
+        assert_eq!(fmaximum(f64::NEG_INFINITY, -1.0), -1.0);
+    }
+}
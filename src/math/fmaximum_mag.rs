@@ -0,0 +1,43 @@
+use super::{fabs, fmaximum};
+
+/// Maximum magnitude (f64)
+///
+/// Returns the argument with the larger magnitude, or `fmaximum(x, y)` if
+/// both have the same magnitude. If either argument is a NaN, a quiet NaN is
+/// returned.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_mag(x: f64, y: f64) -> f64 {
+    let ax = fabs(x);
+    let ay = fabs(y);
+    if ax > ay {
+        x
+    } else if ay > ax {
+        y
+    } else {
+        // equal magnitudes or NaNs
+        fmaximum(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_mag;
+    use core::f64;
+
+    #[test]
+    fn magnitudes() {
+        assert_eq!(fmaximum_mag(-3.0, 2.0), -3.0);
+        assert_eq!(fmaximum_mag(2.0, -3.0), -3.0);
+        assert_eq!(fmaximum_mag(-2.0, 2.0), 2.0);
+        assert_eq!(fmaximum_mag(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(fmaximum_mag(-0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f64::from_bits(0x7ff0_0000_0000_0001);
+        assert!(fmaximum_mag(f64::NAN, f64::INFINITY).is_nan());
+        assert_ne!(fmaximum_mag(1.0, snan).to_bits() & 0x0008_0000_0000_0000, 0);
+    }
+}
@@ -0,0 +1,75 @@
+use super::{fabs, fmaximum_num};
+
+/// Maximum magnitude number (f64)
+///
+/// Returns the argument with the larger magnitude, or `fmaximum_num(x, y)`
+/// if both have the same magnitude. If one argument is a NaN, the other one is
+/// returned, and a signaling NaN raises the invalid exception. A quiet NaN is
+/// returned only if both arguments are NaNs.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_mag_num(x: f64, y: f64) -> f64 {
+    let ax = fabs(x);
+    let ay = fabs(y);
+    if ax > ay {
+        x
+    } else if ay > ax {
+        y
+    } else {
+        // equal magnitudes or NaNs
+        fmaximum_num(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_mag_num;
+    use core::f64;
+
+    const SNAN: u64 = 0x7ff0_0000_0000_0001;
+    const QUIET: u64 = 0x0008_0000_0000_0000;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fmaximum_mag_num(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(fmaximum_mag_num(0.0, -0.0).to_bits(), 0);
+        assert_eq!(fmaximum_mag_num(-0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(fmaximum_mag_num(0.0, 0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f64::from_bits(SNAN);
+        assert_eq!(fmaximum_mag_num(f64::NAN, -1.0), -1.0);
+        assert_eq!(fmaximum_mag_num(-1.0, f64::NAN), -1.0);
+        assert_eq!(fmaximum_mag_num(snan, 2.0), 2.0);
+        assert_eq!(fmaximum_mag_num(2.0, snan), 2.0);
+        assert_eq!(
+            fmaximum_mag_num(f64::NAN, -0.0).to_bits(),
+            (-0.0_f64).to_bits()
+        );
+        assert_eq!(fmaximum_mag_num(f64::INFINITY, snan), f64::INFINITY);
+        let r = fmaximum_mag_num(snan, f64::NAN);
+        assert!(r.is_nan());
+        assert_ne!(r.to_bits() & QUIET, 0);
+        let r = fmaximum_mag_num(snan, snan);
+        assert_ne!(r.to_bits() & QUIET, 0);
+    }
+
+    #[test]
+    fn magnitudes() {
+        assert_eq!(fmaximum_mag_num(-3.0, 2.0), -3.0);
+        assert_eq!(fmaximum_mag_num(2.0, -3.0), -3.0);
+        assert_eq!(
+            fmaximum_mag_num(f64::NEG_INFINITY, f64::MAX),
+            f64::NEG_INFINITY
+        );
+        /* ties go to the larger value */
+        assert_eq!(fmaximum_mag_num(-2.0, 2.0), 2.0);
+        assert_eq!(fmaximum_mag_num(2.0, -2.0), 2.0);
+        assert_eq!(
+            fmaximum_mag_num(f64::INFINITY, f64::NEG_INFINITY),
+            f64::INFINITY
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use super::{fabsf, fmaximum_numf};
+
+/// Maximum magnitude number (f32)
+///
+/// Returns the argument with the larger magnitude, or `fmaximum_numf(x, y)`
+/// if both have the same magnitude. If one argument is a NaN, the other one is
+/// returned, and a signaling NaN raises the invalid exception. A quiet NaN is
+/// returned only if both arguments are NaNs.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_mag_numf(x: f32, y: f32) -> f32 {
+    let ax = fabsf(x);
+    let ay = fabsf(y);
+    if ax > ay {
+        x
+    } else if ay > ax {
+        y
+    } else {
+        // equal magnitudes or NaNs
+        fmaximum_numf(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_mag_numf;
+    use core::f32;
+
+    const SNAN: u32 = 0x7f80_0001;
+    const QUIET: u32 = 0x0040_0000;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fmaximum_mag_numf(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(fmaximum_mag_numf(0.0, -0.0).to_bits(), 0);
+        assert_eq!(
+            fmaximum_mag_numf(-0.0, -0.0).to_bits(),
+            (-0.0_f32).to_bits()
+        );
+        assert_eq!(fmaximum_mag_numf(0.0, 0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f32::from_bits(SNAN);
+        assert_eq!(fmaximum_mag_numf(f32::NAN, -1.0), -1.0);
+        assert_eq!(fmaximum_mag_numf(-1.0, f32::NAN), -1.0);
+        assert_eq!(fmaximum_mag_numf(snan, 2.0), 2.0);
+        assert_eq!(fmaximum_mag_numf(2.0, snan), 2.0);
+        assert_eq!(
+            fmaximum_mag_numf(f32::NAN, -0.0).to_bits(),
+            (-0.0_f32).to_bits()
+        );
+        assert_eq!(fmaximum_mag_numf(f32::INFINITY, snan), f32::INFINITY);
+        let r = fmaximum_mag_numf(snan, f32::NAN);
+        assert!(r.is_nan());
+        assert_ne!(r.to_bits() & QUIET, 0);
+        let r = fmaximum_mag_numf(snan, snan);
+        assert_ne!(r.to_bits() & QUIET, 0);
+    }
+
+    #[test]
+    fn magnitudes() {
+        assert_eq!(fmaximum_mag_numf(-3.0, 2.0), -3.0);
+        assert_eq!(fmaximum_mag_numf(2.0, -3.0), -3.0);
+        assert_eq!(
+            fmaximum_mag_numf(f32::NEG_INFINITY, f32::MAX),
+            f32::NEG_INFINITY
+        );
+        /* ties go to the larger value */
+        assert_eq!(fmaximum_mag_numf(-2.0, 2.0), 2.0);
+        assert_eq!(fmaximum_mag_numf(2.0, -2.0), 2.0);
+        assert_eq!(
+            fmaximum_mag_numf(f32::INFINITY, f32::NEG_INFINITY),
+            f32::INFINITY
+        );
+    }
+}
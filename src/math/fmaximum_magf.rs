@@ -0,0 +1,66 @@
+use super::{fabsf, fmaximumf};
+
+/// Maximum magnitude (f32)
+///
+/// Returns the argument with the larger magnitude, or `fmaximumf(x, y)` if
+/// both have the same magnitude. If either argument is a NaN, a quiet NaN is
+/// returned.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_magf(x: f32, y: f32) -> f32 {
+    let ax = fabsf(x);
+    let ay = fabsf(y);
+    if ax > ay {
+        x
+    } else if ay > ax {
+        y
+    } else {
+        // equal magnitudes or NaNs
+        fmaximumf(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_magf;
+    use core::f32;
+
+    const SNAN: u32 = 0x7f80_0001;
+    const QUIET: u32 = 0x0040_0000;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fmaximum_magf(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(fmaximum_magf(0.0, -0.0).to_bits(), 0);
+        assert_eq!(fmaximum_magf(-0.0, -0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(fmaximum_magf(0.0, 0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f32::from_bits(SNAN);
+        assert!(fmaximum_magf(f32::NAN, 1.0).is_nan());
+        assert!(fmaximum_magf(1.0, f32::NAN).is_nan());
+        assert!(fmaximum_magf(f32::INFINITY, f32::NAN).is_nan());
+        assert_ne!(fmaximum_magf(snan, 1.0).to_bits() & QUIET, 0);
+        assert_ne!(fmaximum_magf(1.0, snan).to_bits() & QUIET, 0);
+        assert_ne!(fmaximum_magf(-0.0, snan).to_bits() & QUIET, 0);
+    }
+
+    #[test]
+    fn magnitudes() {
+        assert_eq!(fmaximum_magf(-3.0, 2.0), -3.0);
+        assert_eq!(fmaximum_magf(2.0, -3.0), -3.0);
+        assert_eq!(
+            fmaximum_magf(f32::NEG_INFINITY, f32::MAX),
+            f32::NEG_INFINITY
+        );
+        /* ties go to the larger value */
+        assert_eq!(fmaximum_magf(-2.0, 2.0), 2.0);
+        assert_eq!(fmaximum_magf(2.0, -2.0), 2.0);
+        assert_eq!(
+            fmaximum_magf(f32::INFINITY, f32::NEG_INFINITY),
+            f32::INFINITY
+        );
+    }
+}
@@ -0,0 +1,51 @@
+/// Maximum number (f64)
+///
+/// Returns the larger of `x` and `y`, treating -0 as less than +0. If one
+/// argument is a NaN, the other one is returned, and a signaling NaN raises the
+/// invalid exception. A quiet NaN is returned only if both arguments are NaNs.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_num(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        if x.is_nan() && y.is_nan() {
+            return x + y;
+        }
+        let (n, r) = if x.is_nan() { (x, y) } else { (y, x) };
+        if n.to_bits() & 0x0008000000000000 == 0 {
+            // raise invalid if the NaN is signaling
+            force_eval!(x + y);
+        }
+        return r;
+    }
+    // -0 is less than +0
+    if y > x || (y == x && x.is_sign_negative()) {
+        y
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_num;
+    use core::f64;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fmaximum_num(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(fmaximum_num(0.0, -0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f64::from_bits(0x7ff0_0000_0000_0001);
+        assert_eq!(fmaximum_num(f64::NAN, -1.0), -1.0);
+        assert_eq!(fmaximum_num(-1.0, f64::NAN), -1.0);
+        assert_eq!(fmaximum_num(snan, -1.0), -1.0);
+        assert_eq!(fmaximum_num(-1.0, snan), -1.0);
+        assert_eq!(fmaximum_num(f64::NAN, -0.0).to_bits(), (-0.0_f64).to_bits());
+        let r = fmaximum_num(snan, f64::NAN);
+        assert!(r.is_nan());
+        assert_ne!(r.to_bits() & 0x0008_0000_0000_0000, 0);
+    }
+}
@@ -0,0 +1,69 @@
+/// Maximum number (f32)
+///
+/// Returns the larger of `x` and `y`, treating -0 as less than +0. If one
+/// argument is a NaN, the other one is returned, and a signaling NaN raises the
+/// invalid exception. A quiet NaN is returned only if both arguments are NaNs.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_numf(x: f32, y: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        if x.is_nan() && y.is_nan() {
+            return x + y;
+        }
+        let (n, r) = if x.is_nan() { (x, y) } else { (y, x) };
+        if n.to_bits() & 0x00400000 == 0 {
+            // raise invalid if the NaN is signaling
+            force_eval!(x + y);
+        }
+        return r;
+    }
+    // -0 is less than +0
+    if y > x || (y == x && x.is_sign_negative()) {
+        y
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_numf;
+    use core::f32;
+
+    const SNAN: u32 = 0x7f80_0001;
+    const QUIET: u32 = 0x0040_0000;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fmaximum_numf(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(fmaximum_numf(0.0, -0.0).to_bits(), 0);
+        assert_eq!(fmaximum_numf(-0.0, -0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(fmaximum_numf(0.0, 0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f32::from_bits(SNAN);
+        assert_eq!(fmaximum_numf(f32::NAN, -1.0), -1.0);
+        assert_eq!(fmaximum_numf(-1.0, f32::NAN), -1.0);
+        assert_eq!(fmaximum_numf(snan, 2.0), 2.0);
+        assert_eq!(fmaximum_numf(2.0, snan), 2.0);
+        assert_eq!(
+            fmaximum_numf(f32::NAN, -0.0).to_bits(),
+            (-0.0_f32).to_bits()
+        );
+        assert_eq!(fmaximum_numf(f32::INFINITY, snan), f32::INFINITY);
+        let r = fmaximum_numf(snan, f32::NAN);
+        assert!(r.is_nan());
+        assert_ne!(r.to_bits() & QUIET, 0);
+        let r = fmaximum_numf(snan, snan);
+        assert_ne!(r.to_bits() & QUIET, 0);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fmaximum_numf(1.0, 2.0), 2.0);
+        assert_eq!(fmaximum_numf(-1.0, -2.0), -1.0);
+        assert_eq!(fmaximum_numf(f32::NEG_INFINITY, -1.0), -1.0);
+    }
+}
@@ -0,0 +1,52 @@
+/// Maximum (f32)
+///
+/// Returns the larger of `x` and `y`, treating -0 as less than +0. If either
+/// argument is a NaN, a quiet NaN is returned.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximumf(x: f32, y: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    // -0 is less than +0
+    if y > x || (y == x && x.is_sign_negative()) {
+        y
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximumf;
+    use core::f32;
+
+    const SNAN: u32 = 0x7f80_0001;
+    const QUIET: u32 = 0x0040_0000;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fmaximumf(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(fmaximumf(0.0, -0.0).to_bits(), 0);
+        assert_eq!(fmaximumf(-0.0, -0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(fmaximumf(0.0, 0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f32::from_bits(SNAN);
+        assert!(fmaximumf(f32::NAN, 1.0).is_nan());
+        assert!(fmaximumf(1.0, f32::NAN).is_nan());
+        assert!(fmaximumf(f32::INFINITY, f32::NAN).is_nan());
+        assert_ne!(fmaximumf(snan, 1.0).to_bits() & QUIET, 0);
+        assert_ne!(fmaximumf(1.0, snan).to_bits() & QUIET, 0);
+        assert_ne!(fmaximumf(-0.0, snan).to_bits() & QUIET, 0);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fmaximumf(1.0, 2.0), 2.0);
+        assert_eq!(fmaximumf(-1.0, -2.0), -1.0);
+        assert_eq!(fmaximumf(f32::NEG_INFINITY, -1.0), -1.0);
+    }
+}
@@ -0,0 +1,38 @@
+/// Minimum (f64)
+///
+/// Returns the smaller of `x` and `y`, treating -0 as less than +0. If either
+/// argument is a NaN, a quiet NaN is returned.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    // -0 is less than +0
+    if y < x || (y == x && y.is_sign_negative()) {
+        y
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fminimum;
+    use core::f64;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fminimum(-0.0, 0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(fminimum(0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(fminimum(0.0, 0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f64::from_bits(0x7ff0_0000_0000_0001);
+        assert!(fminimum(f64::NAN, 1.0).is_nan());
+        assert!(fminimum(f64::NEG_INFINITY, f64::NAN).is_nan());
+        assert_ne!(fminimum(snan, 1.0).to_bits() & 0x0008_0000_0000_0000, 0);
+    }
+}
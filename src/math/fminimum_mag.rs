@@ -0,0 +1,63 @@
+use super::{fabs, fminimum};
+
+/// Minimum magnitude (f64)
+///
+/// Returns the argument with the smaller magnitude, or `fminimum(x, y)` if
+/// both have the same magnitude. If either argument is a NaN, a quiet NaN is
+/// returned.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_mag(x: f64, y: f64) -> f64 {
+    let ax = fabs(x);
+    let ay = fabs(y);
+    if ax < ay {
+        x
+    } else if ay < ax {
+        y
+    } else {
+        // equal magnitudes or NaNs
+        fminimum(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fminimum_mag;
+    use core::f64;
+
+    const SNAN: u64 = 0x7ff0_0000_0000_0001;
+    const QUIET: u64 = 0x0008_0000_0000_0000;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fminimum_mag(-0.0, 0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(fminimum_mag(0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(fminimum_mag(-0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(fminimum_mag(0.0, 0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f64::from_bits(SNAN);
+        assert!(fminimum_mag(f64::NAN, 1.0).is_nan());
+        assert!(fminimum_mag(1.0, f64::NAN).is_nan());
+        assert!(fminimum_mag(f64::NEG_INFINITY, f64::NAN).is_nan());
+        assert_ne!(fminimum_mag(snan, 1.0).to_bits() & QUIET, 0);
+        assert_ne!(fminimum_mag(1.0, snan).to_bits() & QUIET, 0);
+        assert_ne!(fminimum_mag(-0.0, snan).to_bits() & QUIET, 0);
+    }
+
+    #[test]
+    fn magnitudes() {
+        assert_eq!(fminimum_mag(-3.0, 2.0), 2.0);
+        assert_eq!(fminimum_mag(2.0, -3.0), 2.0);
+        assert_eq!(fminimum_mag(f64::NEG_INFINITY, -f64::MAX), -f64::MAX);
+        /* ties go to the smaller value */
+        assert_eq!(fminimum_mag(-2.0, 2.0), -2.0);
+        assert_eq!(fminimum_mag(2.0, -2.0), -2.0);
+        assert_eq!(
+            fminimum_mag(f64::INFINITY, f64::NEG_INFINITY),
+            f64::NEG_INFINITY
+        );
+    }
+}
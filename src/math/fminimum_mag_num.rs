@@ -0,0 +1,43 @@
+use super::{fabs, fminimum_num};
+
+/// Minimum magnitude number (f64)
+///
+/// Returns the argument with the smaller magnitude, or `fminimum_num(x, y)`
+/// if both have the same magnitude. If one argument is a NaN, the other one is
+/// returned, and a signaling NaN raises the invalid exception. A quiet NaN is
+/// returned only if both arguments are NaNs.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_mag_num(x: f64, y: f64) -> f64 {
+    let ax = fabs(x);
+    let ay = fabs(y);
+    if ax < ay {
+        x
+    } else if ay < ax {
+        y
+    } else {
+        // equal magnitudes or NaNs
+        fminimum_num(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fminimum_mag_num;
+    use core::f64;
+
+    #[test]
+    fn magnitudes() {
+        assert_eq!(fminimum_mag_num(-3.0, 2.0), 2.0);
+        assert_eq!(fminimum_mag_num(-2.0, 2.0), -2.0);
+        assert_eq!(fminimum_mag_num(0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f64::from_bits(0x7ff0_0000_0000_0001);
+        assert_eq!(fminimum_mag_num(f64::NAN, -3.0), -3.0);
+        assert_eq!(fminimum_mag_num(snan, -3.0), -3.0);
+        assert!(fminimum_mag_num(f64::NAN, snan).is_nan());
+    }
+}
@@ -0,0 +1,63 @@
+use super::{fabsf, fminimumf};
+
+/// Minimum magnitude (f32)
+///
+/// Returns the argument with the smaller magnitude, or `fminimumf(x, y)` if
+/// both have the same magnitude. If either argument is a NaN, a quiet NaN is
+/// returned.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_magf(x: f32, y: f32) -> f32 {
+    let ax = fabsf(x);
+    let ay = fabsf(y);
+    if ax < ay {
+        x
+    } else if ay < ax {
+        y
+    } else {
+        // equal magnitudes or NaNs
+        fminimumf(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fminimum_magf;
+    use core::f32;
+
+    const SNAN: u32 = 0x7f80_0001;
+    const QUIET: u32 = 0x0040_0000;
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(fminimum_magf(-0.0, 0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(fminimum_magf(0.0, -0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(fminimum_magf(-0.0, -0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(fminimum_magf(0.0, 0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn nans() {
+        let snan = f32::from_bits(SNAN);
+        assert!(fminimum_magf(f32::NAN, 1.0).is_nan());
+        assert!(fminimum_magf(1.0, f32::NAN).is_nan());
+        assert!(fminimum_magf(f32::NEG_INFINITY, f32::NAN).is_nan());
+        assert_ne!(fminimum_magf(snan, 1.0).to_bits() & QUIET, 0);
+        assert_ne!(fminimum_magf(1.0, snan).to_bits() & QUIET, 0);
+        assert_ne!(fminimum_magf(-0.0, snan).to_bits() & QUIET, 0);
+    }
+
+    #[test]
+    fn magnitudes() {
+        assert_eq!(fminimum_magf(-3.0, 2.0), 2.0);
+        assert_eq!(fminimum_magf(2.0, -3.0), 2.0);
+        assert_eq!(fminimum_magf(f32::NEG_INFINITY, -f32::MAX), -f32::MAX);
+        /* ties go to the smaller value */
+        assert_eq!(fminimum_magf(-2.0, 2.0), -2.0);
+        assert_eq!(fminimum_magf(2.0, -2.0), -2.0);
+        assert_eq!(
+            fminimum_magf(f32::INFINITY, f32::NEG_INFINITY),
+            f32::NEG_INFINITY
+        );
+    }
+}
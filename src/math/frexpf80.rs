@@ -0,0 +1,57 @@
+use super::f80;
+
+/// Split into fraction and exponent (f80)
+///
+/// Returns `(m, e)` with `x = m * 2^e` and `0.5 <= |m| < 1`. Zeros,
+/// infinities and NaN are returned unchanged with an exponent of 0.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn frexpf80(x: f80) -> (f80, i32) {
+    let mut y = x.to_bits();
+    let mut ee = ((y >> 64) & 0x7fff) as i32;
+
+    if ee == 0 {
+        if x == f80::ZERO {
+            return (x, 0);
+        }
+        /* scale a subnormal x into the normal range */
+        let x1p120 = f80::from_bits(0x4077 << 64 | 1 << 63);
+        y = (x * x1p120).to_bits();
+        ee = ((y >> 64) & 0x7fff) as i32 - 120;
+    } else if ee == 0x7fff {
+        return (x, 0);
+    }
+
+    let e = ee - 0x3ffe;
+    y &= 0x8000ffffffffffffffff;
+    y |= 0x3ffe << 64;
+    (f80::from_bits(y), e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::ldexpf80;
+    use super::{f80, frexpf80};
+
+    #[test]
+    fn round_trip() {
+        let cases = [
+            f80::from(1.0),
+            f80::from(-0.1),
+            f80::MAX,
+            f80::MIN_POSITIVE,
+            f80::from_bits(0x00000000000000000001),
+            f80::from_bits(0x00000123456789abcdef),
+        ];
+        for &x in cases.iter() {
+            let (m, e) = frexpf80(x);
+            let am = if m.is_sign_negative() { -m } else { m };
+            assert!(f80::from(0.5) <= am && am < f80::ONE, "{:?}", x);
+            assert_eq!(ldexpf80(m, e).to_bits(), x.to_bits());
+        }
+        assert_eq!(frexpf80(f80::from(8.0)), (f80::from(0.5), 4));
+        assert_eq!(frexpf80(f80::from_bits(1)).1, -16444);
+        assert_eq!(ldexpf80(f80::ONE, 16384), f80::INFINITY);
+        assert_eq!(ldexpf80(f80::from(1.5), -16445).to_bits(), 2);
+    }
+}
@@ -0,0 +1,163 @@
+use super::fenv::{feclearexcept, fetestexcept, invalid, FE_INEXACT};
+use super::{ceil, floor, round, roundeven, trunc};
+
+/// Rounding direction for `fromfp`: upward, toward positive infinity.
+pub const FP_INT_UPWARD: i32 = 0;
+/// Rounding direction for `fromfp`: downward, toward negative infinity.
+pub const FP_INT_DOWNWARD: i32 = 1;
+/// Rounding direction for `fromfp`: toward zero.
+pub const FP_INT_TOWARDZERO: i32 = 2;
+/// Rounding direction for `fromfp`: to nearest, halfway cases away from zero.
+pub const FP_INT_TONEARESTFROMZERO: i32 = 3;
+/// Rounding direction for `fromfp`: to nearest, halfway cases to even.
+pub const FP_INT_TONEAREST: i32 = 4;
+
+#[inline]
+fn round_dir(x: f64, rnd: i32) -> f64 {
+    match rnd {
+        FP_INT_UPWARD => ceil(x),
+        FP_INT_DOWNWARD => floor(x),
+        FP_INT_TOWARDZERO => trunc(x),
+        FP_INT_TONEARESTFROMZERO => round(x),
+        _ => roundeven(x),
+    }
+}
+
+/// Round to a signed integer of a given width (f64)
+///
+/// Rounds `x` to an integral value in the direction `rnd`, one of the
+/// `FP_INT_*` constants, and returns it if it is representable as a signed
+/// integer of `width` bits. Otherwise, or if `width` is zero, NaN is returned
+/// and the invalid exception is raised. A `width` larger than 64 is treated
+/// as 64. The inexact exception is never raised.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfp(x: f64, rnd: i32, width: u32) -> f64 {
+    let e = fetestexcept(FE_INEXACT);
+    let y = round_dir(x, rnd);
+    if e == 0 {
+        feclearexcept(FE_INEXACT);
+    }
+
+    if width == 0 {
+        return invalid(f64::NAN);
+    }
+    let w = if width > 64 { 64 } else { width };
+    // -2^(w-1) <= y < 2^(w-1)
+    let lim = f64::from_bits(((0x3ff + w - 1) as u64) << 52);
+    if y >= -lim && y < lim {
+        y
+    } else {
+        invalid(f64::NAN)
+    }
+}
+
+/// Round to an unsigned integer of a given width (f64)
+///
+/// Rounds `x` to an integral value in the direction `rnd`, one of the
+/// `FP_INT_*` constants, and returns it if it is representable as an unsigned
+/// integer of `width` bits. Otherwise, or if `width` is zero, NaN is returned
+/// and the invalid exception is raised. A `width` larger than 64 is treated
+/// as 64. The inexact exception is never raised.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfp(x: f64, rnd: i32, width: u32) -> f64 {
+    let e = fetestexcept(FE_INEXACT);
+    let y = round_dir(x, rnd);
+    if e == 0 {
+        feclearexcept(FE_INEXACT);
+    }
+
+    if width == 0 {
+        return invalid(f64::NAN);
+    }
+    let w = if width > 64 { 64 } else { width };
+    // 0 <= y < 2^w
+    let lim = f64::from_bits(((0x3ff + w) as u64) << 52);
+    if y >= 0. && y < lim {
+        y
+    } else {
+        invalid(f64::NAN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64;
+
+    #[test]
+    fn directions() {
+        assert_eq!(fromfp(2.5, FP_INT_UPWARD, 8), 3.0);
+        assert_eq!(fromfp(2.5, FP_INT_DOWNWARD, 8), 2.0);
+        assert_eq!(fromfp(-2.5, FP_INT_TOWARDZERO, 8), -2.0);
+        assert_eq!(fromfp(2.5, FP_INT_TONEARESTFROMZERO, 8), 3.0);
+        assert_eq!(fromfp(2.5, FP_INT_TONEAREST, 8), 2.0);
+        assert_eq!(fromfp(3.5, FP_INT_TONEAREST, 8), 4.0);
+        assert_eq!(
+            fromfp(-0.25, FP_INT_TONEAREST, 8).to_bits(),
+            (-0.0f64).to_bits()
+        );
+    }
+
+    #[test]
+    fn width() {
+        assert_eq!(fromfp(-128.0, FP_INT_TONEAREST, 8), -128.0);
+        assert!(fromfp(127.5, FP_INT_TONEAREST, 8).is_nan());
+        assert!(fromfp(-128.5, FP_INT_DOWNWARD, 8).is_nan());
+        assert_eq!(ufromfp(255.4, FP_INT_TONEAREST, 8), 255.0);
+        assert!(ufromfp(-1.0, FP_INT_TONEAREST, 8).is_nan());
+        assert_eq!(ufromfp(-0.5, FP_INT_UPWARD, 8), 0.0);
+        assert!(fromfp(1.0, FP_INT_TONEAREST, 0).is_nan());
+        assert_eq!(
+            fromfp(-9223372036854775808.0, FP_INT_TONEAREST, 64),
+            -9223372036854775808.0
+        );
+        assert!(fromfp(9223372036854775808.0, FP_INT_TONEAREST, 100).is_nan());
+        assert_eq!(
+            ufromfp(9223372036854775808.0, FP_INT_TONEAREST, 100),
+            9223372036854775808.0
+        );
+    }
+
+    #[test]
+    fn nan_and_infinity() {
+        assert!(fromfp(f64::NAN, FP_INT_TONEAREST, 64).is_nan());
+        assert!(fromfp(f64::INFINITY, FP_INT_TONEAREST, 64).is_nan());
+        assert!(ufromfp(f64::NEG_INFINITY, FP_INT_UPWARD, 64).is_nan());
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{
+        FE_INVALID, FP_INT_DOWNWARD, FP_INT_TONEAREST, FP_INT_TOWARDZERO, FP_INT_UPWARD,
+    };
+    use super::{fromfp, ufromfp};
+    use core::f64;
+
+    #[test]
+    fn widths() {
+        const I: i32 = FE_INVALID;
+        /* x, rnd, width, then the exceptions of fromfp and ufromfp */
+        let cases = [
+            (127.4, FP_INT_TONEAREST, 8, 0, 0),
+            (-128.5, FP_INT_TOWARDZERO, 8, 0, I),
+            (127.5, FP_INT_TONEAREST, 8, I, 0),
+            (255.5, FP_INT_DOWNWARD, 8, I, 0),
+            (255.5, FP_INT_UPWARD, 8, I, I),
+            (-3.0, FP_INT_TONEAREST, 8, 0, I),
+            (1e30, FP_INT_TONEAREST, 8, I, I),
+            (1.0, FP_INT_TONEAREST, 0, I, I),
+            (f64::INFINITY, FP_INT_TONEAREST, 64, I, I),
+            (f64::NAN, FP_INT_TONEAREST, 64, I, I),
+        ];
+        for &(x, rnd, w, e, ue) in cases.iter() {
+            let r = raised(|(x, rnd, w)| fromfp(x, rnd, w), (x, rnd, w));
+            assert_eq!(r, e, "fromfp({}, {}, {})", x, rnd, w);
+            let r = raised(|(x, rnd, w)| ufromfp(x, rnd, w), (x, rnd, w));
+            assert_eq!(r, ue, "ufromfp({}, {}, {})", x, rnd, w);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use super::fenv::{feclearexcept, fetestexcept, invalid, FE_INEXACT};
+use super::fromfp::{FP_INT_DOWNWARD, FP_INT_TONEARESTFROMZERO, FP_INT_TOWARDZERO, FP_INT_UPWARD};
+use super::{ceilf, floorf, roundevenf, roundf, truncf};
+
+#[inline]
+fn round_dir(x: f32, rnd: i32) -> f32 {
+    match rnd {
+        FP_INT_UPWARD => ceilf(x),
+        FP_INT_DOWNWARD => floorf(x),
+        FP_INT_TOWARDZERO => truncf(x),
+        FP_INT_TONEARESTFROMZERO => roundf(x),
+        _ => roundevenf(x),
+    }
+}
+
+/// Round to a signed integer of a given width (f32)
+///
+/// Rounds `x` to an integral value in the direction `rnd`, one of the
+/// `FP_INT_*` constants, and returns it if it is representable as a signed
+/// integer of `width` bits. Otherwise, or if `width` is zero, NaN is returned
+/// and the invalid exception is raised. A `width` larger than 64 is treated
+/// as 64. The inexact exception is never raised.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpf(x: f32, rnd: i32, width: u32) -> f32 {
+    let e = fetestexcept(FE_INEXACT);
+    let y = round_dir(x, rnd);
+    if e == 0 {
+        feclearexcept(FE_INEXACT);
+    }
+
+    if width == 0 {
+        return invalid(f32::NAN);
+    }
+    let w = if width > 64 { 64 } else { width };
+    // -2^(w-1) <= y < 2^(w-1)
+    let lim = f32::from_bits((0x7f + w - 1) << 23);
+    if y >= -lim && y < lim {
+        y
+    } else {
+        invalid(f32::NAN)
+    }
+}
+
+/// Round to an unsigned integer of a given width (f32)
+///
+/// Rounds `x` to an integral value in the direction `rnd`, one of the
+/// `FP_INT_*` constants, and returns it if it is representable as an unsigned
+/// integer of `width` bits. Otherwise, or if `width` is zero, NaN is returned
+/// and the invalid exception is raised. A `width` larger than 64 is treated
+/// as 64. The inexact exception is never raised.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpf(x: f32, rnd: i32, width: u32) -> f32 {
+    let e = fetestexcept(FE_INEXACT);
+    let y = round_dir(x, rnd);
+    if e == 0 {
+        feclearexcept(FE_INEXACT);
+    }
+
+    if width == 0 {
+        return invalid(f32::NAN);
+    }
+    let w = if width > 64 { 64 } else { width };
+    // 0 <= y < 2^w
+    let lim = f32::from_bits((0x7f + w) << 23);
+    if y >= 0. && y < lim {
+        y
+    } else {
+        invalid(f32::NAN)
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{
+        FE_INVALID, FP_INT_DOWNWARD, FP_INT_TONEAREST, FP_INT_TOWARDZERO, FP_INT_UPWARD,
+    };
+    use super::{fromfpf, ufromfpf};
+    use core::f32;
+
+    #[test]
+    fn widths() {
+        const I: i32 = FE_INVALID;
+        /* x, rnd, width, then the exceptions of fromfpf and ufromfpf */
+        let cases = [
+            (127.4, FP_INT_TONEAREST, 8, 0, 0),
+            (-128.5, FP_INT_TOWARDZERO, 8, 0, I),
+            (127.5, FP_INT_TONEAREST, 8, I, 0),
+            (255.5, FP_INT_DOWNWARD, 8, I, 0),
+            (255.5, FP_INT_UPWARD, 8, I, I),
+            (-3.0, FP_INT_TONEAREST, 8, 0, I),
+            (1e30, FP_INT_TONEAREST, 8, I, I),
+            (1.0, FP_INT_TONEAREST, 0, I, I),
+            (f32::INFINITY, FP_INT_TONEAREST, 64, I, I),
+            (f32::NAN, FP_INT_TONEAREST, 64, I, I),
+        ];
+        for &(x, rnd, w, e, ue) in cases.iter() {
+            let r = raised(|(x, rnd, w)| fromfpf(x, rnd, w), (x, rnd, w));
+            assert_eq!(r, e, "fromfpf({}, {}, {})", x, rnd, w);
+            let r = raised(|(x, rnd, w)| ufromfpf(x, rnd, w), (x, rnd, w));
+            assert_eq!(r, ue, "ufromfpf({}, {}, {})", x, rnd, w);
+        }
+    }
+}
@@ -0,0 +1,576 @@
+/* Regularized incomplete gamma functions, after
+ *   A. R. DiDonato and A. H. Morris, Computation of the incomplete gamma
+ *   function ratios and their inverse, ACM TOMS 12 (1986), 377-393,
+ * with the choice of method in each region of the (a, x) plane mostly as
+ * in Cephes and SciPy:
+ *   - for a > 20 and x within 30% of a, Temme's uniform asymptotic
+ *     expansion,
+ *   - for x < a, the power series of P,
+ *   - for x > a, the continued fraction of Q,
+ *   - for small x, a series for Q that keeps its relative accuracy near 1,
+ * taking the other function as 1 minus the one computed where that does not
+ * cancel. The factor x^a*exp(-x)/gamma(a) of the series is computed with
+ * Stirling's formula for large a, so that it does not lose accuracy as a
+ * and x grow. Where the result is normal, the relative error is a few
+ * times 1e-15 times 1 + |x - a|, the factor by which the result amplifies
+ * a relative change in x, so about 1e-13 deep in the tails for a of a few
+ * thousand.
+ */
+
+use super::k_gamma::{gamma_prefix, lgamma1p, log1pmx};
+use super::{erfc, exp, expm1, fabs, lgamma_r, log, pow, sqrt, tgamma};
+
+const EPS: f64 = 1.1102230246251565e-16; /* 0x1p-53 */
+const BIG: f64 = 4.503599627370496e15; /* 0x1p52 */
+const MAXITER: usize = 2000;
+
+/* the Taylor coefficients d[k][n] of Temme's C_k(eta) */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const D: [[f64; 25]; 25] = [
+    [
+        -3.3333333333333331e-01, 8.3333333333333329e-02, -1.4814814814814815e-02,
+        1.1574074074074073e-03, 3.5273368606701942e-04, -1.7875514403292180e-04,
+        3.9192631785224377e-05, -2.1854485106799920e-06, -1.8540622107151600e-06,
+        8.2967113409530865e-07, -1.7665952736826078e-07, 6.7078535434014984e-09,
+        1.0261809784240309e-08, -4.3820360184533529e-09, 9.1476995822367902e-10,
+        -2.5514193994946248e-11, -5.8307721325504256e-11, 2.4361948020667415e-11,
+        -5.0276692801141755e-12, 1.1004392031956135e-13, 3.3717632624009851e-13,
+        -1.3923887224181621e-13, 2.8534893807047445e-14, -5.1391118342425723e-16,
+        -1.9752288294349442e-15,
+    ],
+    [
+        -1.8518518518518519e-03, -3.4722222222222220e-03, 2.6455026455026454e-03,
+        -9.9022633744855963e-04, 2.0576131687242798e-04, -4.0187757201646090e-07,
+        -1.8098550334489977e-05, 7.6491609160811098e-06, -1.6120900894563446e-06,
+        4.6471278028074340e-09, 1.3786334469157209e-07, -5.7525456035177047e-08,
+        1.1951628599778148e-08, -1.7543241719747647e-11, -1.0091543710600413e-09,
+        4.1627929918425828e-10, -8.5639070264929801e-11, 6.0672151016047582e-14,
+        7.1624989648114856e-12, -2.9331866437714371e-12, 5.9966963656836885e-13,
+        -2.1671786527323313e-16, -4.9783399723692617e-14, 2.0291628823713425e-14,
+        -4.1312557138106099e-15,
+    ],
+    [
+        4.1335978835978834e-03, -2.6813271604938273e-03, 7.7160493827160490e-04,
+        2.0093878600823047e-06, -1.0736653226365160e-04, 5.2923448829120125e-05,
+        -1.2760635188618728e-05, 3.4235787340961378e-08, 1.3721957309062934e-06,
+        -6.2989921383800548e-07, 1.4280614206064242e-07, -2.0477098421990866e-10,
+        -1.4092529910867520e-08, 6.2289740849220218e-09, -1.3670488396617114e-09,
+        9.4283561590146780e-13, 1.2872252400089318e-10, -5.5645956134363323e-11,
+        1.1975935546366981e-11, -4.1689782251838634e-15, -1.0940640427884595e-12,
+        4.6622399463901356e-13, -9.9051057639069066e-14, 1.8931876768373515e-17,
+        8.8592218725911265e-15,
+    ],
+    [
+        6.4943415637860077e-04, 2.2947209362139917e-04, -4.6918949439525570e-04,
+        2.6772063206283885e-04, -7.5618016718839766e-05, -2.3965051138672968e-07,
+        1.1082654115347302e-05, -5.6749528269915965e-06, 1.4230900732435883e-06,
+        -2.7861080291528143e-11, -1.6958404091930278e-07, 8.0994649053880827e-08,
+        -1.9111168485973655e-08, 2.3928620439808118e-12, 2.0620131815488797e-09,
+        -9.4604966618551330e-10, 2.1541049775774907e-10, -1.3888233368139030e-14,
+        -2.1894761681963938e-11, 9.7909989511716844e-12, -2.1782191880180961e-12,
+        6.2088195734079008e-17, 2.1269783632797371e-13, -9.3446887915174330e-14,
+        2.0453671226782849e-14,
+    ],
+    [
+        -8.6188829091671173e-04, 7.8403922172006662e-04, -2.9907248030319018e-04,
+        -1.4638452578843418e-06, 6.6414982154651219e-05, -3.9683650471794347e-05,
+        1.1375726970678419e-05, 2.5074972262375329e-10, -1.6954149536558305e-06,
+        8.9075075322053094e-07, -2.2929348340008049e-07, 2.9567941375440492e-11,
+        2.8865829742708783e-08, -1.4189739437803219e-08, 3.4463580499464896e-09,
+        -2.3024517174528067e-13, -3.9409233028046403e-10, 1.8602338968504501e-10,
+        -4.3563230050566177e-11, 1.2786001016296230e-15, 4.6792750266579197e-12,
+        -2.1492464706134830e-12, 4.9088156148096520e-13, -6.3385914848915601e-18,
+        -5.0453320690800942e-14,
+    ],
+    [
+        -3.3679855336635813e-04, -6.9728137583658571e-05, 2.7727532449593918e-04,
+        -1.9932570516188847e-04, 6.7977804779372080e-05, 1.4190629206439671e-07,
+        -1.3594048189768693e-05, 8.0184702563342020e-06, -2.2914811765080952e-06,
+        -3.2524735512984538e-10, 3.4652846491085265e-07, -1.8447187191171344e-07,
+        4.8240967037894184e-08, -1.7989466721743514e-14, -6.3061945000135231e-09,
+        3.1624176287745678e-09, -7.8409242536974288e-10, 5.1926791652540408e-15,
+        9.3589442423067842e-11, -4.5134262161632780e-11, 1.0799129993116828e-11,
+        -3.6618867126852520e-17, -1.2109020690551549e-12, 5.6807435849905644e-13,
+        -1.3249659916340829e-13,
+    ],
+    [
+        5.3130793646399225e-04, -5.9216643735369393e-04, 2.7087820967180450e-04,
+        7.9023532326603281e-07, -8.1539693675619691e-05, 5.6116827531062497e-05,
+        -1.8329116582843375e-05, -3.0796134506033047e-09, 3.4651553688036091e-06,
+        -2.0291327396058603e-06, 5.7887928631490039e-07, 2.3386306738266568e-13,
+        -8.8286007463304840e-08, 4.7435958880408125e-08, -1.2545415020710383e-08,
+        8.6496488580102926e-14, 1.6846058979264062e-09, -8.5754928235775943e-10,
+        2.1598224929232125e-10, -7.6132305204761534e-16, -2.6639822008536144e-11,
+        1.3065700536611057e-11, -3.1799163902367977e-12, 4.7109761213674312e-18,
+        3.6902800842763465e-13,
+    ],
+    [
+        3.4436760689237765e-04, 5.1717909082605919e-05, -3.3493161081142234e-04,
+        2.8126951547632369e-04, -1.0976582244684731e-04, -1.2741009095484485e-07,
+        2.7744451511563645e-05, -1.8263488805711332e-05, 5.7876949497350525e-06,
+        4.9387589339362701e-10, -1.0595367014026043e-06, 6.1667143761104078e-07,
+        -1.7562973359060463e-07, -1.2974473287015439e-12, 2.6954236062889659e-08,
+        -1.4578352908731272e-08, 3.8876459593861750e-09, -3.8810022510194121e-17,
+        -5.3279941738772864e-10, 2.7437977643314844e-10, -6.9957960920705680e-11,
+        2.5899863874868481e-17, 8.8566890996696389e-12, -4.4031688158713109e-12,
+        1.0865561947091654e-12,
+    ],
+    [
+        -6.5262391859530937e-04, 8.3949872067208726e-04, -4.3829709854172099e-04,
+        -6.9690914584205523e-07, 1.6644846642067547e-04, -1.2783517679769218e-04,
+        4.6299532636913042e-05, 4.5579098679227080e-09, -1.0595271125805195e-05,
+        6.7833429048651668e-06, -2.1075476666258803e-06, -1.7213731432817144e-11,
+        3.7735877416110978e-07, -2.1867506700122867e-07, 6.2202288040189267e-08,
+        6.5977038267330002e-16, -9.5903864974256859e-09, 5.2132144922808074e-09,
+        -1.3991589583935709e-09, 5.3820589990605749e-16, 1.9484714275467745e-10,
+        -1.0127287556389682e-10, 2.6077347197254926e-11, -5.0904186999932991e-18,
+        -3.3721464474854593e-12,
+    ],
+    [
+        -5.9676129019274626e-04, -7.2048954160200109e-05, 6.7823088376673280e-04,
+        -6.4014752602627580e-04, 2.7750107634328704e-04, 1.8197008380465151e-07,
+        -8.4795071170685031e-05, 6.1051920825015314e-05, -2.1073920183404862e-05,
+        -8.8585890141255993e-10, 4.5284535953805374e-06, -2.8427815022504407e-06,
+        8.7082341778646408e-07, 3.6886101871706966e-12, -1.5344695190702061e-07,
+        8.8624667787906948e-08, -2.5184812301826817e-08, -1.0225912098215092e-14,
+        3.8969470758154778e-09, -2.1267304792235634e-09, 5.7370135528051383e-10,
+        -1.8877498501697116e-19, -8.0931538694657872e-11, 4.2382723283449200e-11,
+        -1.1002224534207725e-11,
+    ],
+    [
+        1.3324454494800656e-03, -1.9144384985654776e-03, 1.1089369134596636e-03,
+        9.9324041226422995e-07, -5.0874501293093194e-04, 4.2735056665392886e-04,
+        -1.6858853767910798e-04, -8.1301893922785004e-09, 4.5284402370562144e-05,
+        -3.1270536747817339e-05, 1.0449868285303381e-05, 4.8435226265680926e-11,
+        -2.1482565873456259e-06, 1.3293697010974920e-06, -4.0295693092101030e-07,
+        -1.7567877666323291e-13, 7.0145043163668253e-08, -4.0407877349994832e-08,
+        1.1474026743371964e-08, 3.9642746853563942e-18, -1.7804938269892715e-09,
+        9.7480262548731649e-10, -2.6405338676507616e-10, 5.7948751634037602e-18,
+        3.7647749553543838e-11,
+    ],
+    [
+        1.5797276607308350e-03, 1.6251626278391583e-04, -2.0633421035543276e-03,
+        2.1389686185689098e-03, -1.0108559391263003e-03, -3.9912705529919201e-07,
+        3.6235025084764691e-04, -2.8143901463712157e-04, 1.0449513336495887e-04,
+        2.1211418491830298e-09, -2.5779417251947842e-05, 1.7281818956040464e-05,
+        -5.6413773872904282e-06, -1.1024320105776174e-11, 1.1223224418895174e-06,
+        -6.8693396379526738e-07, 2.0653236975414888e-07, 4.6714772409838506e-14,
+        -3.5609886164949055e-08, 2.0470855345905963e-08, -5.8091738633283357e-09,
+        -1.3328212875828647e-16, 9.0354604391335135e-10, -4.9598782517330839e-10,
+        1.3481607129399748e-10,
+    ],
+    [
+        -4.0725121195140162e-03, 6.4033628338080696e-03, -4.0410161081676620e-03,
+        -2.1837328028662328e-06, 2.1740441801254640e-03, -1.9700440518418891e-03,
+        8.3595469747962459e-04, 1.9445447567109655e-08, -2.5779387120421698e-04,
+        1.9009987368139304e-04, -6.7696499937438964e-05, -1.4440629666426571e-10,
+        1.5712512518742267e-05, -1.0304008744776894e-05, 3.3045177674013869e-06,
+        7.9829760242325708e-13, -6.4097794149312999e-07, 3.8894624761300054e-07,
+        -1.1618347644948870e-07, -2.8168086305964423e-15, 1.9878012911297094e-08,
+        -1.1407719956357511e-08, 3.2355857064185554e-09, 4.1759462466484876e-20,
+        -5.0423112718105821e-10,
+    ],
+    [
+        -5.9475779383993000e-03, -5.4016476789260452e-04, 8.7910413550767903e-03,
+        -9.8576315587856119e-03, 5.0134695031021541e-03, 1.2807521786221875e-06,
+        -2.0626019342754685e-03, 1.7109128573523059e-03, -6.7695312714133803e-04,
+        -6.9011545676562137e-09, 1.8855128143995903e-04, -1.3395215663491970e-04,
+        4.6263183033528039e-05, 4.0034230613321352e-11, -1.0255652921494033e-05,
+        6.6120863727976508e-06, -2.0913022027253007e-06, -2.0951775649603821e-13,
+        3.9756029041993248e-07, -2.3956211978815888e-07, 7.1182883382145858e-08,
+        8.9255748717132516e-16, -1.2101547235064677e-08, 6.9350618248334389e-09,
+        -1.9661464453856089e-09,
+    ],
+    [
+        1.7402027787522711e-02, -2.9527880945699119e-02, 2.0045875571402798e-02,
+        7.0289515966903405e-06, -1.2375421071343148e-02, 1.1976293444235255e-02,
+        -5.4156038466518525e-03, -6.3290893396418622e-08, 1.8855118129005065e-03,
+        -1.4734732748250010e-03, 5.5515810097708383e-04, 5.2406834412550662e-10,
+        -1.4357913535784835e-04, 9.9181293224943293e-05, -3.3460834749478309e-05,
+        -3.5755837291098967e-12, 7.1560851960630075e-06, -4.5516802628155527e-06,
+        1.4236576649271474e-06, 1.8803149079275236e-14, -2.6623403898929208e-07,
+        1.5950642189595716e-07, -4.7187514673841069e-08, -6.5107812648216942e-17,
+        7.9795091026746769e-09,
+    ],
+    [
+        3.0249124160905892e-02, 2.4817436002649977e-03, -4.9939134373457025e-02,
+        5.9915643009307872e-02, -3.2483207601623390e-02, -5.7212968652103442e-06,
+        1.5085251778569354e-02, -1.3261324005088445e-02, 5.5515262632426145e-03,
+        3.0263182257030013e-08, -1.7229548406756724e-03, 1.2893570099929638e-03,
+        -4.6845138348319875e-04, -1.8302599378930445e-10, 1.1449739014822654e-04,
+        -7.7378565221244473e-05, 2.5625836246985202e-05, 1.0766165332658074e-12,
+        -5.3246809282422619e-06, 3.3496348630644643e-06, -1.0381253128684010e-06,
+        -5.6089085334787489e-15, 1.9150821930676722e-07, -1.1418365800203775e-07,
+        3.3654425209152331e-08,
+    ],
+    [
+        -9.9051020880159046e-02, 1.7954011706123485e-01, -1.2989606383463778e-01,
+        -3.1478872752284355e-05, 9.0510635276848134e-02, -9.2828824411184396e-02,
+        4.4412112839877810e-02, 2.7779236316835886e-07, -1.7229543805449696e-02,
+        1.4182925050891573e-02, -5.6214161633747336e-03, -2.3959850918638095e-09,
+        1.6029634366079909e-03, -1.1606784674435774e-03, 4.1001337768153875e-04,
+        1.8365800753181603e-11, -9.5844256563655899e-05, 6.3643062337764711e-05,
+        -2.0762506244890635e-05, -1.1806017999805486e-13, 4.2131808239120937e-06,
+        -2.6262241337013133e-06, 8.0770620494883960e-07, 5.9964096905633376e-16,
+        -1.4729737374446200e-07,
+    ],
+    [
+        -1.9994542198219728e-01, -1.5056113040026424e-02, 3.6470239469348492e-01,
+        -4.6435192311733547e-01, 2.6640934719197895e-01, 3.4038266027147192e-05,
+        -1.3784338709329624e-01, 1.2764671783370560e-01, -5.6213828755200985e-02,
+        -1.7531508854830108e-07, 1.9235592956768112e-02, -1.5088821281095316e-02,
+        5.7401854451350123e-03, 1.0622382710173866e-09, -1.5335082692563998e-03,
+        1.0819320643228215e-03, -3.7372510193945632e-04, -6.6170904194333890e-12,
+        8.4263617380910205e-05, -5.5150706827484874e-05, 1.7769536448337793e-05,
+        3.8790705710065682e-14, -3.5351369749902462e-06, 2.1865832127706725e-06,
+        -6.6812849492405421e-07,
+    ],
+    [
+        7.2438608504029434e-01, -1.3918010932653375e+00, 1.0654143352413967e+00,
+        1.8761738689502581e-04, -8.2705501176152696e-01, 8.9352433347828408e-01,
+        -4.4971003995291337e-01, -1.6107401567546651e-06, 1.9235590165271091e-01,
+        -1.6597702160042610e-01, 6.8882222681814334e-02, 1.3910091724431419e-08,
+        -2.1469115615086628e-02, 1.6228980898865892e-02, -5.9796016172584216e-03,
+        -1.1287468171928069e-10, 1.5167451119784960e-03, -1.0478634293554165e-03,
+        3.5539072889105875e-04, 8.1626165361507830e-13, -7.7773013444708863e-05,
+        5.0291413891629069e-05, -1.6035083877747675e-05, -5.0260094840152135e-15,
+        3.1369106037108428e-06,
+    ],
+    [
+        1.6668949727276812e+00, 1.1654627659946321e-01, -3.3288393225018904e+00,
+        4.4692325482864037e+00, -2.6977693045875806e+00, -2.6006678598910609e-04,
+        1.5389017615694538e+00, -1.4937962361134611e+00, 6.8881964633233150e-01,
+        1.3077482004532885e-06, -2.5762963325596289e-01, 2.1097676102125451e-01,
+        -8.3714408359219825e-02, -7.7920427470006737e-09, 2.4267923064833764e-02,
+        -1.7813678334552763e-02, 6.3970330388863073e-03, 4.9415957307924725e-11,
+        -1.5554602758916928e-03, 1.0561196918773697e-03, -3.5277184484116647e-04,
+        -3.0939103274354235e-13, 7.5285854528775128e-05, -4.8186515801973001e-05,
+        1.5227272261135095e-05,
+    ],
+    [
+        -6.6188298861372932e+00, 1.3397985455142589e+01, -1.0789350606845145e+01,
+        -1.4352254537875018e-03, 9.2333694596189808e+00, -1.0456552819547769e+01,
+        5.5105526029033474e+00, 1.2024439690699193e-05, -2.5762961164755818e+00,
+        2.3207442745387179e+00, -1.0045728797216278e+00, -1.0207833106546672e-07,
+        3.3975092171169696e-01, -2.6720517450758147e-01, 1.0235252851556788e-01,
+        8.4304485854526760e-10, -2.7998284959254920e-02, 2.0066274142830732e-02,
+        -7.0554368962373734e-03, -6.5100368967900423e-12, 1.6562887995937561e-03,
+        -1.1082898634291512e-03, 3.6545453426391661e-04, 4.5041940828204548e-14,
+        -7.6340113005579318e-05,
+    ],
+    [
+        -1.7112706061976095e+01, -1.1208044642899115e+00, 3.7131966511885445e+01,
+        -5.2298271025348960e+01, 3.3058589696624615e+01, 2.4791298976198995e-03,
+        -2.0610894034115258e+01, 2.0886727751455822e+01, -1.0045703956517746e+01,
+        -1.2238783428880736e-05, 4.0770134274221421e+00, -3.4736673584702831e+00,
+        1.4329352617303721e+00, 7.1356127717327988e-08, -4.4797257160415332e-01,
+        3.4112666076996123e-01, -1.2699786335106672e-01, -4.5009172627885263e-10,
+        3.3125776059235765e-02, -2.3274087133486544e-02, 8.0399997492886822e-03,
+        2.9014850241350154e-12, -1.8321627125162138e-03, 1.2108140743081241e-03,
+        -3.9487195597625305e-04,
+    ],
+    [
+        7.3890331535674250e+01, -1.5680141270402274e+02, 1.3221775427591641e+02,
+        1.3692876877323932e-02, -1.2366496885920151e+02, 1.4620689391062729e+02,
+        -8.0365587724865293e+01, -1.1259851130717249e-04, 4.0770132196180214e+01,
+        -3.8210340013273999e+01, 1.7195222942763678e+01, 9.3514785252251112e-07,
+        -6.2716159909566649e+00, 5.1168999066380128e+00, -2.0319658125917894e+00,
+        -7.6801557692727896e-09, 5.9626396900089218e-01, -4.4220765550893937e-01,
+        1.6079999498013861e-01, 6.1054523223997452e-11, -4.0307579674978791e-02,
+        2.7848723708930791e-02, -9.4769269433980906e-03, -4.5311013227974904e-13,
+        2.1053350936332208e-03,
+    ],
+    [
+        2.1216837098382521e+02, 1.3107863022633866e+01, -4.9698285932871750e+02,
+        7.3121595266969211e+02, -4.8213821720890815e+02, -2.8817248691623405e-02,
+        3.2616720302947323e+02, -3.4389340280087987e+02, 1.7195193870806290e+02,
+        1.4038023228364068e-04, -7.5259419591943427e+01, 6.6519699838095079e+01,
+        -2.8447519767218779e+01, -8.0231177475248858e-07, 9.5402236474514961e+00,
+        -7.5175301476526304e+00, 2.8943999004997618e+00, 4.9800238071185723e-09,
+        -8.0615159428792149e-01, 5.8482319790480164e-01, -2.0849239270188827e-01,
+        -3.2254384913265948e-11, 5.0528042251671609e-02, -3.4412052806288070e-02,
+        1.1560069800937042e-02,
+    ],
+    [
+        -9.8959643098322363e+02, 2.1925555360905232e+03, -1.9283586782723344e+03,
+        -1.5925738121579511e-01, 1.9569985945919989e+03, -2.4072514765082165e+03,
+        1.3756149959328543e+03, 1.2920686502737788e-03, -7.5259417161664078e+02,
+        7.3171668734383024e+02, -3.4137023489099647e+02, -1.0517978697205148e-05,
+        1.3356313092981054e+02, -1.1276295215735033e+02, 4.6310398396005510e+01,
+        8.4994841281034395e-08, -1.4510728696418298e+01, 1.1111640759871898e+01,
+        -4.1698478539718637e+00, -6.7878452381260726e-10, 1.1116169295323557e+00,
+        -7.9147721454280051e-01, 2.7744167522211499e-01, 5.2261682227809740e-12,
+        -6.5314399924991162e-02,
+    ],
+];
+
+/* the asymptotic expansion in the transition region a ~ x, returning P if
+ * lower is set and Q otherwise */
+#[inline]
+fn temme(a: f64, x: f64, lower: bool) -> f64 {
+    let sgn = if lower { -1.0 } else { 1.0 };
+    let lambda = x / a;
+    let sigma = (x - a) / a;
+    let eta = if lambda > 1.0 {
+        sqrt(-2.0 * log1pmx(sigma))
+    } else if lambda < 1.0 {
+        -sqrt(-2.0 * log1pmx(sigma))
+    } else {
+        0.0
+    };
+    let res = 0.5 * erfc(sgn * eta * sqrt(a / 2.0));
+
+    let mut sum = 0.0;
+    let mut afac = 1.0;
+    let mut absoldterm = f64::INFINITY;
+    for dk in D.iter() {
+        let mut ck = dk[0];
+        let mut etapow = 1.0;
+        for &d in dk[1..].iter() {
+            etapow *= eta;
+            let ckterm = d * etapow;
+            ck += ckterm;
+            if fabs(ckterm) < EPS * fabs(ck) {
+                break;
+            }
+        }
+        let term = ck * afac;
+        let absterm = fabs(term);
+        if absterm > absoldterm {
+            break;
+        }
+        sum += term;
+        if absterm < EPS * fabs(sum) {
+            break;
+        }
+        absoldterm = absterm;
+        afac /= a;
+    }
+    res + sgn * exp(-0.5 * a * eta * eta) * sum / sqrt(2.0 * core::f64::consts::PI * a)
+}
+
+/* whether (a, x) is in the region of the asymptotic expansion, where its
+ * error, dominated by the erfc term, grows as a*eta^2 toward the tails but
+ * stays below a few hundred ulps before the result underflows, while the
+ * series would need about 1/|x/a - 1| terms */
+#[inline]
+fn use_temme(a: f64, x: f64) -> bool {
+    a > 20.0 && fabs(x - a) < 0.3 * a
+}
+
+/* P by its power series
+ * x^a*exp(-x)/gamma(a + 1)*(1 + x/(a + 1) + x^2/((a + 1)*(a + 2)) + ...) */
+#[inline]
+fn p_series(a: f64, x: f64) -> f64 {
+    /* the leading factor, computed directly for a < 1, where dividing the
+     * prefix by a would lose its accuracy once it is subnormal for tiny a */
+    let ax = if a < 1.0 {
+        pow(x, a) * exp(-x) / tgamma(1.0 + a)
+    } else {
+        gamma_prefix(a, x) / a
+    };
+    if ax == 0.0 {
+        return 0.0;
+    }
+    let mut r = a;
+    let mut c = 1.0;
+    let mut ans = 1.0;
+    for _ in 0..MAXITER {
+        r += 1.0;
+        c *= x / r;
+        ans += c;
+        if c <= EPS * ans {
+            break;
+        }
+    }
+    /* for tiny a, where 1 - P is below the rounding error, this can round
+     * to just above 1 */
+    let p = ans * ax;
+    if p > 1.0 {
+        1.0
+    } else {
+        p
+    }
+}
+
+/* Q by its continued fraction, for x > a */
+#[inline]
+fn q_cf(a: f64, x: f64) -> f64 {
+    let ax = gamma_prefix(a, x);
+    if ax == 0.0 {
+        return 0.0;
+    }
+    let mut y = 1.0 - a;
+    let mut z = x + y + 1.0;
+    let mut c = 0.0;
+    let mut pkm2 = 1.0;
+    let mut qkm2 = x;
+    let mut pkm1 = x + 1.0;
+    let mut qkm1 = z * x;
+    let mut ans = pkm1 / qkm1;
+    for _ in 0..MAXITER {
+        c += 1.0;
+        y += 1.0;
+        z += 2.0;
+        let yc = y * c;
+        let pk = pkm1 * z - pkm2 * yc;
+        let qk = qkm1 * z - qkm2 * yc;
+        let t = if qk != 0.0 {
+            let r = pk / qk;
+            let t = fabs((ans - r) / r);
+            ans = r;
+            t
+        } else {
+            1.0
+        };
+        pkm2 = pkm1;
+        pkm1 = pk;
+        qkm2 = qkm1;
+        qkm1 = qk;
+        if fabs(pk) > BIG {
+            pkm2 /= BIG;
+            pkm1 /= BIG;
+            qkm2 /= BIG;
+            qkm1 /= BIG;
+        }
+        if t <= EPS {
+            break;
+        }
+    }
+    ans * ax
+}
+
+/* Q for small x, as 1 - x^a/gamma(a + 1) - x^a/gamma(a)*sum of
+ * (-x)^n/(n!*(a + n)) for n >= 1 */
+#[inline]
+fn q_series(a: f64, x: f64) -> f64 {
+    let mut fac = 1.0;
+    let mut sum = 0.0;
+    for n in 1..MAXITER {
+        fac *= -x / n as f64;
+        let term = fac / (a + n as f64);
+        sum += term;
+        if fabs(term) <= EPS * fabs(sum) {
+            break;
+        }
+    }
+    let logx = log(x);
+    -expm1(a * logx - lgamma1p(a)) - exp(a * logx - lgamma_r(a).0) * sum
+}
+
+/* the special cases of both functions, as P */
+#[inline]
+fn special(a: f64, x: f64) -> Option<f64> {
+    if a.is_nan() || x.is_nan() || a < 0.0 || x < 0.0 {
+        return Some(f64::NAN);
+    }
+    if a == 0.0 {
+        return Some(if x > 0.0 { 1.0 } else { f64::NAN });
+    }
+    if a.is_infinite() {
+        return Some(if x.is_infinite() { f64::NAN } else { 0.0 });
+    }
+    if x == 0.0 {
+        return Some(0.0);
+    }
+    if x.is_infinite() {
+        return Some(1.0);
+    }
+    None
+}
+
+/// Regularized lower incomplete gamma function (f64)
+///
+/// Computes `P(a, x)`, the integral of `t^(a-1)*exp(-t)` from 0 to `x`
+/// divided by `gamma(a)`, for `a >= 0` and `x >= 0`. It is the probability
+/// that a gamma distributed variable of shape `a` is at most `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn gamma_p(a: f64, x: f64) -> f64 {
+    if let Some(p) = special(a, x) {
+        return p;
+    }
+    if use_temme(a, x) {
+        return temme(a, x, true);
+    }
+    if x > 1.0 && x > a {
+        return 1.0 - gamma_q(a, x);
+    }
+    p_series(a, x)
+}
+
+/// Regularized upper incomplete gamma function (f64)
+///
+/// Computes `Q(a, x) = 1 - P(a, x)`, the integral of `t^(a-1)*exp(-t)` from
+/// `x` to infinity divided by `gamma(a)`, for `a >= 0` and `x >= 0`. The
+/// result keeps its relative accuracy where it is small.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn gamma_q(a: f64, x: f64) -> f64 {
+    if let Some(p) = special(a, x) {
+        return 1.0 - p;
+    }
+    if use_temme(a, x) {
+        return temme(a, x, false);
+    }
+    if x > 1.1 {
+        if x < a {
+            1.0 - p_series(a, x)
+        } else {
+            q_cf(a, x)
+        }
+    } else if x <= 0.5 {
+        if -0.4 / log(x) < a {
+            1.0 - p_series(a, x)
+        } else {
+            q_series(a, x)
+        }
+    } else if x * 1.1 < a {
+        1.0 - p_series(a, x)
+    } else {
+        q_series(a, x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testutil::close;
+    use super::{gamma_p, gamma_q};
+
+    #[test]
+    fn special_values() {
+        assert!(gamma_p(f64::NAN, 1.0).is_nan());
+        assert!(gamma_q(1.0, f64::NAN).is_nan());
+        assert!(gamma_p(-1.0, 1.0).is_nan());
+        assert!(gamma_q(1.0, -1.0).is_nan());
+        assert!(gamma_p(0.0, 0.0).is_nan());
+        assert_eq!(gamma_p(0.0, 1.0), 1.0);
+        assert_eq!(gamma_q(0.0, 1.0), 0.0);
+        assert_eq!(gamma_p(2.0, 0.0), 0.0);
+        assert_eq!(gamma_q(2.0, 0.0), 1.0);
+        assert_eq!(gamma_p(2.0, f64::INFINITY), 1.0);
+        assert_eq!(gamma_q(2.0, f64::INFINITY), 0.0);
+        assert_eq!(gamma_p(f64::INFINITY, 2.0), 0.0);
+        assert!(gamma_p(f64::INFINITY, f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn values() {
+        /* (a, x, P, Q) */
+        let cases = [
+            (1.0, 1.0, 0.63212055882855768, 0.36787944117144232),
+            (0.5, 2.0, 0.95449973610364159, 0.045500263896358414),
+            (3.0, 2.0, 0.32332358381693654, 0.67667641618306346),
+            (1e-3, 1e-3, 0.99368764670886029, 0.0063123532911397099),
+            (100.0, 90.0, 0.15822098918643017, 0.84177901081356983),
+            (100.0, 130.0, 0.99724959163269347, 0.0027504083673065263),
+            (5.0, 50.0, 1.0, 5.4497019829205293e-17),
+            (1e4, 1e4, 0.5013298083399552, 0.4986701916600448),
+            (0.1, 30.0, 0.99999999999999955, 4.4767665800126917e-16),
+        ];
+        for &(a, x, p, q) in cases.iter() {
+            assert!(close(gamma_p(a, x), p, 1e-14), "P({}, {})", a, x);
+            assert!(close(gamma_q(a, x), q, 1e-14), "Q({}, {})", a, x);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+/* Inverses of the regularized incomplete gamma functions, by Newton's
+ * method on the logarithm of whichever of P and Q is below 1/2, so that the
+ * solution keeps the relative accuracy of a small probability. For a > 1
+ * the starting value is the Wilson-Hilferty approximation (A&S 26.4.17)
+ * with the normal quantile of A&S 26.2.22, or x^a/gamma(a + 1) = p far in
+ * the lower tail. For a <= 1 it is that leading term of the series of P
+ * (A&S 6.5.29) or, where it exceeds 1, the leading term of the asymptotic
+ * expansion of Q (A&S 6.5.32). The iterates stay within a bracket of the root that
+ * shrinks with every step, falling back to bisection when a step would
+ * leave it.
+ */
+
+use super::gamma_p::{gamma_p, gamma_q};
+use super::k_gamma::{gamma_prefix, lgamma1p};
+use super::{exp, fabs, log, sqrt};
+
+const TOL: f64 = 1e-14;
+
+/* the starting value for the solution of P(a, x) = p, Q(a, x) = q */
+#[inline]
+fn guess(a: f64, p: f64, q: f64) -> f64 {
+    /* P is about x^a/gamma(a + 1) for small x */
+    let xs = exp((log(p) + lgamma1p(a)) / a);
+    if a <= 1.0 {
+        if xs <= 1.0 {
+            return xs;
+        }
+        /* Q is about x^(a-1)*exp(-x)/gamma(a) for large x */
+        let l = -log(q) - (lgamma1p(a) - log(a));
+        return if l > 1.0 { l + (a - 1.0) * log(l) } else { 1.0 };
+    }
+    /* the normal quantile of the smaller tail, to about 3e-3 */
+    let t = sqrt(-2.0 * log(if p < 0.5 { p } else { q }));
+    let mut z = t - (2.30753 + t * 0.27061) / (1.0 + t * (0.99229 + t * 0.04481));
+    if p < 0.5 {
+        z = -z;
+    }
+    let w = 1.0 - 1.0 / (9.0 * a) + z / (3.0 * sqrt(a));
+    let x = a * w * w * w;
+    if p < 0.5 && xs > x {
+        xs
+    } else {
+        x
+    }
+}
+
+/* the x with P(a, x) = p and Q(a, x) = q, where p + q = 1 and the smaller
+ * of the two is exact, for finite a > 0 and 0 < p, q < 1 */
+#[inline]
+fn inverse(a: f64, p: f64, q: f64) -> f64 {
+    let lower = p < 0.5;
+    let mut x = guess(a, p, q);
+    let mut lo = 0.0;
+    let mut hi = f64::INFINITY;
+    for _ in 0..100 {
+        /* Newton's method on f = log(P/p) or log(q/Q), which increase with
+         * x and are close to linear in the tails, where P and Q are not */
+        let r = if lower { gamma_p(a, x) } else { gamma_q(a, x) };
+        let f = if lower { log(r / p) } else { log(q / r) };
+        if f == 0.0 {
+            return x;
+        }
+        if f < 0.0 {
+            lo = x;
+        } else {
+            hi = x;
+        }
+        let next = x - f * x * (r / gamma_prefix(a, x));
+        if fabs(next - x) <= TOL * next {
+            return next;
+        }
+        x = if next > lo && next < hi {
+            next
+        } else if hi.is_infinite() {
+            2.0 * x
+        } else {
+            0.5 * (lo + hi)
+        };
+    }
+    x
+}
+
+/// Inverse of the regularized lower incomplete gamma function (f64)
+///
+/// Returns the `x >= 0` with `gamma_p(a, x) == p`, for `a > 0` and
+/// `0 <= p <= 1`: the `p` quantile of the gamma distribution of shape `a`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn gamma_p_inv(a: f64, p: f64) -> f64 {
+    if !(a > 0.0) || !(p >= 0.0 && p <= 1.0) || a.is_infinite() {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return 0.0;
+    }
+    if p == 1.0 {
+        return f64::INFINITY;
+    }
+    inverse(a, p, 1.0 - p)
+}
+
+/// Inverse of the regularized upper incomplete gamma function (f64)
+///
+/// Returns the `x >= 0` with `gamma_q(a, x) == q`, for `a > 0` and
+/// `0 <= q <= 1`. Unlike `gamma_p_inv(a, 1 - q)`, it keeps its accuracy
+/// for small `q`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn gamma_q_inv(a: f64, q: f64) -> f64 {
+    if !(a > 0.0) || !(q >= 0.0 && q <= 1.0) || a.is_infinite() {
+        return f64::NAN;
+    }
+    if q == 0.0 {
+        return f64::INFINITY;
+    }
+    if q == 1.0 {
+        return 0.0;
+    }
+    inverse(a, 1.0 - q, q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::gamma_p::{gamma_p, gamma_q};
+    use super::super::testutil::close;
+    use super::super::{log, log1p};
+    use super::{gamma_p_inv, gamma_q_inv};
+
+    #[test]
+    fn special_values() {
+        assert!(gamma_p_inv(0.0, 0.5).is_nan());
+        assert!(gamma_p_inv(f64::INFINITY, 0.5).is_nan());
+        assert!(gamma_p_inv(1.0, 1.5).is_nan());
+        assert!(gamma_q_inv(1.0, -0.5).is_nan());
+        assert!(gamma_q_inv(f64::NAN, 0.5).is_nan());
+        assert_eq!(gamma_p_inv(2.0, 0.0), 0.0);
+        assert_eq!(gamma_p_inv(2.0, 1.0), f64::INFINITY);
+        assert_eq!(gamma_q_inv(2.0, 0.0), f64::INFINITY);
+        assert_eq!(gamma_q_inv(2.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn exponential() {
+        /* P(1, x) = 1 - exp(-x) */
+        for &p in [1e-300, 1e-10, 0.25, 0.5, 0.9].iter() {
+            assert!(close(gamma_p_inv(1.0, p), -log1p(-p), 1e-14), "{}", p);
+        }
+        for &q in [1e-300, 1e-10, 0.25, 0.5, 0.9].iter() {
+            assert!(close(gamma_q_inv(1.0, q), -log(q), 1e-14), "{}", q);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let shapes = [1e-3, 0.1, 0.5, 2.0, 7.5, 100.0, 1e4];
+        let probs = [1e-200, 1e-30, 1e-5, 0.01, 0.3, 0.5];
+        for &a in shapes.iter() {
+            for &p in probs.iter() {
+                let x = gamma_p_inv(a, p);
+                if x > 1e-300 {
+                    assert!(close(gamma_p(a, x), p, 1e-12), "P({}, {})", a, x);
+                }
+                let x = gamma_q_inv(a, p);
+                assert!(close(gamma_q(a, x), p, 1e-12), "Q({}, {})", a, x);
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use super::gamma_p_inv::{gamma_p_inv, gamma_q_inv};
+
+/// Inverse of the regularized lower incomplete gamma function (f32)
+///
+/// Returns the `x >= 0` with `gamma_pf(a, x) == p`, for `a > 0` and
+/// `0 <= p <= 1`, by evaluating [`gamma_p_inv`](fn.gamma_p_inv.html) in
+/// double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn gamma_p_invf(a: f32, p: f32) -> f32 {
+    gamma_p_inv(a as f64, p as f64) as f32
+}
+
+/// Inverse of the regularized upper incomplete gamma function (f32)
+///
+/// Returns the `x >= 0` with `gamma_qf(a, x) == q`, for `a > 0` and
+/// `0 <= q <= 1`, by evaluating [`gamma_q_inv`](fn.gamma_q_inv.html) in
+/// double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn gamma_q_invf(a: f32, q: f32) -> f32 {
+    gamma_q_inv(a as f64, q as f64) as f32
+}
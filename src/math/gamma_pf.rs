@@ -0,0 +1,22 @@
+use super::gamma_p::{gamma_p, gamma_q};
+
+/// Regularized lower incomplete gamma function (f32)
+///
+/// Computes `P(a, x)`, the integral of `t^(a-1)*exp(-t)` from 0 to `x`
+/// divided by `gamma(a)`, for `a >= 0` and `x >= 0`, by evaluating
+/// [`gamma_p`](fn.gamma_p.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn gamma_pf(a: f32, x: f32) -> f32 {
+    gamma_p(a as f64, x as f64) as f32
+}
+
+/// Regularized upper incomplete gamma function (f32)
+///
+/// Computes `Q(a, x) = 1 - P(a, x)` for `a >= 0` and `x >= 0`, by
+/// evaluating [`gamma_q`](fn.gamma_q.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn gamma_qf(a: f32, x: f32) -> f32 {
+    gamma_q(a as f64, x as f64) as f32
+}
@@ -0,0 +1,12 @@
+/// Payload of a NaN (f32)
+///
+/// Returns the payload of `x` as a non-negative integral value if `x` is a
+/// NaN, and -1 otherwise. The quiet bit is not part of the payload.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn getpayloadf(x: f32) -> f32 {
+    if !x.is_nan() {
+        return -1.0;
+    }
+    (x.to_bits() & 0x003f_ffff) as f32
+}
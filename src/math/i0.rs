@@ -0,0 +1,225 @@
+/* i0(x), k0(x)
+ * Modified Bessel functions of the first and second kinds of order zero,
+ * and their exponentially scaled forms i0e(x) = exp(-|x|)*i0(x) and
+ * k0e(x) = exp(x)*k0(x).
+ *
+ * Method -- i0(x):
+ *      1. Reduce x to |x| since i0(x)=i0(-x).
+ *      2. For x in [0,8], i0(x) is its power series
+ *              i0(x) = sum of (x^2/4)^k/(k!)^2,
+ *         whose terms are all positive.
+ *      3. For x in (8,inf), exp(-x)*sqrt(x)*i0(x) is a Chebyshev series
+ *         in 16/x-1, which tends to 1/sqrt(2*pi).
+ *
+ * Method -- k0(x):
+ *      1. For x in (0,2],
+ *              k0(x) = -log(x/2)*i0(x) + A(x^2)
+ *         where A(x^2) = sum of (x^2/4)^k/(k!)^2*psi(k+1) is a Chebyshev
+ *         series in x^2/2-1.
+ *      2. For x in (2,inf), exp(x)*sqrt(x)*k0(x) is a Chebyshev series in
+ *         4/x-1, which tends to sqrt(pi/2).
+ *
+ * The Chebyshev series are in the manner of the Cephes library, with
+ * coefficients computed anew to double precision.
+ *
+ * Special cases:
+ *      i0(+-inf) = inf, i0e(+-inf) = 0, i0(nan) = nan
+ *      k0(0) = inf, k0(inf) = k0e(inf) = 0, k0(x<0) = nan
+ */
+
+use super::{exp, log, sqrt};
+
+/* exp(-x)*sqrt(x)*i0(x) on (8, inf), in 16/x - 1 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const I0B: [f64; 27] = [
+    4.02245205507054393e-01, 3.36911647825569429e-03, 6.88975834691682454e-05,
+    2.89137052083475665e-06, 2.04891858946906384e-07, 2.26666899049817804e-08,
+    3.39623202570838651e-09, 4.94060238822497006e-10, 1.18891471078464390e-11,
+    -3.14991652796324165e-11, -1.32158118404477133e-11, -1.79417853150680615e-12,
+    7.18012445138366601e-13, 3.85277838274214259e-13, 1.54008621752140996e-14,
+    -4.15056934728722224e-14, -9.55484669882830731e-15, 3.81168066935262240e-15,
+    1.77256013305652631e-15, -3.42548561967721900e-16, -2.82762398051658365e-16,
+    3.46122286769746122e-17, 4.46562142029675975e-17, -4.83050448594418188e-18,
+    -7.23318048787475380e-18, 9.92147541217369872e-19, 1.19365089084598204e-18,
+];
+
+/* k0(x) + log(x/2)*i0(x) on (0, 2], in x^2/2 - 1 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const K0A: [f64; 11] = [
+    -2.67663696616951385e-01, 3.44289899924628495e-01, 3.59799365153615006e-02,
+    1.26461541144692598e-03, 2.28621210311945192e-05, 2.53479107902614939e-07,
+    1.90451637722020905e-09, 1.03496952576336253e-11, 4.25981614279108258e-14,
+    1.37446543588075084e-16, 3.57089652850837364e-19,
+];
+
+/* exp(x)*sqrt(x)*k0(x) on (2, inf), in 4/x - 1 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const K0B: [f64; 26] = [
+    1.22015154103297774e+00, -3.14481013119645020e-02, 1.56988388573005332e-03,
+    -1.28495495816278017e-04, 1.39498137188765002e-05, -1.83175552271911953e-06,
+    2.76681363944501486e-07, -4.66048989768794783e-08, 8.57403401741422527e-09,
+    -1.69753450938906142e-09, 3.57739728140032832e-10, -7.95748924447739648e-11,
+    1.85594911495492645e-11, -4.51459788337451925e-12, 1.14034058820734414e-12,
+    -2.98009692314817842e-13, 8.03289077506837463e-14, -2.22751332674629647e-14,
+    6.34007647627664606e-15, -1.84859337792090710e-15, 5.51205599940433350e-16,
+    -1.67823112575490059e-16, 5.21039177764355432e-17, -1.64758059398426321e-17,
+    5.30043377117733540e-18, -1.73317120058210011e-18,
+];
+
+/// The sum of `c[k]*T_k(t)` for `-1 <= t <= 1`, by Clenshaw's recurrence.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn cheb(t: f64, c: &[f64]) -> f64 {
+    let t2 = 2.0 * t;
+    let mut b1 = 0.0;
+    let mut b2 = 0.0;
+    for &ck in c[1..].iter().rev() {
+        let b0 = t2 * b1 - b2 + ck;
+        b2 = b1;
+        b1 = b0;
+    }
+    t * b1 - b2 + c[0]
+}
+
+/// `sum of z^k/(k!*(k+m)!)` times `m!` for `0 <= z <= 16`, the power series
+/// of `i0` and `i1` in `z = x^2/4`, summed from its smallest terms.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn series(z: f64, m: f64) -> f64 {
+    let mut p = 1.0;
+    for k in (1..=27).rev() {
+        let k = k as f64;
+        p = 1.0 + p * z / (k * (k + m));
+    }
+    p
+}
+
+/// `exp(x)*y` for `x >= 0` and `y <= 1`, without overflow in `exp(x)` where
+/// the product is finite.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn exp_mul(x: f64, y: f64) -> f64 {
+    if x < 700.0 {
+        exp(x) * y
+    } else {
+        let e = exp(0.5 * x);
+        e * y * e
+    }
+}
+
+/// Exponentially scaled modified Bessel function of the first kind of order
+/// zero (f64)
+///
+/// Computes `exp(-|x|)*i0(x)`, which unlike `i0(x)` does not overflow for
+/// large `|x|`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn i0e(x: f64) -> f64 {
+    let x = x.abs();
+    if x <= 8.0 {
+        series(0.25 * x * x, 0.0) * exp(-x)
+    } else {
+        /* also 0 for x = inf */
+        cheb(16.0 / x - 1.0, &I0B) / sqrt(x)
+    }
+}
+
+/// Modified Bessel function of the first kind of order zero (f64)
+///
+/// Computes `i0(x)`, the solution of `x^2*y'' + x*y' - x^2*y = 0` that is 1
+/// at `x = 0`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn i0(x: f64) -> f64 {
+    let ax = x.abs();
+    if ax <= 8.0 {
+        return series(0.25 * ax * ax, 0.0);
+    }
+    if ax == f64::INFINITY {
+        return ax;
+    }
+    exp_mul(ax, i0e(x))
+}
+
+/// Exponentially scaled modified Bessel function of the second kind of
+/// order zero (f64)
+///
+/// Computes `exp(x)*k0(x)` for `x >= 0`, which unlike `k0(x)` does not
+/// underflow for large `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn k0e(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::INFINITY;
+    }
+    if x <= 2.0 {
+        let k = cheb(0.5 * x * x - 1.0, &K0A) - log(0.5 * x) * i0(x);
+        return k * exp(x);
+    }
+    /* also 0 for x = inf */
+    cheb(4.0 / x - 1.0, &K0B) / sqrt(x)
+}
+
+/// Modified Bessel function of the second kind of order zero (f64)
+///
+/// Computes `k0(x)` for `x >= 0`, the solution of
+/// `x^2*y'' + x*y' - x^2*y = 0` that decays like `exp(-x)` as `x` grows.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn k0(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::INFINITY;
+    }
+    if x <= 2.0 {
+        return cheb(0.5 * x * x - 1.0, &K0A) - log(0.5 * x) * i0(x);
+    }
+    /* with the square root of exp(-x) twice where exp(-x) underflows */
+    let e = exp(-0.5 * x);
+    cheb(4.0 / x - 1.0, &K0B) / sqrt(x) * e * e
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testutil::close;
+    use super::{i0, i0e, k0, k0e};
+
+    #[test]
+    fn special_values() {
+        assert!(i0(f64::NAN).is_nan());
+        assert!(k0(-1.0).is_nan());
+        assert!(k0e(f64::NAN).is_nan());
+        assert_eq!(i0(0.0), 1.0);
+        assert_eq!(i0(f64::NEG_INFINITY), f64::INFINITY);
+        assert_eq!(i0e(f64::INFINITY), 0.0);
+        assert_eq!(k0(0.0), f64::INFINITY);
+        assert_eq!(k0(f64::INFINITY), 0.0);
+        assert_eq!(k0e(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn values() {
+        /* (x, i0(x), k0(x)) */
+        let cases = [
+            (1e-10, 1.0, 23.14178244559887),
+            (0.5, 1.0634833707413236, 0.9244190712276659),
+            (2.0, 2.2795853023360673, 0.11389387274953344),
+            (7.5, 268.16131151518937, 0.00024917761635611437),
+            (8.5, 683.1619269901156, 8.625756634932507e-05),
+            (30.0, 781672297823.9775, 2.1324774964630563e-14),
+            (700.0, 1.5295933476718737e+302, 4.669776431685377e-306),
+        ];
+        for &(x, i, k) in cases.iter() {
+            assert!(close(i0(x), i, 1e-15), "i0({})", x);
+            assert!(close(i0(-x), i, 1e-15), "i0({})", -x);
+            assert!(close(k0(x), k, 1e-15), "k0({})", x);
+        }
+        assert!(close(i0e(1e4), 0.003989472674604732, 1e-15));
+        assert!(close(k0e(1e4), 0.012532984717699286, 1e-15));
+    }
+}
@@ -0,0 +1,42 @@
+use super::i0::{i0, i0e, k0, k0e};
+
+/// Modified Bessel function of the first kind of order zero (f32)
+///
+/// Computes `i0(x)` by evaluating [`i0`](fn.i0.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn i0f(x: f32) -> f32 {
+    i0(x as f64) as f32
+}
+
+/// Exponentially scaled modified Bessel function of the first kind of order
+/// zero (f32)
+///
+/// Computes `exp(-|x|)*i0(x)` by evaluating [`i0e`](fn.i0e.html) in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn i0ef(x: f32) -> f32 {
+    i0e(x as f64) as f32
+}
+
+/// Modified Bessel function of the second kind of order zero (f32)
+///
+/// Computes `k0(x)` for `x >= 0` by evaluating [`k0`](fn.k0.html) in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn k0f(x: f32) -> f32 {
+    k0(x as f64) as f32
+}
+
+/// Exponentially scaled modified Bessel function of the second kind of
+/// order zero (f32)
+///
+/// Computes `exp(x)*k0(x)` for `x >= 0` by evaluating [`k0e`](fn.k0e.html)
+/// in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn k0ef(x: f32) -> f32 {
+    k0e(x as f64) as f32
+}
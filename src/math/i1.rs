@@ -0,0 +1,186 @@
+/* i1(x), k1(x)
+ * Modified Bessel functions of the first and second kinds of order one,
+ * and their exponentially scaled forms i1e(x) = exp(-|x|)*i1(x) and
+ * k1e(x) = exp(x)*k1(x).
+ *
+ * Method -- i1(x):
+ *      1. Reduce x to |x| since i1(x)=-i1(-x).
+ *      2. For x in [0,8], i1(x) is its power series
+ *              i1(x) = x/2 * sum of (x^2/4)^k/(k!*(k+1)!),
+ *         whose terms are all positive.
+ *      3. For x in (8,inf), exp(-x)*sqrt(x)*i1(x) is a Chebyshev series
+ *         in 16/x-1, which tends to 1/sqrt(2*pi).
+ *
+ * Method -- k1(x):
+ *      1. For x in (0,2],
+ *              k1(x) = log(x/2)*i1(x) + A(x^2)/x
+ *         where A(x^2) = x*(k1(x) - log(x/2)*i1(x)), which is 1 at x = 0, is
+ *         a Chebyshev series in x^2/2-1.
+ *      2. For x in (2,inf), exp(x)*sqrt(x)*k1(x) is a Chebyshev series in
+ *         4/x-1, which tends to sqrt(pi/2).
+ *
+ * Special cases:
+ *      i1(+-inf) = +-inf, i1e(+-inf) = +-0, i1(nan) = nan
+ *      k1(0) = inf, k1(inf) = k1e(inf) = 0, k1(x<0) = nan
+ */
+
+use super::i0::{cheb, exp_mul, series};
+use super::{exp, log, sqrt};
+
+/* exp(-x)*sqrt(x)*i1(x) on (8, inf), in 16/x - 1 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const I1B: [f64; 27] = [
+    3.89288117509140053e-01, -9.76109749136146870e-03, -1.10588938762623713e-04,
+    -3.88256480887769059e-06, -2.51223623787020884e-07, -2.63146884688951959e-08,
+    -3.83538038596423700e-09, -5.58974346219658378e-10, -1.89749581235054126e-11,
+    3.25260358301548844e-11, 1.41258074366137819e-11, 2.03562854414708956e-12,
+    -7.19855177624590836e-13, -4.08355111109219740e-13, -2.10154184277266430e-14,
+    4.27244001671195105e-14, 1.04202769841288021e-14, -3.81440307243700754e-15,
+    -1.88035477551078251e-15, 3.30820231092092852e-16, 2.96262899764595008e-16,
+    -3.20952592199342376e-17, -4.65030536848935863e-17, 4.41434832307170765e-18,
+    7.51729631084210521e-18, -9.31417886732688422e-19, -1.24219327519489097e-18,
+];
+
+/* x*(k1(x) - log(x/2)*i1(x)) on (0, 2], in x^2/2 - 1 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const K1A: [f64; 11] = [
+    7.62650113669473884e-01, -3.53155960776544875e-01, -1.22611180822657151e-01,
+    -6.97572385963986415e-03, -1.73028895751305199e-04, -2.43340614156596836e-06,
+    -2.21338763073472599e-08, -1.41148839263352781e-10, -6.66690169419932948e-13,
+    -2.42744985051936596e-15, -7.02386347938628815e-18,
+];
+
+/* exp(x)*sqrt(x)*k1(x) on (2, inf), in 4/x - 1 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const K1B: [f64; 26] = [
+    1.36031309524222133e+00, 1.03923736576817236e-01, -2.85781685962277921e-03,
+    1.95215518471351620e-04, -1.93619797416608301e-05, 2.40648494783721699e-06,
+    -3.50196060308781256e-07, 5.74108412545004947e-08, -1.03457624656780968e-08,
+    2.01504975519703466e-09, -4.19035475934192542e-10, 9.21831518760531460e-11,
+    -2.12996783842779092e-11, 5.13963967348234321e-12, -1.28917396094982285e-12,
+    3.34841966605224312e-13, -8.97670518201014629e-14, 2.47715442421959878e-14,
+    -7.01983708921476847e-15, 2.03870316623986097e-15, -6.05704727064301766e-16,
+    1.83809357524304548e-16, -5.68946284919364841e-17, 1.79405104788635718e-17,
+    -5.75674448207330252e-18, 1.87786519016232677e-18,
+];
+
+/// Exponentially scaled modified Bessel function of the first kind of order
+/// one (f64)
+///
+/// Computes `exp(-|x|)*i1(x)`, which unlike `i1(x)` does not overflow for
+/// large `|x|`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn i1e(x: f64) -> f64 {
+    let ax = x.abs();
+    let r = if ax <= 8.0 {
+        0.5 * ax * series(0.25 * ax * ax, 1.0) * exp(-ax)
+    } else {
+        /* also 0 for x = inf */
+        cheb(16.0 / ax - 1.0, &I1B) / sqrt(ax)
+    };
+    if x < 0.0 {
+        -r
+    } else {
+        r
+    }
+}
+
+/// Modified Bessel function of the first kind of order one (f64)
+///
+/// Computes `i1(x)`, the solution of `x^2*y'' + x*y' - (x^2 + 1)*y = 0`
+/// that behaves like `x/2` near `x = 0`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn i1(x: f64) -> f64 {
+    if x.abs() <= 8.0 {
+        return 0.5 * x * series(0.25 * x * x, 1.0);
+    }
+    if x.is_infinite() {
+        return x;
+    }
+    exp_mul(x.abs(), i1e(x))
+}
+
+/// Exponentially scaled modified Bessel function of the second kind of
+/// order one (f64)
+///
+/// Computes `exp(x)*k1(x)` for `x >= 0`, which unlike `k1(x)` does not
+/// underflow for large `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn k1e(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::INFINITY;
+    }
+    if x <= 2.0 {
+        return k1(x) * exp(x);
+    }
+    /* also 0 for x = inf */
+    cheb(4.0 / x - 1.0, &K1B) / sqrt(x)
+}
+
+/// Modified Bessel function of the second kind of order one (f64)
+///
+/// Computes `k1(x)` for `x >= 0`, the solution of
+/// `x^2*y'' + x*y' - (x^2 + 1)*y = 0` that decays like `exp(-x)` as `x`
+/// grows.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn k1(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::INFINITY;
+    }
+    if x <= 2.0 {
+        return log(0.5 * x) * i1(x) + cheb(0.5 * x * x - 1.0, &K1A) / x;
+    }
+    /* with the square root of exp(-x) twice where exp(-x) underflows */
+    let e = exp(-0.5 * x);
+    cheb(4.0 / x - 1.0, &K1B) / sqrt(x) * e * e
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testutil::close;
+    use super::{i1, i1e, k1, k1e};
+
+    #[test]
+    fn special_values() {
+        assert!(i1(f64::NAN).is_nan());
+        assert!(k1(-1.0).is_nan());
+        assert!(k1e(f64::NAN).is_nan());
+        assert_eq!(i1(0.0), 0.0);
+        assert_eq!(i1(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(i1e(f64::INFINITY), 0.0);
+        assert_eq!(k1(0.0), f64::INFINITY);
+        assert_eq!(k1(f64::INFINITY), 0.0);
+        assert_eq!(k1e(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn values() {
+        /* (x, i1(x), k1(x)) */
+        let cases = [
+            (1e-10, 5e-11, 10000000000.0),
+            (0.5, 0.2578943053908963, 1.656441120003301),
+            (2.0, 1.590636854637329, 0.13986588181652243),
+            (7.5, 249.58436542268814, 0.0002652973901252895),
+            (8.5, 641.6199025400667, 9.119724775006898e-05),
+            (30.0, 768532038938.957, 2.1677320018915495e-14),
+            (700.0, 1.5285003902339006e+302, 4.6731107967079664e-306),
+        ];
+        for &(x, i, k) in cases.iter() {
+            assert!(close(i1(x), i, 1e-15), "i1({})", x);
+            assert!(close(i1(-x), -i, 1e-15), "i1({})", -x);
+            assert!(close(k1(x), k, 1e-15), "k1({})", x);
+        }
+        assert!(close(i1e(1e4), 0.003989273195983662, 1e-15));
+        assert!(close(k1e(1e4), 0.012533611351270506, 1e-15));
+    }
+}
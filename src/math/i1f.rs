@@ -0,0 +1,42 @@
+use super::i1::{i1, i1e, k1, k1e};
+
+/// Modified Bessel function of the first kind of order one (f32)
+///
+/// Computes `i1(x)` by evaluating [`i1`](fn.i1.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn i1f(x: f32) -> f32 {
+    i1(x as f64) as f32
+}
+
+/// Exponentially scaled modified Bessel function of the first kind of order
+/// one (f32)
+///
+/// Computes `exp(-|x|)*i1(x)` by evaluating [`i1e`](fn.i1e.html) in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn i1ef(x: f32) -> f32 {
+    i1e(x as f64) as f32
+}
+
+/// Modified Bessel function of the second kind of order one (f32)
+///
+/// Computes `k1(x)` for `x >= 0` by evaluating [`k1`](fn.k1.html) in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn k1f(x: f32) -> f32 {
+    k1(x as f64) as f32
+}
+
+/// Exponentially scaled modified Bessel function of the second kind of
+/// order one (f32)
+///
+/// Computes `exp(x)*k1(x)` for `x >= 0` by evaluating [`k1e`](fn.k1e.html)
+/// in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn k1ef(x: f32) -> f32 {
+    k1e(x as f64) as f32
+}
@@ -1,6 +1,18 @@
-const FP_ILOGBNAN: i32 = -1 - 0x7fffffff;
-const FP_ILOGB0: i32 = FP_ILOGBNAN;
+/// Value returned by `ilogb` and `ilogbf` for a zero argument.
+pub const FP_ILOGB0: i32 = -0x7fffffff;
+/// Value returned by `ilogb` and `ilogbf` for a NaN argument.
+pub const FP_ILOGBNAN: i32 = -1 - 0x7fffffff;
 
+/// Unbiased exponent (f64)
+///
+/// Returns the exponent of `x` as a signed integer, the value of
+/// `floor(log2(|x|))` for a finite nonzero `x`. Returns `FP_ILOGB0` for zero,
+/// `FP_ILOGBNAN` for NaN and `i32::max_value()` for infinity, raising the
+/// invalid exception in all three cases.
+///
+/// `ilogb(±0)` returns `FP_ILOGB0`, `-i32::max_value()`, and no longer
+/// `i32::min_value()` as musl does, so that zero and NaN can be told apart.
+#[inline]
 pub fn ilogb(x: f64) -> i32 {
     let mut i: u64 = x.to_bits();
     let e = ((i >> 52) & 0x7ff) as i32;
@@ -29,3 +41,22 @@ pub fn ilogb(x: f64) -> i32 {
         e - 0x3ff
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::ilogbf;
+    use super::*;
+    use core::{f32, f64};
+
+    #[test]
+    fn special_values() {
+        assert_eq!(ilogb(0.0), FP_ILOGB0);
+        assert_eq!(ilogb(-0.0), FP_ILOGB0);
+        assert_eq!(ilogb(f64::NAN), FP_ILOGBNAN);
+        assert_ne!(ilogb(0.0), ilogb(f64::NAN));
+        assert_eq!(ilogb(f64::INFINITY), i32::max_value());
+        assert_eq!(ilogbf(0.0), FP_ILOGB0);
+        assert_eq!(ilogbf(f32::NAN), FP_ILOGBNAN);
+        assert_eq!(ilogb(f64::from_bits(1)), -1074);
+    }
+}
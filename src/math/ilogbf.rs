@@ -1,6 +1,12 @@
-const FP_ILOGBNAN: i32 = -1 - 0x7fffffff;
-const FP_ILOGB0: i32 = FP_ILOGBNAN;
+use super::{FP_ILOGB0, FP_ILOGBNAN};
 
+/// Unbiased exponent (f32)
+///
+/// Returns the exponent of `x` as a signed integer, the value of
+/// `floor(log2(|x|))` for a finite nonzero `x`. Returns `FP_ILOGB0` for zero,
+/// `FP_ILOGBNAN` for NaN and `i32::max_value()` for infinity, raising the
+/// invalid exception in all three cases.
+#[inline]
 pub fn ilogbf(x: f32) -> i32 {
     let mut i = x.to_bits();
     let e = ((i >> 23) & 0xff) as i32;
@@ -0,0 +1,226 @@
+/* in_(n, x), kn(n, x)
+ * Modified Bessel functions of the first and second kinds of order n.
+ *
+ * Method -- in_(n, x):
+ *      1. Reduce to n > 0 and x > 0 since in_(-n,x) = in_(n,x) and
+ *         in_(n,-x) = (-1)^n*in_(n,x).
+ *      2. Where the leading term of the uniform asymptotic expansion
+ *              in_(n,x) ~ exp(s - n*log((n+s)/x))/sqrt(2*pi*s)
+ *         with s = sqrt(n^2+x^2) is far out of range, return inf or 0.
+ *      3. Otherwise the ratio in_(n,x)/in_(n-1,x) is evaluated by its
+ *         continued fraction
+ *              1/(2n/x + 1/(2(n+1)/x + 1/(2(n+2)/x + ...)))
+ *         with the modified Lentz method, which takes about 6*sqrt(x)
+ *         terms for large x, and backward recursion
+ *              in_(k-1,x) = in_(k+1,x) + (2k/x)*in_(k,x),
+ *         which is stable and has no cancellation, is used down to
+ *         in_(0,x) from a supposed value of in_(n,x). The result is
+ *         the supposed value scaled by the actual i0(x).
+ *
+ * Method -- kn(n, x):
+ *      kn(-n,x) = kn(n,x), and forward recursion
+ *              kn(k+1,x) = kn(k-1,x) + (2k/x)*kn(k,x)
+ *      is used from k0e(x) and k1e(x), which is stable and has no
+ *      cancellation. The scaling by exp(-x) is applied at the end, or
+ *      earlier where the scaled values would overflow.
+ *
+ * Special cases:
+ *      in_(n,+-inf) = +-inf, in_(n,nan) = nan
+ *      kn(n,0) = inf, kn(n,inf) = 0, kn(n,x<0) = nan
+ */
+
+use super::i0::{exp_mul, i0e};
+use super::{exp, fabs, hypot, i0, i1, k0, k0e, k1, k1e, log};
+
+const EPS: f64 = 1.1102230246251565e-16; /* 0x1p-53 */
+const TWO_PI: f64 = 6.28318530717958647693e+00; /* 2*pi */
+
+/// `exp(-x)*y` for `x >= 0`, without underflow in `exp(-x)` where the
+/// product is in range.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn exp_neg_mul(mut x: f64, mut y: f64) -> f64 {
+    while x > 700.0 && y != 0.0 {
+        y *= exp(-700.0);
+        x -= 700.0;
+    }
+    y * exp(-x)
+}
+
+/// Modified Bessel function of the first kind of order n (f64)
+///
+/// Computes `in_(n, x)`, the solution of
+/// `x^2*y'' + x*y' - (x^2 + n^2)*y = 0` that is finite at `x = 0`. It is
+/// [`i0`](fn.i0.html) and [`i1`](fn.i1.html) for `n = 0` and `n = 1`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn in_(n: i32, x: f64) -> f64 {
+    let n = if n < 0 { -(n as i64) } else { n as i64 };
+    if n == 0 {
+        return i0(x);
+    }
+    if n == 1 {
+        return i1(x);
+    }
+    if x.is_nan() || x.is_infinite() {
+        return if n & 1 == 1 { x } else { fabs(x) };
+    }
+    let negate = x < 0.0 && n & 1 == 1;
+    let ax = fabs(x);
+    let r = positive(n as f64, ax);
+    if negate {
+        -r
+    } else {
+        r
+    }
+}
+
+/* in_(n, x) for n >= 2 and finite x >= 0 */
+#[inline]
+fn positive(n: f64, x: f64) -> f64 {
+    if x == 0.0 {
+        return 0.0;
+    }
+    /* the logarithm of the leading term of the uniform expansion, which is
+     * within a small fraction of 1 of the logarithm of the result */
+    let s = hypot(n, x);
+    let eta = s - n * log((n + s) / x) - 0.5 * log(TWO_PI * s);
+    if eta > 711.0 {
+        return f64::INFINITY;
+    }
+    if eta < -760.0 {
+        return 0.0;
+    }
+
+    /* f = in_(n-1,x)/in_(n,x) by the modified Lentz method, where 2n/x
+     * is finite since the result is not too small */
+    let mut f = 2.0 * n / x;
+    let mut c = f;
+    let mut d = 0.0;
+    let mut k = n;
+    loop {
+        k += 1.0;
+        let b = 2.0 * k / x;
+        d = 1.0 / (b + d);
+        c = b + 1.0 / c;
+        let del = c * d;
+        f *= del;
+        if fabs(del - 1.0) <= EPS {
+            break;
+        }
+    }
+
+    /* backward recursion from in_(n,x) = 1 and in_(n-1,x) = f, with r
+     * tracking in_(n,x) through the rescalings */
+    let mut r = 1.0;
+    let mut b = 1.0;
+    let mut a = f;
+    let mut k = n - 1.0;
+    while k >= 1.0 {
+        let t = b + 2.0 * k / x * a;
+        b = a;
+        a = t;
+        if a > 1e250 {
+            a *= 1e-250;
+            b *= 1e-250;
+            r *= 1e-250;
+        }
+        k -= 1.0;
+    }
+    exp_mul(x, r / a * i0e(x))
+}
+
+/// Modified Bessel function of the second kind of order n (f64)
+///
+/// Computes `kn(n, x)` for `x >= 0`, the solution of
+/// `x^2*y'' + x*y' - (x^2 + n^2)*y = 0` that decays like `exp(-x)` as `x`
+/// grows. It is [`k0`](fn.k0.html) and [`k1`](fn.k1.html) for `n = 0` and
+/// `n = 1`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn kn(n: i32, x: f64) -> f64 {
+    let n = if n < 0 { -(n as i64) } else { n as i64 };
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::INFINITY;
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+    if n == 0 {
+        return k0(x);
+    }
+    if n == 1 {
+        return k1(x);
+    }
+    /* exp(x)*kn(k,x) for k = 0, 1, ..., until the values would overflow,
+     * and kn(k,x) from there */
+    let mut a = k0e(x);
+    let mut b = k1e(x);
+    let mut scaled = true;
+    let mut k = 1;
+    while k < n {
+        let t = a + 2.0 * k as f64 / x * b;
+        a = b;
+        b = t;
+        if b == f64::INFINITY {
+            return b;
+        }
+        if scaled && b > 1e300 {
+            a = exp_neg_mul(x, a);
+            b = exp_neg_mul(x, b);
+            scaled = false;
+        }
+        k += 1;
+    }
+    if scaled {
+        exp_neg_mul(x, b)
+    } else {
+        b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testutil::close;
+    use super::super::{i0, i1, k0, k1};
+    use super::{in_, kn};
+
+    #[test]
+    fn special_values() {
+        assert!(in_(3, f64::NAN).is_nan());
+        assert!(kn(3, -1.0).is_nan());
+        assert_eq!(in_(2, 0.0), 0.0);
+        assert_eq!(in_(3, f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(in_(4, f64::NEG_INFINITY), f64::INFINITY);
+        assert_eq!(kn(3, 0.0), f64::INFINITY);
+        assert_eq!(kn(3, f64::INFINITY), 0.0);
+        assert_eq!(in_(0, 1.5), i0(1.5));
+        assert_eq!(in_(-1, 1.5), i1(1.5));
+        assert_eq!(kn(0, 1.5), k0(1.5));
+        assert_eq!(kn(-1, 1.5), k1(1.5));
+    }
+
+    #[test]
+    fn values() {
+        /* (n, x, in_(n, x), kn(n, x)) */
+        let cases = [
+            (2, 1e-5, 1.2500000000104167e-11, 19999999999.5),
+            (2, 1.0, 0.13574766976703828, 1.6248388986351774),
+            (3, 10.0, 1758.3807166108531, 2.725270025659869e-05),
+            (5, 0.5, 8.223171313109265e-06, 12097.979476096394),
+            (10, 30.0, 145831809975.96713, 1.0842816942222974e-13),
+            (50, 20.0, 2.255120575760404e-14, 411711209122.0177),
+            (100, 700.0, 1.2176413667078896e+299, 5.807185567419916e-303),
+            (400, 500.0, 1.010145100281558e+149, 7.730262426079562e-153),
+        ];
+        for &(n, x, i, k) in cases.iter() {
+            assert!(close(in_(n, x), i, 1e-14), "in_({}, {})", n, x);
+            assert!(close(kn(n, x), k, 1e-14), "kn({}, {})", n, x);
+        }
+        assert!(close(in_(3, -2.0), -in_(3, 2.0), 0.0));
+        assert!(close(in_(-4, -2.0), in_(4, 2.0), 0.0));
+    }
+}
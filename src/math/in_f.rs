@@ -0,0 +1,21 @@
+use super::in_::{in_, kn};
+
+/// Modified Bessel function of the first kind of order n (f32)
+///
+/// Computes `in_(n, x)` by evaluating [`in_`](fn.in_.html) in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn in_f(n: i32, x: f32) -> f32 {
+    in_(n, x as f64) as f32
+}
+
+/// Modified Bessel function of the second kind of order n (f32)
+///
+/// Computes `kn(n, x)` for `x >= 0` by evaluating [`kn`](fn.kn.html) in
+/// double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn knf(n: i32, x: f32) -> f32 {
+    kn(n, x as f64) as f32
+}
@@ -0,0 +1,423 @@
+/* iv(nu, x), kv(nu, x)
+ * Modified Bessel functions of the first and second kinds of real order nu.
+ *
+ * Method:
+ *      1. Reduce to nu >= 0 with
+ *              iv(-nu,x) = iv(nu,x) + (2/pi)*sin(pi*nu)*kv(nu,x)
+ *              kv(-nu,x) = kv(nu,x)
+ *         and, for integer nu, to x >= 0 with iv(nu,-x) = (-1)^nu*iv(nu,x).
+ *      2. For nu >= 50, the uniform asymptotic expansions
+ *              iv(nu,x) ~ exp(eta)/sqrt(2*pi*s) * sum of U_k(p)/nu^k
+ *              kv(nu,x) ~ sqrt(pi/(2*s))*exp(-eta) * sum of (-U_k(p))/nu^k
+ *         with s = sqrt(nu^2+x^2), p = nu/s, eta = s - nu*log((nu+s)/x)
+ *         and Debye's polynomials U_k (Abramowitz and Stegun 9.7.7-10) are
+ *         summed to k = 10.
+ *      3. Otherwise, where the leading term of 2 shows iv is far out of
+ *         range, return inf or 0 for it. Let mu = nu - m be nu less its
+ *         nearest integer, so that |mu| <= 1/2. Backward recurrence from
+ *         order nu, with iv(nu+1,x)/iv(nu,x) from its continued fraction,
+ *         gives iv at the orders mu and mu+1 relative to iv(nu,x). For
+ *         x < 2, kv(mu,x) and kv(mu+1,x) are summed by Temme's series
+ *         (Temme, J. Comput. Phys. 19, 1975), and for x >= 2 taken from
+ *              kv(mu,x) = integral over t > 0 of exp(-x*cosh(t))*cosh(mu*t)
+ *         (A&S 9.6.24) by the trapezoidal rule. The Wronskian
+ *              iv(mu,x)*kv(mu+1,x) + iv(mu+1,x)*kv(mu,x) = 1/x,
+ *         whose terms are all positive, then gives iv(nu,x), and forward
+ *         recurrence, which is stable for kv, gives kv(nu,x). Both are
+ *         carried without their factor exp(x) or exp(-x), which is applied
+ *         at the end. For x < 1e-50, iv(nu,x) is the leading term of its
+ *         power series.
+ *
+ * Special cases:
+ *      iv(nu,nan) = iv(nan,x) = iv(+-inf,x) = nan, and the same for kv
+ *      iv(nu,inf) = inf, kv(nu,inf) = 0, kv(nu,0) = inf
+ *      iv(nu,x<0) = nan for non-integer nu, kv(nu,x<0) = nan
+ */
+
+use super::i0::exp_mul;
+use super::in_::exp_neg_mul;
+use super::jv::{is_odd, mul, ratio_cf, temme_start};
+use super::{cosh, exp, fabs, floor, hypot, log, log1p, pow, sinh, sinpi, sqrt, tgamma};
+
+const PI: f64 = 3.141592653589793238462643383279502884;
+const LN_2PI: f64 = 1.83787706640934548356e+00; /* log(2*pi) */
+const SQRT_2PI: f64 = 2.50662827463100050242e+00; /* sqrt(2*pi) */
+const SQRT_PIO2: f64 = 1.25331413731550025121e+00; /* sqrt(pi/2) */
+const EPS: f64 = 2.2204460492503131e-16; /* 0x1p-52 */
+
+/* the order from which the uniform expansions are used */
+const NU_DEBYE: f64 = 50.0;
+
+/* U_k(p) = sum of c*p^j for j = k, k+2, ..., 3k, k = 1, 2, ..., 10 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const DEBYE: [f64; 65] = [
+    1.25000000000000000e-01, -2.08333333333333343e-01, 7.03125000000000000e-02,
+    -4.01041666666666685e-01, 3.34201388888888895e-01, 7.32421875000000000e-02,
+    -8.91210937500000022e-01, 1.84646267361111116e+00, -1.02581259645061729e+00,
+    1.12152099609375000e-01, -2.36408691406249982e+00, 8.78912353515625000e+00,
+    -1.12070026162229937e+01, 4.66958442342624735e+00, 2.27108001708984375e-01,
+    -7.36879435947963213e+00, 4.25349987453884566e+01, -9.18182415432400205e+01,
+    8.46362176746007293e+01, -2.82120725582002443e+01, 5.72501420974731445e-01,
+    -2.64914304869515540e+01, 2.18190511744211591e+02, -6.99579627376132521e+02,
+    1.05999045252799988e+03, -7.65252468141181680e+02, 2.12570130039217133e+02,
+    1.72772750258445740e+00, -1.08090919788394658e+02, 1.20090291321635254e+03,
+    -5.30564697861340301e+03, 1.16553933368645339e+04, -1.35865500064341377e+04,
+    8.06172218173730926e+03, -1.91945766231840707e+03, 6.07404200127348304e+00,
+    -4.93915304773087996e+02, 7.10951430248936413e+03, -4.11926549688975501e+04,
+    1.22200464983017460e+05, -2.03400177280415548e+05, 1.92547001232531533e+05,
+    -9.69805983886375179e+04, 2.02042913309661490e+04, 2.43805296995560639e+01,
+    -2.49983048181120967e+03, 4.52187689813627294e+04, -3.31645172484563605e+05,
+    1.26836527332162485e+06, -2.81356322658653418e+06, 3.76327129765640385e+06,
+    -2.99801591853810661e+06, 1.31176361466297717e+06, -2.42919187900551333e+05,
+    1.10017140269246738e+02, -1.38860897537170404e+04, 3.08186404612662387e+05,
+    -2.78561812808645470e+06, 1.32887671664218176e+07, -3.75671766607633531e+07,
+    6.63445122747290283e+07, -7.41051482115326524e+07, 5.09526024926646426e+07,
+    -1.97068191184322275e+07, 3.28446985307203792e+06,
+];
+
+/* exp(t)*y for y <= 1, without overflow or underflow in exp(t) where the
+ * product is in range */
+#[inline]
+fn exp_scale(t: f64, y: f64) -> f64 {
+    if t >= 0.0 {
+        exp_mul(t, y)
+    } else {
+        exp_neg_mul(-t, y)
+    }
+}
+
+/* (iv(nu,x), kv(nu,x)) for nu >= 50 and finite x > 0 */
+#[inline]
+fn debye(nu: f64, x: f64) -> (f64, f64) {
+    let s = hypot(nu, x);
+    let p = nu / s;
+    let p2 = p * p;
+    /* (nu+s)/x - 1 = (nu + nu^2/(s+x))/x, which keeps the accuracy of the
+     * logarithm for large x, or where that overflows the logarithm as
+     * log(nu/x) + log1p(nu/(s+x)), which then does not */
+    let w = (nu + nu / (s + x) * nu) / x;
+    let l = if w < f64::INFINITY {
+        log1p(w)
+    } else {
+        log(nu) - log(x) + log1p(nu / (s + x))
+    };
+    let eta = s - nu * l;
+    let t = 1.0 / nu;
+    let mut si = 1.0;
+    let mut sk = 1.0;
+    let mut tk = 1.0;
+    let mut j = 0;
+    for k in 1..=10 {
+        tk *= t * p;
+        let mut u = 0.0;
+        for i in (j..j + k + 1).rev() {
+            u = u * p2 + i!(DEBYE, i);
+        }
+        j += k + 1;
+        let term = u * tk;
+        si += term;
+        sk += if k & 1 == 1 { -term } else { term };
+    }
+    (
+        exp_scale(eta, si / (SQRT_2PI * sqrt(s))),
+        exp_scale(-eta, SQRT_PIO2 / sqrt(s) * sk),
+    )
+}
+
+/* (exp(x)*kv(mu,x), exp(x)*kv(mu+1,x)) for |mu| <= 1/2 and 0 < x < 2 by
+ * Temme's series
+ *      kv(mu,x) = sum of c_k*f_k,
+ *      kv(mu+1,x) = (2/x)*sum of c_k*(p_k - k*f_k),
+ * where c_k = (x^2/4)^k/k!, and f_k, p_k and q_k follow the recurrences
+ * of temme_y in jv.rs from f0, p0/2 and q0/2 of temme_start */
+#[inline]
+fn temme_k(mu: f64, x: f64) -> (f64, f64) {
+    let (f0, p0, q0) = temme_start(mu, x);
+    let mut f = f0;
+    let mut p = 0.5 * p0;
+    let mut q = 0.5 * q0;
+    let z = 0.25 * x * x;
+    let mut c = 1.0;
+    let mut s0 = f;
+    let mut s1 = p;
+    let mut k = 1.0;
+    loop {
+        f = (k * f + p + q) / ((k - mu) * (k + mu));
+        p /= k - mu;
+        q /= k + mu;
+        c *= z / k;
+        let g = c * f;
+        let h = c * (p - k * f);
+        s0 += g;
+        s1 += h;
+        if fabs(g) + fabs(h) <= EPS * (s0 + s1) {
+            break;
+        }
+        k += 1.0;
+    }
+    let e = exp(x);
+    (s0 * e, 2.0 / x * s1 * e)
+}
+
+/* (exp(x)*kv(mu,x), exp(x)*kv(mu+1,x)) for |mu| <= 1/2 and x >= 2 from
+ *      exp(x)*kv(nu,x) = integral over t > 0 of
+ *                        exp(-2x*sinh(t/2)^2)*cosh(nu*t)
+ * by the trapezoidal rule. Its error falls exponentially with 1/h for an
+ * integrand analytic in a strip about the real axis; with a step
+ * h = min(0.2, 0.5/sqrt(x)) it is below 1e-18 relative for x >= 2. The
+ * positive terms are summed until they are negligible. */
+#[inline]
+fn quad_k(mu: f64, x: f64) -> (f64, f64) {
+    let h = if x > 6.25 { 0.5 / sqrt(x) } else { 0.2 };
+    let mut s0 = 0.5;
+    let mut s1 = 0.5;
+    let mut k = 1.0;
+    loop {
+        let t = k * h;
+        let u = sinh(0.5 * t);
+        let e = exp(-2.0 * x * u * u);
+        let a = e * cosh(mu * t);
+        /* the larger term, as |mu + 1| >= |mu| */
+        let b = e * cosh((mu + 1.0) * t);
+        s0 += a;
+        s1 += b;
+        if b <= 1e-18 * s1 {
+            break;
+        }
+        k += 1.0;
+    }
+    (h * s0, h * s1)
+}
+
+/* (exp(x)*kv(mu,x), exp(x)*kv(mu+1,x)) for |mu| <= 1/2 and finite x > 0 */
+#[inline]
+fn k_mu(mu: f64, x: f64) -> (f64, f64) {
+    if x < 2.0 {
+        temme_k(mu, x)
+    } else {
+        quad_k(mu, x)
+    }
+}
+
+/* iv(nu,x) for nu >= 0 and x >= 0 */
+#[inline]
+fn iv_positive(nu: f64, x: f64) -> f64 {
+    if x == 0.0 {
+        return if nu == 0.0 { 1.0 } else { 0.0 };
+    }
+    if x == f64::INFINITY {
+        return x;
+    }
+    if nu >= NU_DEBYE {
+        return debye(nu, x).0;
+    }
+    let s = hypot(nu, x);
+    let eta = s - nu * log((nu + s) / x) - 0.5 * (LN_2PI + log(s));
+    if eta > 711.0 {
+        return f64::INFINITY;
+    }
+    if eta < -760.0 {
+        return 0.0;
+    }
+
+    let m = floor(nu + 0.5);
+    let mu = nu - m;
+    if x < 1e-50 {
+        /* the leading term of the power series */
+        return pow(x, nu) * pow(0.5, nu) / tgamma(nu + 1.0);
+    }
+
+    /* iv at the orders mu and mu+1 relative to iv(nu,x) = 1 by backward
+     * recurrence
+     *      iv(k-1,x) = (2k/x)*iv(k,x) + iv(k+1,x),
+     * which is stable as iv decreases with the order, with inu tracking
+     * iv(nu,x) through the rescalings */
+    let mut i0 = 1.0;
+    let mut i1 = ratio_cf(nu, x, 1.0);
+    let mut inu = 1.0;
+    let d = 2.0 / x;
+    let mut k = m;
+    while k >= 1.0 {
+        let t = (mu + k) * d * i0 + i1;
+        i1 = i0;
+        i0 = t;
+        if i0 > 1e100 {
+            i0 *= 1e-100;
+            i1 *= 1e-100;
+            inu *= 1e-100;
+        }
+        k -= 1.0;
+    }
+
+    /* the Wronskian, free of cancellation as all terms are positive,
+     * fixes the scale */
+    let (k0, k1) = k_mu(mu, x);
+    exp_mul(x, inu / (x * (i0 * k1 + i1 * k0)))
+}
+
+/* kv(nu,x) for nu >= 0 and x >= 0 */
+#[inline]
+fn kv_positive(nu: f64, x: f64) -> f64 {
+    if x == 0.0 {
+        return f64::INFINITY;
+    }
+    if x == f64::INFINITY {
+        return 0.0;
+    }
+    if nu >= NU_DEBYE {
+        return debye(nu, x).1;
+    }
+    let d = 2.0 / x;
+    let m = floor(nu + 0.5);
+    let mu = nu - m;
+    /* exp(x)*kv(mu+k,x) for k = 0, 1, ..., until the values would
+     * overflow, and kv(mu+k,x) from there */
+    let (mut a, mut b) = k_mu(mu, x);
+    if m == 0.0 {
+        return exp_neg_mul(x, a);
+    }
+    let mut scaled = true;
+    let mut k = 1.0;
+    while k < m {
+        let t = a + (mu + k) * d * b;
+        a = b;
+        b = t;
+        if b == f64::INFINITY {
+            return b;
+        }
+        if scaled && b > 1e300 {
+            a = exp_neg_mul(x, a);
+            b = exp_neg_mul(x, b);
+            scaled = false;
+        }
+        k += 1.0;
+    }
+    if scaled {
+        exp_neg_mul(x, b)
+    } else {
+        b
+    }
+}
+
+/// Modified Bessel function of the first kind of real order (f64)
+///
+/// Computes `iv(nu, x)`, the solution of
+/// `x^2*y'' + x*y' - (x^2 + nu^2)*y = 0` that is finite at `x = 0` for
+/// `nu >= 0`. It agrees with [`in_`](fn.in_.html) at integer orders. For
+/// `x < 0` it is real only for integer `nu`, and is NaN otherwise.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn iv(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || nu.is_infinite() || x.is_nan() {
+        return f64::NAN;
+    }
+    let ax = fabs(x);
+    let integer = nu == floor(nu);
+    if x < 0.0 && !integer {
+        return f64::NAN;
+    }
+    let r = if nu >= 0.0 || integer {
+        iv_positive(fabs(nu), ax)
+    } else {
+        iv_positive(-nu, ax) + mul(2.0 / PI * sinpi(-nu), kv_positive(-nu, ax))
+    };
+    if x < 0.0 && is_odd(nu) {
+        -r
+    } else {
+        r
+    }
+}
+
+/// Modified Bessel function of the second kind of real order (f64)
+///
+/// Computes `kv(nu, x)` for `x >= 0`, the solution of
+/// `x^2*y'' + x*y' - (x^2 + nu^2)*y = 0` that decays like `exp(-x)` as `x`
+/// grows. It agrees with [`kn`](fn.kn.html) at integer orders.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn kv(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || nu.is_infinite() || x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    kv_positive(fabs(nu), x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testutil::close;
+    use super::super::{in_, kn};
+    use super::{iv, kv};
+
+    #[test]
+    fn special_values() {
+        assert!(iv(0.5, f64::NAN).is_nan());
+        assert!(kv(f64::NAN, 1.0).is_nan());
+        assert!(iv(f64::NEG_INFINITY, 1.0).is_nan());
+        assert!(iv(0.5, -1.0).is_nan());
+        assert!(kv(2.0, -1.0).is_nan());
+        assert_eq!(iv(0.0, 0.0), 1.0);
+        assert_eq!(iv(2.5, 0.0), 0.0);
+        assert_eq!(iv(-0.5, 0.0), f64::INFINITY);
+        assert_eq!(iv(-1.5, 0.0), f64::NEG_INFINITY);
+        assert_eq!(kv(1.5, 0.0), f64::INFINITY);
+        assert_eq!(iv(1.5, f64::INFINITY), f64::INFINITY);
+        assert_eq!(kv(1.5, f64::INFINITY), 0.0);
+        assert_eq!(iv(0.5, 1e4), f64::INFINITY);
+        assert_eq!(kv(0.5, 1e4), 0.0);
+        assert_eq!(iv(1.5, 1e308), f64::INFINITY);
+        assert_eq!(kv(1.5, 1e308), 0.0);
+    }
+
+    #[test]
+    fn huge_orders() {
+        assert_eq!(kv(f64::MAX, 1.0), f64::INFINITY);
+        assert_eq!(kv(1e305, 1.0), f64::INFINITY);
+        assert_eq!(kv(-1e300, 1e299), f64::INFINITY);
+        assert_eq!(iv(f64::MAX, 1.0), 0.0);
+        assert_eq!(iv(-1e300, 1e299), 0.0);
+        assert_eq!(iv(1e300, f64::MAX), f64::INFINITY);
+        assert_eq!(kv(1e300, f64::MAX), 0.0);
+    }
+
+    #[test]
+    fn integer_orders() {
+        let xs = [1e-5, 0.3, 1.0, 1.9, 2.0, 5.5, 10.0, 37.0, 150.0, 650.0];
+        for n in [0, 1, 2, 3, 7, 20, 49, 50, 80].iter().cloned() {
+            for &x in xs.iter() {
+                let (i, k) = (in_(n, x), kn(n, x));
+                assert!(close(iv(n as f64, x), i, 1e-13), "iv({}, {})", n, x);
+                assert!(close(kv(n as f64, x), k, 1e-13), "kv({}, {})", n, x);
+                let s = if n & 1 == 1 { -1.0 } else { 1.0 };
+                assert!(close(iv(-n as f64, x), i, 1e-13));
+                assert!(close(kv(-n as f64, x), k, 1e-13));
+                assert!(close(iv(n as f64, -x), s * i, 1e-13));
+            }
+        }
+    }
+
+    #[test]
+    fn values() {
+        /* (nu, x, iv(nu, x), kv(nu, x)) */
+        let cases = [
+            (0.5, 1.0, 0.9376748882454876, 0.46106850444789454),
+            (-0.5, 3.0, 4.637757757861503, 0.036025985131764596),
+            (1.5, 0.25, 0.03345343680843307, 9.760820315757739),
+            (2.3, 1e-60, 7.567275482354111e-140, 2.8727816881353665e+138),
+            (2.3, 7.5, 184.07403454528094, 0.00034661604695874045),
+            (0.3, 30.0, 780480421399.8335, 2.1356270283260948e-14),
+            (-2.7, 4.0, 4.167456310709368, 0.024880852242241593),
+            (12.25, 9.0, 0.47683845321061, 0.06895243611063542),
+            (60.5, 30.0, 7.71228768056532e-11, 96003126.94700645),
+            (
+                100.5,
+                500.0,
+                1.0533555212151448e+211,
+                9.307323414488398e-215,
+            ),
+        ];
+        for &(nu, x, i, k) in cases.iter() {
+            assert!(close(iv(nu, x), i, 1e-13), "iv({}, {})", nu, x);
+            assert!(close(kv(nu, x), k, 1e-13), "kv({}, {})", nu, x);
+        }
+    }
+}
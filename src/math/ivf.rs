@@ -0,0 +1,21 @@
+use super::iv::{iv, kv};
+
+/// Modified Bessel function of the first kind of real order (f32)
+///
+/// Computes `iv(nu, x)` by evaluating [`iv`](fn.iv.html) in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ivf(nu: f32, x: f32) -> f32 {
+    iv(nu as f64, x as f64) as f32
+}
+
+/// Modified Bessel function of the second kind of real order (f32)
+///
+/// Computes `kv(nu, x)` for `x >= 0` by evaluating [`kv`](fn.kv.html) in
+/// double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn kvf(nu: f32, x: f32) -> f32 {
+    kv(nu as f64, x as f64) as f32
+}
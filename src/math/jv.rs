@@ -0,0 +1,566 @@
+/* jv(nu, x), yv(nu, x)
+ * Bessel functions of the first and second kinds of real order nu.
+ *
+ * Method:
+ *      1. Reduce to nu >= 0 with
+ *              jv(-nu,x) = cos(pi*nu)*jv(nu,x) - sin(pi*nu)*yv(nu,x)
+ *              yv(-nu,x) = sin(pi*nu)*jv(nu,x) + cos(pi*nu)*yv(nu,x)
+ *         which for integer nu, as are all |nu| >= 2^53, are
+ *         jv(-nu,x) = (-1)^nu*jv(nu,x) and yv(-nu,x) = (-1)^nu*yv(nu,x),
+ *         and, for integer nu, to x >= 0 with jv(nu,-x) = (-1)^nu*jv(nu,x).
+ *      2. For x > 100*max(nu,1), the asymptotic expansions of the
+ *         amplitude and phase of jv and yv (Abramowitz and Stegun 9.2.28
+ *         and 9.2.29) are used.
+ *      3. For x < nu, where the leading term of the uniform asymptotic
+ *         expansion
+ *              jv(nu,x) ~ exp(s - nu*log((nu+s)/x))/sqrt(2*pi*s)
+ *         with s = sqrt(nu^2-x^2) underflows, return 0 and -inf. Beyond
+ *         that, orders above 2^31-1, the largest of jn and yn, are not
+ *         supported as the recurrences below would take too long.
+ *      4. For x >= 25 and x >= nu, jv and yv at the orders frac(nu) and
+ *         frac(nu)+1 are summed by Hankel's expansions, and forward
+ *         recursion, which is stable for both below the turning point,
+ *         carries them to order nu.
+ *      5. Otherwise, let mu = nu - m be nu less its nearest integer, so
+ *         that |mu| <= 1/2. Miller's algorithm gives jv at the orders nu,
+ *         mu and mu+1 up to a common positive factor: backward recurrence
+ *         from an order n = nu + l > x, where jv(n,x) > 0, with
+ *         jv(n+1,x)/jv(n,x) from its continued fraction (A&S 9.1.73). For
+ *         x < 2, yv(mu,x) and yv(mu+1,x) are summed by Temme's series
+ *         (Temme, J. Comput. Phys. 19, 1975) and the Wronskian
+ *              jv(mu+1,x)*yv(mu,x) - jv(mu,x)*yv(mu+1,x) = 2/(pi*x)
+ *         fixes the factor. For x >= 2, the continued fraction of Steed
+ *         and Barnett for
+ *              (jv'(mu,x) + i*yv'(mu,x))/(jv(mu,x) + i*yv(mu,x))
+ *         is summed by Steed's algorithm, and together with the Wronskian
+ *         gives the factor and yv(mu,x). Forward recurrence, which is
+ *         stable for yv, then carries yv(mu,x) and yv(mu+1,x) up to order
+ *         nu. For x < 1e-50, jv(nu,x) is the leading term of its power
+ *         series.
+ *
+ * Special cases:
+ *      jv(nu,nan) = jv(nan,x) = jv(+-inf,x) = nan, and the same for yv
+ *      jv(nu,inf) = yv(nu,inf) = 0, yv(nu,0) = -inf for nu >= 0
+ *      jv(nu,x<0) = nan for non-integer nu, yv(nu,x<0) = nan
+ *      jv(nu,x) = yv(nu,x) = nan for |nu| > 2^31-1 and |x| <= 100*|nu|
+ *      where jv(|nu|,x) does not underflow
+ */
+
+use super::{cos, cosh, cospi, exp, fabs, floor, log, log1p, pow, sin, sinh, sinpi, sqrt, tgamma};
+
+const PI: f64 = 3.141592653589793238462643383279502884;
+const LN_2PI: f64 = 1.83787706640934548356e+00; /* log(2*pi) */
+const LN2: f64 = 6.93147180559945309417e-01; /* log(2) */
+const EPS: f64 = 2.2204460492503131e-16; /* 0x1p-52 */
+
+/* the largest order for which the recurrences are run, which is that of jn
+ * and yn; beyond it they would take too long, and jv and yv are NaN where
+ * they do not underflow or take their asymptotic form */
+const MAXNU: f64 = 2147483647.0;
+
+/* 1/gamma(z) = sum of c_k*z^k for k = 1, 2, ..., 26 (A&S 6.1.34), so that
+ * 1/gamma(1+mu) = sum of c_k*mu^(k-1) */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const RGAMMA: [f64; 26] = [
+    1.0, 5.77215664901532861e-01, -6.55878071520253881e-01,
+    -4.20026350340952355e-02, 1.66538611382291490e-01, -4.21977345555443367e-02,
+    -9.62197152787697356e-03, 7.21894324666309954e-03, -1.16516759185906511e-03,
+    -2.15241674114950973e-04, 1.28050282388116186e-04, -2.01348547807882387e-05,
+    -1.25049348214267066e-06, 1.13302723198169588e-06, -2.05633841697760710e-07,
+    6.11609510448141582e-09, 5.00200764446922293e-09, -1.18127457048702014e-09,
+    1.04342671169110051e-10, 7.78226343990507125e-12, -3.69680561864220571e-12,
+    5.10037028745447598e-13, -2.05832605356650678e-14, -5.34812253942301798e-15,
+    1.22677862823826079e-15, -1.18125930169745877e-16,
+];
+
+/// The first terms `(f0, p0, q0)` of Temme's series for `|mu| <= 1/2` and
+/// `x > 0`:
+/// `f0 = mu*pi/sin(mu*pi)*(cosh(s)*g1 + sinh(s)/s*log(2/x)*g2)`,
+/// `p0 = (x/2)^-mu*gamma(1+mu)` and `q0 = (x/2)^mu*gamma(1-mu)`, where
+/// `s = mu*log(2/x)`, `g1 = (1/gamma(1-mu) - 1/gamma(1+mu))/(2*mu)` and
+/// `g2 = (1/gamma(1-mu) + 1/gamma(1+mu))/2`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn temme_start(mu: f64, x: f64) -> (f64, f64, f64) {
+    /* g1 and g2 are even in mu: minus the even and the odd terms of
+     * RGAMMA */
+    let z = mu * mu;
+    let mut g1 = 0.0;
+    let mut g2 = 0.0;
+    for j in (0..13).rev() {
+        g1 = g1 * z - i!(RGAMMA, 2 * j + 1);
+        g2 = g2 * z + i!(RGAMMA, 2 * j);
+    }
+    let l = LN2 - log(x);
+    let s = mu * l;
+    /* mu*pi/sin(mu*pi) and sinh(s)/s, which tend to 1 at 0 */
+    let a = if mu == 0.0 { 1.0 } else { PI * mu / sinpi(mu) };
+    let b = if s == 0.0 { 1.0 } else { sinh(s) / s };
+    let e = exp(s);
+    (
+        a * (cosh(s) * g1 + b * l * g2),
+        e / (g2 - mu * g1),
+        1.0 / (e * (g2 + mu * g1)),
+    )
+}
+
+/// The ratio `jv(nu+1,x)/jv(nu,x)` for `sign = -1`, or
+/// `iv(nu+1,x)/iv(nu,x)` for `sign = 1`, where `x > 0` and, for `jv`,
+/// `nu > x`, by the continued fraction
+/// `1/(2(nu+1)/x + sign/(2(nu+2)/x + sign/(2(nu+3)/x + ...)))`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn ratio_cf(nu: f64, x: f64, sign: f64) -> f64 {
+    /* the convergents a/b by Wallis' recurrences, rescaled as they grow;
+     * the partial denominators exceed 2 for jv, so that b does not vanish */
+    let d = 2.0 / x;
+    let (mut a0, mut a1) = (0.0, 1.0);
+    let (mut b0, mut b1) = (1.0, (nu + 1.0) * d);
+    let mut r = a1 / b1;
+    let mut k = 1.0;
+    loop {
+        k += 1.0;
+        let c = (nu + k) * d;
+        let a = c * a1 + sign * a0;
+        let b = c * b1 + sign * b0;
+        a0 = a1;
+        b0 = b1;
+        a1 = a;
+        b1 = b;
+        if fabs(b1) > 1e200 {
+            let t = 1.0 / b1;
+            a0 *= t;
+            b0 *= t;
+            a1 *= t;
+            b1 = 1.0;
+        }
+        let rk = a1 / b1;
+        if fabs(rk - r) <= EPS * fabs(rk) {
+            return rk;
+        }
+        r = rk;
+    }
+}
+
+/// `c*v`, which is 0 for `c = 0` even where `v` is infinite.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn mul(c: f64, v: f64) -> f64 {
+    if c == 0.0 {
+        0.0
+    } else {
+        c * v
+    }
+}
+
+/// Whether the integer `nu` is odd.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn is_odd(nu: f64) -> bool {
+    2.0 * floor(0.5 * nu) != nu
+}
+
+/* the sine and cosine of x - pi*(nu/2 + 1/4), by the addition formulas */
+#[inline]
+fn shifted_sincos(nu: f64, x: f64) -> (f64, f64) {
+    let (sx, cx) = (sin(x), cos(x));
+    let (si, ci) = (sinpi(0.5 * nu + 0.25), cospi(0.5 * nu + 0.25));
+    (sx * ci - cx * si, cx * ci + sx * si)
+}
+
+/* (jv(nu,x), yv(nu,x)) for x > 100*max(nu,1), with the phase less
+ * x - pi*(nu/2 + 1/4) in terms of r = nu/x, so that nothing overflows */
+#[inline]
+fn asymptotic(nu: f64, x: f64) -> (f64, f64) {
+    let r = nu / x;
+    let r2 = r * r;
+    let y = 1.0 / (x * x);
+    let u1 = r2 - 0.25 * y;
+    let u2 = r2 - 2.25 * y;
+    let u3 = r2 - 6.25 * y;
+    let s = 1.0 + u1 * (0.5 + u2 * (0.375 + u3 * 0.3125));
+    let ampl = sqrt(2.0 * s / (PI * x));
+
+    let t1 = 0.5 * nu * r - 0.125 / x;
+    let a2 = r2 / 12.0 - 25.0 / 48.0 * y;
+    let a3 = (16.0 * r2 * r2 - 456.0 * r2 * y + 1073.0 * y * y) / 640.0;
+    let a4 = (((320.0 * r2 - 24560.0 * y) * r2 + 218812.0 * y * y) * r2 - 375733.0 * y * y * y)
+        / 28672.0;
+    let phase = t1 + t1 * (a2 + a3 + a4);
+
+    let (sb, cb) = shifted_sincos(nu, x);
+    let (sp, cp) = (sin(phase), cos(phase));
+    (ampl * (cp * cb - sp * sb), ampl * (sp * cb + cp * sb))
+}
+
+/* (jv(mu,x), yv(mu,x)) for 0 <= mu < 2 and x >= 25 by Hankel's expansions
+ * of P and Q, whose terms fall below EPS long before they diverge */
+#[inline]
+fn hankel(mu: f64, x: f64) -> (f64, f64) {
+    let m4 = 4.0 * mu * mu;
+    let z = 8.0 * x;
+    let mut p = 1.0;
+    let mut q = 0.0;
+    let mut t = 1.0;
+    let mut k = 1;
+    while fabs(t) >= 0.25 * EPS {
+        let o = (2 * k - 1) as f64;
+        t *= (m4 - o * o) / (k as f64 * z);
+        match k & 3 {
+            1 => q += t,
+            2 => p -= t,
+            3 => q -= t,
+            _ => p += t,
+        }
+        k += 1;
+    }
+    let (sb, cb) = shifted_sincos(mu, x);
+    let a = sqrt(2.0 / (PI * x));
+    (a * (p * cb - q * sb), a * (p * sb + q * cb))
+}
+
+/* (jv(nu,x), yv(nu,x)) for x >= 25 and x >= nu by forward recursion from
+ * the two orders below 2 of the same fraction, which is stable for both
+ * below the turning point */
+#[inline]
+fn forward(nu: f64, x: f64) -> (f64, f64) {
+    let m = floor(nu);
+    let mu = nu - m;
+    let (mut j0, mut y0) = hankel(mu, x);
+    if m == 0.0 {
+        return (j0, y0);
+    }
+    let (mut j1, mut y1) = hankel(mu + 1.0, x);
+    let d = 2.0 / x;
+    let mut k = 1.0;
+    while k < m {
+        let c = (mu + k) * d;
+        let j = c * j1 - j0;
+        let y = c * y1 - y0;
+        j0 = j1;
+        y0 = y1;
+        j1 = j;
+        y1 = y;
+        k += 1.0;
+    }
+    (j1, y1)
+}
+
+/* jv at the orders nu, mu = nu - m and mu+1, up to a common positive
+ * factor, for x > 0 and nu >= 0, by backward recurrence
+ *      jv(k-1,x) = (2k/x)*jv(k,x) - jv(k+1,x)
+ * from the first order n = nu + l above x, where jv(n,x) > 0. It is stable
+ * as jv is the solution that decreases fastest with the order beyond x. */
+#[inline]
+fn miller(nu: f64, m: f64, x: f64) -> (f64, f64, f64) {
+    let mu = nu - m;
+    let l = if x < nu { 0.0 } else { floor(x - nu) + 1.0 };
+    let mut lo = 1.0;
+    let mut hi = ratio_cf(nu + l, x, -1.0);
+    let mut jnu = lo;
+    let d = 2.0 / x;
+    /* lo is jv at the order mu + k */
+    let mut k = m + l;
+    while k > 0.0 {
+        let t = (mu + k) * d * lo - hi;
+        hi = lo;
+        lo = t;
+        k -= 1.0;
+        if k == m {
+            jnu = lo;
+        }
+        if fabs(lo) > 1e100 {
+            lo *= 1e-100;
+            hi *= 1e-100;
+            jnu *= 1e-100;
+        }
+    }
+    (jnu, lo, hi)
+}
+
+/* (yv(mu,x), yv(mu+1,x)) for |mu| <= 1/2 and 0 < x < 2 by Temme's series
+ *      yv(mu,x) = -sum of c_k*g_k,
+ *      yv(mu+1,x) = -(2/x)*sum of c_k*(p_k - k*g_k),
+ * where c_k = (-x^2/4)^k/k!, g_k = f_k + 2*sin(pi*mu/2)^2/mu*q_k, and
+ *      f_k = (k*f_(k-1) + p_(k-1) + q_(k-1))/(k^2 - mu^2),
+ *      p_k = p_(k-1)/(k - mu), q_k = q_(k-1)/(k + mu)
+ * start from 2/pi*f0, p0/pi and q0/pi of temme_start */
+#[inline]
+fn temme_y(mu: f64, x: f64) -> (f64, f64) {
+    let (f0, p0, q0) = temme_start(mu, x);
+    let mut f = 2.0 / PI * f0;
+    let mut p = p0 / PI;
+    let mut q = q0 / PI;
+    let w = if mu == 0.0 {
+        0.0
+    } else {
+        let s = sinpi(0.5 * mu);
+        2.0 * s * s / mu
+    };
+    let z = -0.25 * x * x;
+    let mut c = 1.0;
+    let mut s0 = f + w * q;
+    let mut s1 = p;
+    let mut k = 1.0;
+    loop {
+        f = (k * f + p + q) / ((k - mu) * (k + mu));
+        p /= k - mu;
+        q /= k + mu;
+        c *= z / k;
+        let g = c * (f + w * q);
+        let h = c * p - k * g;
+        s0 += g;
+        s1 += h;
+        /* the two sums do not vanish together, by the Wronskian */
+        if fabs(g) + fabs(h) <= EPS * (fabs(s0) + fabs(s1)) {
+            break;
+        }
+        k += 1.0;
+    }
+    (-s0, -2.0 / x * s1)
+}
+
+#[inline]
+fn cmul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+#[inline]
+fn cinv(a: (f64, f64)) -> (f64, f64) {
+    let t = 1.0 / (a.0 * a.0 + a.1 * a.1);
+    (a.0 * t, -a.1 * t)
+}
+
+/* (p, q) with p + i*q = (jv'(mu,x) + i*yv'(mu,x))/(jv(mu,x) + i*yv(mu,x))
+ * for |mu| <= 1/2 and x >= 2, from the continued fraction
+ *      p + i*q = -1/(2x) + i + (i/x)*a_1/(b_1 + a_2/(b_2 + ...)),
+ *      a_k = (k - 1/2)^2 - mu^2, b_k = 2(x + i*k),
+ * by Steed's algorithm, which sums the differences of its convergents */
+#[inline]
+fn steed(mu: f64, x: f64) -> (f64, f64) {
+    let a1 = 0.25 - mu * mu;
+    let mut d = cinv((2.0 * x, 2.0));
+    let mut delta = (a1 * d.0, a1 * d.1);
+    let mut t = delta;
+    let mut k = 1.0;
+    while fabs(delta.0) + fabs(delta.1) > EPS * (fabs(t.0) + fabs(t.1)) {
+        k += 1.0;
+        let a = (k - 0.5) * (k - 0.5) - mu * mu;
+        let b = (2.0 * x, 2.0 * k);
+        d = cinv((b.0 + a * d.0, b.1 + a * d.1));
+        let bd = cmul(b, d);
+        delta = cmul((bd.0 - 1.0, bd.1), delta);
+        t = (t.0 + delta.0, t.1 + delta.1);
+    }
+    (-(0.5 + t.1) / x, 1.0 + t.0 / x)
+}
+
+/* (jv(nu,x), yv(nu,x)) for nu >= 0 and x >= 0 */
+#[inline]
+fn positive(nu: f64, x: f64) -> (f64, f64) {
+    if x == 0.0 {
+        return (if nu == 0.0 { 1.0 } else { 0.0 }, f64::NEG_INFINITY);
+    }
+    if x == f64::INFINITY {
+        return (0.0, 0.0);
+    }
+    if x > 100.0 * if nu > 1.0 { nu } else { 1.0 } {
+        return asymptotic(nu, x);
+    }
+    if x < nu {
+        /* jv is positive and yv negative below the turning point; s and
+         * eta are formed so that nothing overflows for any finite nu */
+        let r = x / nu;
+        let s = nu * sqrt((1.0 - r) * (1.0 + r));
+        let eta = s - nu * (log(nu / x) + log1p(s / nu)) - 0.5 * (LN_2PI + log(s));
+        if eta < -760.0 {
+            return (0.0, f64::NEG_INFINITY);
+        }
+    }
+    if nu > MAXNU {
+        return (f64::NAN, f64::NAN);
+    }
+    if x >= 25.0 && x >= nu {
+        return forward(nu, x);
+    }
+
+    let m = floor(nu + 0.5);
+    let mu = nu - m;
+    let (j, mut ymu, mut y1) = if x < 2.0 {
+        let (ymu, y1) = temme_y(mu, x);
+        let j = if x < 1e-50 {
+            /* the leading term of the power series */
+            pow(x, nu) * pow(0.5, nu) / tgamma(nu + 1.0)
+        } else {
+            /* the factor from the Wronskian */
+            let (jnu, j0, j1) = miller(nu, m, x);
+            2.0 / (PI * x) / (j1 * ymu - j0 * y1) * jnu
+        };
+        (j, ymu, y1)
+    } else {
+        let (jnu, j0, j1) = miller(nu, m, x);
+        let (p, q) = steed(mu, x);
+        /* with jv(mu,x) = c*j0, jv'(mu,x) = c*dj, and v = p*j0 - dj,
+         * yv(mu,x) = c*v/q and yv'(mu,x) = c*(q*j0 + p*v/q), and the
+         * Wronskian jv*yv' - jv'*yv = 2/(pi*x) gives c > 0 */
+        let dj = mu / x * j0 - j1;
+        let v = p * j0 - dj;
+        let c = sqrt(2.0 * q / (PI * x) / (q * q * j0 * j0 + v * v));
+        let ymu = c * v / q;
+        let dy = c * (q * j0 + p * v / q);
+        (c * jnu, ymu, mu / x * ymu - dy)
+    };
+
+    /* forward recurrence of yv up to order nu, which may overflow */
+    let d = 2.0 / x;
+    let mut k = 1.0;
+    while k <= m && ymu.is_finite() {
+        let t = (mu + k) * d * y1 - ymu;
+        ymu = y1;
+        y1 = t;
+        if t.is_infinite() {
+            ymu = t;
+        }
+        k += 1.0;
+    }
+    (j, ymu)
+}
+
+/* (jv(nu,x), yv(nu,x)) for x >= 0 */
+#[inline]
+fn jyv(nu: f64, x: f64) -> (f64, f64) {
+    if nu >= 0.0 {
+        return positive(nu, x);
+    }
+    let (j, y) = positive(-nu, x);
+    if nu == floor(nu) {
+        return if is_odd(nu) { (-j, -y) } else { (j, y) };
+    }
+    let (c, s) = (cospi(nu), sinpi(-nu));
+    (mul(c, j) - mul(s, y), mul(s, j) + mul(c, y))
+}
+
+/// Bessel function of the first kind of real order (f64)
+///
+/// Computes `jv(nu, x)`, the solution of
+/// `x^2*y'' + x*y' + (x^2 - nu^2)*y = 0` that is finite at `x = 0` for
+/// `nu >= 0`. It agrees with [`jn`](fn.jn.html) at integer orders. For
+/// `x < 0` it is real only for integer `nu`, and is NaN otherwise.
+/// Orders beyond those of `jn`, `|nu| > 2^31 - 1`, are supported only where
+/// the result underflows or `|x| > 100*|nu|`; it is NaN elsewhere.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn jv(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || nu.is_infinite() || x.is_nan() {
+        return f64::NAN;
+    }
+    if x >= 0.0 {
+        return jyv(nu, x).0;
+    }
+    if nu != floor(nu) {
+        return f64::NAN;
+    }
+    let r = jyv(nu, -x).0;
+    if is_odd(nu) {
+        -r
+    } else {
+        r
+    }
+}
+
+/// Bessel function of the second kind of real order (f64)
+///
+/// Computes `yv(nu, x)` for `x >= 0`, the solution of
+/// `x^2*y'' + x*y' + (x^2 - nu^2)*y = 0` that is independent of
+/// [`jv`](fn.jv.html). It agrees with [`yn`](fn.yn.html) at integer orders.
+/// Orders beyond those of `yn`, `|nu| > 2^31 - 1`, are supported only where
+/// `jv` underflows or `x > 100*|nu|`; it is NaN elsewhere.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn yv(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || nu.is_infinite() || x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    jyv(nu, x).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testutil::close;
+    use super::super::{jn, yn};
+    use super::{jv, yv};
+
+    #[test]
+    fn special_values() {
+        assert!(jv(0.5, f64::NAN).is_nan());
+        assert!(jv(f64::NAN, 1.0).is_nan());
+        assert!(yv(f64::INFINITY, 1.0).is_nan());
+        assert!(jv(0.5, -1.0).is_nan());
+        assert!(yv(2.0, -1.0).is_nan());
+        assert_eq!(jv(0.0, 0.0), 1.0);
+        assert_eq!(jv(2.5, 0.0), 0.0);
+        assert_eq!(jv(-0.5, 0.0), f64::INFINITY);
+        assert_eq!(yv(1.5, 0.0), f64::NEG_INFINITY);
+        assert_eq!(yv(-0.5, 0.0), 0.0);
+        assert_eq!(jv(1.5, f64::INFINITY), 0.0);
+        assert_eq!(yv(1.5, f64::INFINITY), 0.0);
+        assert_eq!(jv(300.0, 1.0), 0.0);
+        assert_eq!(yv(300.0, 1.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn huge_orders() {
+        assert_eq!(jv(1e200, 1.0), 0.0);
+        assert_eq!(yv(1e200, 1.0), f64::NEG_INFINITY);
+        assert_eq!(jv(-1e300, 1.0), 0.0);
+        assert_eq!(yv(-1e300, 2.0), f64::NEG_INFINITY);
+        assert_eq!(jv(f64::MAX, f64::MAX * 0.5), 0.0);
+        assert_eq!(yv(-9007199254740991.0, 1.0), f64::INFINITY);
+        assert_eq!(jv(3e9, 1e9), 0.0);
+        /* orders beyond those of jn near the turning point */
+        assert!(jv(3e9, 2999999000.0).is_nan());
+        assert!(yv(3e9, 1e10).is_nan());
+        /* the squared amplitude, 2/(pi*x)*(1 + (nu/x)^2/2 + ...) */
+        let (j, y) = (jv(1e200, 1e203), yv(1e200, 1e203));
+        let a = (j * j + y * y) * (core::f64::consts::PI * 1e203);
+        assert!(close(a, 2.000001, 1e-11), "{} {}", j, y);
+    }
+
+    #[test]
+    fn integer_orders() {
+        let xs = [1e-5, 0.3, 1.0, 1.9, 2.0, 5.5, 10.0, 37.0, 150.0, 1e4];
+        for n in [0, 1, 2, 3, 7, 20, 80].iter().cloned() {
+            for &x in xs.iter() {
+                let (j, y) = (jn(n, x), yn(n, x));
+                assert!(close(jv(n as f64, x), j, 1e-13), "jv({}, {})", n, x);
+                assert!(close(yv(n as f64, x), y, 1e-13), "yv({}, {})", n, x);
+                let s = if n & 1 == 1 { -1.0 } else { 1.0 };
+                assert!(close(jv(-n as f64, x), s * j, 1e-13));
+                assert!(close(yv(-n as f64, x), s * y, 1e-13));
+                assert!(close(jv(n as f64, -x), s * j, 1e-13));
+            }
+        }
+    }
+
+    #[test]
+    fn values() {
+        /* (nu, x, jv(nu, x), yv(nu, x)) */
+        let cases = [
+            (0.5, 1.0, 0.6713967071418031, -0.4310988680183761),
+            (-0.5, 3.0, -0.45604882079463316, 0.06500818287737578),
+            (1.5, 0.25, 0.03303787085940323, -6.579441695165678),
+            (2.3, 1e-60, 7.567275482354111e-140, -1.828869624362493e+138),
+            (2.3, 7.5, -0.2851322290407061, -0.08681665496817778),
+            (0.3, 30.0, -0.13011079142417548, -0.06549777194112158),
+            (2.3, 150.0, 0.029243460986254965, 0.05821878731353807),
+            (-2.7, 4.0, -0.207794615180708, 0.39954075629772895),
+            (12.25, 9.0, 0.02192928038401284, -1.789801733156445),
+            (40.5, 20.0, 5.066196007077179e-10, -17844038.067349),
+            (100.5, 10000.0, -0.005809772986531448, 0.005469161750678183),
+        ];
+        for &(nu, x, j, y) in cases.iter() {
+            assert!(close(jv(nu, x), j, 1e-14), "jv({}, {})", nu, x);
+            assert!(close(yv(nu, x), y, 1e-14), "yv({}, {})", nu, x);
+        }
+    }
+}
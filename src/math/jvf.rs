@@ -0,0 +1,21 @@
+use super::jv::{jv, yv};
+
+/// Bessel function of the first kind of real order (f32)
+///
+/// Computes `jv(nu, x)` by evaluating [`jv`](fn.jv.html) in double
+/// precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn jvf(nu: f32, x: f32) -> f32 {
+    jv(nu as f64, x as f64) as f32
+}
+
+/// Bessel function of the second kind of real order (f32)
+///
+/// Computes `yv(nu, x)` for `x >= 0` by evaluating [`yv`](fn.yv.html) in
+/// double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn yvf(nu: f32, x: f32) -> f32 {
+    yv(nu as f64, x as f64) as f32
+}
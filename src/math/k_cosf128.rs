@@ -0,0 +1,54 @@
+// origin: FreeBSD /usr/src/lib/msun/ld128/k_cosl.c
+//
+// ====================================================
+// Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+// Copyright (c) 2008 Steven G. Kargl, David Schultz, Bruce D. Evans.
+//
+// Developed at SunSoft, a Sun Microsystems, Inc. business.
+// Permission to use, copy, modify, and distribute this
+// software is freely granted, provided that this notice
+// is preserved.
+// ====================================================
+
+use super::f128;
+
+// Coefficients of the Taylor series of cos(x) from x^4 on; on [-pi/4, pi/4]
+// the terms after x^30 stay below 2^-120.
+const C: [f128; 14] = [
+    f128::from_bits(0x3ffa5555555555555555555555555555), /* 1/4! */
+    f128::from_bits(0xbff56c16c16c16c16c16c16c16c16c17), /* -1/6! */
+    f128::from_bits(0x3fefa01a01a01a01a01a01a01a01a01a), /* 1/8! */
+    f128::from_bits(0xbfe927e4fb7789f5c72ef016d3ea6679), /* -1/10! */
+    f128::from_bits(0x3fe21eed8eff8d897b544da987acfe85), /* 1/12! */
+    f128::from_bits(0xbfda93974a8c07c9d20badf145dfa3e5), /* -1/14! */
+    f128::from_bits(0x3fd2ae7f3e733b81f11d8656b0ee8cb0), /* 1/16! */
+    f128::from_bits(0xbfca6827863b97d977bb004886a2c2ab), /* -1/18! */
+    f128::from_bits(0x3fc1e542ba402022507a9cad2bf8f0bb), /* 1/20! */
+    f128::from_bits(0xbfb90ce396db7f8529450c90b7f338ec), /* -1/22! */
+    f128::from_bits(0x3faff2cf01972f577cca4b4067ca9d8a), /* 1/24! */
+    f128::from_bits(0xbfa688e85fc6a4e59a38f2050ba6b015), /* -1/26! */
+    f128::from_bits(0x3f9d0a18a2635085d373c5c51c354a8d), /* 1/28! */
+    f128::from_bits(0xbf933932c5047d60e60caded4c2989c5), /* -1/30! */
+];
+
+// kernel cos function on [-pi/4, pi/4], pi/4 ~ 0.785398164
+// Input x is assumed to be bounded by ~pi/4 in magnitude.
+// Input y is the tail of x.
+//
+// As in k_cos, with z*r = C1*x^4 + ... + C14*x^30 and
+//              cos(x+y) ~ w + (tmp + (r-x*y)),
+// where w = 1 - x*x/2 and tmp is a tiny correction term.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_cosf128(x: f128, y: f128) -> f128 {
+    let half = f128::from_bits(0x3ffe << 112);
+    let z = x * x;
+    let mut r = C[13];
+    for &c in C[..13].iter().rev() {
+        r = r * z + c;
+    }
+    let r = z * r;
+    let hz = half * z;
+    let w = f128::ONE - hz;
+    w + (((f128::ONE - w) - hz) + (z * r - x * y))
+}
@@ -0,0 +1,47 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/k_cospi.h */
+/*-
+ * Copyright (c) 2017 Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice unmodified, this list of conditions, and the following
+ *    disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ * IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ * OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ * IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ * INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ * NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ * DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ * THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ * (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ * THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use super::{k_cos, with_set_low_word};
+
+const PI_HI: f64 = 3.1415926814079285e+00; /* 0x400921fb, 0x58000000 */
+const PI_LO: f64 = -2.7818135228334233e-08; /* 0xbe5dde97, 0x3dcb3b3a */
+
+// kernel cospi function on [0, 0.25]
+//
+// See k_sinpi for the multiplication by pi.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_cospi(x: f64) -> f64 {
+    let mut hi = with_set_low_word(x, 0);
+    let mut lo = x - hi;
+    lo = lo * (PI_LO + PI_HI) + hi * PI_LO;
+    hi *= PI_HI;
+    let s = hi + lo;
+    lo -= s - hi;
+    hi = s;
+    k_cos(hi, lo)
+}
@@ -0,0 +1,132 @@
+// Double-double kernel of the arctangent
+//
+// Method
+//   For v, or 1/v if v > 1, with c = k/64 the nearest multiple of 1/64
+//   and t = (v - c)/(1 + v*c), |t| <= 2^-7,
+//
+//          atan(v) = atan(c) + atan(t),
+//
+//   where atan(c) is read from a table of double-doubles and atan(t) is
+//   given by its Taylor polynomial of degree 11, whose truncation error is
+//   below 2^-84 relative. v and t are carried as double-doubles and the
+//   higher terms in double precision, which contributes an error below
+//   2^-67 relative to atan(t). For v > 1, atan(v) = pi/2 - atan(1/v).
+
+use super::double_double::{add, div, fast_two_sum, two_prod, two_sum};
+
+const PIO2_HI: f64 = 1.5707963267948966; /* 0x3ff921fb, 0x54442d18 */
+const PIO2_LO: f64 = 6.123233995736766e-17; /* 0x3c91a626, 0x33145c07 */
+const PI_HI: f64 = 3.141592653589793; /* 0x400921fb, 0x54442d18 */
+const PI_LO: f64 = 1.2246467991473532e-16; /* 0x3ca1a626, 0x33145c07 */
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+static T: [u64; 130] = [
+    //  atan(k/64) as hi, lo
+    0x0000000000000000, 0x0000000000000000,
+    0x3f8fff555bbb729b, 0xbc2220c39d4dff50,
+    0x3f9ffd55bba97625, 0xbc35ec431444912c,
+    0x3fa7fb818430da2a, 0xbc086ef8f794f105,
+    0x3faff55bb72cfdea, 0xbc3c934d86d23f1d,
+    0x3fb3f59f0e7c559d, 0x3c5ac4ce285df847,
+    0x3fb7ee182602f10f, 0xbc5cfb654c0c3d98,
+    0x3fbbe39ebe6f07c3, 0x3c5f7b8f29a05987,
+    0x3fbfd5ba9aac2f6e, 0xbc4cd37686760c17,
+    0x3fc1e1fafb043727, 0xbc4b485914dacf8c,
+    0x3fc3d6eee8c6626c, 0x3c661a3b0ce9281b,
+    0x3fc5c9811e3ec26a, 0xbc5054ab2c010f3d,
+    0x3fc7b97b4bce5b02, 0x3c5347b0b4f881ca,
+    0x3fc9a6a8e96c8626, 0x3c4cf601e7b4348e,
+    0x3fcb90d7529260a2, 0x3c217b10d2e0e5ab,
+    0x3fcd77d5df205736, 0x3c6c648d1534597e,
+    0x3fcf5b75f92c80dd, 0x3c68ab6e3cf7afbd,
+    0x3fd09dc597d86362, 0x3c762e47390cb865,
+    0x3fd18bf5a30bf178, 0x3c630ca4748b1bf9,
+    0x3fd278372057ef46, 0xbc7077cdd36dfc81,
+    0x3fd362773707ebcc, 0xbc6963a544b672d8,
+    0x3fd44aa436c2af0a, 0xbc75d5e43c55b3ba,
+    0x3fd530ad9951cd4a, 0xbc62566480884082,
+    0x3fd614840309cfe2, 0xbc7a725715711f00,
+    0x3fd6f61941e4def1, 0xbc7c63aae6f6e918,
+    0x3fd7d5604b63b3f7, 0x3c769c885c2b249a,
+    0x3fd8b24d394a1b25, 0x3c7b6d0ba3748fa8,
+    0x3fd98cd5454d6b18, 0x3c79e6c988fd0a77,
+    0x3fda64eec3cc23fd, 0xbc724dec1b50b7ff,
+    0x3fdb3a911da65c6c, 0x3c7ae187b1ca5040,
+    0x3fdc0db4c94ec9f0, 0xbc7cc1ce70934c34,
+    0x3fdcde53432c1351, 0xbc7a2cfa4418f1ad,
+    0x3fddac670561bb4f, 0x3c7a2b7f222f65e2,
+    0x3fde77eb7f175a34, 0x3c70e53dc1bf3435,
+    0x3fdf40dd0b541418, 0xbc6a3992dc382a23,
+    0x3fe0039c73c1a40c, 0xbc8b32c949c9d593,
+    0x3fe0657e94db30d0, 0xbc7d5b495f6349e6,
+    0x3fe0c6145b5b43da, 0x3c5974fa13b5404f,
+    0x3fe1255d9bfbd2a9, 0xbc52bdaee1c0ee35,
+    0x3fe1835a88be7c13, 0x3c8c621cec00c301,
+    0x3fe1e00babdefeb4, 0xbc5928df287a668f,
+    0x3fe23b71e2cc9e6a, 0x3c6c421c9f38224e,
+    0x3fe2958e59308e31, 0xbc709e73b0c6c087,
+    0x3fe2ee628406cbca, 0x3c8c5d5e9ff0cf8d,
+    0x3fe345f01cce37bb, 0x3c81021137c71102,
+    0x3fe39c391cd4171a, 0xbc82304331d8bf46,
+    0x3fe3f13fb89e96f4, 0x3c7ecf8b492644f0,
+    0x3fe445065b795b56, 0xbc7f76d0163f79c8,
+    0x3fe4978fa3269ee1, 0x3c72419a87f2a458,
+    0x3fe4e8de5bb6ec04, 0x3c84a33dbeb3796c,
+    0x3fe538f57b89061f, 0xbc81bb74abda520c,
+    0x3fe587d81f732fbb, 0xbc75e5c9d8c5a950,
+    0x3fe5d58987169b18, 0x3c60028e4bc5e7ca,
+    0x3fe6220d115d7b8e, 0xbc62b785350ee8c1,
+    0x3fe66d663923e087, 0xbc76ea6febe8bbba,
+    0x3fe6b798920b3d99, 0xbc8a80386188c50e,
+    0x3fe700a7c5784634, 0xbc78c34d25aadef6,
+    0x3fe748978fba8e0f, 0x3c47b2a6165884a1,
+    0x3fe78f6bbd5d315e, 0x3c8406a089803740,
+    0x3fe7d528289fa093, 0x3c8560821e2f3aa9,
+    0x3fe819d0b7158a4d, 0xbc7bf76229d3b917,
+    0x3fe85d69576cc2c5, 0x3c66b66e7fc8b8c3,
+    0x3fe89ff5ff57f1f8, 0xbc855b9a5e177a1b,
+    0x3fe8e17aa99cc05e, 0xbc7ec182ab042f61,
+    0x3fe921fb54442d18, 0x3c81a62633145c07,
+];
+
+/// `atan(v)` as a double-double, for a positive finite double-double `v`,
+/// with a relative error below 2^-64 unless `v` or `1/v` is subnormal.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_cratan(v: (f64, f64)) -> (f64, f64) {
+    let x1p52 = f64::from_bits(0x4338000000000000); // 0x1.8p52 === 1.5 * 2 ^ 52
+
+    let big = v.0 > 1.0;
+    let v = if big { div((1.0, 0.0), v) } else { v };
+    let kf = (v.0 * 64.0 + x1p52) - x1p52;
+    let k = kf as usize;
+    let c = kf * (1.0 / 64.0);
+    /* t = (v - c)/(1 + v*c), where v.0 - c is exact */
+    let (p, pe) = two_prod(v.0, c);
+    let den = add((1.0, 0.0), (p, pe + v.1 * c));
+    let (th, tl) = div(two_sum(v.0 - c, v.1), den);
+
+    let z = th * th;
+    let poly = th
+        * z
+        * (-1.0 / 3.0 + z * (1.0 / 5.0 + z * (-1.0 / 7.0 + z * (1.0 / 9.0 - z * (1.0 / 11.0)))));
+    let atant = fast_two_sum(th, tl + poly);
+    let atanc = (
+        f64::from_bits(i!(T, 2 * k)),
+        f64::from_bits(i!(T, 2 * k + 1)),
+    );
+    let r = add(atanc, atant);
+    if big {
+        add((PIO2_HI, PIO2_LO), (-r.0, -r.1))
+    } else {
+        r
+    }
+}
+
+/// `atan(v)/pi` as a double-double, for `v` as in `k_cratan`, with a
+/// relative error below 2^-63.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_cratanpi(v: (f64, f64)) -> (f64, f64) {
+    div(k_cratan(v), (PI_HI, PI_LO))
+}
@@ -0,0 +1,145 @@
+// Double-double kernel of the correctly rounded exponentials
+//
+// Method
+//   1. Argument reduction: with k = round(x*64/ln2), k = 64*m + j and
+//
+//          x = k*ln2/64 + r,   |r| <= ln2/128,
+//
+//      so that exp(x) = 2^m * 2^(j/64) * exp(r). ln2/64 is split into
+//      three parts, the first of 36 bits, so that k*ln2/64 is subtracted
+//      exactly for |k| < 2^17.
+//
+//   2. exp(r) - 1 = r + r^2/2 + r^3*P(r), with the Taylor polynomial P of
+//      degree 5, whose truncation error is below 2^-86. r and r^2 are
+//      carried as double-doubles and the rest in double precision, which
+//      contributes an error below 2^-76, or 2^-69 relative to r.
+//
+//   3. 2^(j/64) is read from a table of double-doubles.
+
+use super::double_double::{add, fast_two_sum, mul, two_prod, two_sum};
+
+const INVLN2_64: f64 = 92.33248261689366; /* 0x40571547, 0x652b82fe */
+const LN2_64_H: f64 = 0.010830424696223417; /* 0x3f862e42, 0xfefa0000 */
+const LN2_64_M: f64 = 2.572804622327669e-14; /* 0x3d1cf79a, 0xbc9e3b3a */
+const LN2_64_L: f64 = -1.5746795524851787e-30; /* 0xb9bff034, 0x2542fc33 */
+
+const C3: f64 = 1.0 / 6.0;
+const C4: f64 = 1.0 / 24.0;
+const C5: f64 = 1.0 / 120.0;
+const C6: f64 = 1.0 / 720.0;
+const C7: f64 = 1.0 / 5040.0;
+const C8: f64 = 1.0 / 40320.0;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+static T: [u64; 128] = [
+    //  2^(j/64) as hi, lo
+    0x3ff0000000000000, 0x0000000000000000,
+    0x3ff02c9a3e778061, 0xbc719083535b085d,
+    0x3ff059b0d3158574, 0x3c8d73e2a475b465,
+    0x3ff0874518759bc8, 0x3c6186be4bb284ff,
+    0x3ff0b5586cf9890f, 0x3c98a62e4adc610b,
+    0x3ff0e3ec32d3d1a2, 0x3c403a1727c57b53,
+    0x3ff11301d0125b51, 0xbc96c51039449b3a,
+    0x3ff1429aaea92de0, 0xbc932fbf9af1369e,
+    0x3ff172b83c7d517b, 0xbc819041b9d78a76,
+    0x3ff1a35beb6fcb75, 0x3c8e5b4c7b4968e4,
+    0x3ff1d4873168b9aa, 0x3c9e016e00a2643c,
+    0x3ff2063b88628cd6, 0x3c8dc775814a8495,
+    0x3ff2387a6e756238, 0x3c99b07eb6c70573,
+    0x3ff26b4565e27cdd, 0x3c82bd339940e9d9,
+    0x3ff29e9df51fdee1, 0x3c8612e8afad1255,
+    0x3ff2d285a6e4030b, 0x3c90024754db41d5,
+    0x3ff306fe0a31b715, 0x3c86f46ad23182e4,
+    0x3ff33c08b26416ff, 0x3c932721843659a6,
+    0x3ff371a7373aa9cb, 0xbc963aeabf42eae2,
+    0x3ff3a7db34e59ff7, 0xbc75e436d661f5e3,
+    0x3ff3dea64c123422, 0x3c8ada0911f09ebc,
+    0x3ff4160a21f72e2a, 0xbc5ef3691c309278,
+    0x3ff44e086061892d, 0x3c489b7a04ef80d0,
+    0x3ff486a2b5c13cd0, 0x3c73c1a3b69062f0,
+    0x3ff4bfdad5362a27, 0x3c7d4397afec42e2,
+    0x3ff4f9b2769d2ca7, 0xbc94b309d25957e3,
+    0x3ff5342b569d4f82, 0xbc807abe1db13cad,
+    0x3ff56f4736b527da, 0x3c99bb2c011d93ad,
+    0x3ff5ab07dd485429, 0x3c96324c054647ad,
+    0x3ff5e76f15ad2148, 0x3c9ba6f93080e65e,
+    0x3ff6247eb03a5585, 0xbc9383c17e40b497,
+    0x3ff6623882552225, 0xbc9bb60987591c34,
+    0x3ff6a09e667f3bcd, 0xbc9bdd3413b26456,
+    0x3ff6dfb23c651a2f, 0xbc6bbe3a683c88ab,
+    0x3ff71f75e8ec5f74, 0xbc816e4786887a99,
+    0x3ff75feb564267c9, 0xbc90245957316dd3,
+    0x3ff7a11473eb0187, 0xbc841577ee04992f,
+    0x3ff7e2f336cf4e62, 0x3c705d02ba15797e,
+    0x3ff82589994cce13, 0xbc9d4c1dd41532d8,
+    0x3ff868d99b4492ed, 0xbc9fc6f89bd4f6ba,
+    0x3ff8ace5422aa0db, 0x3c96e9f156864b27,
+    0x3ff8f1ae99157736, 0x3c85cc13a2e3976c,
+    0x3ff93737b0cdc5e5, 0xbc675fc781b57ebc,
+    0x3ff97d829fde4e50, 0xbc9d185b7c1b85d1,
+    0x3ff9c49182a3f090, 0x3c7c7c46b071f2be,
+    0x3ffa0c667b5de565, 0xbc9359495d1cd533,
+    0x3ffa5503b23e255d, 0xbc9d2f6edb8d41e1,
+    0x3ffa9e6b5579fdbf, 0x3c90fac90ef7fd31,
+    0x3ffae89f995ad3ad, 0x3c97a1cd345dcc81,
+    0x3ffb33a2b84f15fb, 0xbc62805e3084d708,
+    0x3ffb7f76f2fb5e47, 0xbc75584f7e54ac3b,
+    0x3ffbcc1e904bc1d2, 0x3c823dd07a2d9e84,
+    0x3ffc199bdd85529c, 0x3c811065895048dd,
+    0x3ffc67f12e57d14b, 0x3c92884dff483cad,
+    0x3ffcb720dcef9069, 0x3c7503cbd1e949db,
+    0x3ffd072d4a07897c, 0xbc9cbc3743797a9c,
+    0x3ffd5818dcfba487, 0x3c82ed02d75b3707,
+    0x3ffda9e603db3285, 0x3c9c2300696db532,
+    0x3ffdfc97337b9b5f, 0xbc91a5cd4f184b5c,
+    0x3ffe502ee78b3ff6, 0x3c839e8980a9cc8f,
+    0x3ffea4afa2a490da, 0xbc9e9c23179c2893,
+    0x3ffefa1bee615a27, 0x3c9dc7f486a4b6b0,
+    0x3fff50765b6e4540, 0x3c99d3e12dd8a18b,
+    0x3fffa7c1819e90d8, 0x3c874853f3a5931e,
+];
+
+/// Splits `exp(xh + xl)` into `2^m * t * (1 + p)`, with `t = 2^(j/64)` and
+/// `p = exp(r) - 1` as double-doubles, for `|xh| < 746` and `|xl| <=
+/// ulp(xh)`. The relative error of `t * (1 + p)` is below 2^-75, and that
+/// of `p` below 2^-68.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_crexp(xh: f64, xl: f64) -> (i32, (f64, f64), (f64, f64)) {
+    let x1p52 = f64::from_bits(0x4338000000000000); // 0x1.8p52 === 1.5 * 2 ^ 52
+
+    /* round to nearest without a rounding function */
+    let kf = (xh * INVLN2_64 + x1p52) - x1p52;
+    let k = kf as i32;
+    let j = (k & 63) as usize;
+
+    /* r = x - k*ln2/64, with xh - k*LN2_64_H exact */
+    let (a, b) = two_prod(kf, LN2_64_M);
+    let (s, e) = two_sum(xh - kf * LN2_64_H, -a);
+    let (rh, rl) = fast_two_sum(s, e - b - kf * LN2_64_L + xl);
+
+    /* p = r + r^2/2 + r^3*P(r) */
+    let (q, qe) = two_prod(rh, rh);
+    let poly = q * rh * (C3 + rh * (C4 + rh * (C5 + rh * (C6 + rh * (C7 + rh * C8)))));
+    let (ph, pl) = fast_two_sum(rh, 0.5 * q);
+    let p = fast_two_sum(ph, pl + (rl + (0.5 * qe + rh * rl + poly)));
+
+    let t = (
+        f64::from_bits(i!(T, 2 * j)),
+        f64::from_bits(i!(T, 2 * j + 1)),
+    );
+    (k >> 6, t, p)
+}
+
+/// `exp(x) - 1` as a double-double, for `-40 < x < 709`, with a relative
+/// error below 2^-67.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_crexpm1(x: f64) -> (f64, f64) {
+    let (m, t, p) = k_crexp(x, 0.0);
+    let s = f64::from_bits(((0x3ff + m) as u64) << 52);
+    /* (2^m*t - 1) + 2^m*t*p, with the leading difference formed exactly */
+    let (a, b) = two_sum(s * t.0, -1.0);
+    let tp = mul(t, p);
+    add((a, b + s * t.1), (s * tp.0, s * tp.1))
+}
@@ -0,0 +1,208 @@
+// Double-double kernel of the correctly rounded logarithms
+//
+// Method
+//   1. Argument reduction: x = 2^e * m, where the significand m is
+//      taken close to c = 1 + t/128 for an integer t in [0, 127],
+//      |m/c - 1| <= 2^-8, and with inv the double nearest to 1/c,
+//
+//          log(x) = e*ln2 - log(inv) + log(1 + r),   r = m*inv - 1.
+//
+//      m*inv - 1 is formed exactly as a double-double. For x near 1,
+//      e = t = 0 and inv = 1, so that the result stays accurate
+//      relative to log(x).
+//
+//   2. log(1 + r) = r - r^2/2 + r^3*P(r), with the Taylor polynomial P
+//      of degree 6, whose truncation error is below 2^-75 relative to r.
+//      r and r^2 are carried as double-doubles, the rest in double
+//      precision, which contributes an error below 2^-69 relative to r.
+//
+//   3. -log(inv) is read from a table of double-doubles, and ln2 is split
+//      into a part of 42 bits, whose multiples by e are exact, and a tail.
+
+use super::double_double::{add, fast_two_sum, two_prod};
+
+const LN2_HI: f64 = 6.931471805598903e-01; /* 0x3fe62e42, 0xfefa3800 */
+const LN2_LO: f64 = 5.497923018708371e-14; /* 0x3d2ef357, 0x93c76730 */
+
+const C4: f64 = -1.0 / 4.0;
+const C5: f64 = 1.0 / 5.0;
+const C6: f64 = -1.0 / 6.0;
+const C7: f64 = 1.0 / 7.0;
+const C8: f64 = -1.0 / 8.0;
+const C9: f64 = 1.0 / 9.0;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+static T: [u64; 384] = [
+    //  inv,               -log(inv) as hi, lo
+    0x3ff0000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x3fefc07f01fc07f0, 0x3f7fe02a6b106799, 0xbbce44b7e3711e7f,
+    0x3fef81f81f81f820, 0x3f8fc0a8b0fc03c4, 0xbc183092c5964281,
+    0x3fef44659e4a4271, 0x3f97b91b07d5b126, 0xbc16d80ab38e9430,
+    0x3fef07c1f07c1f08, 0x3f9f829b0e7832f8, 0x3c333e3f04f1ef25,
+    0x3feecc07b301ecc0, 0x3fa39e87b9febd68, 0xbc45bfa937f551b7,
+    0x3fee9131abf0b767, 0x3fa77458f632dcff, 0x3c08d3ca87b92968,
+    0x3fee573ac901e574, 0x3fab42dd711971b9, 0x3c40a34531f67db5,
+    0x3fee1e1e1e1e1e1e, 0x3faf0a30c01162a8, 0x3c485f325c5bbacd,
+    0x3fede5d6e3f8868a, 0x3fb16536eea37ae3, 0x3c52189705cf74ca,
+    0x3fedae6076b981db, 0x3fb341d7961bd1d0, 0xbc53599f227becbb,
+    0x3fed77b654b82c34, 0x3fb51b073f06183c, 0xbc55b61c65e5741a,
+    0x3fed41d41d41d41d, 0x3fb6f0d28ae56b4e, 0xbc420db323097324,
+    0x3fed0cb58f6ec074, 0x3fb8c345d6319b23, 0xbc5294d2f5668495,
+    0x3fecd85689039b0b, 0x3fba926d3a4ad562, 0xbc4d7a16eab1e2ad,
+    0x3feca4b3055ee191, 0x3fbc5e548f5bc743, 0x3c42eb0bf7c0b0d9,
+    0x3fec71c71c71c71c, 0x3fbe27076e2af2ea, 0xbc361578001e015a,
+    0x3fec3f8f01c3f8f0, 0x3fbfec9131dbeabc, 0xbc55746b9981b36c,
+    0x3fec0e070381c0e0, 0x3fc0d77e7cd08e5b, 0x3c69a5dc5e9030ad,
+    0x3febdd2b899406f7, 0x3fc1b72ad52f67a2, 0xbc6fbe7ee5c69946,
+    0x3febacf914c1bad0, 0x3fc29552f81ff521, 0x3c6301771c407dc0,
+    0x3feb7d6c3dda338b, 0x3fc371fc201e8f75, 0x3c1e6cb62af18a02,
+    0x3feb4e81b4e81b4f, 0x3fc44d2b6ccb7d1c, 0x3c47d3d950f87e23,
+    0x3feb2036406c80d9, 0x3fc526e5e3a1b438, 0xbc6546ff8a470d3a,
+    0x3feaf286bca1af28, 0x3fc5ff3070a793d6, 0xbc5bc60efafc6f6c,
+    0x3feac5701ac5701b, 0x3fc6d60fe719d21b, 0x3c6d551d97132e87,
+    0x3fea98ef606a63be, 0x3fc7ab890210d907, 0xbc61072534a57e7d,
+    0x3fea6d01a6d01a6d, 0x3fc87fa06520c911, 0xbc69f7fdbfa08d9a,
+    0x3fea41a41a41a41a, 0x3fc9525a9cf456b6, 0xbc626fb3e2b1d1da,
+    0x3fea16d3f97a4b02, 0x3fca23bc1fe2b561, 0x3c624dc46c1ea664,
+    0x3fe9ec8e951033d9, 0x3fcaf3c94e80bff3, 0x3c6a3398064df33e,
+    0x3fe9c2d14ee4a102, 0x3fcbc286742d8cd4, 0x3c5cfce744870f57,
+    0x3fe999999999999a, 0x3fcc8ff7c79a9a20, 0xbc64f689f8434011,
+    0x3fe970e4f80cb872, 0x3fcd5c216b4fbb94, 0xbc5a37794d03657d,
+    0x3fe948b0fcd6e9e0, 0x3fce27076e2af2e8, 0xbc461578001e015e,
+    0x3fe920fb49d0e229, 0x3fcef0adcbdc5935, 0x3c6e8637950dc20d,
+    0x3fe8f9c18f9c18fa, 0x3fcfb9186d5e3e29, 0x3c6355519b0de535,
+    0x3fe8d3018d3018d3, 0x3fd0402594b4d041, 0xbc608ec217a5022d,
+    0x3fe8acb90f6bf3aa, 0x3fd0a324e27390e2, 0x3c7bdcfde8061c03,
+    0x3fe886e5f0abb04a, 0x3fd1058bf9ae4ad4, 0x3c03f415699663ec,
+    0x3fe8618618618618, 0x3fd1675cababa60f, 0x3c2ce63eab883727,
+    0x3fe83c977ab2bedd, 0x3fd1c898c16999fb, 0x3c79f1a39d500e3c,
+    0x3fe8181818181818, 0x3fd22941fbcf7966, 0xbc5dbd7ac258a2bd,
+    0x3fe7f405fd017f40, 0x3fd2895a13de86a4, 0x3c77ad24c13f040f,
+    0x3fe7d05f417d05f4, 0x3fd2e8e2bae11d31, 0xbc61e99b72bd7bf2,
+    0x3fe7ad2208e0ecc3, 0x3fd347dd9a987d56, 0xbc716ea62c048cfb,
+    0x3fe78a4c8178a4c8, 0x3fd3a64c556945ea, 0x3c3cbcd735d03424,
+    0x3fe767dce434a9b1, 0x3fd404308686a7e4, 0xbc6f79f6c1059cdb,
+    0x3fe745d1745d1746, 0x3fd4618bc21c5ec2, 0xbc27a42642661c62,
+    0x3fe724287f46debc, 0x3fd4be5f957778a1, 0xbc54b366b609027a,
+    0x3fe702e05c0b8170, 0x3fd51aad872df82e, 0xbc7d8db0a7cc1543,
+    0x3fe6e1f76b4337c7, 0x3fd5767717455a6c, 0xbc6fb2a49af933e8,
+    0x3fe6c16c16c16c17, 0x3fd5d1bdbf5809ca, 0xbc77dc9c7c23801f,
+    0x3fe6a13cd1537290, 0x3fd62c82f2b9c796, 0xbc5090a0dd59fe35,
+    0x3fe6816816816817, 0x3fd686c81e9b14ad, 0x3c7710af840538e3,
+    0x3fe661ec6a5122f9, 0x3fd6e08eaa2ba1e4, 0xbc7bfb1b39ca3a0f,
+    0x3fe642c8590b2164, 0x3fd739d7f6bbd007, 0x3c5ce24c53fad3f0,
+    0x3fe623fa77016240, 0x3fd792a55fdd47a1, 0x3c7f057691fe9ed7,
+    0x3fe6058160581606, 0x3fd7eaf83b82afc2, 0xbc4698b43096b576,
+    0x3fe5e75bb8d015e7, 0x3fd842d1da1e8b18, 0x3c754ec519784677,
+    0x3fe5c9882b931057, 0x3fd89a3386c1425b, 0x3c62d38c40881e0b,
+    0x3fe5ac056b015ac0, 0x3fd8f11e873662c8, 0x3c7f85da755a61a3,
+    0x3fe58ed2308158ed, 0x3fd947941c2116fb, 0x3c61266e8a3e8838,
+    0x3fe571ed3c506b3a, 0x3fd99d958117e08a, 0xbc7315b444ee1f38,
+    0x3fe5555555555555, 0x3fd9f323ecbf984d, 0xbc4a92e513217f58,
+    0x3fe5390948f40feb, 0x3fda484090e5bb09, 0x3c7fff29adc3ad3b,
+    0x3fe51d07eae2f815, 0x3fda9cec9a9a084a, 0xbc5ab7b00ad0dabc,
+    0x3fe5015015015015, 0x3fdaf1293247786b, 0x3c5533844a15dc28,
+    0x3fe4e5e0a72f0539, 0x3fdb44f77bcc8f64, 0xbc2a0892a8b38eed,
+    0x3fe4cab88725af6e, 0x3fdb9858969310fd, 0xbc6f3827583b8877,
+    0x3fe4afd6a052bf5b, 0x3fdbeb4d9da71b7a, 0x3c7be1874deaef08,
+    0x3fe49539e3b2d067, 0x3fdc3dd7a7cdad4d, 0x3c67d9e0a5bd4d37,
+    0x3fe47ae147ae147b, 0x3fdc8ff7c79a9a21, 0x3c73097607bcbfee,
+    0x3fe460cbc7f5cf9a, 0x3fdce1af0b85f3ec, 0xbc66416a1aa97b31,
+    0x3fe446f86562d9fb, 0x3fdd32fe7e00ebd5, 0x3c64ef6465f5f46e,
+    0x3fe42d6625d51f87, 0x3fdd83e7258a2f3e, 0x3c5c515ba2ec9444,
+    0x3fe4141414141414, 0x3fddd46a04c1c4a1, 0xbc119d95b62e2476,
+    0x3fe3fb013fb013fb, 0x3fde24881a7c6c26, 0x3c605ec7a2caa523,
+    0x3fe3e22cbce4a902, 0x3fde744261d68789, 0x3c7cdf68dbcf2ed3,
+    0x3fe3c995a47babe7, 0x3fdec399d2468cc1, 0xbc494623581958cf,
+    0x3fe3b13b13b13b14, 0x3fdf128f5faf06ec, 0xbc7328df13bb38c2,
+    0x3fe3991c2c187f63, 0x3fdf6123fa7028ad, 0x3c55456c3cb6cd06,
+    0x3fe3813813813814, 0x3fdfaf588f78f31d, 0x3c6cd7d9f2754362,
+    0x3fe3698df3de0748, 0x3fdffd2e0857f497, 0xbc44d05f9366f27f,
+    0x3fe3521cfb2b78c1, 0x3fe02552a5a5d0ff, 0x3c6e9c695d7ee800,
+    0x3fe33ae45b57bcb2, 0x3fe04bdf9da926d2, 0x3c78fe60804593bf,
+    0x3fe323e34a2b10bf, 0x3fe0723e5c1cdf41, 0xbc46a1a71dbba44e,
+    0x3fe30d190130d190, 0x3fe0986f4f573521, 0xbc737012b5805e02,
+    0x3fe2f684bda12f68, 0x3fe0be72e4252a83, 0x3c7b4c4bdd99efff,
+    0x3fe2e025c04b8097, 0x3fe0e44985d1cc8c, 0xbc4c546885a5a707,
+    0x3fe2c9fb4d812ca0, 0x3fe109f39e2d4c96, 0x3c8f78fb26c2de46,
+    0x3fe2b404ad012b40, 0x3fe12f719593efbd, 0xbc767f6e731c1795,
+    0x3fe29e4129e4129e, 0x3fe154c3d2f4d5ea, 0x3c698f33a3965e29,
+    0x3fe288b01288b013, 0x3fe179eabbd899a0, 0xbc5c73e320bf059f,
+    0x3fe27350b8812735, 0x3fe19ee6b467c96f, 0xbc6fa3422887e218,
+    0x3fe25e22708092f1, 0x3fe1c3b81f713c25, 0xbc70b583899021d1,
+    0x3fe2492492492492, 0x3fe1e85f5e7040d1, 0xbc8084e99683070e,
+    0x3fe23456789abcdf, 0x3fe20cdcd192ab6e, 0xbc8aabf0bc229014,
+    0x3fe21fb78121fb78, 0x3fe23130d7bebf43, 0xbc8748725e374d6e,
+    0x3fe20b470c67c0d9, 0x3fe2555bce98f7ca, 0x3c89810eb6b440f4,
+    0x3fe1f7047dc11f70, 0x3fe2795e1289b11b, 0x3c8ade0fcf6e5a1d,
+    0x3fe1e2ef3b3fb874, 0x3fe29d37fec2b08b, 0x3c801735b2e9733f,
+    0x3fe1cf06ada2811d, 0x3fe2c0e9ed448e8c, 0xbc88a158f3917586,
+    0x3fe1bb4a4046ed29, 0x3fe2e47436e40268, 0x3c80950861a4886b,
+    0x3fe1a7b9611a7b96, 0x3fe307d7334f10be, 0x3c7fdac850fab36d,
+    0x3fe19453808ca29c, 0x3fe32b1339121d71, 0x3c7d02ab5b3d916b,
+    0x3fe1811811811812, 0x3fe34e289d9ce1d2, 0x3c7775c96c42e729,
+    0x3fe16e0689427379, 0x3fe37117b54747b6, 0xbc8808bf6deec882,
+    0x3fe15b1e5f75270d, 0x3fe393e0d3562a1a, 0xbc838eef67f2483a,
+    0x3fe1485f0e0acd3b, 0x3fe3b68449fffc23, 0x3c8c63b7b06164da,
+    0x3fe135c81135c811, 0x3fe3d9026a7156fb, 0x3c50084c7a15a4f5,
+    0x3fe12358e75d3033, 0x3fe3fb5b84d16f43, 0x3c70a74ea82e55df,
+    0x3fe1111111111111, 0x3fe41d8fe84672af, 0xbc8ee6d0cf42e7fa,
+    0x3fe0fef010fef011, 0x3fe43f9fe2f9ce67, 0x3c8e1c9ee6d83b86,
+    0x3fe0ecf56be69c90, 0x3fe4618bc21c5ec2, 0x3c7e85bd9bd99e3a,
+    0x3fe0db20a88f4696, 0x3fe48353d1ea88df, 0xbc840a85d133f80b,
+    0x3fe0c9714fbcda3b, 0x3fe4a4f85db03ebb, 0xbc8d76102e1644f2,
+    0x3fe0b7e6ec259dc8, 0x3fe4c679afccee39, 0xbc6e971322ce7900,
+    0x3fe0a6810a6810a7, 0x3fe4e7d811b75bb0, 0xbc85d3d9ea6e9ea8,
+    0x3fe0953f39010954, 0x3fe50913cc01686b, 0x3c79e59d2d85ab62,
+    0x3fe0842108421084, 0x3fe52a2d265bc5ab, 0x3c773be4578ad97b,
+    0x3fe073260a47f7c6, 0x3fe54b2467999498, 0x3c8f4550a2d0f60c,
+    0x3fe0624dd2f1a9fc, 0x3fe56bf9d5b3f399, 0x3c611c6217363fcb,
+    0x3fe05197f7d73404, 0x3fe58cadb5cd7989, 0x3c8624bc9764c22c,
+    0x3fe0410410410410, 0x3fe5ad404c359f2d, 0x3c8eca6aa97c08e7,
+    0x3fe03091b51f5e1a, 0x3fe5cdb1dc6c1765, 0x3c747b71e2eb8419,
+    0x3fe0204081020408, 0x3fe5ee02a9241676, 0xbc8bca7da80b6f7e,
+    0x3fe0101010101010, 0x3fe60e32f44788d9, 0xbc658376a5f4b135,
+];
+
+/// `log(x)` as a double-double, for positive finite `x`, with a relative
+/// error below 2^-68.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_crlog(x: f64) -> (f64, f64) {
+    let x1p54 = f64::from_bits(0x4350000000000000); // 0x1p54 === 2 ^ 54
+
+    let mut ix = x.to_bits();
+    let mut e = 0;
+    if ix < 0x0010000000000000 {
+        /* subnormal, scale up */
+        ix = (x * x1p54).to_bits();
+        e = -54;
+    }
+    /* the significand rounded to 7 bits selects c = 1 + t/128 */
+    let tmp = ix + 0x0000100000000000;
+    let t = (tmp >> 45 & 0x7f) as usize;
+    let k = (tmp >> 52) as i64 - 1023;
+    e += k as i32;
+    let m = f64::from_bits(ix.wrapping_sub((k << 52) as u64));
+    let inv = f64::from_bits(i!(T, 3 * t));
+    let l = (
+        f64::from_bits(i!(T, 3 * t + 1)),
+        f64::from_bits(i!(T, 3 * t + 2)),
+    );
+
+    /* r = m*inv - 1, exactly */
+    let (p, pe) = two_prod(m, inv);
+    let (rh, rl) = fast_two_sum(p - 1.0, pe);
+
+    /* log(1 + r) = r - r^2/2 + r^3*P(r) */
+    let (q, qe) = two_prod(rh, rh);
+    let poly = q
+        * rh
+        * ((1.0 / 3.0) + rh * (C4 + rh * (C5 + rh * (C6 + rh * (C7 + rh * (C8 + rh * C9))))));
+    let (h, lo) = fast_two_sum(rh, -0.5 * q);
+    let log1p = fast_two_sum(h, lo + (rl + (poly - (0.5 * qe + rh * rl))));
+
+    let ef = e as f64;
+    let eln2 = fast_two_sum(ef * LN2_HI, ef * LN2_LO);
+    add(add(eln2, l), log1p)
+}
@@ -0,0 +1,120 @@
+// Double-double kernel of the correctly rounded sine and cosine
+//
+// Method
+//   For |r| <= pi/4, with a = k/64 the nearest multiple of 1/64 and
+//   b = r - a, |b| <= 1/128,
+//
+//          sin(r) = sin(a)*cos(b) + cos(a)*sin(b),
+//          cos(r) = cos(a)*cos(b) - sin(a)*sin(b),
+//
+//   where sin(a) and cos(a) are read from a table of double-doubles and
+//   sin(b), cos(b) are given by their Taylor polynomials of degree 9 and
+//   8, whose truncation errors are below 2^-90. b and b^2 are carried as
+//   double-doubles and the higher terms in double precision, which
+//   contributes an error below 2^-68 relative to sin(b) and 2^-84 to
+//   cos(b).
+
+use super::double_double::{add, fast_two_sum, mul, two_prod};
+
+const S3: f64 = -1.0 / 6.0;
+const S5: f64 = 1.0 / 120.0;
+const S7: f64 = -1.0 / 5040.0;
+const S9: f64 = 1.0 / 362880.0;
+const C4: f64 = 1.0 / 24.0;
+const C6: f64 = -1.0 / 720.0;
+const C8: f64 = 1.0 / 40320.0;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+static T: [u64; 204] = [
+    //  sin(k/64) as hi, lo,               cos(k/64) as hi, lo
+    0x0000000000000000, 0x0000000000000000, 0x3ff0000000000000, 0x0000000000000000,
+    0x3f8fffaaaaeeeed5, 0xbc02ab639a9f0776, 0x3fefff000155549f, 0x3c828a28a03a5ef3,
+    0x3f9ffeaaaeeee86f, 0xbc3cd406fb224ae2, 0x3feffc00155527d3, 0xbc83b54492d89b5b,
+    0x3fa7fdc01032fba9, 0xbc4599bdf46e997a, 0x3feff7006bfdf99f, 0xbc78b3b560648d5f,
+    0x3faffaaaeeed4edb, 0xbc42d16d32684b69, 0x3feff0015549f4d3, 0x3c8328387b99426f,
+    0x3fb3facb12d1755b, 0xbc5921915299468b, 0x3fefe7034129ef6f, 0xbc6cbf4337c96f97,
+    0x3fb7f701032550e4, 0x3c3afc2d1800501a, 0x3fefdc06bf7e6b9b, 0x3c831902b535f8db,
+    0x3fbbf1b78568391d, 0x3c5e91841dea4cc8, 0x3fefcf0c800e99b1, 0x3c6ea3d786d186ac,
+    0x3fbfeaaeee86ee36, 0xbc4afcb2bcc6f03b, 0x3fefc015527d5bd3, 0x3c8b68f35094efb8,
+    0x3fc1f0d3d7afceaf, 0xbc66ef95099769a5, 0x3fefaf22263c4bd3, 0xbc552ace133a2769,
+    0x3fc3eb312c5d66cb, 0x3c647d666b66cb91, 0x3fef9c340a7cc428, 0x3c8c5b6b063b7462,
+    0x3fc5e44fcfa126f3, 0xbc66f443063f89b6, 0x3fef874c2e1eecf6, 0xbc8c6514e1332b16,
+    0x3fc7dc102fbaf2b5, 0x3c45ab50e23c97c3, 0x3fef706bdf9ece1c, 0xbc8698c80c36dcb4,
+    0x3fc9d252d0cec312, 0x3c59c43d80b1137d, 0x3fef57948cff6797, 0x3c6e3a0d3e03b1d4,
+    0x3fcbc6f84edc6199, 0x3c69c1a56a7b0cab, 0x3fef3cc7c3b3d16e, 0xbc621a3ad28a3494,
+    0x3fcdb9e15fb5a5d0, 0xbc632e20d6cc6fc2, 0x3fef20073086649f, 0x3c7b940416c1984b,
+    0x3fcfaaeed4f31577, 0xbc615d88508e32b8, 0x3fef01549f7deea1, 0x3c8d3c1e99e5cafd,
+    0x3fd0cd00cef36436, 0xbc79fb0a0c93e2b4, 0x3feee0b1fbc0f11c, 0xbc4bfd2380bbc3b1,
+    0x3fd1c37d64c6b876, 0x3c746076fe0dcff4, 0x3feebe214f76efa8, 0xbc802f9f12ba543e,
+    0x3fd2b8ddc43eb49f, 0x3c61553899f2d807, 0x3fee99a4c3a7cd83, 0xbc82264b1bc53ce8,
+    0x3fd3ad129769d3d8, 0x3c003d550487839a, 0x3fee733ea0193d40, 0xbc86428b3546ce13,
+    0x3fd4a00c9b0f3d20, 0x3c7823ba6bb08ead, 0x3fee4af14b2a449c, 0xbc868ca02e8a6833,
+    0x3fd591bc9fa2f597, 0x3c67c74bac3fe0cb, 0x3fee20bf49acd6c1, 0xbc5660aec7ef636b,
+    0x3fd682138a38d7f7, 0xbc7d889202444aad, 0x3fedf4ab3ebd875e, 0xbc8e2d8a7e6736c4,
+    0x3fd7710255764214, 0xbc66ead7314bb6ce, 0x3fedc6b7eb995912, 0x3c54b364776dcd35,
+    0x3fd85e7a12826949, 0x3c78a40e9b5face0, 0x3fed96e82f71a9dc, 0x3c8ff61bd5d2039d,
+    0x3fd94a6be9f546c5, 0xbc769ce13e683f58, 0x3fed653f073e4040, 0xbc876236434bec37,
+    0x3fda34c91cc50cca, 0xbc5a310e3b50cecd, 0x3fed31bf8d8d7c06, 0x3c7e60dd3089cbdd,
+    0x3fdb1d8305321617, 0xbc7ae242cb99f519, 0x3fecfc6cfa52ad9f, 0x3c88b5b5508f2a0d,
+    0x3fdc048b17b140a3, 0x3c619fe6757e9fa7, 0x3fecc54aa2b2972e, 0x3c64ee162ba83a98,
+    0x3fdce9d2e3d4a51f, 0xbc62fc8a12dae298, 0x3fec8c5bf8ce1a84, 0x3c7ab3d1a1590123,
+    0x3fddcd4c15329c9a, 0x3c70d4c6e171fd9a, 0x3fec51a48b8b175e, 0xbc61bbb43b9aa880,
+    0x3fdeaee8744b05f0, 0xbc5789b43c9b027d, 0x3fec1528065b7d50, 0xbc8892111312e828,
+    0x3fdf8e99e76abc97, 0x3c59d950af2d00a3, 0x3febd6ea310294f5, 0x3c731bbcc88c109d,
+    0x3fe0362939c69955, 0xbc82d8cd78397b01, 0x3feb96eeef58840e, 0x3c545a3cc78fade0,
+    0x3fe0a4021e9e1001, 0xbc86f643a13914f6, 0x3feb553a410c104e, 0x3c58ff7947027a15,
+    0x3fe110d0c4b69c3b, 0x3c8d918998809981, 0x3feb11d04162a4c6, 0x3c71dd561efbc0c2,
+    0x3fe17c8e5f2eedb0, 0x3c635e57102e2488, 0x3feaccb526f69de5, 0x3c88fb6a8dd6b6cc,
+    0x3fe1e7343236574c, 0x3c722a3fa4f41d5a, 0x3fea85ed4373e02d, 0x3c69be06385ec792,
+    0x3fe250bb93788bbb, 0x3c7ea3d02457bcce, 0x3fea3d7d0352bdcf, 0xbc868dbaeca19669,
+    0x3fe2b91dea88421e, 0xbc8fa371db216ab0, 0x3fe9f368ed912f85, 0xbc81d200c5791606,
+    0x3fe32054b148bc4f, 0x3c8f6b42095a135b, 0x3fe9a7b5a36a6514, 0x3c8722cfcc9fa7a9,
+    0x3fe386597456282b, 0xbc710fada93b07a8, 0x3fe95a67e00cb1fd, 0xbc80befda21f862d,
+    0x3fe3eb25d36cd53a, 0xbc5be570e1570fc0, 0x3fe90b84784ddaf7, 0xbc70feb10ab93b87,
+    0x3fe44eb381cf386b, 0xbc83ed6c1e6a5505, 0x3fe8bb105a5dc900, 0x3c8863e03e9474c1,
+    0x3fe4b0fc46aab761, 0x3c20da05738cc59c, 0x3fe869108d77a6c6, 0x3c7338ffe2bfe9dd,
+    0x3fe511f9fd7b351c, 0xbc85c0e861c48831, 0x3fe8158a31916d5d, 0xbc6de8b90b8228de,
+    0x3fe571a6966d59b3, 0x3c5c843b4d0fb197, 0x3fe7c0827f09e54f, 0xbc6c73d6d72aee68,
+    0x3fe5cffc16bf8f0d, 0x3c896cb370eb578a, 0x3fe769fec655211f, 0xbc6827d5cf8c68c5,
+    0x3fe62cf49921ac79, 0xbc8edd9855b6241a, 0x3fe712046fa77678, 0x3c8425b0a5029c81,
+    0x3fe6888a4e134b2f, 0xbc86b7d37644d5e6, 0x3fe6b898fa9efb5d, 0x3c715ac786ccf4b2,
+];
+
+/// `sin(r)` and `cos(r)` as double-doubles, for `r = rh + rl` with `|r| <=
+/// pi/4`, with relative errors below 2^-67.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_crsincos(rh: f64, rl: f64) -> ((f64, f64), (f64, f64)) {
+    let x1p52 = f64::from_bits(0x4338000000000000); // 0x1.8p52 === 1.5 * 2 ^ 52
+
+    let sign = rh.is_sign_negative();
+    let (rh, rl) = if sign { (-rh, -rl) } else { (rh, rl) };
+    let kf = (rh * 64.0 + x1p52) - x1p52;
+    let k = kf as usize;
+    let (bh, bl) = fast_two_sum(rh - kf * (1.0 / 64.0), rl);
+
+    let (q, qe) = two_prod(bh, bh);
+    let z = q + 2.0 * bh * bl;
+    let ps = q * bh * (S3 + z * (S5 + z * (S7 + z * S9)));
+    let sinb = fast_two_sum(bh, bl + ps);
+    let pc = q * q * (C4 + z * (C6 + z * C8));
+    let (ch, cl) = fast_two_sum(1.0, -0.5 * q);
+    let cosb = fast_two_sum(ch, cl + (pc - (0.5 * qe + bh * bl)));
+
+    let sina = (
+        f64::from_bits(i!(T, 4 * k)),
+        f64::from_bits(i!(T, 4 * k + 1)),
+    );
+    let cosa = (
+        f64::from_bits(i!(T, 4 * k + 2)),
+        f64::from_bits(i!(T, 4 * k + 3)),
+    );
+    let (sh, sl) = add(mul(sina, cosb), mul(cosa, sinb));
+    let (ph, pl) = mul(sina, sinb);
+    let cos = add(mul(cosa, cosb), (-ph, -pl));
+    if sign {
+        ((-sh, -sl), cos)
+    } else {
+        ((sh, sl), cos)
+    }
+}
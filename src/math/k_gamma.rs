@@ -0,0 +1,177 @@
+// Kernels shared by the incomplete gamma and beta functions
+
+use super::{exp, lgamma_r, log, log1p, pow, sqrt, tgamma};
+
+const EULER: f64 = 5.7721566490153286e-01; /* Euler's constant */
+const INV_SQRT_2PI: f64 = 3.9894228040143268e-01; /* 1/sqrt(2*pi) */
+
+/* zeta(2), zeta(3), ..., zeta(25) */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const ZETA: [f64; 24] = [
+    1.6449340668482264, 1.2020569031595942, 1.0823232337111381, 1.03692775514337,
+    1.0173430619844492, 1.008349277381923, 1.0040773561979444, 1.0020083928260821,
+    1.000994575127818, 1.0004941886041194, 1.000246086553308, 1.0001227133475785,
+    1.0000612481350588, 1.000030588236307, 1.0000152822594086, 1.0000076371976379,
+    1.000003817293265, 1.0000019082127165, 1.0000009539620338, 1.0000004769329869,
+    1.0000002384505027, 1.000000119219926, 1.000000059608189, 1.0000000298035034,
+];
+
+/* B_2k/(2k*(2k - 1)), the coefficients of Stirling's series */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const STIRLING: [f64; 7] = [
+    8.3333333333333333e-02,  /* 1/12 */
+    -2.7777777777777778e-03, /* -1/360 */
+    7.9365079365079365e-04,  /* 1/1260 */
+    -5.9523809523809524e-04, /* -1/1680 */
+    8.4175084175084175e-04,  /* 1/1188 */
+    -1.9175269175269175e-03, /* -691/360360 */
+    6.4102564102564103e-03,  /* 1/156 */
+];
+
+/// `log(1 + x) - x`, without the cancellation of the difference for small
+/// `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn log1pmx(x: f64) -> f64 {
+    if x.abs() >= 0.5 {
+        return log1p(x) - x;
+    }
+    /* log(1 + x) = 2*atanh(s) = 2*(s + s^3/3 + s^5/5 + ...) with
+     * s = x/(2 + x), |s| <= 1/3, and 2*s - x = -s*x */
+    let s = x / (2.0 + x);
+    let z = s * s;
+    let mut p = 0.0;
+    for k in (1..=20).rev() {
+        p = p * z + 1.0 / (2 * k + 1) as f64;
+    }
+    -s * x + 2.0 * s * z * p
+}
+
+/// `log(gamma(1 + a))` for `a >= 0`, accurate relative to the result also
+/// near its zero at `a = 0`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn lgamma1p(a: f64) -> f64 {
+    if a >= 0.2 {
+        return lgamma_r(1.0 + a).0;
+    }
+    /* -euler*a + sum of zeta(n)*(-a)^n/n */
+    let mut p = 0.0;
+    for n in (2..=25).rev() {
+        p = p * -a + ZETA[n - 2] / n as f64;
+    }
+    a * (a * p - EULER)
+}
+
+/// The remainder `log(gamma(a)) - ((a - 1/2)*log(a) - a + log(2*pi)/2)` of
+/// Stirling's formula, for `a >= 10`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn stirling_rem(a: f64) -> f64 {
+    let z = 1.0 / (a * a);
+    let mut p = 0.0;
+    for &c in STIRLING.iter().rev() {
+        p = p * z + c;
+    }
+    p / a
+}
+
+/// `x^a*exp(-x)/gamma(a)` for `a > 0` and finite `x >= 0`, the factor
+/// common to the series and continued fractions of the incomplete gamma
+/// functions.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn gamma_prefix(a: f64, x: f64) -> f64 {
+    /* the direct product is accurate to a few ulps unless one of its
+     * factors overflows or underflows */
+    if a < 170.0 && x < 700.0 {
+        let p = pow(x, a);
+        let g = tgamma(a);
+        if p >= f64::MIN_POSITIVE && p < 1e300 && g.is_finite() {
+            return p / g * exp(-x);
+        }
+    }
+    if a < 10.0 {
+        return exp(a * log(x) - x - lgamma_r(a).0);
+    }
+    /* with Stirling's formula, sqrt(a/(2*pi))*(x/a)^a*exp(a - x - rem(a))
+     * = sqrt(a/(2*pi))*exp(a*log1pmx(t) - rem(a)) with x = a*(1 + t),
+     * where x - a is exact for x near a */
+    let e = if x < 0.5 * a {
+        a * log(x / a) + (a - x)
+    } else {
+        a * log1pmx((x - a) / a)
+    };
+    sqrt(a) * INV_SQRT_2PI * exp(e - stirling_rem(a))
+}
+
+/// `log(gamma(x + n)/gamma(x))`, the logarithm of the rising factorial, for
+/// `x > 0` and `x + n > 0`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn lpochhammer(x: f64, n: f64) -> f64 {
+    /* shift x and x + n up to 10 with gamma(x + n)/gamma(x) =
+     * gamma(x + 1 + n)/gamma(x + 1)*x/(x + n), rather than take the
+     * difference of two log-gammas that cancels where they are large */
+    let mut x = x;
+    let mut s = 0.0;
+    while x < 10.0 || x + n < 10.0 {
+        s += log1p(n / x);
+        x += 1.0;
+    }
+    /* with Stirling's formula for both, where the difference of the
+     * (x - 1/2)*log(x) terms is taken as log1p(n/x) without cancellation */
+    let t = n / x;
+    n * log(x) + x * log1pmx(t) + (n - 0.5) * log1p(t) + stirling_rem(x + n) - stirling_rem(x) - s
+}
+
+/// `gamma(x + n)/gamma(x)`, the rising factorial, for `x > 0` and
+/// `x + n > 0`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn pochhammer(x: f64, n: f64) -> f64 {
+    if x < 10.0 || x + n < 10.0 {
+        if x + n < 170.0 {
+            let g = tgamma(x);
+            if g.is_finite() {
+                return tgamma(x + n) / g;
+            }
+        }
+        return exp(lpochhammer(x, n));
+    }
+    /* x^n*exp(s), where s is small unless n is large compared to x */
+    let t = n / x;
+    let s = x * log1pmx(t) + (n - 0.5) * log1p(t) + stirling_rem(x + n) - stirling_rem(x);
+    let p = pow(x, n);
+    if p >= f64::MIN_POSITIVE && p.is_finite() {
+        return p * exp(s);
+    }
+    exp(n * log(x) + s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testutil::close;
+    use super::{gamma_prefix, lgamma1p, log1pmx, lpochhammer, pochhammer, stirling_rem};
+
+    #[test]
+    fn kernels() {
+        assert!(close(log1pmx(1e-5), -4.999966666916665e-11, 1e-15));
+        assert!(close(log1pmx(-0.4), -0.1108256237659907, 1e-15));
+        assert!(close(log1pmx(3.0), -1.6137056388801094, 1e-15));
+        assert!(close(lgamma1p(1e-10), -5.7721566481928616e-11, 1e-15));
+        assert!(close(lgamma1p(0.1), -0.04987244125983973, 1e-15));
+        assert!(close(stirling_rem(10.0), 0.00833056343336287, 1e-14));
+        assert!(close(gamma_prefix(3.0, 2.0), 0.5413411329464508, 1e-15));
+        assert!(close(gamma_prefix(100.0, 90.0), 2.33208254251418, 1e-13));
+        assert!(close(pochhammer(3.0, 2.5), 26.17138889227676, 1e-15));
+        assert!(close(pochhammer(20.0, 0.5), 4.444275161239915, 1e-15));
+        assert!(close(pochhammer(1e10, 0.5), 99999.99999875, 1e-15));
+        assert!(close(
+            pochhammer(50.0, -20.5),
+            2.6875904354355853e-33,
+            1e-14
+        ));
+        assert!(close(lpochhammer(0.5, 200.0), 860.0098385668569, 1e-14));
+    }
+}
@@ -0,0 +1,17 @@
+use super::fenv::invalid;
+
+/* y, an integral value, converted to i64. If y is NaN, infinite or does
+ * not fit in an i64, the invalid exception is raised and i64::MIN is
+ * returned, as C does on x86, where the conversion instruction gives that
+ * value; fromfp is the way to detect these cases. C's long is taken to be
+ * 64 bits wide, so the l and ll functions are the same. */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_lrint(y: f64) -> i64 {
+    // -0x1p63 <= y < 0x1p63
+    if y >= -9223372036854775808.0 && y < 9223372036854775808.0 {
+        y as i64
+    } else {
+        invalid(i64::min_value())
+    }
+}
@@ -0,0 +1,58 @@
+// origin: FreeBSD /usr/src/lib/msun/ld128/k_sinl.c
+//
+// ====================================================
+// Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+// Copyright (c) 2008 Steven G. Kargl, David Schultz, Bruce D. Evans.
+//
+// Developed at SunSoft, a Sun Microsystems, Inc. business.
+// Permission to use, copy, modify, and distribute this
+// software is freely granted, provided that this notice
+// is preserved.
+// ====================================================
+
+use super::f128;
+
+// Coefficients of the Taylor series of sin(x); on [-pi/4, pi/4] the terms
+// after x^31 stay below 2^-120 relative to sin(x).
+const S: [f128; 15] = [
+    f128::from_bits(0xbffc5555555555555555555555555555), /* -1/3! */
+    f128::from_bits(0x3ff81111111111111111111111111111), /* 1/5! */
+    f128::from_bits(0xbff2a01a01a01a01a01a01a01a01a01a), /* -1/7! */
+    f128::from_bits(0x3fec71de3a556c7338faac1c88e50017), /* 1/9! */
+    f128::from_bits(0xbfe5ae64567f544e38fe747e4b837dc7), /* -1/11! */
+    f128::from_bits(0x3fde6124613a86d097ca38331d23af68), /* 1/13! */
+    f128::from_bits(0xbfd6ae7f3e733b81f11d8656b0ee8cb0), /* -1/15! */
+    f128::from_bits(0x3fce952c77030ad4a6b2605197771b00), /* 1/17! */
+    f128::from_bits(0xbfc62f49b4681415724ca1ec3b7b9675), /* -1/19! */
+    f128::from_bits(0x3fbd71b8ef6dcf5718bef146fcee6e45), /* 1/21! */
+    f128::from_bits(0xbfb4761b413163819d97b8704dd7f628), /* -1/23! */
+    f128::from_bits(0x3fab3f3ccdd165fa8d4e44a419776f11), /* 1/25! */
+    f128::from_bits(0xbfa1d1ab1c2dccea320a9a18f15d4277), /* -1/27! */
+    f128::from_bits(0x3f98259f98b4358ad7abe30e7766f129), /* 1/29! */
+    f128::from_bits(0xbf8e434d2e783f5bc42e1ee46fa6bfc4), /* -1/31! */
+];
+
+// kernel sin function on ~[-pi/4, pi/4] (except on -0), pi/4 ~ 0.7854
+// Input x is assumed to be bounded by ~pi/4 in magnitude.
+// Input y is the tail of x.
+// Input iy indicates whether y is 0. (if iy=0, y assume to be 0).
+//
+// As in k_sin, with
+//              r = S2+x*x*(S3+...+x*x*S15)
+//              sin(x) = x + (S1*x^3 + (x*x*(x^3*r-y/2)+y))
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_sinf128(x: f128, y: f128, iy: i32) -> f128 {
+    let half = f128::from_bits(0x3ffe << 112);
+    let z = x * x;
+    let v = z * x;
+    let mut r = S[14];
+    for &c in S[1..14].iter().rev() {
+        r = r * z + c;
+    }
+    if iy == 0 {
+        x + v * (S[0] + z * r)
+    } else {
+        x - ((z * (half * y - v * r) - y) - v * S[0])
+    }
+}
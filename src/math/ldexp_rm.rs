@@ -0,0 +1,48 @@
+use super::soft_float::Soft;
+use super::RoundingMode;
+
+/// Multiply by integral power of two with explicit rounding (f64)
+///
+/// Returns `x * 2^n`, which is exact unless the result is subnormal or
+/// overflows, correctly rounded in the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ldexp_rm(x: f64, n: i32, rm: RoundingMode) -> f64 {
+    Soft::from_f64(x).scale(n).to_f64_rm(rm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::ldexpf_rm;
+    use super::ldexp_rm;
+    use super::RoundingMode::*;
+    use core::f64;
+
+    #[test]
+    fn subnormal_results() {
+        /* 1.5*2^-1074 and 1.25*2^-1074 */
+        assert_eq!(ldexp_rm(1.5, -1074, TowardZero).to_bits(), 1);
+        assert_eq!(ldexp_rm(1.5, -1074, TowardPositive).to_bits(), 2);
+        assert_eq!(ldexp_rm(1.5, -1074, TiesToEven).to_bits(), 2);
+        assert_eq!(ldexp_rm(1.25, -1074, TiesToEven).to_bits(), 1);
+        assert_eq!(ldexp_rm(1.25, -1074, TowardPositive).to_bits(), 2);
+        assert_eq!(ldexp_rm(-1.5, -1074, TowardPositive).to_bits(), 1 << 63 | 1);
+        assert_eq!(ldexp_rm(1.0, -1075, TiesToEven).to_bits(), 0);
+        assert_eq!(ldexp_rm(1.0, -1075, TiesToAway).to_bits(), 1);
+        assert_eq!(ldexp_rm(1.0, i32::min_value(), TowardPositive).to_bits(), 1);
+        assert_eq!(ldexpf_rm(1.0, -150, TiesToEven).to_bits(), 0);
+        assert_eq!(ldexpf_rm(1.0, -150, TowardPositive).to_bits(), 1);
+        assert_eq!(ldexpf_rm(3.0, -150, TiesToEven).to_bits(), 2);
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(ldexp_rm(1.0, 1023, TowardZero), 8.98846567431158e307);
+        assert_eq!(ldexp_rm(1.0, 1024, TowardZero), f64::MAX);
+        assert_eq!(ldexp_rm(1.0, 1024, TowardPositive), 1.0 / 0.0);
+        assert_eq!(ldexp_rm(-1.0, i32::max_value(), TowardPositive), -f64::MAX);
+        assert_eq!(ldexpf_rm(1.0, 128, TowardNegative), core::f32::MAX);
+        assert_eq!(ldexp_rm(1.0 / 0.0, -5000, TowardZero), 1.0 / 0.0);
+        assert!(ldexp_rm(0.0 / 0.0, 1, TowardZero).is_nan());
+    }
+}
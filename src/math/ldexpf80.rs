@@ -0,0 +1,10 @@
+use super::f80;
+
+/// Multiply by an integral power of two (f80)
+///
+/// Computes `x * 2^n`, the same as `scalbnf80`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ldexpf80(x: f80, n: i32) -> f80 {
+    super::scalbnf80(x, n)
+}
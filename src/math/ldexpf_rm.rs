@@ -0,0 +1,12 @@
+use super::soft_float::Soft;
+use super::RoundingMode;
+
+/// Multiply by integral power of two with explicit rounding (f32)
+///
+/// Returns `x * 2^n`, which is exact unless the result is subnormal or
+/// overflows, correctly rounded in the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ldexpf_rm(x: f32, n: i32, rm: RoundingMode) -> f32 {
+    Soft::from_f64(x as f64).scale(n).to_f32_rm(rm)
+}
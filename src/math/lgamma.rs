@@ -1,5 +1,6 @@
 use super::lgamma_r;
 
+#[inline]
 pub fn lgamma(x: f64) -> f64 {
     lgamma_r(x).0
 }
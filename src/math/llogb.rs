@@ -0,0 +1,50 @@
+use super::{ilogb, FP_ILOGB0, FP_ILOGBNAN};
+
+/// Value returned by `llogb` and `llogbf` for a zero argument.
+pub const FP_LLOGB0: i64 = -0x7fffffffffffffff;
+/// Value returned by `llogb` and `llogbf` for a NaN argument.
+pub const FP_LLOGBNAN: i64 = -1 - 0x7fffffffffffffff;
+
+/// Unbiased exponent as a long (f64)
+///
+/// Like `ilogb`, but returns an `i64`. Returns `FP_LLOGB0` for zero,
+/// `FP_LLOGBNAN` for NaN and `i64::max_value()` for infinity, raising the
+/// invalid exception in all three cases.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llogb(x: f64) -> i64 {
+    let e = ilogb(x);
+    if e == FP_ILOGB0 {
+        FP_LLOGB0
+    } else if e == FP_ILOGBNAN {
+        FP_LLOGBNAN
+    } else if e == i32::max_value() {
+        i64::max_value()
+    } else {
+        e as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(llogb(0.0), FP_LLOGB0);
+        assert_eq!(llogb(-0.0), FP_LLOGB0);
+        assert_eq!(llogb(f64::NAN), FP_LLOGBNAN);
+        assert_ne!(llogb(0.0), llogb(f64::NAN));
+        assert_eq!(llogb(f64::INFINITY), i64::max_value());
+        assert_eq!(llogb(f64::NEG_INFINITY), i64::max_value());
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(llogb(1.0), 0);
+        assert_eq!(llogb(-0.75), -1);
+        assert_eq!(llogb(f64::MAX), 1023);
+        assert_eq!(llogb(f64::from_bits(1)), -1074);
+    }
+}
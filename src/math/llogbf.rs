@@ -0,0 +1,21 @@
+use super::{ilogbf, FP_ILOGB0, FP_ILOGBNAN, FP_LLOGB0, FP_LLOGBNAN};
+
+/// Unbiased exponent as a long (f32)
+///
+/// Like `ilogbf`, but returns an `i64`. Returns `FP_LLOGB0` for zero,
+/// `FP_LLOGBNAN` for NaN and `i64::max_value()` for infinity, raising the
+/// invalid exception in all three cases.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llogbf(x: f32) -> i64 {
+    let e = ilogbf(x);
+    if e == FP_ILOGB0 {
+        FP_LLOGB0
+    } else if e == FP_ILOGBNAN {
+        FP_LLOGBNAN
+    } else if e == i32::max_value() {
+        i64::max_value()
+    } else {
+        e as i64
+    }
+}
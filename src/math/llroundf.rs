@@ -0,0 +1,36 @@
+use super::k_lrint::k_lrint;
+use super::roundf;
+
+/// Round to nearest integer, halfway cases away from zero (f32)
+///
+/// Returns `roundf(x)` as an `i64`. If that is NaN, infinite or out of range,
+/// the invalid exception is raised and `i64::MIN` is returned.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llroundf(x: f32) -> i64 {
+    k_lrint(roundf(x) as f64)
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INVALID;
+    use super::llroundf;
+    use core::f32;
+
+    #[test]
+    fn invalid() {
+        let cases = [
+            (2.5, 0),
+            (-9223372036854775808.0, 0),
+            (9223372036854775808.0, FE_INVALID),
+            (-1e30, FE_INVALID),
+            (f32::INFINITY, FE_INVALID),
+            (f32::NAN, FE_INVALID),
+        ];
+        /* the rounding raises inexact on its own */
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(llroundf, x) & FE_INVALID, e, "llroundf({:?})", x);
+        }
+    }
+}
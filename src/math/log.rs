@@ -60,6 +60,8 @@
  * to produce the hexadecimal values shown.
  */
 
+use super::fenv::{divzero, inexact, invalid};
+
 const LN2_HI: f64 = 6.93147180369123816490e-01; /* 3fe62e42 fee00000 */
 const LN2_LO: f64 = 1.90821492927058770002e-10; /* 3dea39ef 35793c76 */
 const LG1: f64 = 6.666666666666735130e-01; /* 3FE55555 55555593 */
@@ -82,10 +84,15 @@ pub fn log(mut x: f64) -> f64 {
     if (hx < 0x00100000) || ((hx >> 31) != 0) {
         /* x < 2**-126  */
         if ui << 1 == 0 {
-            return -1. / (x * x); /* log(+-0)=-inf */
+            return divzero(-1. / (x * x)); /* log(+-0)=-inf */
         }
         if hx >> 31 != 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            /* log(-#) = NaN, quiet for a NaN x */
+            return if x.is_nan() {
+                x
+            } else {
+                invalid((x - x) / 0.0)
+            };
         }
         /* subnormal number, scale x up */
         k -= 54;
@@ -106,6 +113,8 @@ pub fn log(mut x: f64) -> f64 {
     x = f64::from_bits(ui);
 
     let f: f64 = x - 1.0;
+    /* log(x) is exact only for x = 1 */
+    inexact(k != 0 || f != 0.0);
     let hfsq: f64 = 0.5 * f * f;
     let s: f64 = f / (2.0 + f);
     let z: f64 = s * s;
@@ -116,3 +125,28 @@ pub fn log(mut x: f64) -> f64 {
     let dk: f64 = k as f64;
     s * (hfsq + r) + dk * LN2_LO - hfsq + f + dk * LN2_HI
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::log;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (0.0, FE_DIVBYZERO),
+            (-0.0, FE_DIVBYZERO),
+            (-1.0, FE_INVALID),
+            (1.0, 0),
+            (2.0, FE_INEXACT),
+            (f64::INFINITY, 0),
+            (f64::NEG_INFINITY, FE_INVALID),
+            (f64::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(log, x), e, "log({:?})", x);
+        }
+    }
+}
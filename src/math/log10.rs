@@ -17,6 +17,7 @@
  *    log10(x) = (f - f*f/2 + r)/log(10) + k*log10(2)
  */
 
+use super::fenv::{divzero, inexact, invalid};
 use core::f64;
 
 const IVLN10HI: f64 = 4.34294481878168880939e-01; /* 0x3fdbcb7b, 0x15200000 */
@@ -31,6 +32,16 @@ const LG5: f64 = 1.818357216161805012e-01; /* 3FC74664 96CB03DE */
 const LG6: f64 = 1.531383769920937332e-01; /* 3FC39A09 D078C69F */
 const LG7: f64 = 1.479819860511658591e-01; /* 3FC2F112 DF3E5244 */
 
+/* whether x is 10^n for an integer n, which is a double only for
+ * 0 <= n <= 22 */
+fn is_pow10(x: f64) -> bool {
+    let mut p = 1.0;
+    while p < x && p < 1e22 {
+        p *= 10.0;
+    }
+    p == x
+}
+
 #[inline]
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn log10(mut x: f64) -> f64 {
@@ -58,10 +69,15 @@ pub fn log10(mut x: f64) -> f64 {
     k = 0;
     if hx < 0x00100000 || (hx >> 31) > 0 {
         if ui << 1 == 0 {
-            return -1. / (x * x); /* log(+-0)=-inf */
+            return divzero(-1. / (x * x)); /* log(+-0)=-inf */
         }
         if (hx >> 31) > 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            /* log(-#) = NaN, quiet for a NaN x */
+            return if x.is_nan() {
+                x
+            } else {
+                invalid((x - x) / 0.0)
+            };
         }
         /* subnormal number, scale x up */
         k -= 54;
@@ -74,6 +90,9 @@ pub fn log10(mut x: f64) -> f64 {
         return 0.;
     }
 
+    /* log10(x) is exact only for x = 10^n */
+    inexact(!is_pow10(x));
+
     /* reduce x into [sqrt(2)/2, sqrt(2)] */
     hx += 0x3ff00000 - 0x3fe6a09e;
     k += (hx >> 20) as i32 - 0x3ff;
@@ -116,3 +135,25 @@ pub fn log10(mut x: f64) -> f64 {
 
     val_lo + val_hi
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::log10;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (100.0, 0),
+            (3.0, FE_INEXACT),
+            (0.0, FE_DIVBYZERO),
+            (-1.0, FE_INVALID),
+            (f64::INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(log10, x), e, "log10({:?})", x);
+        }
+    }
+}
@@ -13,6 +13,7 @@
  * See comments in log10.c.
  */
 
+use super::fenv::{divzero, inexact, invalid};
 use core::f32;
 
 const IVLN10HI: f32 = 4.3432617188e-01; /* 0x3ede6000 */
@@ -25,6 +26,16 @@ const LG2: f32 = 0.40000972152; /* 0xccce13.0p-25 */
 const LG3: f32 = 0.28498786688; /* 0x91e9ee.0p-25 */
 const LG4: f32 = 0.24279078841; /* 0xf89e26.0p-26 */
 
+/* whether x is 10^n for an integer n, which is a float only for
+ * 0 <= n <= 10 */
+fn is_pow10(x: f32) -> bool {
+    let mut p = 1.0;
+    while p < x && p < 1e10 {
+        p *= 10.0;
+    }
+    p == x
+}
+
 #[inline]
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn log10f(mut x: f32) -> f32 {
@@ -50,10 +61,15 @@ pub fn log10f(mut x: f32) -> f32 {
     if ix < 0x00800000 || (ix >> 31) > 0 {
         /* x < 2**-126  */
         if ix << 1 == 0 {
-            return -1. / (x * x); /* log(+-0)=-inf */
+            return divzero(-1. / (x * x)); /* log(+-0)=-inf */
         }
         if (ix >> 31) > 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            /* log(-#) = NaN, quiet for a NaN x */
+            return if x.is_nan() {
+                x
+            } else {
+                invalid((x - x) / 0.0)
+            };
         }
         /* subnormal number, scale up x */
         k -= 25;
@@ -66,6 +82,9 @@ pub fn log10f(mut x: f32) -> f32 {
         return 0.;
     }
 
+    /* log10(x) is exact only for x = 10^n */
+    inexact(!is_pow10(x));
+
     /* reduce x into [sqrt(2)/2, sqrt(2)] */
     ix += 0x3f800000 - 0x3f3504f3;
     k += (ix >> 23) as i32 - 0x7f;
@@ -90,3 +109,25 @@ pub fn log10f(mut x: f32) -> f32 {
     dk = k as f32;
     dk * LOG10_2LO + (lo + hi) * IVLN10LO + lo * IVLN10HI + hi * IVLN10HI + dk * LOG10_2HI
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::log10f;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (100.0, 0),
+            (3.0, FE_INEXACT),
+            (0.0, FE_DIVBYZERO),
+            (-1.0, FE_INVALID),
+            (f32::INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(log10f, x), e, "log10f({:?})", x);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use super::{log1p, with_set_low_word};
+
+const INVLN10_HI: f64 = 4.34294480830430984497e-01; /* 0x3fdbcb7b, 0x14000000 */
+const INVLN10_LO: f64 = 1.07282084315405848856e-09; /* 0x3e126e50, 0xe32a6ab7 */
+
+/// Base 10 logarithm of 1+x (f64)
+///
+/// Calculates the base 10 logarithm of `1+x`. The result is accurate even
+/// for values of `x` near zero, where using `log10(1+x)` would lose many
+/// significant digits.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10p1(x: f64) -> f64 {
+    let y = log1p(x);
+
+    /* nan, inf, zero, or tiny enough that the split below is not exact */
+    let iy = (y.to_bits() >> 32) as u32 & 0x7fffffff;
+    if iy >= 0x7ff00000 || iy < 0x03600000 {
+        return y * (INVLN10_HI + INVLN10_LO);
+    }
+
+    /* y/ln(10) with the product evaluated in double-double */
+    let yh = with_set_low_word(y, 0);
+    let yl = y - yh;
+    yh * INVLN10_HI + (yl * INVLN10_HI + y * INVLN10_LO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::log10p1;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(log10p1(0.0).to_bits(), 0);
+        assert_eq!(log10p1(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(log10p1(9.0), 1.0);
+        assert_eq!(log10p1(99.0), 2.0);
+        assert_eq!(log10p1(-1.0), f64::NEG_INFINITY);
+        assert_eq!(log10p1(f64::INFINITY), f64::INFINITY);
+        assert!(log10p1(-2.0).is_nan());
+        assert!(log10p1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn small_arguments() {
+        assert_eq!(log10p1(1e-10), 4.342944818815371e-11);
+        assert_eq!(log10p1(-0.5), -0.3010299956639812);
+        assert_eq!(log10p1(f64::from_bits(2)), f64::from_bits(1));
+    }
+}
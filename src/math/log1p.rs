@@ -53,6 +53,7 @@
  *       See HP-15C Advanced Functions Handbook, p.193.
  */
 
+use super::fenv::{divzero, inexact, invalid};
 use core::f64;
 
 const LN2_HI: f64 = 6.93147180369123816490e-01; /* 3fe62e42 fee00000 */
@@ -85,19 +86,27 @@ pub fn log1p(x: f64) -> f64 {
 
     hx = (ui >> 32) as u32;
     k = 1;
+    /* log1p(x) is inexact for finite x > -1, x != 0 */
+    inexact(x > -1.0 && x != 0.0 && x.is_finite());
+
     if hx < 0x3fda827a || (hx >> 31) > 0 {
         /* 1+x < sqrt(2)+ */
         if hx >= 0xbff00000 {
             /* x <= -1.0 */
             if x == -1. {
-                return x / 0.0; /* log1p(-1) = -inf */
+                return divzero(x / 0.0); /* log1p(-1) = -inf */
             }
-            return (x - x) / 0.0; /* log1p(x<-1) = NaN */
+            /* log1p(x<-1) = NaN, quiet for a NaN x */
+            return if x.is_nan() {
+                x
+            } else {
+                invalid((x - x) / 0.0)
+            };
         }
         if hx << 1 < 0x3ca00000 << 1 {
             /* |x| < 2**-53 */
             /* underflow if subnormal */
-            if (hx & 0x7ff00000) == 0 {
+            if (hx & 0x7ff00000) == 0 && x != 0.0 {
                 force_eval!(x as f32);
             }
             return x;
@@ -142,3 +151,26 @@ pub fn log1p(x: f64) -> f64 {
     dk = k as f64;
     s * (hfsq + r) + (dk * LN2_LO + c) - hfsq + f + dk * LN2_HI
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::log1p;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (-1.0, FE_DIVBYZERO),
+            (-2.0, FE_INVALID),
+            (0.0, 0),
+            (1.0, FE_INEXACT),
+            (f64::INFINITY, 0),
+            (f64::NEG_INFINITY, FE_INVALID),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(log1p, x), e, "log1p({:?})", x);
+        }
+    }
+}
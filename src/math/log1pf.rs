@@ -10,6 +10,7 @@
  * ====================================================
  */
 
+use super::fenv::{divzero, inexact, invalid};
 use core::f32;
 
 const LN2_HI: f32 = 6.9313812256e-01; /* 0x3f317180 */
@@ -40,19 +41,27 @@ pub fn log1pf(x: f32) -> f32 {
 
     ix = ui;
     k = 1;
+    /* log1pf(x) is inexact for finite x > -1, x != 0 */
+    inexact(x > -1.0 && x != 0.0 && x.is_finite());
+
     if ix < 0x3ed413d0 || (ix >> 31) > 0 {
         /* 1+x < sqrt(2)+  */
         if ix >= 0xbf800000 {
             /* x <= -1.0 */
             if x == -1. {
-                return x / 0.0; /* log1p(-1)=+inf */
+                return divzero(x / 0.0); /* log1p(-1)=-inf */
             }
-            return (x - x) / 0.0; /* log1p(x<-1)=NaN */
+            /* log1p(x<-1) = NaN, quiet for a NaN x */
+            return if x.is_nan() {
+                x
+            } else {
+                invalid((x - x) / 0.0)
+            };
         }
         if ix << 1 < 0x33800000 << 1 {
             /* |x| < 2**-24 */
             /* underflow if subnormal */
-            if (ix & 0x7f800000) == 0 {
+            if (ix & 0x7f800000) == 0 && x != 0.0 {
                 force_eval!(x * x);
             }
             return x;
@@ -97,3 +106,26 @@ pub fn log1pf(x: f32) -> f32 {
     dk = k as f32;
     s * (hfsq + r) + (dk * LN2_LO + c) - hfsq + f + dk * LN2_HI
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::log1pf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (-1.0, FE_DIVBYZERO),
+            (-2.0, FE_INVALID),
+            (0.0, 0),
+            (1.0, FE_INEXACT),
+            (f32::INFINITY, 0),
+            (f32::NEG_INFINITY, FE_INVALID),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(log1pf, x), e, "log1pf({:?})", x);
+        }
+    }
+}
@@ -17,6 +17,7 @@
  *    log2(x) = (f - f*f/2 + r)/log(2) + k
  */
 
+use super::fenv::{divzero, inexact, invalid};
 use core::f64;
 
 const IVLN2HI: f64 = 1.44269504072144627571e+00; /* 0x3ff71547, 0x65200000 */
@@ -55,10 +56,15 @@ pub fn log2(mut x: f64) -> f64 {
     k = 0;
     if hx < 0x00100000 || (hx >> 31) > 0 {
         if ui << 1 == 0 {
-            return -1. / (x * x); /* log(+-0)=-inf */
+            return divzero(-1. / (x * x)); /* log(+-0)=-inf */
         }
         if (hx >> 31) > 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            /* log(-#) = NaN, quiet for a NaN x */
+            return if x.is_nan() {
+                x
+            } else {
+                invalid((x - x) / 0.0)
+            };
         }
         /* subnormal number, scale x up */
         k -= 54;
@@ -79,6 +85,8 @@ pub fn log2(mut x: f64) -> f64 {
     x = f64::from_bits(ui);
 
     f = x - 1.0;
+    /* log2(x) is exact for x a power of 2 */
+    inexact(f != 0.0);
     hfsq = 0.5 * f * f;
     s = f / (2.0 + f);
     z = s * s;
@@ -105,3 +113,25 @@ pub fn log2(mut x: f64) -> f64 {
 
     val_lo + val_hi
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::log2;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (8.0, 0),
+            (3.0, FE_INEXACT),
+            (0.0, FE_DIVBYZERO),
+            (-1.0, FE_INVALID),
+            (f64::INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(log2, x), e, "log2({:?})", x);
+        }
+    }
+}
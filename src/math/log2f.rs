@@ -13,6 +13,7 @@
  * See comments in log2.c.
  */
 
+use super::fenv::{divzero, inexact, invalid};
 use core::f32;
 
 const IVLN2HI: f32 = 1.4428710938e+00; /* 0x3fb8b000 */
@@ -47,10 +48,15 @@ pub fn log2f(mut x: f32) -> f32 {
     if ix < 0x00800000 || (ix >> 31) > 0 {
         /* x < 2**-126  */
         if ix << 1 == 0 {
-            return -1. / (x * x); /* log(+-0)=-inf */
+            return divzero(-1. / (x * x)); /* log(+-0)=-inf */
         }
         if (ix >> 31) > 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            /* log(-#) = NaN, quiet for a NaN x */
+            return if x.is_nan() {
+                x
+            } else {
+                invalid((x - x) / 0.0)
+            };
         }
         /* subnormal number, scale up x */
         k -= 25;
@@ -71,6 +77,8 @@ pub fn log2f(mut x: f32) -> f32 {
     x = f32::from_bits(ui);
 
     f = x - 1.0;
+    /* log2f(x) is exact for x a power of 2 */
+    inexact(f != 0.0);
     s = f / (2.0 + f);
     z = s * s;
     w = z * z;
@@ -86,3 +94,25 @@ pub fn log2f(mut x: f32) -> f32 {
     lo = f - hi - hfsq + s * (hfsq + r);
     (lo + hi) * IVLN2LO + lo * IVLN2HI + hi * IVLN2HI + k as f32
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::log2f;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (8.0, 0),
+            (3.0, FE_INEXACT),
+            (0.0, FE_DIVBYZERO),
+            (-1.0, FE_INVALID),
+            (f32::INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(log2f, x), e, "log2f({:?})", x);
+        }
+    }
+}
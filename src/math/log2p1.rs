@@ -0,0 +1,51 @@
+use super::{log1p, with_set_low_word};
+
+const INVLN2_HI: f64 = 1.4426950365304947e+00; /* 0x3ff71547, 0x64000000 */
+const INVLN2_LO: f64 = 4.3584687174185184e-09; /* 0x3e32b82f, 0xe1777d10 */
+
+/// Base 2 logarithm of 1+x (f64)
+///
+/// Calculates the base 2 logarithm of `1+x`. The result is accurate even
+/// for values of `x` near zero, where using `log2(1+x)` would lose many
+/// significant digits.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2p1(x: f64) -> f64 {
+    let y = log1p(x);
+
+    /* nan, inf, zero, or tiny enough that the split below is not exact */
+    let iy = (y.to_bits() >> 32) as u32 & 0x7fffffff;
+    if iy >= 0x7ff00000 || iy < 0x03600000 {
+        return y * (INVLN2_HI + INVLN2_LO);
+    }
+
+    /* y/ln(2) with the product evaluated in double-double */
+    let yh = with_set_low_word(y, 0);
+    let yl = y - yh;
+    yh * INVLN2_HI + (yl * INVLN2_HI + y * INVLN2_LO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::log2p1;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(log2p1(0.0).to_bits(), 0);
+        assert_eq!(log2p1(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(log2p1(1.0), 1.0);
+        assert_eq!(log2p1(3.0), 2.0);
+        assert_eq!(log2p1(-0.5), -1.0);
+        assert_eq!(log2p1(-1.0), f64::NEG_INFINITY);
+        assert_eq!(log2p1(f64::INFINITY), f64::INFINITY);
+        assert!(log2p1(-2.0).is_nan());
+        assert!(log2p1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn small_arguments() {
+        assert_eq!(log2p1(1e-10), 1.4426950408168288e-10);
+        assert_eq!(log2p1(f64::from_bits(2)), f64::from_bits(3));
+    }
+}
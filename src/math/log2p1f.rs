@@ -0,0 +1,15 @@
+use core::f64::consts::LOG2_E;
+
+use super::log1p;
+
+/// Base 2 logarithm of 1+x (f32)
+///
+/// Calculates the base 2 logarithm of `1+x` by evaluating
+/// `log1p(x) / ln(2)` in double precision. The result is accurate even for
+/// values of `x` near zero, where using `log2f(1+x)` would lose many
+/// significant digits.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2p1f(x: f32) -> f32 {
+    (log1p(x as f64) * LOG2_E) as f32
+}
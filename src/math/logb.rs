@@ -0,0 +1,58 @@
+use super::fenv::divzero;
+use super::ilogb;
+
+/// Unbiased exponent as a floating-point value (f64)
+///
+/// Returns the exponent of `x`, the value of `floor(log2(|x|))` for a finite
+/// nonzero `x`. Returns negative infinity for zero, raising the divide-by-zero
+/// exception, and positive infinity for infinity.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logb(x: f64) -> f64 {
+    if !x.is_finite() {
+        return x * x;
+    }
+    if x == 0.0 {
+        return divzero(f64::NEG_INFINITY);
+    }
+    ilogb(x) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::logb;
+    use core::f64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(logb(8.0), 3.0);
+        assert_eq!(logb(-0.3), -2.0);
+        assert_eq!(logb(f64::from_bits(1)), -1074.0);
+        assert_eq!(logb(0.0), f64::NEG_INFINITY);
+        assert_eq!(logb(-0.0), f64::NEG_INFINITY);
+        assert_eq!(logb(f64::NEG_INFINITY), f64::INFINITY);
+        assert!(logb(f64::NAN).is_nan());
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_DIVBYZERO;
+    use super::logb;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (0.0, FE_DIVBYZERO),
+            (-0.0, FE_DIVBYZERO),
+            (0.3, 0),
+            (f64::INFINITY, 0),
+            (f64::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(logb, x), e, "logb({:?})", x);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use super::fenv::divzero;
+use super::ilogbf;
+
+/// Unbiased exponent as a floating-point value (f32)
+///
+/// Returns the exponent of `x`, the value of `floor(log2(|x|))` for a finite
+/// nonzero `x`. Returns negative infinity for zero, raising the divide-by-zero
+/// exception, and positive infinity for infinity.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logbf(x: f32) -> f32 {
+    if !x.is_finite() {
+        return x * x;
+    }
+    if x == 0.0 {
+        return divzero(f32::NEG_INFINITY);
+    }
+    ilogbf(x) as f32
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_DIVBYZERO;
+    use super::logbf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (0.0, FE_DIVBYZERO),
+            (-0.0, FE_DIVBYZERO),
+            (0.3, 0),
+            (f32::INFINITY, 0),
+            (f32::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(logbf, x), e, "logbf({:?})", x);
+        }
+    }
+}
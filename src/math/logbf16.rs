@@ -0,0 +1,33 @@
+use super::{bf16, log};
+
+/// Natural logarithm (bf16)
+///
+/// Calculates the natural logarithm of `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logbf16(x: bf16) -> bf16 {
+    bf16::from_f64(log(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bfloat16::{check, check_f32};
+    use super::super::{cr_log, logf};
+    use super::{bf16, logbf16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check_f32(x, logbf16(x), logf(x.to_f32()), 1);
+        }
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check(x, logbf16(x), cr_log(x.to_f64()));
+        }
+    }
+}
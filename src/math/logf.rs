@@ -13,6 +13,8 @@
  * ====================================================
  */
 
+use super::fenv::{divzero, inexact, invalid};
+
 const LN2_HI: f32 = 6.9313812256e-01; /* 0x3f317180 */
 const LN2_LO: f32 = 9.0580006145e-06; /* 0x3717f7d1 */
 /* |(log(1+s)-log(1-s))/s - Lg(s)| < 2**-34.24 (~[-4.95e-11, 4.97e-11]). */
@@ -32,10 +34,11 @@ pub fn logf(mut x: f32) -> f32 {
     if (ix < 0x00800000) || ((ix >> 31) != 0) {
         /* x < 2**-126  */
         if ix << 1 == 0 {
-            return -1. / (x * x); /* log(+-0)=-inf */
+            return divzero(-1. / (x * x)); /* log(+-0)=-inf */
         }
         if (ix >> 31) != 0 {
-            return (x - x) / 0.; /* log(-#) = NaN */
+            /* log(-#) = NaN, quiet for a NaN x */
+            return if x.is_nan() { x } else { invalid((x - x) / 0.) };
         }
         /* subnormal number, scale up x */
         k -= 25;
@@ -54,6 +57,8 @@ pub fn logf(mut x: f32) -> f32 {
     x = f32::from_bits(ix);
 
     let f = x - 1.;
+    /* logf(x) is exact only for x = 1 */
+    inexact(k != 0 || f != 0.0);
     let s = f / (2. + f);
     let z = s * s;
     let w = z * z;
@@ -64,3 +69,28 @@ pub fn logf(mut x: f32) -> f32 {
     let dk = k as f32;
     s * (hfsq + r) + dk * LN2_LO - hfsq + f + dk * LN2_HI
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID};
+    use super::logf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (0.0, FE_DIVBYZERO),
+            (-0.0, FE_DIVBYZERO),
+            (-1.0, FE_INVALID),
+            (1.0, 0),
+            (2.0, FE_INEXACT),
+            (f32::INFINITY, 0),
+            (f32::NEG_INFINITY, FE_INVALID),
+            (f32::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(logf, x), e, "logf({:?})", x);
+        }
+    }
+}
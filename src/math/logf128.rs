@@ -0,0 +1,184 @@
+/* logf128(x)
+ * Return the logarithm of x
+ *
+ * Method :
+ *   1. Argument Reduction: find k and f such that
+ *                      x = 2^k * (1+f),
+ *         where  sqrt(2)/2 < 1+f < sqrt(2) .
+ *
+ *   2. Approximation of log(1+f).
+ *      Let s = f/(2+f) ; based on log(1+f) = log(1+s) - log(1-s)
+ *               = 2s + 2/3 s**3 + 2/5 s**5 + .....,
+ *               = 2s + s*R
+ *      Since |s| < 0.1716, the series is summed up to s**47, where its
+ *      truncation error drops below 2**-120. s, s*s and the leading terms
+ *      of R are carried as unevaluated sums hi + lo of two f128 numbers,
+ *      so that log(1+f) is obtained to about 2**-125 relative error.
+ *
+ *   3. Finally,  log(x) = k*ln2 + log(1+f), again as a sum hi + lo.
+ *         Here ln2 is split into two floating point number:
+ *                      ln2_hi + ln2_lo,
+ *         where n*ln2_hi is always exact for |n| < 32768.
+ *
+ * Special cases:
+ *      log(x) is NaN with signal if x < 0 (including -INF) ;
+ *      log(+INF) is +INF; log(0) is -INF with signal;
+ *      log(NaN) is that NaN with no signal.
+ *
+ * Accuracy:
+ *      the error is below 0.5 ulp plus 2**-120 relative, so the result is
+ *      correctly rounded in all but rare cases.
+ */
+
+use super::{f128, fmaf128};
+
+const LN2_HI: f128 = f128::from_bits(0x3ffe62e42fefa39ef35793c767300000); /* 0.693147180559945309417232121457981864 */
+const LN2_LO: f128 = f128::from_bits(0x3f98f97b57a079a193394c5b16c5068c); /* 1.94704509238074995158795957333327386e-31 */
+const SQRT2: f128 = f128::from_bits(0x3fff6a09e667f3bcc908b2fb1366ea95); /* 1.41421356237309504880168872420969798 */
+const C1_LO: f128 = f128::from_bits(0x3f8c5555555555555555555555555555); /* 3.20988324064539308842662990430821204e-35 */
+const C2_LO: f128 = f128::from_bits(0xbf8b999999999999999999999999999a); /* -1.92592994438723585305597794258492741e-35 */
+const C: [f128; 23] = [
+    f128::from_bits(0x3ffe5555555555555555555555555555), /* 2/3 */
+    f128::from_bits(0x3ffd999999999999999999999999999a), /* 2/5 */
+    f128::from_bits(0x3ffd2492492492492492492492492492), /* 2/7 */
+    f128::from_bits(0x3ffcc71c71c71c71c71c71c71c71c71c), /* 2/9 */
+    f128::from_bits(0x3ffc745d1745d1745d1745d1745d1746), /* 2/11 */
+    f128::from_bits(0x3ffc3b13b13b13b13b13b13b13b13b14), /* 2/13 */
+    f128::from_bits(0x3ffc1111111111111111111111111111), /* 2/15 */
+    f128::from_bits(0x3ffbe1e1e1e1e1e1e1e1e1e1e1e1e1e2), /* 2/17 */
+    f128::from_bits(0x3ffbaf286bca1af286bca1af286bca1b), /* 2/19 */
+    f128::from_bits(0x3ffb8618618618618618618618618618), /* 2/21 */
+    f128::from_bits(0x3ffb642c8590b21642c8590b21642c86), /* 2/23 */
+    f128::from_bits(0x3ffb47ae147ae147ae147ae147ae147b), /* 2/25 */
+    f128::from_bits(0x3ffb2f684bda12f684bda12f684bda13), /* 2/27 */
+    f128::from_bits(0x3ffb1a7b9611a7b9611a7b9611a7b961), /* 2/29 */
+    f128::from_bits(0x3ffb0842108421084210842108421084), /* 2/31 */
+    f128::from_bits(0x3ffaf07c1f07c1f07c1f07c1f07c1f08), /* 2/33 */
+    f128::from_bits(0x3ffad41d41d41d41d41d41d41d41d41d), /* 2/35 */
+    f128::from_bits(0x3ffabacf914c1bacf914c1bacf914c1c), /* 2/37 */
+    f128::from_bits(0x3ffaa41a41a41a41a41a41a41a41a41a), /* 2/39 */
+    f128::from_bits(0x3ffa8f9c18f9c18f9c18f9c18f9c18fa), /* 2/41 */
+    f128::from_bits(0x3ffa7d05f417d05f417d05f417d05f41), /* 2/43 */
+    f128::from_bits(0x3ffa6c16c16c16c16c16c16c16c16c17), /* 2/45 */
+    f128::from_bits(0x3ffa5c9882b9310572620ae4c415c988), /* 2/47 */
+];
+
+/* a + b = s + e exactly, for |a| >= |b| */
+#[inline]
+fn fast_two_sum(a: f128, b: f128) -> (f128, f128) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/* a + b = s + e exactly */
+#[inline]
+fn two_sum(a: f128, b: f128) -> (f128, f128) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+#[inline]
+fn dw_add(ah: f128, al: f128, bh: f128, bl: f128) -> (f128, f128) {
+    let (h, l) = two_sum(ah, bh);
+    fast_two_sum(h, l + (al + bl))
+}
+
+#[inline]
+fn dw_mul(ah: f128, al: f128, bh: f128, bl: f128) -> (f128, f128) {
+    let h = ah * bh;
+    fast_two_sum(h, fmaf128(ah, bh, -h) + (ah * bl + al * bh))
+}
+
+/* log(x) as hi + lo, for positive finite x */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_logf128(x: f128) -> (f128, f128) {
+    let x1p113 = f128::from_bits(0x4070 << 112); // 0x1p113 === 2 ^ 113
+    let two = f128::from(2.0);
+
+    let mut ui = x.to_bits();
+    let mut k = (ui >> 112) as i32 - 0x3fff;
+    if ui >> 112 == 0 {
+        /* subnormal x, scale up */
+        ui = (x * x1p113).to_bits();
+        k = (ui >> 112) as i32 - 0x3fff - 113;
+    }
+    /* reduce x into [sqrt(2)/2, sqrt(2)] */
+    ui = ui & ((1 << 112) - 1) | 0x3fff << 112;
+    if ui > SQRT2.to_bits() {
+        ui -= 1 << 112;
+        k += 1;
+    }
+    let f = f128::from_bits(ui) - f128::ONE;
+
+    /* s = f/(2+f) */
+    let dh = two + f;
+    let dl = f - (dh - two);
+    let sh = f / dh;
+    let sl = (fmaf128(-sh, dh, f) - sh * dl) / dh;
+
+    /* R = z*(2/3 + z*(2/5 + z*(2/7 + ...))), z = s*s */
+    let (zh, zl) = dw_mul(sh, sl, sh, sl);
+    let mut t = C[22];
+    for &c in C[2..22].iter().rev() {
+        t = t * zh + c;
+    }
+    let (th, tl) = dw_add(C[1], C2_LO, zh * t, f128::ZERO);
+    let (th, tl) = dw_mul(zh, zl, th, tl);
+    let (th, tl) = dw_add(C[0], C1_LO, th, tl);
+    let (th, tl) = dw_mul(zh, zl, th, tl);
+
+    /* log(1+f) = 2s + s*R */
+    let (th, tl) = dw_mul(sh, sl, th, tl);
+    let (hi, lo) = dw_add(two * sh, two * sl, th, tl);
+
+    let kf = f128::from(k);
+    dw_add(kf * LN2_HI, kf * LN2_LO, hi, lo)
+}
+
+/// Natural logarithm (f128)
+///
+/// Calculates the natural logarithm of `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logf128(x: f128) -> f128 {
+    if x.is_nan() {
+        return x;
+    }
+    if x == f128::ZERO {
+        return f128::NEG_INFINITY;
+    }
+    if x.is_sign_negative() {
+        return f128::NAN;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+    k_logf128(x).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{f128, logf128};
+
+    #[test]
+    fn special_values() {
+        assert!(logf128(f128::NAN).is_nan());
+        assert!(logf128(-f128::ONE).is_nan());
+        assert!(logf128(f128::NEG_INFINITY).is_nan());
+        assert_eq!(logf128(f128::ZERO), f128::NEG_INFINITY);
+        assert_eq!(logf128(-f128::ZERO), f128::NEG_INFINITY);
+        assert_eq!(logf128(f128::INFINITY), f128::INFINITY);
+        assert_eq!(logf128(f128::ONE).to_bits(), 0);
+    }
+
+    #[test]
+    fn values() {
+        /* correctly rounded results */
+        let cases = [(f128::from(2.0), 0x3ffe62e42fefa39ef35793c7673007e6)];
+        for &(x, r) in cases.iter() {
+            assert_eq!(logf128(x).to_bits(), r, "log({:?})", x);
+        }
+    }
+}
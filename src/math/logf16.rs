@@ -0,0 +1,25 @@
+use super::{f16, log};
+
+/// Natural logarithm (f16)
+///
+/// Calculates the natural logarithm of `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logf16(x: f16) -> f16 {
+    f16::from_f64(log(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cr_log;
+    use super::super::float16::check;
+    use super::{f16, logf16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = f16::from_bits(i);
+            check(x, logf16(x), cr_log(x.to_f64()));
+        }
+    }
+}
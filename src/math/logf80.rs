@@ -0,0 +1,11 @@
+use super::{f128, f80, logf128};
+
+/// Natural logarithm (f80)
+///
+/// Calculates the natural logarithm of `x`. The logarithm is computed in
+/// f128, see logf128, and rounded once.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logf80(x: f80) -> f80 {
+    f80::from_f128(logf128(f128::from(x)))
+}
@@ -0,0 +1,38 @@
+use super::k_lrint::k_lrint;
+use super::rint;
+
+/// Round to integer in the current rounding direction (f64)
+///
+/// Returns `rint(x)` as an `i64`. If that is NaN, infinite or out of range,
+/// the invalid exception is raised and `i64::MIN` is returned.
+///
+/// C's `long` is taken to be 64 bits wide, so this is the same as `llrint`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lrint(x: f64) -> i64 {
+    k_lrint(rint(x))
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INVALID;
+    use super::lrint;
+    use core::f64;
+
+    #[test]
+    fn invalid() {
+        let cases = [
+            (2.5, 0),
+            (-9223372036854775808.0, 0),
+            (9223372036854775808.0, FE_INVALID),
+            (-1e30, FE_INVALID),
+            (f64::INFINITY, FE_INVALID),
+            (f64::NAN, FE_INVALID),
+        ];
+        /* the rounding raises inexact on its own */
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(lrint, x) & FE_INVALID, e, "lrint({:?})", x);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use super::k_lrint::k_lrint;
+use super::rintf;
+
+/// Round to integer in the current rounding direction (f32)
+///
+/// Returns `rintf(x)` as an `i64`. If that is NaN, infinite or out of range,
+/// the invalid exception is raised and `i64::MIN` is returned.
+///
+/// C's `long` is taken to be 64 bits wide, so this is the same as `llrintf`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lrintf(x: f32) -> i64 {
+    k_lrint(rintf(x) as f64)
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INVALID;
+    use super::lrintf;
+    use core::f32;
+
+    #[test]
+    fn invalid() {
+        let cases = [
+            (2.5, 0),
+            (-9223372036854775808.0, 0),
+            (9223372036854775808.0, FE_INVALID),
+            (-1e30, FE_INVALID),
+            (f32::INFINITY, FE_INVALID),
+            (f32::NAN, FE_INVALID),
+        ];
+        /* the rounding raises inexact on its own */
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(lrintf, x) & FE_INVALID, e, "lrintf({:?})", x);
+        }
+    }
+}
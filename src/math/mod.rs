@@ -1,3 +1,4 @@
+#[cfg(not(feature = "soft-fenv"))]
 macro_rules! force_eval {
     ($e:expr) => {
         unsafe {
@@ -6,6 +7,14 @@ macro_rules! force_eval {
     };
 }
 
+/* records the exceptions raised by $e in the software fenv */
+#[cfg(feature = "soft-fenv")]
+macro_rules! force_eval {
+    ($e:expr) => {
+        $crate::math::force_eval($e)
+    };
+}
+
 #[cfg(not(feature = "checked"))]
 macro_rules! i {
     ($array:expr, $index:expr) => {
@@ -74,162 +83,554 @@ mod acos;
 mod acosf;
 mod acosh;
 mod acoshf;
+mod acospi;
+mod acospif;
+mod add_rm;
+mod addf_rm;
 mod asin;
 mod asinf;
 mod asinh;
 mod asinhf;
+mod asinpi;
+mod asinpif;
 mod atan;
 mod atan2;
 mod atan2f;
+mod atan2pi;
+mod atan2pif;
 mod atanf;
 mod atanh;
 mod atanhf;
+mod atanpi;
+mod atanpif;
+mod beta;
+mod betaf;
+mod betainc;
+mod betainc_inv;
+mod betainc_invf;
+mod betaincf;
+mod bfloat16;
+mod canonicalize;
+mod canonicalizef;
 mod cbrt;
 mod cbrtf;
 mod ceil;
 mod ceilf;
+mod ceilf128;
+mod ceilf16;
+mod ceilf80;
+mod compoundn;
+mod compoundnf;
 mod copysign;
 mod copysignf;
 mod cos;
 mod cosf;
+mod cosf128;
+mod cosf16;
+mod cosf80;
 mod cosh;
 mod coshf;
+mod cospi;
+mod cospif;
+mod cr_acosf;
+mod cr_acoshf;
+mod cr_asinf;
+mod cr_asinhf;
+mod cr_atan;
+mod cr_atan2f;
+mod cr_atanf;
+mod cr_atanhf;
+mod cr_cbrtf;
+mod cr_cos;
+mod cr_cosf;
+mod cr_cosh;
+mod cr_coshf;
+mod cr_erfcf;
+mod cr_erff;
+mod cr_exp;
+mod cr_exp10f;
+mod cr_exp2;
+mod cr_exp2f;
+mod cr_expf;
+mod cr_expm1;
+mod cr_expm1f;
+mod cr_hypotf;
+mod cr_lgammaf;
+mod cr_log;
+mod cr_log10f;
+mod cr_log1p;
+mod cr_log1pf;
+mod cr_log2;
+mod cr_log2f;
+mod cr_logf;
+mod cr_pow;
+mod cr_powf;
+mod cr_sin;
+mod cr_sinf;
+mod cr_sinh;
+mod cr_sinhf;
+mod cr_tan;
+mod cr_tanf;
+mod cr_tanhf;
+mod cr_tgammaf;
+mod digamma;
+mod digammaf;
+mod div_rm;
+mod divf_rm;
 mod erf;
+mod erfbf16;
 mod erff;
+mod erff16;
 mod exp;
 mod exp10;
 mod exp10f;
+mod exp10m1;
+mod exp10m1f;
 mod exp2;
 mod exp2f;
+mod exp2m1;
+mod exp2m1f;
+mod expbf16;
 mod expf;
+mod expf128;
+mod expf16;
+mod expf80;
 mod expm1;
 mod expm1f;
 mod fabs;
 mod fabsf;
 mod fdim;
 mod fdimf;
+mod float128;
+mod float16;
+mod float80;
 mod floor;
 mod floorf;
+mod floorf128;
+mod floorf16;
+mod floorf80;
 mod fma;
+mod fma_rm;
 mod fmaf;
+mod fmaf128;
+mod fmaf80;
+mod fmaf_rm;
 mod fmax;
 mod fmaxf;
+mod fmaximum;
+mod fmaximum_mag;
+mod fmaximum_mag_num;
+mod fmaximum_mag_numf;
+mod fmaximum_magf;
+mod fmaximum_num;
+mod fmaximum_numf;
+mod fmaximumf;
 mod fmin;
 mod fminf;
+mod fminimum;
+mod fminimum_mag;
+mod fminimum_mag_num;
+mod fminimum_mag_numf;
+mod fminimum_magf;
+mod fminimum_num;
+mod fminimum_numf;
+mod fminimumf;
 mod fmod;
 mod fmodf;
 mod frexp;
 mod frexpf;
+mod frexpf80;
+mod fromfp;
+mod fromfpf;
+mod gamma_p;
+mod gamma_p_inv;
+mod gamma_p_invf;
+mod gamma_pf;
+mod getpayload;
+mod getpayloadf;
 mod hypot;
 mod hypotf;
+mod i0;
+mod i0f;
+mod i1;
+mod i1f;
 mod ilogb;
 mod ilogbf;
+mod in_;
+mod in_f;
+mod iv;
+mod ivf;
 mod j0;
 mod j0f;
 mod j1;
 mod j1f;
 mod jn;
 mod jnf;
+mod jv;
+mod jvf;
 mod ldexp;
+mod ldexp_rm;
 mod ldexpf;
+mod ldexpf80;
+mod ldexpf_rm;
 mod lgamma;
 mod lgamma_r;
 mod lgammaf;
 mod lgammaf_r;
+mod llogb;
+mod llogbf;
+mod llrint;
+mod llrintf;
+mod llround;
+mod llroundf;
 mod log;
 mod log10;
 mod log10f;
+mod log10p1;
+mod log10p1f;
 mod log1p;
 mod log1pf;
 mod log2;
 mod log2f;
+mod log2p1;
+mod log2p1f;
+mod logb;
+mod logbf;
+mod logbf16;
 mod logf;
+mod logf128;
+mod logf16;
+mod logf80;
+mod lrint;
+mod lrintf;
+mod lround;
+mod lroundf;
 mod modf;
 mod modff;
+mod mul_rm;
+mod mulf_rm;
+mod nan;
+mod nanf;
+mod nearbyint;
+mod nearbyintf;
+mod nextafter;
+mod nextafterf;
+mod nextdown;
+mod nextdownf;
+mod nexttowardf;
+mod nextup;
+mod nextupf;
+mod polygamma;
+mod polygammaf;
 mod pow;
 mod powf;
+mod powf128;
+mod powf80;
+mod pown;
+mod pownf;
+mod powr;
+mod powrf;
 mod remainder;
 mod remainderf;
 mod remquo;
 mod remquof;
+mod rint;
+mod rintf;
+mod rintf128;
+mod rintf16;
+mod rintf80;
+mod rootn;
+mod rootnf;
 mod round;
+mod roundeven;
+mod roundevenf;
 mod roundf;
+mod roundf128;
+mod roundf16;
+mod roundf80;
+mod rounding_mode;
+mod rsqrt;
+mod rsqrtbf16;
+mod rsqrtf;
+mod scalbln;
+mod scalblnf;
 mod scalbn;
 mod scalbnf;
+mod scalbnf128;
+mod scalbnf80;
+mod setpayload;
+mod setpayloadf;
+mod sigmoidbf16;
+mod significand;
+mod significandf;
 mod sin;
 mod sincos;
 mod sincosf;
+mod sincospi;
+mod sincospif;
 mod sinf;
+mod sinf128;
+mod sinf16;
+mod sinf80;
 mod sinh;
 mod sinhf;
+mod sinpi;
+mod sinpif;
 mod sqrt;
+mod sqrt_rm;
+mod sqrtbf16;
 mod sqrtf;
+mod sqrtf128;
+mod sqrtf16;
+mod sqrtf80;
+mod sqrtf_rm;
+mod sub_rm;
+mod subf_rm;
 mod tan;
 mod tanf;
+mod tanf80;
 mod tanh;
+mod tanhbf16;
 mod tanhf;
+mod tanhf16;
+mod tanpi;
+mod tanpif;
 mod tgamma;
 mod tgammaf;
+mod totalorder;
+mod totalorderf;
+mod totalordermag;
+mod totalordermagf;
 mod trunc;
 mod truncf;
+mod truncf128;
+mod truncf16;
+mod truncf80;
 
 // Use separated imports instead of {}-grouped imports for easier merging.
 pub use self::acos::acos;
 pub use self::acosf::acosf;
 pub use self::acosh::acosh;
 pub use self::acoshf::acoshf;
+pub use self::acospi::acospi;
+pub use self::acospif::acospif;
+pub use self::add_rm::add_rm;
+pub use self::addf_rm::addf_rm;
 pub use self::asin::asin;
 pub use self::asinf::asinf;
 pub use self::asinh::asinh;
 pub use self::asinhf::asinhf;
+pub use self::asinpi::asinpi;
+pub use self::asinpif::asinpif;
 pub use self::atan::atan;
 pub use self::atan2::atan2;
 pub use self::atan2f::atan2f;
+pub use self::atan2pi::atan2pi;
+pub use self::atan2pif::atan2pif;
 pub use self::atanf::atanf;
 pub use self::atanh::atanh;
 pub use self::atanhf::atanhf;
+pub use self::atanpi::atanpi;
+pub use self::atanpif::atanpif;
+pub use self::beta::beta;
+pub use self::beta::lbeta;
+pub use self::betaf::betaf;
+pub use self::betaf::lbetaf;
+pub use self::betainc::betainc;
+pub use self::betainc_inv::betainc_inv;
+pub use self::betainc_invf::betainc_invf;
+pub use self::betaincf::betaincf;
+pub use self::bfloat16::bf16;
+pub use self::canonicalize::canonicalize;
+pub use self::canonicalizef::canonicalizef;
 pub use self::cbrt::cbrt;
 pub use self::cbrtf::cbrtf;
 pub use self::ceil::ceil;
 pub use self::ceilf::ceilf;
+pub use self::ceilf128::ceilf128;
+pub use self::ceilf16::ceilf16;
+pub use self::ceilf80::ceilf80;
+pub use self::compoundn::compoundn;
+pub use self::compoundnf::compoundnf;
 pub use self::copysign::copysign;
 pub use self::copysignf::copysignf;
 pub use self::cos::cos;
 pub use self::cosf::cosf;
+pub use self::cosf128::cosf128;
+pub use self::cosf16::cosf16;
+pub use self::cosf80::cosf80;
 pub use self::cosh::cosh;
 pub use self::coshf::coshf;
+pub use self::cospi::cospi;
+pub use self::cospif::cospif;
+pub use self::cr_acosf::cr_acosf;
+pub use self::cr_acoshf::cr_acoshf;
+pub use self::cr_asinf::cr_asinf;
+pub use self::cr_asinhf::cr_asinhf;
+pub use self::cr_atan::cr_atan;
+pub use self::cr_atan2f::cr_atan2f;
+pub use self::cr_atanf::cr_atanf;
+pub use self::cr_atanhf::cr_atanhf;
+pub use self::cr_cbrtf::cr_cbrtf;
+pub use self::cr_cos::cr_cos;
+pub use self::cr_cosf::cr_cosf;
+pub use self::cr_cosh::cr_cosh;
+pub use self::cr_coshf::cr_coshf;
+pub use self::cr_erfcf::cr_erfcf;
+pub use self::cr_erff::cr_erff;
+pub use self::cr_exp::cr_exp;
+pub use self::cr_exp10f::cr_exp10f;
+pub use self::cr_exp2::cr_exp2;
+pub use self::cr_exp2f::cr_exp2f;
+pub use self::cr_expf::cr_expf;
+pub use self::cr_expm1::cr_expm1;
+pub use self::cr_expm1f::cr_expm1f;
+pub use self::cr_hypotf::cr_hypotf;
+pub use self::cr_lgammaf::cr_lgammaf;
+pub use self::cr_log::cr_log;
+pub use self::cr_log10f::cr_log10f;
+pub use self::cr_log1p::cr_log1p;
+pub use self::cr_log1pf::cr_log1pf;
+pub use self::cr_log2::cr_log2;
+pub use self::cr_log2f::cr_log2f;
+pub use self::cr_logf::cr_logf;
+pub use self::cr_pow::cr_pow;
+pub use self::cr_powf::cr_powf;
+pub use self::cr_sin::cr_sin;
+pub use self::cr_sinf::cr_sinf;
+pub use self::cr_sinh::cr_sinh;
+pub use self::cr_sinhf::cr_sinhf;
+pub use self::cr_tan::cr_tan;
+pub use self::cr_tanf::cr_tanf;
+pub use self::cr_tanhf::cr_tanhf;
+pub use self::cr_tgammaf::cr_tgammaf;
+pub use self::digamma::digamma;
+pub use self::digammaf::digammaf;
+pub use self::div_rm::div_rm;
+pub use self::divf_rm::divf_rm;
 pub use self::erf::erf;
 pub use self::erf::erfc;
+pub use self::erfbf16::erfbf16;
 pub use self::erff::erfcf;
 pub use self::erff::erff;
+pub use self::erff16::erff16;
 pub use self::exp::exp;
 pub use self::exp10::exp10;
 pub use self::exp10f::exp10f;
+pub use self::exp10m1::exp10m1;
+pub use self::exp10m1f::exp10m1f;
 pub use self::exp2::exp2;
 pub use self::exp2f::exp2f;
+pub use self::exp2m1::exp2m1;
+pub use self::exp2m1f::exp2m1f;
+pub use self::expbf16::expbf16;
 pub use self::expf::expf;
+pub use self::expf128::expf128;
+pub use self::expf16::expf16;
+pub use self::expf80::expf80;
 pub use self::expm1::expm1;
 pub use self::expm1f::expm1f;
 pub use self::fabs::fabs;
 pub use self::fabsf::fabsf;
 pub use self::fdim::fdim;
 pub use self::fdimf::fdimf;
+pub use self::fenv::feclearexcept;
+pub use self::fenv::feraiseexcept;
+pub use self::fenv::fetestexcept;
+pub use self::fenv::FE_ALL_EXCEPT;
+pub use self::fenv::FE_DIVBYZERO;
+pub use self::fenv::FE_INEXACT;
+pub use self::fenv::FE_INVALID;
+pub use self::fenv::FE_OVERFLOW;
+pub use self::fenv::FE_UNDERFLOW;
+pub use self::float128::f128;
+pub use self::float16::f16;
+pub use self::float80::f80;
 pub use self::floor::floor;
 pub use self::floorf::floorf;
+pub use self::floorf128::floorf128;
+pub use self::floorf16::floorf16;
+pub use self::floorf80::floorf80;
 pub use self::fma::fma;
+pub use self::fma_rm::fma_rm;
 pub use self::fmaf::fmaf;
+pub use self::fmaf128::fmaf128;
+pub use self::fmaf80::fmaf80;
+pub use self::fmaf_rm::fmaf_rm;
 pub use self::fmax::fmax;
 pub use self::fmaxf::fmaxf;
+pub use self::fmaximum::fmaximum;
+pub use self::fmaximum_mag::fmaximum_mag;
+pub use self::fmaximum_mag_num::fmaximum_mag_num;
+pub use self::fmaximum_mag_numf::fmaximum_mag_numf;
+pub use self::fmaximum_magf::fmaximum_magf;
+pub use self::fmaximum_num::fmaximum_num;
+pub use self::fmaximum_numf::fmaximum_numf;
+pub use self::fmaximumf::fmaximumf;
 pub use self::fmin::fmin;
 pub use self::fminf::fminf;
+pub use self::fminimum::fminimum;
+pub use self::fminimum_mag::fminimum_mag;
+pub use self::fminimum_mag_num::fminimum_mag_num;
+pub use self::fminimum_mag_numf::fminimum_mag_numf;
+pub use self::fminimum_magf::fminimum_magf;
+pub use self::fminimum_num::fminimum_num;
+pub use self::fminimum_numf::fminimum_numf;
+pub use self::fminimumf::fminimumf;
 pub use self::fmod::fmod;
 pub use self::fmodf::fmodf;
 pub use self::frexp::frexp;
 pub use self::frexpf::frexpf;
+pub use self::frexpf80::frexpf80;
+pub use self::fromfp::fromfp;
+pub use self::fromfp::ufromfp;
+pub use self::fromfp::FP_INT_DOWNWARD;
+pub use self::fromfp::FP_INT_TONEAREST;
+pub use self::fromfp::FP_INT_TONEARESTFROMZERO;
+pub use self::fromfp::FP_INT_TOWARDZERO;
+pub use self::fromfp::FP_INT_UPWARD;
+pub use self::fromfpf::fromfpf;
+pub use self::fromfpf::ufromfpf;
+pub use self::gamma_p::gamma_p;
+pub use self::gamma_p::gamma_q;
+pub use self::gamma_p_inv::gamma_p_inv;
+pub use self::gamma_p_inv::gamma_q_inv;
+pub use self::gamma_p_invf::gamma_p_invf;
+pub use self::gamma_p_invf::gamma_q_invf;
+pub use self::gamma_pf::gamma_pf;
+pub use self::gamma_pf::gamma_qf;
+pub use self::getpayload::getpayload;
+pub use self::getpayloadf::getpayloadf;
 pub use self::hypot::hypot;
 pub use self::hypotf::hypotf;
+pub use self::i0::i0;
+pub use self::i0::i0e;
+pub use self::i0::k0;
+pub use self::i0::k0e;
+pub use self::i0f::i0ef;
+pub use self::i0f::i0f;
+pub use self::i0f::k0ef;
+pub use self::i0f::k0f;
+pub use self::i1::i1;
+pub use self::i1::i1e;
+pub use self::i1::k1;
+pub use self::i1::k1e;
+pub use self::i1f::i1ef;
+pub use self::i1f::i1f;
+pub use self::i1f::k1ef;
+pub use self::i1f::k1f;
 pub use self::ilogb::ilogb;
+pub use self::ilogb::FP_ILOGB0;
+pub use self::ilogb::FP_ILOGBNAN;
 pub use self::ilogbf::ilogbf;
+pub use self::in_::in_;
+pub use self::in_::kn;
+pub use self::in_f::in_f;
+pub use self::in_f::knf;
+pub use self::iv::iv;
+pub use self::iv::kv;
+pub use self::ivf::ivf;
+pub use self::ivf::kvf;
 pub use self::j0::j0;
 pub use self::j0::y0;
 pub use self::j0f::j0f;
@@ -242,77 +643,210 @@ pub use self::jn::jn;
 pub use self::jn::yn;
 pub use self::jnf::jnf;
 pub use self::jnf::ynf;
+pub use self::jv::jv;
+pub use self::jv::yv;
+pub use self::jvf::jvf;
+pub use self::jvf::yvf;
 pub use self::ldexp::ldexp;
+pub use self::ldexp_rm::ldexp_rm;
 pub use self::ldexpf::ldexpf;
+pub use self::ldexpf80::ldexpf80;
+pub use self::ldexpf_rm::ldexpf_rm;
 pub use self::lgamma::lgamma;
 pub use self::lgamma_r::lgamma_r;
 pub use self::lgammaf::lgammaf;
 pub use self::lgammaf_r::lgammaf_r;
+pub use self::llogb::llogb;
+pub use self::llogb::FP_LLOGB0;
+pub use self::llogb::FP_LLOGBNAN;
+pub use self::llogbf::llogbf;
+pub use self::llrint::llrint;
+pub use self::llrintf::llrintf;
+pub use self::llround::llround;
+pub use self::llroundf::llroundf;
 pub use self::log::log;
 pub use self::log10::log10;
 pub use self::log10f::log10f;
+pub use self::log10p1::log10p1;
+pub use self::log10p1f::log10p1f;
 pub use self::log1p::log1p;
 pub use self::log1pf::log1pf;
 pub use self::log2::log2;
 pub use self::log2f::log2f;
+pub use self::log2p1::log2p1;
+pub use self::log2p1f::log2p1f;
+pub use self::logb::logb;
+pub use self::logbf::logbf;
+pub use self::logbf16::logbf16;
 pub use self::logf::logf;
+pub use self::logf128::logf128;
+pub use self::logf16::logf16;
+pub use self::logf80::logf80;
+pub use self::lrint::lrint;
+pub use self::lrintf::lrintf;
+pub use self::lround::lround;
+pub use self::lroundf::lroundf;
 pub use self::modf::modf;
 pub use self::modff::modff;
+pub use self::mul_rm::mul_rm;
+pub use self::mulf_rm::mulf_rm;
+pub use self::nan::nan;
+pub use self::nanf::nanf;
+pub use self::nearbyint::nearbyint;
+pub use self::nearbyintf::nearbyintf;
+pub use self::nextafter::nextafter;
+pub use self::nextafterf::nextafterf;
+pub use self::nextdown::nextdown;
+pub use self::nextdownf::nextdownf;
+pub use self::nexttowardf::nexttowardf;
+pub use self::nextup::nextup;
+pub use self::nextupf::nextupf;
+pub use self::polygamma::polygamma;
+pub use self::polygamma::trigamma;
+pub use self::polygammaf::polygammaf;
+pub use self::polygammaf::trigammaf;
 pub use self::pow::pow;
 pub use self::powf::powf;
+pub use self::powf128::powf128;
+pub use self::powf80::powf80;
+pub use self::pown::pown;
+pub use self::pownf::pownf;
+pub use self::powr::powr;
+pub use self::powrf::powrf;
 pub use self::remainder::remainder;
 pub use self::remainderf::remainderf;
 pub use self::remquo::remquo;
 pub use self::remquof::remquof;
+pub use self::rint::rint;
+pub use self::rintf::rintf;
+pub use self::rintf128::rintf128;
+pub use self::rintf16::rintf16;
+pub use self::rintf80::rintf80;
+pub use self::rootn::rootn;
+pub use self::rootnf::rootnf;
 pub use self::round::round;
+pub use self::roundeven::roundeven;
+pub use self::roundevenf::roundevenf;
 pub use self::roundf::roundf;
+pub use self::roundf128::roundf128;
+pub use self::roundf16::roundf16;
+pub use self::roundf80::roundf80;
+pub use self::rounding_mode::RoundingMode;
+pub use self::rsqrt::rsqrt;
+pub use self::rsqrtbf16::rsqrtbf16;
+pub use self::rsqrtf::rsqrtf;
+pub use self::scalbln::scalbln;
+pub use self::scalblnf::scalblnf;
 pub use self::scalbn::scalbn;
 pub use self::scalbnf::scalbnf;
+pub use self::scalbnf128::scalbnf128;
+pub use self::scalbnf80::scalbnf80;
+pub use self::setpayload::setpayload;
+pub use self::setpayload::setpayloadsig;
+pub use self::setpayloadf::setpayloadf;
+pub use self::setpayloadf::setpayloadsigf;
+pub use self::sigmoidbf16::sigmoidbf16;
+pub use self::significand::significand;
+pub use self::significandf::significandf;
 pub use self::sin::sin;
 pub use self::sincos::sincos;
 pub use self::sincosf::sincosf;
+pub use self::sincospi::sincospi;
+pub use self::sincospif::sincospif;
 pub use self::sinf::sinf;
+pub use self::sinf128::sinf128;
+pub use self::sinf16::sinf16;
+pub use self::sinf80::sinf80;
 pub use self::sinh::sinh;
 pub use self::sinhf::sinhf;
+pub use self::sinpi::sinpi;
+pub use self::sinpif::sinpif;
 pub use self::sqrt::sqrt;
+pub use self::sqrt_rm::sqrt_rm;
+pub use self::sqrtbf16::sqrtbf16;
 pub use self::sqrtf::sqrtf;
+pub use self::sqrtf128::sqrtf128;
+pub use self::sqrtf16::sqrtf16;
+pub use self::sqrtf80::sqrtf80;
+pub use self::sqrtf_rm::sqrtf_rm;
+pub use self::sub_rm::sub_rm;
+pub use self::subf_rm::subf_rm;
 pub use self::tan::tan;
 pub use self::tanf::tanf;
+pub use self::tanf80::tanf80;
 pub use self::tanh::tanh;
+pub use self::tanhbf16::tanhbf16;
 pub use self::tanhf::tanhf;
+pub use self::tanhf16::tanhf16;
+pub use self::tanpi::tanpi;
+pub use self::tanpif::tanpif;
 pub use self::tgamma::tgamma;
 pub use self::tgammaf::tgammaf;
+pub use self::totalorder::totalorder;
+pub use self::totalorderf::totalorderf;
+pub use self::totalordermag::totalordermag;
+pub use self::totalordermagf::totalordermagf;
 pub use self::trunc::trunc;
 pub use self::truncf::truncf;
+pub use self::truncf128::truncf128;
+pub use self::truncf16::truncf16;
+pub use self::truncf80::truncf80;
 
 // Private modules
+mod crf32;
+mod double_double;
 mod expo2;
 mod fenv;
 mod k_cos;
 mod k_cosf;
+mod k_cosf128;
+mod k_cospi;
+mod k_cratan;
+mod k_crexp;
+mod k_crlog;
+mod k_crsin;
+mod k_gamma;
 mod k_expo2;
 mod k_expo2f;
+mod k_lrint;
 mod k_sin;
 mod k_sinf;
+mod k_sinf128;
+mod k_sinpi;
 mod k_tan;
 mod k_tanf;
 mod rem_pio2;
+mod rem_pio2_cr;
 mod rem_pio2_large;
 mod rem_pio2f;
+mod rem_pio2f128;
+mod rem_pio2f80;
+mod soft_float;
+mod soft_math;
+#[cfg(test)]
+mod testutil;
 
 // Private re-imports
 use self::expo2::expo2;
+#[cfg(feature = "soft-fenv")]
+pub(crate) use self::fenv::force_eval;
 use self::k_cos::k_cos;
 use self::k_cosf::k_cosf;
+use self::k_cosf128::k_cosf128;
+use self::k_cospi::k_cospi;
 use self::k_expo2::k_expo2;
 use self::k_expo2f::k_expo2f;
 use self::k_sin::k_sin;
 use self::k_sinf::k_sinf;
+use self::k_sinf128::k_sinf128;
+use self::k_sinpi::k_sinpi;
 use self::k_tan::k_tan;
 use self::k_tanf::k_tanf;
 use self::rem_pio2::rem_pio2;
 use self::rem_pio2_large::rem_pio2_large;
 use self::rem_pio2f::rem_pio2f;
+use self::rem_pio2f128::rem_pio2f128;
+use self::rem_pio2f80::rem_pio2f80;
 
 #[inline]
 fn get_high_word(x: f64) -> u32 {
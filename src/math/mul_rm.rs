@@ -0,0 +1,47 @@
+use super::soft_float::{self, Soft};
+use super::RoundingMode;
+
+/// Multiplication with explicit rounding (f64)
+///
+/// Returns `x * y`, correctly rounded in the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn mul_rm(x: f64, y: f64, rm: RoundingMode) -> f64 {
+    soft_float::mul(Soft::from_f64(x), Soft::from_f64(y)).to_f64_rm(rm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fma, mulf_rm, nextafter};
+    use super::mul_rm;
+    use super::RoundingMode::*;
+
+    #[test]
+    fn directed() {
+        for i in 0..20000u64 {
+            let h = i.wrapping_mul(0x9e3779b97f4a7c15);
+            let a = f64::from_bits(h & 0x800fffffffffffff | (0x3c0 + h % 127) << 52);
+            let b = f64::from_bits(h.rotate_left(29) & 0x800fffffffffffff | 0x3ff << 52);
+            /* the rounding error of the product, exactly */
+            let p = a * b;
+            let err = fma(a, b, -p);
+            let up = if err > 0.0 {
+                nextafter(p, 1.0 / 0.0)
+            } else {
+                p
+            };
+            let down = if err < 0.0 {
+                nextafter(p, -1.0 / 0.0)
+            } else {
+                p
+            };
+            let zero = if p > 0.0 { down } else { up };
+            assert_eq!(mul_rm(a, b, TowardPositive), up, "{:e} * {:e}", a, b);
+            assert_eq!(mul_rm(a, b, TowardNegative), down, "{:e} * {:e}", a, b);
+            assert_eq!(mul_rm(a, b, TowardZero), zero, "{:e} * {:e}", a, b);
+        }
+        assert_eq!(mul_rm(1e-200, 1e-200, TowardPositive).to_bits(), 1);
+        assert_eq!(mul_rm(-1e-200, 1e-200, TowardPositive).to_bits(), 1 << 63);
+        assert_eq!(mulf_rm(1e30, 1e30, TowardZero), core::f32::MAX);
+    }
+}
@@ -0,0 +1,72 @@
+/// Parses the payload of a `nan` tag like `strtoull(tagp, _, 0)` does.
+///
+/// The tag is read as a decimal, octal (leading `0`) or hexadecimal (leading
+/// `0x`) integer, saturating on overflow. A tag that is empty or isn't entirely
+/// made of such an integer gives a zero payload.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn nan_payload(tagp: &str) -> u64 {
+    let mut s = tagp.as_bytes();
+    let mut radix = 10;
+    if s.len() > 2 && s[0] == b'0' && (s[1] == b'x' || s[1] == b'X') {
+        radix = 16;
+        s = &s[2..];
+    } else if s.len() > 1 && s[0] == b'0' {
+        radix = 8;
+        s = &s[1..];
+    }
+
+    let mut payload: u64 = 0;
+    for &c in s {
+        let d = match c {
+            b'0'..=b'9' => (c - b'0') as u64,
+            b'a'..=b'f' => (c - b'a' + 10) as u64,
+            b'A'..=b'F' => (c - b'A' + 10) as u64,
+            _ => return 0,
+        };
+        if d >= radix {
+            return 0;
+        }
+        payload = payload
+            .checked_mul(radix)
+            .and_then(|p| p.checked_add(d))
+            .unwrap_or(u64::max_value());
+    }
+    payload
+}
+
+/// Quiet NaN with a payload (f64)
+///
+/// Returns a quiet NaN whose payload is given by the string `tagp`, as
+/// `strtod("NAN(tagp)")` would. The tag is parsed as an integer in C syntax
+/// and truncated to the 51 payload bits; any other tag gives the default NaN.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nan(tagp: &str) -> f64 {
+    f64::from_bits(0x7ff8_0000_0000_0000 | (nan_payload(tagp) & 0x0007_ffff_ffff_ffff))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nan;
+
+    #[test]
+    fn payloads() {
+        assert_eq!(nan("").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("42").to_bits(), 0x7ff8_0000_0000_002a);
+        assert_eq!(nan("0x2A").to_bits(), 0x7ff8_0000_0000_002a);
+        assert_eq!(nan("052").to_bits(), 0x7ff8_0000_0000_002a);
+        assert_eq!(nan("0").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("0xfffffffffffff").to_bits(), 0x7fff_ffff_ffff_ffff);
+        assert_eq!(nan("99999999999999999999").to_bits(), 0x7fff_ffff_ffff_ffff);
+    }
+
+    #[test]
+    fn invalid_tags() {
+        assert_eq!(nan("abc").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("08").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("0x").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("-1").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("1 ").to_bits(), 0x7ff8_0000_0000_0000);
+    }
+}
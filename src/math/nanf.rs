@@ -0,0 +1,12 @@
+use super::nan::nan_payload;
+
+/// Quiet NaN with a payload (f32)
+///
+/// Returns a quiet NaN whose payload is given by the string `tagp`, as
+/// `strtof("NAN(tagp)")` would. The tag is parsed as an integer in C syntax
+/// and truncated to the 22 payload bits; any other tag gives the default NaN.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nanf(tagp: &str) -> f32 {
+    f32::from_bits(0x7fc0_0000 | (nan_payload(tagp) & 0x003f_ffff) as u32)
+}
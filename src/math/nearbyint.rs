@@ -0,0 +1,59 @@
+use super::fenv::{feclearexcept, fetestexcept, FE_INEXACT};
+use super::rint;
+
+/// Round to integral value without inexact (f64)
+///
+/// Rounds `x` to an integer in the current rounding direction, like `rint`,
+/// but never raises the inexact exception.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nearbyint(x: f64) -> f64 {
+    let e = fetestexcept(FE_INEXACT);
+    let y = rint(x);
+    if e == 0 {
+        feclearexcept(FE_INEXACT);
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nearbyint;
+
+    #[test]
+    fn ties_to_even() {
+        assert_eq!(nearbyint(0.5), 0.0);
+        assert_eq!(nearbyint(1.5), 2.0);
+        assert_eq!(nearbyint(2.5), 2.0);
+        assert_eq!(nearbyint(-2.5), -2.0);
+        assert_eq!(nearbyint(-3.5), -4.0);
+        assert_eq!(nearbyint(4503599627370495.5), 4503599627370496.0);
+    }
+
+    #[test]
+    fn negative_zero() {
+        assert_eq!(nearbyint(-0.25).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(nearbyint(-0.5).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(nearbyint(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(nearbyint(0.0).to_bits(), 0);
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{feclearexcept, feraiseexcept, fetestexcept, FE_ALL_EXCEPT, FE_INEXACT};
+    use super::nearbyint;
+
+    #[test]
+    fn inexact() {
+        for &x in [2.5, -0.25, 3.0, -0.0].iter() {
+            assert_eq!(raised(nearbyint, x), 0, "nearbyint({:?})", x);
+        }
+        /* an inexact raised before the call is kept */
+        feclearexcept(FE_ALL_EXCEPT);
+        feraiseexcept(FE_INEXACT);
+        nearbyint(2.5);
+        assert_eq!(fetestexcept(FE_ALL_EXCEPT), FE_INEXACT);
+    }
+}
@@ -0,0 +1,59 @@
+use super::fenv::{feclearexcept, fetestexcept, FE_INEXACT};
+use super::rintf;
+
+/// Round to integral value without inexact (f32)
+///
+/// Rounds `x` to an integer in the current rounding direction, like `rintf`,
+/// but never raises the inexact exception.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nearbyintf(x: f32) -> f32 {
+    let e = fetestexcept(FE_INEXACT);
+    let y = rintf(x);
+    if e == 0 {
+        feclearexcept(FE_INEXACT);
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nearbyintf;
+
+    #[test]
+    fn ties_to_even() {
+        assert_eq!(nearbyintf(0.5), 0.0);
+        assert_eq!(nearbyintf(1.5), 2.0);
+        assert_eq!(nearbyintf(2.5), 2.0);
+        assert_eq!(nearbyintf(-2.5), -2.0);
+        assert_eq!(nearbyintf(-3.5), -4.0);
+        assert_eq!(nearbyintf(8388607.5), 8388608.0);
+    }
+
+    #[test]
+    fn negative_zero() {
+        assert_eq!(nearbyintf(-0.25).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(nearbyintf(-0.5).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(nearbyintf(-0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(nearbyintf(0.0).to_bits(), 0);
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{feclearexcept, feraiseexcept, fetestexcept, FE_ALL_EXCEPT, FE_INEXACT};
+    use super::nearbyintf;
+
+    #[test]
+    fn inexact() {
+        for &x in [2.5, -0.25, 3.0, -0.0].iter() {
+            assert_eq!(raised(nearbyintf, x), 0, "nearbyintf({:?})", x);
+        }
+        /* an inexact raised before the call is kept */
+        feclearexcept(FE_ALL_EXCEPT);
+        feraiseexcept(FE_INEXACT);
+        nearbyintf(2.5);
+        assert_eq!(fetestexcept(FE_ALL_EXCEPT), FE_INEXACT);
+    }
+}
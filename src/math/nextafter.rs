@@ -0,0 +1,76 @@
+/// Next representable value (f64)
+///
+/// Returns the next representable value after `x` in the direction of `y`.
+/// If `x` equals `y`, `y` is returned. Raises overflow if the result is
+/// infinite and `x` is finite, and underflow if it is subnormal or zero.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextafter(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    let mut ux_i = x.to_bits();
+    let uy_i = y.to_bits();
+    if ux_i == uy_i {
+        return y;
+    }
+
+    let ax = ux_i & 0x7fff_ffff_ffff_ffff;
+    let ay = uy_i & 0x7fff_ffff_ffff_ffff;
+    if ax == 0 {
+        if ay == 0 {
+            return y;
+        }
+        ux_i = (uy_i & 1_u64 << 63) | 1;
+    } else if ax > ay || ((ux_i ^ uy_i) & 1_u64 << 63) != 0 {
+        ux_i -= 1;
+    } else {
+        ux_i += 1;
+    }
+
+    let e = ux_i >> 52 & 0x7ff;
+    // raise overflow if ux_f is infinite and x is finite
+    if e == 0x7ff {
+        force_eval!(x + x);
+    }
+    let ux_f = f64::from_bits(ux_i);
+    // raise underflow if ux_f is subnormal or zero
+    if e == 0 {
+        force_eval!(x * x + ux_f * ux_f);
+    }
+    ux_f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nextafter;
+    use core::f64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(nextafter(1.0, 2.0), 1.0000000000000002);
+        assert_eq!(nextafter(1.0, 0.0), 0.9999999999999999);
+        assert_eq!(nextafter(-1.0, 0.0), -0.9999999999999999);
+        assert_eq!(nextafter(f64::MAX, f64::INFINITY), f64::INFINITY);
+        assert_eq!(nextafter(f64::INFINITY, 0.0), f64::MAX);
+        assert!(nextafter(f64::NAN, 1.0).is_nan());
+        assert!(nextafter(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn subnormals_and_zeros() {
+        let min = f64::from_bits(1);
+        assert_eq!(nextafter(0.0, 1.0), min);
+        assert_eq!(nextafter(0.0, -1.0), -min);
+        assert_eq!(nextafter(-0.0, 1.0), min);
+        assert_eq!(nextafter(min, 0.0).to_bits(), 0);
+        assert_eq!(nextafter(-min, 0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(nextafter(0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(nextafter(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(
+            nextafter(f64::MIN_POSITIVE, 0.0),
+            f64::from_bits(0x000fffffffffffff)
+        );
+    }
+}
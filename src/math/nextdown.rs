@@ -0,0 +1,11 @@
+use super::nextup;
+
+/// Next representable value toward negative infinity (f64)
+///
+/// Returns the greatest floating-point number less than `x`. This is
+/// `-nextup(-x)`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextdown(x: f64) -> f64 {
+    -nextup(-x)
+}
@@ -0,0 +1,11 @@
+use super::nextupf;
+
+/// Next representable value toward negative infinity (f32)
+///
+/// Returns the greatest floating-point number less than `x`. This is
+/// `-nextupf(-x)`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextdownf(x: f32) -> f32 {
+    -nextupf(-x)
+}
@@ -0,0 +1,63 @@
+/// Next representable value toward a wider value (f32)
+///
+/// Returns the next representable `f32` after `x` in the direction of the
+/// `f64` value `y`, without rounding `y` to `f32` first. If `x` equals `y`,
+/// `y` is returned. Raises overflow if the result is infinite and `x` is
+/// finite, and underflow if it is subnormal or zero.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nexttowardf(x: f32, y: f64) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return (x as f64 + y) as f32;
+    }
+    if x as f64 == y {
+        return y as f32;
+    }
+
+    let mut ux_i = x.to_bits();
+    if x == 0. {
+        ux_i = 1;
+        if y.is_sign_negative() {
+            ux_i |= 0x8000_0000_u32;
+        }
+    } else if (x as f64) < y {
+        if x.is_sign_negative() {
+            ux_i -= 1;
+        } else {
+            ux_i += 1;
+        }
+    } else if x.is_sign_negative() {
+        ux_i += 1;
+    } else {
+        ux_i -= 1;
+    }
+
+    let e = ux_i & 0x7f80_0000_u32;
+    // raise overflow if ux_f is infinite and x is finite
+    if e == 0x7f80_0000_u32 {
+        force_eval!(x + x);
+    }
+    let ux_f = f32::from_bits(ux_i);
+    // raise underflow if ux_f is subnormal or zero
+    if e == 0 {
+        force_eval!(x * x + ux_f * ux_f);
+    }
+    ux_f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nexttowardf;
+    use core::{f32, f64};
+
+    #[test]
+    fn sanity_check() {
+        // 1 + 2^-30 is not an f32, but still pulls 1.0 upward
+        assert_eq!(nexttowardf(1.0, 1.0000000009313226), 1.0000001);
+        assert_eq!(nexttowardf(1.0, 0.9999999999999999), 0.99999994);
+        assert_eq!(nexttowardf(0.0, -1e-300), -f32::from_bits(1));
+        assert_eq!(nexttowardf(f32::MAX, 1e39), f32::INFINITY);
+        assert_eq!(nexttowardf(f32::INFINITY, f64::INFINITY), f32::INFINITY);
+        assert!(nexttowardf(1.0, f64::NAN).is_nan());
+    }
+}
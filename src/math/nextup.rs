@@ -0,0 +1,48 @@
+/// Next representable value toward positive infinity (f64)
+///
+/// Returns the least floating-point number greater than `x`. The result of
+/// `nextup(f64::INFINITY)` is infinity and the result of `nextup(-0.0)` and
+/// `nextup(0.0)` is the smallest positive subnormal. No exception is raised
+/// for a finite `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextup(x: f64) -> f64 {
+    let mut ui = x.to_bits();
+    let a = ui & 0x7fff_ffff_ffff_ffff;
+
+    if a > 0x7ff0_0000_0000_0000 {
+        // quiet a signaling NaN
+        return x + x;
+    }
+    if ui == 0x7ff0_0000_0000_0000 {
+        return x;
+    }
+    if a == 0 {
+        return f64::from_bits(1);
+    }
+    if ui >> 63 == 0 {
+        ui += 1;
+    } else {
+        ui -= 1;
+    }
+    f64::from_bits(ui)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nextup;
+    use core::f64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(nextup(1.0), 1.0000000000000002);
+        assert_eq!(nextup(-1.0), -0.9999999999999999);
+        assert_eq!(nextup(0.0), f64::from_bits(1));
+        assert_eq!(nextup(-0.0), f64::from_bits(1));
+        assert_eq!(nextup(-f64::from_bits(1)).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(nextup(f64::MAX), f64::INFINITY);
+        assert_eq!(nextup(f64::INFINITY), f64::INFINITY);
+        assert_eq!(nextup(f64::NEG_INFINITY), f64::MIN);
+        assert!(nextup(f64::NAN).is_nan());
+    }
+}
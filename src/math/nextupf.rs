@@ -0,0 +1,29 @@
+/// Next representable value toward positive infinity (f32)
+///
+/// Returns the least floating-point number greater than `x`. The result of
+/// `nextupf(f32::INFINITY)` is infinity and the result of `nextupf(-0.0)` and
+/// `nextupf(0.0)` is the smallest positive subnormal. No exception is raised
+/// for a finite `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextupf(x: f32) -> f32 {
+    let mut ui = x.to_bits();
+    let a = ui & 0x7fff_ffff;
+
+    if a > 0x7f80_0000 {
+        // quiet a signaling NaN
+        return x + x;
+    }
+    if ui == 0x7f80_0000 {
+        return x;
+    }
+    if a == 0 {
+        return f32::from_bits(1);
+    }
+    if ui >> 31 == 0 {
+        ui += 1;
+    } else {
+        ui -= 1;
+    }
+    f32::from_bits(ui)
+}
@@ -0,0 +1,251 @@
+/* The polygamma functions psi^(n)(x), the n-th derivatives of digamma(x).
+ *
+ * For n >= 1, psi^(n)(x) = (-1)^(n+1)*n!*zeta(n + 1, x) with the Hurwitz
+ * zeta function, which is summed with the Euler-Maclaurin formula
+ *
+ *   zeta(s, x) = sum of (x + k)^-s for k < N + w^(1-s)/(s - 1) + w^-s/2
+ *                + sum of B_2j/(2j)!*s*(s + 1)*...*(s + 2j - 2)*w^(1-s-2j)
+ *
+ * where w = x + N >= 10 + s/2, so that 20 terms of the last sum suffice.
+ * Negative x are reflected with
+ *
+ *   psi^(n)(x) = (-1)^n*psi^(n)(1 - x) - pi*d^n/dx^n cot(pi*x)
+ *
+ * where d^n/dx^n cot(pi*x) = pi^n*n!*Q_n(cot(pi*x)) for the polynomials
+ * Q_0(c) = c and Q_(n+1)(c) = -(1 + c^2)*Q_n'(c)/(n + 1), whose
+ * coefficients all have the same sign and stay small.
+ */
+
+use super::{ceil, digamma, exp, fabs, floor, lgamma_r, log, log1p, pow, tanpi, tgamma};
+
+const PI: f64 = 3.141592653589793238462643383279502884;
+const EPS: f64 = 1.1102230246251565e-16; /* 0x1p-53 */
+
+/* the largest n for which n! is finite, and beyond which the reflected
+ * part is zero or overflows */
+const MAXN: usize = 170;
+
+/* B_2j/(2j)!, j = 1, 2, ..., 20 */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const BERN: [f64; 20] = [
+    8.3333333333333333e-02, -1.3888888888888889e-03, 3.3068783068783069e-05,
+    -8.2671957671957672e-07, 2.0876756987868099e-08, -5.2841901386874932e-10,
+    1.3382536530684679e-11, -3.3896802963225827e-13, 8.5860620562778446e-15,
+    -2.1748686985580619e-16, 5.5090028283602295e-18, -1.3954464685812522e-19,
+    3.5347070396294675e-21, -8.9535174270375462e-23, 2.2679524523376831e-24,
+    -5.7447906688722024e-26, 1.4551724756148649e-27, -3.6859949406653103e-29,
+    9.3367342570950447e-31, -2.3650224157006300e-32,
+];
+
+/* n!/y^(n+1) for y > 0 */
+#[inline]
+fn scaled(n: i32, y: f64) -> f64 {
+    let s = (n + 1) as f64;
+    if n as usize <= MAXN {
+        let p = pow(y, s);
+        if p >= f64::MIN_POSITIVE && p.is_finite() {
+            return tgamma(s) / p;
+        }
+    }
+    exp(lgamma_r(s).0 - s * log(y))
+}
+
+/* n!*zeta(n + 1, x) for n >= 1 and x > 0 */
+#[inline]
+fn zeta(n: i32, x: f64) -> f64 {
+    let s = (n + 1) as f64;
+    let wmin = 10.0 + 0.5 * s;
+    let mut w = x;
+    let mut sum = 0.0;
+    /* the first two terms of the tail, (n-1)!/w^n, and n!/w^(n+1) */
+    let mut lead = 0.0;
+    let mut base = 0.0;
+    if x >= wmin {
+        lead = scaled(n - 1, x);
+        base = scaled(n, x);
+    } else {
+        let b = scaled(n, x);
+        if b.is_infinite() {
+            return b;
+        }
+        /* (x/(x + k))^s through log1p, which keeps the relative accuracy of
+         * the terms that are not negligible */
+        let m = ceil(wmin - x);
+        let mut k = 0.0;
+        while k < m {
+            let t = exp(-s * log1p(k / x));
+            sum += t;
+            if t * m <= 0.1 * EPS {
+                break;
+            }
+            k += 1.0;
+        }
+        sum *= b;
+        w = x + m;
+        let e = exp(-s * log1p(m / x));
+        if e != 0.0 {
+            base = b * e;
+            lead = base * w / n as f64;
+        }
+    }
+    if base == 0.0 {
+        return sum + lead;
+    }
+    /* the sum of B_2j/(2j)!*s*(s + 1)*...*(s + 2j - 2)/w^(2j-1) */
+    let z = 1.0 / (w * w);
+    let mut t = s / w;
+    let mut c = 0.5;
+    for (j, &b) in BERN.iter().enumerate() {
+        let term = b * t;
+        c += term;
+        if fabs(term) <= EPS * c {
+            break;
+        }
+        let j = j as f64;
+        t *= (s + 2.0 * j + 1.0) * (s + 2.0 * j + 2.0) * z;
+    }
+    sum + (lead + base * c)
+}
+
+/* pi*d^n/dx^n cot(pi*x) = pi^(n+1)*n!*Q_n(c) for n >= 1 and c = cot(pi*x) */
+#[inline]
+fn cot_deriv(n: i32, c: f64) -> f64 {
+    let odd = n & 1 == 1;
+    if n as usize > MAXN {
+        /* Q_n is odd for even n, and otherwise at least 2*(2/pi)^(n+1) */
+        if !odd && c == 0.0 {
+            return c;
+        }
+        return if odd || c < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        };
+    }
+    let deg = n as usize + 1;
+    let mut q = [0.0; MAXN + 3];
+    q[1] = 1.0;
+    for m in 0..n as usize {
+        let mut next = [0.0; MAXN + 3];
+        for j in (1..=m + 1).rev().step_by(2) {
+            let d = -(j as f64) * i!(q, j) / (m + 1) as f64;
+            i!(next, j - 1, +=, d);
+            i!(next, j + 1, +=, d);
+        }
+        q = next;
+    }
+    /* the products grow from left to right, so that they only overflow
+     * where the result does */
+    let s = deg as f64;
+    let mut h = 0.0;
+    if fabs(c) <= 1.0 {
+        for i in (0..=deg).rev() {
+            h = h * c + i!(q, i);
+        }
+        h * pow(PI, s) * tgamma(s)
+    } else {
+        /* Q_n(c)/c^(n+1), without overflow in the powers of c */
+        let u = 1.0 / c;
+        for i in 0..=deg {
+            h = h * u + i!(q, i);
+        }
+        h * pow(PI, s) * pow(c, s) * tgamma(s)
+    }
+}
+
+/// Polygamma function (f64)
+///
+/// Computes `psi^(n)(x)`, the `n`-th derivative of
+/// [`digamma`](fn.digamma.html), for `n >= 0`. It has poles at zero and the
+/// negative integers, where the result is positive infinity for odd `n`, a
+/// signed infinity at +0 and -0 for even `n`, and NaN otherwise.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn polygamma(n: i32, x: f64) -> f64 {
+    if n < 0 || x.is_nan() {
+        return f64::NAN;
+    }
+    if n == 0 {
+        return digamma(x);
+    }
+    let odd = n & 1 == 1;
+    if x > 0.0 {
+        /* psi^(n)(inf) is zero with the sign of (-1)^(n+1) */
+        let r = if x == f64::INFINITY { 0.0 } else { zeta(n, x) };
+        return if odd { r } else { -r };
+    }
+    if x == 0.0 {
+        return if odd { f64::INFINITY } else { -1.0 / x };
+    }
+    if x == f64::NEG_INFINITY {
+        return f64::NAN;
+    }
+    if x == floor(x) {
+        return if odd { f64::INFINITY } else { f64::NAN };
+    }
+    /* (-1)^n*psi^(n)(1 - x) is -n!*zeta(n + 1, 1 - x), and tanpi(x) is not
+     * 0 since x is not an integer */
+    let t = cot_deriv(n, 1.0 / tanpi(x));
+    if t.is_infinite() {
+        return -t;
+    }
+    -(zeta(n, 1.0 - x) + t)
+}
+
+/// Trigamma function (f64)
+///
+/// Computes `psi'(x)`, the derivative of [`digamma`](fn.digamma.html) and
+/// the second derivative of `lgamma(x)`. It is
+/// [`polygamma`](fn.polygamma.html) for `n = 1`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn trigamma(x: f64) -> f64 {
+    polygamma(1, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::digamma;
+    use super::super::testutil::close;
+    use super::{polygamma, trigamma};
+
+    #[test]
+    fn special_values() {
+        assert!(polygamma(-1, 1.0).is_nan());
+        assert!(polygamma(2, f64::NAN).is_nan());
+        assert!(trigamma(f64::NEG_INFINITY).is_nan());
+        assert!(polygamma(2, -3.0).is_nan());
+        assert_eq!(trigamma(-3.0), f64::INFINITY);
+        assert_eq!(trigamma(0.0), f64::INFINITY);
+        assert_eq!(polygamma(2, 0.0), f64::NEG_INFINITY);
+        assert_eq!(polygamma(2, -0.0), f64::INFINITY);
+        assert_eq!(trigamma(f64::INFINITY), 0.0);
+        assert!(polygamma(2, f64::INFINITY).is_sign_negative());
+        assert_eq!(polygamma(0, 2.5), digamma(2.5));
+    }
+
+    #[test]
+    fn values() {
+        /* (n, x, psi^(n)(x)) */
+        let cases = [
+            (1, 1.0, 1.6449340668482264),
+            (1, 0.5, 4.934802200544679),
+            (1, 1e-8, 1.0000000000000002e16),
+            (1, 7.25, 0.14787923315893217),
+            (1, 1e300, 1e-300),
+            (2, 1.0, -2.4041138063191885),
+            (3, 0.3, 743.1417646550498),
+            (5, 12.0, 0.00011820829051081491),
+            (20, 2.5, -10709198259.124132),
+            (100, 40.0, -0.00015834291085306513),
+            (1, -0.5, 8.934802200544679),
+            (1, -2.3, 14.725912160961292),
+            (2, -1.75, -124.21382135423057),
+            (7, -0.4, 7990618.245891215),
+            (30, -5.5, -16926495.147567917),
+        ];
+        for &(n, x, v) in cases.iter() {
+            assert!(close(polygamma(n, x), v, 1e-15), "polygamma({}, {})", n, x);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use super::polygamma::{polygamma, trigamma};
+
+/// Polygamma function (f32)
+///
+/// Computes `psi^(n)(x)`, the `n`-th derivative of
+/// [`digammaf`](fn.digammaf.html), for `n >= 0`, by evaluating
+/// [`polygamma`](fn.polygamma.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn polygammaf(n: i32, x: f32) -> f32 {
+    polygamma(n, x as f64) as f32
+}
+
+/// Trigamma function (f32)
+///
+/// Computes `psi'(x)`, the derivative of [`digammaf`](fn.digammaf.html), by
+/// evaluating [`trigamma`](fn.trigamma.html) in double precision.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn trigammaf(x: f32) -> f32 {
+    trigamma(x as f64) as f32
+}
@@ -57,6 +57,9 @@
 // compiler will convert from decimal to binary accurately enough
 // to produce the hexadecimal values shown.
 //
+#[cfg(feature = "soft-fenv")]
+use super::fenv::inexact;
+use super::fenv::{divzero, invalid, oflow, uflow};
 use super::{fabs, get_high_word, scalbn, sqrt, with_set_high_word, with_set_low_word};
 
 const BP: [f64; 2] = [1.0, 1.5];
@@ -177,12 +180,27 @@ pub fn pow(x: f64, y: f64) -> f64 {
 
         if iy == 0x3ff00000 {
             /* y is +-1 */
-            return if hy >= 0 { x } else { 1.0 / x };
+            if hy >= 0 {
+                return x;
+            }
+            let z = 1.0 / x;
+            if ix == 0 && lx == 0 {
+                return divzero(z);
+            }
+            #[cfg(feature = "soft-fenv")]
+            inexact(ix < 0x7ff00000 && !exact(x, y, 53, -1074));
+            return if z.is_infinite() { oflow(z) } else { z };
         }
 
         if hy == 0x40000000 {
             /* y is 2 */
-            return x * x;
+            let z = x * x;
+            #[cfg(feature = "soft-fenv")]
+            inexact((ix | lx as i32) != 0 && ix < 0x7ff00000 && !exact(x, y, 53, -1074));
+            if ix < 0x7ff00000 && z.is_infinite() {
+                return oflow(z);
+            }
+            return if z == 0.0 && x != 0.0 { uflow(z) } else { z };
         }
 
         if hy == 0x3fe00000 {
@@ -204,11 +222,14 @@ pub fn pow(x: f64, y: f64) -> f64 {
             if hy < 0 {
                 /* z = (1/|x|) */
                 z = 1.0 / z;
+                if ix == 0 {
+                    z = divzero(z);
+                }
             }
 
             if hx < 0 {
                 if ((ix - 0x3ff00000) | yisint) == 0 {
-                    z = (z - z) / (z - z); /* (-1)**non-int is NaN */
+                    z = invalid((z - z) / (z - z)); /* (-1)**non-int is NaN */
                 } else if yisint == 1 {
                     z = -z; /* (x<0)**odd = -(|x|**odd) */
                 }
@@ -222,7 +243,7 @@ pub fn pow(x: f64, y: f64) -> f64 {
     if hx < 0 {
         if yisint == 0 {
             /* (x<0)**(non-int) is NaN */
-            return (x - x) / (x - x);
+            return invalid((x - x) / (x - x));
         }
 
         if yisint == 1 {
@@ -231,33 +252,45 @@ pub fn pow(x: f64, y: f64) -> f64 {
         }
     }
 
+    /* x^y is inexact unless it is a double */
+    #[cfg(feature = "soft-fenv")]
+    inexact(!exact(x, y, 53, -1074));
+
     /* |y| is HUGE */
     if iy > 0x41e00000 {
         /* if |y| > 2**31 */
         if iy > 0x43f00000 {
             /* if |y| > 2**64, must o/uflow */
             if ix <= 0x3fefffff {
-                return if hy < 0 { HUGE * HUGE } else { TINY * TINY };
+                return if hy < 0 {
+                    oflow(HUGE * HUGE)
+                } else {
+                    uflow(TINY * TINY)
+                };
             }
 
             if ix >= 0x3ff00000 {
-                return if hy > 0 { HUGE * HUGE } else { TINY * TINY };
+                return if hy > 0 {
+                    oflow(HUGE * HUGE)
+                } else {
+                    uflow(TINY * TINY)
+                };
             }
         }
 
         /* over/underflow if x is not close to one */
         if ix < 0x3fefffff {
             return if hy < 0 {
-                s * HUGE * HUGE
+                oflow(s * HUGE * HUGE)
             } else {
-                s * TINY * TINY
+                uflow(s * TINY * TINY)
             };
         }
         if ix > 0x3ff00000 {
             return if hy > 0 {
-                s * HUGE * HUGE
+                oflow(s * HUGE * HUGE)
             } else {
-                s * TINY * TINY
+                uflow(s * TINY * TINY)
             };
         }
 
@@ -350,11 +383,11 @@ pub fn pow(x: f64, y: f64) -> f64 {
         /* z >= 1024 */
         if (j - 0x40900000) | i != 0 {
             /* if z > 1024 */
-            return s * HUGE * HUGE; /* overflow */
+            return oflow(s * HUGE * HUGE);
         }
 
         if p_l + OVT > z - p_h {
-            return s * HUGE * HUGE; /* overflow */
+            return oflow(s * HUGE * HUGE);
         }
     } else if (j & 0x7fffffff) >= 0x4090cc00 {
         /* z <= -1075 */
@@ -362,11 +395,11 @@ pub fn pow(x: f64, y: f64) -> f64 {
 
         if (((j as u32) - 0xc090cc00) | (i as u32)) != 0 {
             /* z < -1075 */
-            return s * TINY * TINY; /* underflow */
+            return uflow(s * TINY * TINY);
         }
 
         if p_l <= z - p_h {
-            return s * TINY * TINY; /* underflow */
+            return uflow(s * TINY * TINY);
         }
     }
 
@@ -409,6 +442,64 @@ pub fn pow(x: f64, y: f64) -> f64 {
     s * z
 }
 
+#[cfg(feature = "soft-fenv")]
+#[inline]
+fn is_integer(x: f64) -> bool {
+    (x.to_bits() >> 52 & 0x7ff) >= 0x3ff + 52 || x == x as i64 as f64
+}
+
+/* Whether x^y, for finite x != 0 and y, is a floating-point number of
+ * prec bits whose last bit is at least 2^emin. With |x| = m*2^e and m
+ * odd, x^y = m^y*2^(e*y) needs m^y to be an odd integer below 2^prec:
+ * either m = 1 and e*y is an integer, or y = n/2^q with m and e the
+ * 2^q-th powers of an odd integer and of 2. */
+#[cfg(feature = "soft-fenv")]
+#[inline]
+pub(crate) fn exact(x: f64, y: f64, prec: u32, emin: i32) -> bool {
+    let i = x.to_bits() & !(1 << 63);
+    let (mut m, mut e) = if i >> 52 == 0 {
+        (i, -1074)
+    } else {
+        (i & ((1 << 52) - 1) | 1 << 52, (i >> 52) as i32 - 1075)
+    };
+    let z = m.trailing_zeros();
+    m >>= z;
+    e += z as i32;
+
+    let mut y = y;
+    while m != 1 && !is_integer(y) {
+        /* x^y = sqrt(x)^(2*y), where sqrt(m) is an integer s */
+        let mut s = m;
+        let mut t = m / 2 + 1;
+        while t < s {
+            s = t;
+            t = (s + m / s.max(1)) / 2;
+        }
+        if e & 1 != 0 || s * s != m {
+            return false;
+        }
+        m = s;
+        e /= 2;
+        y *= 2.0;
+    }
+    if m == 1 {
+        /* x^y = 2^(e*y) */
+        let t = e as f64 * y;
+        return is_integer(t) && t >= emin as f64;
+    }
+    if y < 0.0 || y > prec as f64 {
+        return false;
+    }
+    let mut p: u64 = 1;
+    for _ in 0..y as u32 {
+        p = match p.checked_mul(m) {
+            Some(p) if p >> prec == 0 => p,
+            _ => return false,
+        };
+    }
+    e as f64 * y >= emin as f64
+}
+
 #[cfg(test)]
 mod tests {
     extern crate core;
@@ -632,3 +723,40 @@ mod tests {
         assert!(pow(-1.0, -1.14).is_nan());
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID, FE_OVERFLOW, FE_UNDERFLOW};
+    use super::pow;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (10.0, 400.0, FE_OVERFLOW | FE_INEXACT),
+            (-10.0, 401.0, FE_OVERFLOW | FE_INEXACT),
+            (10.0, -400.0, FE_UNDERFLOW | FE_INEXACT),
+            (-1e200, 2.0, FE_OVERFLOW | FE_INEXACT),
+            (1e-200, 2.0, FE_UNDERFLOW | FE_INEXACT),
+            (0.0, -1.0, FE_DIVBYZERO),
+            (-0.0, -3.0, FE_DIVBYZERO),
+            (-2.0, 0.5, FE_INVALID),
+            (2.0, 0.5, FE_INEXACT),
+            (2.0, 10.0, 0),
+            (3.0, 33.0, 0),
+            (3.0, 34.0, FE_INEXACT),
+            (3.0, -1.0, FE_INEXACT),
+            (4.0, 1.5, 0),
+            (0.25, -2.5, 0),
+            (2.0, -1074.0, 0),
+            (2.0, -1075.0, FE_UNDERFLOW | FE_INEXACT),
+            (f64::INFINITY, 2.0, 0),
+            (0.0, 0.0, 0),
+        ];
+        for &(x, y, e) in cases.iter() {
+            let r = raised(|(x, y)| pow(x, y), (x, y));
+            assert_eq!(r, e, "pow({}, {})", x, y);
+        }
+    }
+}
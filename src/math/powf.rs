@@ -13,6 +13,11 @@
  * ====================================================
  */
 
+#[cfg(feature = "soft-fenv")]
+use super::fenv::inexact;
+use super::fenv::{divzero, invalid, oflow, uflow};
+#[cfg(feature = "soft-fenv")]
+use super::pow::exact;
 use super::{fabsf, scalbnf, sqrtf};
 
 const BP: [f32; 2] = [1.0, 1.5];
@@ -128,12 +133,27 @@ pub fn powf(x: f32, y: f32) -> f32 {
     }
     if iy == 0x3f800000 {
         /* y is +-1 */
-        return if hy >= 0 { x } else { 1.0 / x };
+        if hy >= 0 {
+            return x;
+        }
+        let z = 1.0 / x;
+        if ix == 0 {
+            return divzero(z);
+        }
+        #[cfg(feature = "soft-fenv")]
+        inexact(ix < 0x7f800000 && !exact(x as f64, y as f64, 24, -149));
+        return if z.is_infinite() { oflow(z) } else { z };
     }
 
     if hy == 0x40000000 {
         /* y is 2 */
-        return x * x;
+        let z = x * x;
+        #[cfg(feature = "soft-fenv")]
+        inexact(ix != 0 && ix < 0x7f800000 && !exact(x as f64, y as f64, 24, -149));
+        if ix < 0x7f800000 && z.is_infinite() {
+            return oflow(z);
+        }
+        return if z == 0.0 && x != 0.0 { uflow(z) } else { z };
     }
 
     if hy == 0x3f000000
@@ -152,11 +172,14 @@ pub fn powf(x: f32, y: f32) -> f32 {
         if hy < 0 {
             /* z = (1/|x|) */
             z = 1.0 / z;
+            if ix == 0 {
+                z = divzero(z);
+            }
         }
 
         if hx < 0 {
             if ((ix - 0x3f800000) | yisint) == 0 {
-                z = (z - z) / (z - z); /* (-1)**non-int is NaN */
+                z = invalid((z - z) / (z - z)); /* (-1)**non-int is NaN */
             } else if yisint == 1 {
                 z = -z; /* (x<0)**odd = -(|x|**odd) */
             }
@@ -168,7 +191,7 @@ pub fn powf(x: f32, y: f32) -> f32 {
     if hx < 0 {
         if yisint == 0 {
             /* (x<0)**(non-int) is NaN */
-            return (x - x) / (x - x);
+            return invalid((x - x) / (x - x));
         }
 
         if yisint == 1 {
@@ -177,23 +200,27 @@ pub fn powf(x: f32, y: f32) -> f32 {
         }
     }
 
+    /* x^y is inexact unless it is a float */
+    #[cfg(feature = "soft-fenv")]
+    inexact(!exact(x as f64, y as f64, 24, -149));
+
     /* |y| is HUGE */
     if iy > 0x4d000000 {
         /* if |y| > 2**27 */
         /* over/underflow if x is not close to one */
         if ix < 0x3f7ffff8 {
             return if hy < 0 {
-                sn * HUGE * HUGE
+                oflow(sn * HUGE * HUGE)
             } else {
-                sn * TINY * TINY
+                uflow(sn * TINY * TINY)
             };
         }
 
         if ix > 0x3f800007 {
             return if hy > 0 {
-                sn * HUGE * HUGE
+                oflow(sn * HUGE * HUGE)
             } else {
-                sn * TINY * TINY
+                uflow(sn * TINY * TINY)
             };
         }
 
@@ -286,21 +313,21 @@ pub fn powf(x: f32, y: f32) -> f32 {
     j = z.to_bits() as i32;
     if j > 0x43000000 {
         /* if z > 128 */
-        return sn * HUGE * HUGE; /* overflow */
+        return oflow(sn * HUGE * HUGE);
     } else if j == 0x43000000 {
         /* if z == 128 */
         if p_l + OVT > z - p_h {
-            return sn * HUGE * HUGE; /* overflow */
+            return oflow(sn * HUGE * HUGE);
         }
     } else if (j & 0x7fffffff) > 0x43160000 {
         /* z < -150 */
         // FIXME: check should be  (uint32_t)j > 0xc3160000
-        return sn * TINY * TINY; /* underflow */
+        return uflow(sn * TINY * TINY);
     } else if j as u32 == 0xc3160000
               /* z == -150 */
               && p_l <= z - p_h
     {
-        return sn * TINY * TINY; /* underflow */
+        return uflow(sn * TINY * TINY);
     }
 
     /*
@@ -341,3 +368,40 @@ pub fn powf(x: f32, y: f32) -> f32 {
     }
     sn * z
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INEXACT, FE_INVALID, FE_OVERFLOW, FE_UNDERFLOW};
+    use super::powf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (10.0, 40.0, FE_OVERFLOW | FE_INEXACT),
+            (-10.0, 41.0, FE_OVERFLOW | FE_INEXACT),
+            (10.0, -50.0, FE_UNDERFLOW | FE_INEXACT),
+            (-1e30, 2.0, FE_OVERFLOW | FE_INEXACT),
+            (1e-30, 2.0, FE_UNDERFLOW | FE_INEXACT),
+            (0.0, -1.0, FE_DIVBYZERO),
+            (-0.0, -3.0, FE_DIVBYZERO),
+            (-2.0, 0.5, FE_INVALID),
+            (2.0, 0.5, FE_INEXACT),
+            (2.0, 10.0, 0),
+            (3.0, 15.0, 0),
+            (3.0, 16.0, FE_INEXACT),
+            (3.0, -1.0, FE_INEXACT),
+            (4.0, 1.5, 0),
+            (0.25, -2.5, 0),
+            (2.0, -149.0, 0),
+            (2.0, -150.0, FE_UNDERFLOW | FE_INEXACT),
+            (f32::INFINITY, 2.0, 0),
+            (0.0, 0.0, 0),
+        ];
+        for &(x, y, e) in cases.iter() {
+            let r = raised(|(x, y)| powf(x, y), (x, y));
+            assert_eq!(r, e, "powf({}, {})", x, y);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+// powf128(x,y) return x**y
+//
+// Method:
+//      1. Compute log(|x|) = hi + lo with a relative error of about
+//         2**-125, see logf128.
+//      2. Multiply by y exactly with the help of fma:
+//              y*log(|x|) = t + t_lo.
+//      3. Return x**y = exp(t + t_lo).
+//      The extra precision of log(|x|) is what keeps the result accurate
+//      when |y*log(x)| is large.
+//
+// Special cases:
+//      1.  (anything) ** 0  is 1
+//      2.  1 ** (anything)  is 1
+//      3.  (anything except 1) ** NAN is NAN
+//      4.  NAN ** (anything except 0) is NAN
+//      5.  +-(|x| > 1) **  +INF is +INF
+//      6.  +-(|x| > 1) **  -INF is +0
+//      7.  +-(|x| < 1) **  +INF is +0
+//      8.  +-(|x| < 1) **  -INF is +INF
+//      9.  -1          ** +-INF is 1
+//      10. +0 ** (+anything except 0, NAN)               is +0
+//      11. -0 ** (+anything except 0, NAN, odd integer)  is +0
+//      12. +0 ** (-anything except 0, NAN)               is +INF
+//      13. -0 ** (-anything except 0, NAN, odd integer)  is +INF
+//      14. -0 ** (+odd integer) is -0
+//      15. -0 ** (-odd integer) is -INF
+//      16. +INF ** (+anything except 0,NAN) is +INF
+//      17. +INF ** (-anything except 0,NAN) is +0
+//      18. -INF ** (+odd integer) is -INF
+//      19. -INF ** (anything) = -0 ** (-anything), (anything except odd integer)
+//      20. (-anything) ** (integer) is (-1)**(integer)*(+anything**integer)
+//      21. (-anything except 0 and inf) ** (non-integer) is NAN
+//
+// Accuracy:
+//      the error is below 1 ulp. pow(integer,integer) returns the correct
+//      integer when it is representable.
+//
+use super::expf128::k_expf128;
+use super::logf128::k_logf128;
+use super::{f128, fmaf128};
+
+const O_THRESHOLD: f128 = f128::from_bits(0x400c62e42fefa39ef35793c7673007e6); /* 11356.523406294143949491931077970765 */
+const U_THRESHOLD: f128 = f128::from_bits(0xc00c654bb3b2c73ebb059fabb506ff34); /* -11433.4627433362978788372438434526231 */
+
+/* 0 if y is not an integer, 1 if y is an odd integer, 2 if it is even */
+#[inline]
+fn yisint(iy: u128) -> i32 {
+    let e = (iy >> 112) as i32 & 0x7fff;
+    if e < 0x3fff {
+        /* 0 < |y| < 1 */
+        return 0;
+    }
+    if e > 0x3fff + 112 {
+        return 2;
+    }
+    /* the number of fraction bits and the significand */
+    let sh = 0x3fff + 112 - e;
+    let m = iy & ((1 << 112) - 1) | 1 << 112;
+    if m & ((1 << sh) - 1) != 0 {
+        0
+    } else if (m >> sh) & 1 == 1 {
+        1
+    } else {
+        2
+    }
+}
+
+/// Power (f128)
+///
+/// Computes `x` raised to the power `y`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powf128(x: f128, y: f128) -> f128 {
+    let ix = x.to_bits();
+    let iy = y.to_bits();
+    let ax = f128::from_bits(ix & !(1 << 127));
+    let ay = f128::from_bits(iy & !(1 << 127));
+
+    /* x**0 = 1, even if x is NaN */
+    if ay == f128::ZERO {
+        return f128::ONE;
+    }
+    /* 1**y = 1, even if y is NaN */
+    if x == f128::ONE {
+        return f128::ONE;
+    }
+    /* NaN if either arg is NaN */
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    if y.is_infinite() {
+        return if ax == f128::ONE {
+            /* (-1)**+-inf is 1 */
+            f128::ONE
+        } else if (ax > f128::ONE) != y.is_sign_negative() {
+            /* (|x|>1)**+inf = inf, (|x|<1)**-inf = inf */
+            f128::INFINITY
+        } else {
+            f128::ZERO
+        };
+    }
+
+    let yint = yisint(iy);
+    let neg = x.is_sign_negative() && yint == 1;
+    if ax == f128::ZERO || ax.is_infinite() {
+        /* +-0**y and +-inf**y, where 1/x selects between the two */
+        let z = if (ax == f128::ZERO) != y.is_sign_negative() {
+            f128::ZERO
+        } else {
+            f128::INFINITY
+        };
+        return if neg { -z } else { z };
+    }
+    if x.is_sign_negative() && yint == 0 {
+        /* (x<0)**(non-int) is NaN */
+        return (x - x) / (x - x);
+    }
+
+    let (hi, lo) = k_logf128(ax);
+    let t = y * hi;
+    let tlo = fmaf128(y, hi, -t) + y * lo;
+    let z = if t > O_THRESHOLD {
+        f128::INFINITY
+    } else if t < U_THRESHOLD {
+        f128::ZERO
+    } else {
+        k_expf128(t, tlo)
+    };
+    if neg {
+        -z
+    } else {
+        z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{f128, powf128};
+
+    fn p(x: f64, y: f64) -> f128 {
+        powf128(f128::from(x), f128::from(y))
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f128::INFINITY;
+        assert_eq!(powf128(f128::NAN, f128::ZERO), f128::ONE);
+        assert_eq!(powf128(f128::ONE, f128::NAN), f128::ONE);
+        assert!(powf128(f128::NAN, f128::ONE).is_nan());
+        assert!(p(-2.0, 0.5).is_nan());
+        assert_eq!(powf128(f128::from(-1.0), -inf), f128::ONE);
+        assert_eq!(powf128(f128::from(0.5), -inf), inf);
+        assert_eq!(powf128(f128::from(2.0), -inf), f128::ZERO);
+        assert_eq!(p(-0.0, 3.0).to_bits(), (-f128::ZERO).to_bits());
+        assert_eq!(p(-0.0, -3.0), -inf);
+        assert_eq!(p(-0.0, -2.0), inf);
+        assert_eq!(powf128(-inf, f128::from(3.0)), -inf);
+        assert_eq!(
+            powf128(-inf, f128::from(-3.0)).to_bits(),
+            (-f128::ZERO).to_bits()
+        );
+        assert_eq!(powf128(-inf, f128::from(0.5)), inf);
+        assert_eq!(p(2.0, 20000.0), inf);
+        assert_eq!(p(2.0, -20000.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(p(2.0, 10.0), f128::from(1024.0));
+        assert_eq!(p(-3.0, 5.0), f128::from(-243.0));
+        assert_eq!(p(2.0, -16494.0).to_bits(), 1);
+        assert_eq!(p(7.0, 40.0).to_bits(), 0x406f39e862f1509ba9c74345f78771c1);
+        assert_eq!(p(10.0, -2.0).to_bits(), 0x3ff847ae147ae147ae147ae147ae147b);
+    }
+}
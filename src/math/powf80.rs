@@ -0,0 +1,33 @@
+use super::{f128, f80, powf128};
+
+/// Power (f80)
+///
+/// Computes `x` raised to the power `y`. The power is computed in f128,
+/// see powf128, and rounded once, so the special cases are the same.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powf80(x: f80, y: f80) -> f80 {
+    f80::from_f128(powf128(f128::from(x), f128::from(y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{f80, powf80};
+
+    fn p(x: f64, y: f64) -> f80 {
+        powf80(f80::from(x), f80::from(y))
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(p(2.0, 10.0), f80::from(1024.0));
+        assert_eq!(p(-3.0, 5.0), f80::from(-243.0));
+        assert_eq!(p(-0.0, -3.0), f80::NEG_INFINITY);
+        assert!(p(-2.0, 0.5).is_nan());
+        /* the smallest subnormal */
+        assert_eq!(p(2.0, -16445.0).to_bits(), 1);
+        assert_eq!(p(7.0, 25.0).to_bits(), 0x4045916621a898bc201c);
+        assert_eq!(p(10.0, -2.0).to_bits(), 0x3ff8a3d70a3d70a3d70a);
+        assert_eq!(p(1.5, 1000.0).to_bits(), 0x4247f96e8089a9b4c5c1);
+    }
+}
@@ -0,0 +1,139 @@
+// pown(x,n) return x**n for an integer n
+//
+// Method:
+//      pow(|x|, n) with the sign taken from x and the parity of n. The
+//      parity is read from n itself, so it stays right where n is not
+//      exactly representable as a double.
+//
+//      For |n| > 2^53, n is split exactly into hi + lo, where hi is n with
+//      the low 11 bits of |n| cleared and lo holds them, and |x|^n is
+//      exp(hi*log|x| + lo*log|x|) with the logarithm, the products and the
+//      exponential carried in double-double. Where that is far out of
+//      range, |x|^n is pow(|x|, hi)*pow(|x|, lo), whose factors lie on the
+//      same side of 1.
+//
+// Special cases:
+//      1.  pown(x, 0) is 1 for any x, even NAN
+//      2.  pown(NAN, n) is NAN for n != 0
+//      3.  pown(+-0, odd n > 0) is +-0
+//      4.  pown(+-0, even n > 0) is +0
+//      5.  pown(+-0, odd n < 0) is +-INF, raise divbyzero
+//      6.  pown(+-0, even n < 0) is +INF, raise divbyzero
+//      7.  pown(+-INF, n) is 1/pown(+-0, n)
+//      8.  pown(-x, odd n) is -pown(x, n), pown(-x, even n) is pown(x, n)
+//
+use super::double_double::{add, mul, mul_f64};
+use super::k_crexp::k_crexp;
+use super::k_crlog::k_crlog;
+use super::{fabs, pow, scalbn};
+
+/// `(hi, lo)` with `hi + lo = n` exactly, both exact as doubles and of the
+/// sign of `n`, and `|lo| < 2048`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn split(n: i64) -> (f64, f64) {
+    let u = if n < 0 {
+        (n as u64).wrapping_neg()
+    } else {
+        n as u64
+    };
+    let hi = (u & !0x7ff) as f64;
+    let lo = (u & 0x7ff) as f64;
+    if n < 0 {
+        (-hi, -lo)
+    } else {
+        (hi, lo)
+    }
+}
+
+/// `exp(n*l)` for a double-double `l`, with `n` split exactly, or `None`
+/// where the result is far out of range.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn exp_mul_n(l: (f64, f64), n: i64) -> Option<f64> {
+    let (hi, lo) = split(n);
+    let (th, tl) = add(mul_f64(l, hi), mul_f64(l, lo));
+    if !(-746.0..746.0).contains(&th) {
+        return None;
+    }
+    let (m, t, p) = k_crexp(th, tl);
+    let (h, l) = add(t, mul(t, p));
+    Some(scalbn(h + l, m))
+}
+
+/* |x|^n for |n| > 2^53 and finite x with 0 < |x| != 1 */
+#[inline]
+fn large(a: f64, n: i64) -> f64 {
+    exp_mul_n(k_crlog(a), n).unwrap_or_else(|| {
+        let (hi, lo) = split(n);
+        pow(a, hi) * pow(a, lo)
+    })
+}
+
+/// Power with integer exponent (f64)
+///
+/// Computes `x` raised to the integer power `n`. Unlike `pow`, the result
+/// follows the exact `n`, in sign and magnitude, even where `n` is too large
+/// to be represented exactly as a double.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn pown(x: f64, n: i64) -> f64 {
+    if n == 0 {
+        return 1.0;
+    }
+    let a = fabs(x);
+    let big = !(-(1 << 53)..=1 << 53).contains(&n);
+    let y = if big && a != 0.0 && a != 1.0 && a.is_finite() {
+        large(a, n)
+    } else {
+        pow(a, n as f64)
+    };
+    if n & 1 != 0 && x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pown;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(pown(f64::NAN, 0), 1.0);
+        assert!(pown(f64::NAN, 1).is_nan());
+        assert_eq!(pown(-0.0, 3).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(pown(-0.0, 2).to_bits(), 0);
+        assert_eq!(pown(-0.0, -3), f64::NEG_INFINITY);
+        assert_eq!(pown(-0.0, -2), f64::INFINITY);
+        assert_eq!(pown(f64::NEG_INFINITY, 3), f64::NEG_INFINITY);
+        assert_eq!(pown(f64::NEG_INFINITY, -3).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(pown(f64::NEG_INFINITY, 2), f64::INFINITY);
+    }
+
+    #[test]
+    fn parity_of_large_exponents() {
+        /* 2^53 + 1 is odd but rounds to an even double */
+        assert_eq!(pown(-1.0, 9007199254740993), -1.0);
+        assert_eq!(pown(-1.0, i64::max_value()), -1.0);
+        assert_eq!(pown(-1.0, i64::min_value()), 1.0);
+        assert_eq!(pown(-0.5, 1075).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(pown(-3.0, 5), -243.0);
+        assert_eq!(pown(2.0, -1074), f64::from_bits(1));
+    }
+
+    #[test]
+    fn large_exponents() {
+        /* 2^61 + 255, which is not a double */
+        let n = 2305843009213694207;
+        assert_eq!(pown(1.0000000000000002, n), 2.2844135865397562e222);
+        assert_eq!(pown(-1.0000000000000002, n), -2.2844135865397562e222);
+        assert_eq!(pown(1.0000000000000002, -n), 4.377491037053053e-223);
+        assert_eq!(pown(0.9999999999999999, n), 6.616261056709204e-112);
+        assert_eq!(pown(1.0000000000000002, i64::max_value()), f64::INFINITY);
+        assert_eq!(pown(0.9999999999999999, i64::min_value()), f64::INFINITY);
+        assert_eq!(pown(0.9999999999999999, i64::max_value()), 0.0);
+    }
+}
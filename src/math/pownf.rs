@@ -0,0 +1,20 @@
+use super::{fabs, pow};
+
+/// Power with integer exponent (f32)
+///
+/// Computes `x` raised to the integer power `n`, by evaluating `pow` in
+/// double precision. Unlike `powf`, the sign of the result follows the
+/// parity of `n` even where `n` is too large to be represented exactly.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn pownf(x: f32, n: i64) -> f32 {
+    if n == 0 {
+        return 1.0;
+    }
+    let y = pow(fabs(x as f64), n as f64) as f32;
+    if n & 1 != 0 && x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
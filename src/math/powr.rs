@@ -0,0 +1,98 @@
+// powr(x,y) return x**y, defined as exp(y*log(x))
+//
+// Method:
+//      pow(x, y) once the special cases below are filtered out, as
+//      exp(y*log(x)) only differs from pow(x, y) in its domain.
+//
+// Special cases:
+//      1.  powr(x, y) is NAN if x or y is NAN
+//      2.  powr(x < 0, y) is NAN, raise invalid
+//      3.  powr(+-0, +-0) is NAN, raise invalid
+//      4.  powr(+INF, +-0) is NAN, raise invalid
+//      5.  powr(1, +-INF) is NAN, raise invalid
+//      6.  powr(+-0, y < 0) is +INF, raise divbyzero
+//      7.  powr(+-0, y > 0) is +0
+//      8.  powr(x, +-0) is 1 for finite x > 0
+//      9.  powr(1, y) is 1 for finite y
+//      10. powr(+INF, y < 0) is +0, powr(+INF, y > 0) is +INF
+//      11. powr(x, +-INF) for finite x > 0 is as for pow
+//
+use super::fenv::invalid;
+use super::{fabs, pow};
+
+/// Power function with the domain of exp(y*log(x)) (f64)
+///
+/// Computes `x` raised to the power `y` for non-negative `x`. Unlike
+/// `pow`, negative `x`, `0^0`, `inf^0` and `1^inf` are invalid and
+/// return NaN, and `powr(1, NaN)` and `powr(NaN, 0)` are NaN.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powr(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if x < 0.0 {
+        return invalid(f64::NAN);
+    }
+    if y == 0.0 {
+        if x == 0.0 || x.is_infinite() {
+            return invalid(f64::NAN);
+        }
+        return 1.0;
+    }
+    if x == 1.0 && y.is_infinite() {
+        return invalid(f64::NAN);
+    }
+    /* -0 is a zero here, not a negative number */
+    pow(fabs(x), y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::powr;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert!(powr(-1.0, 2.0).is_nan());
+        assert!(powr(f64::NEG_INFINITY, 2.0).is_nan());
+        assert!(powr(0.0, 0.0).is_nan());
+        assert!(powr(f64::INFINITY, -0.0).is_nan());
+        assert!(powr(1.0, f64::INFINITY).is_nan());
+        assert!(powr(1.0, f64::NAN).is_nan());
+        assert!(powr(f64::NAN, 0.0).is_nan());
+        assert_eq!(powr(-0.0, -3.0), f64::INFINITY);
+        assert_eq!(powr(-0.0, 3.0).to_bits(), 0);
+        assert_eq!(powr(-0.0, f64::NEG_INFINITY), f64::INFINITY);
+        assert_eq!(powr(2.0, 0.0), 1.0);
+        assert_eq!(powr(1.0, 1e300), 1.0);
+        assert_eq!(powr(f64::INFINITY, -1.0).to_bits(), 0);
+        assert_eq!(powr(0.5, f64::INFINITY), 0.0);
+        assert_eq!(powr(2.0, 10.0), 1024.0);
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INVALID};
+    use super::powr;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (-2.0, 2.0, FE_INVALID),
+            (-0.0, 0.0, FE_INVALID),
+            (f64::INFINITY, 0.0, FE_INVALID),
+            (1.0, f64::NEG_INFINITY, FE_INVALID),
+            (0.0, -1.0, FE_DIVBYZERO),
+            (-0.0, 3.0, 0),
+            (4.0, 0.5, 0),
+        ];
+        for &(x, y, e) in cases.iter() {
+            let r = raised(|(x, y)| powr(x, y), (x, y));
+            assert_eq!(r, e, "powr({}, {})", x, y);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use super::fenv::invalid;
+use super::{fabsf, powf};
+
+/// Power function with the domain of exp(y*log(x)) (f32)
+///
+/// Computes `x` raised to the power `y` for non-negative `x`. Unlike
+/// `powf`, negative `x`, `0^0`, `inf^0` and `1^inf` are invalid and
+/// return NaN, and `powrf(1, NaN)` and `powrf(NaN, 0)` are NaN.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powrf(x: f32, y: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if x < 0.0 {
+        return invalid(f32::NAN);
+    }
+    if y == 0.0 {
+        if x == 0.0 || x.is_infinite() {
+            return invalid(f32::NAN);
+        }
+        return 1.0;
+    }
+    if x == 1.0 && y.is_infinite() {
+        return invalid(f32::NAN);
+    }
+    /* -0 is a zero here, not a negative number */
+    powf(fabsf(x), y)
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INVALID};
+    use super::powrf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (-2.0, 2.0, FE_INVALID),
+            (-0.0, 0.0, FE_INVALID),
+            (f32::INFINITY, 0.0, FE_INVALID),
+            (1.0, f32::NEG_INFINITY, FE_INVALID),
+            (0.0, -1.0, FE_DIVBYZERO),
+            (-0.0, 3.0, 0),
+            (4.0, 0.5, 0),
+        ];
+        for &(x, y, e) in cases.iter() {
+            let r = raised(|(x, y)| powrf(x, y), (x, y));
+            assert_eq!(r, e, "powrf({}, {})", x, y);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// Argument reduction by pi/2 for the correctly rounded trigonometric
+// functions
+//
+// x*2/pi is formed with the method of Payne and Hanek: the 53-bit
+// significand of x is multiplied exactly by the 384 bits of 2/pi that
+// can contribute to x*2/pi modulo 4, which leaves at least 319 bits after
+// the binary point, with an error below 2^-266. The remainder of a double
+// by pi/2 is never smaller than 2^-62, so that the fraction is known to
+// better than 2^-200 relative.
+
+use super::soft_float::{mul, sub, Soft};
+
+/* 2/pi, 64 bits per word */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+static TWO_OVER_PI: [u64; 24] = [
+    0xa2f9836e4e441529, 0xfc2757d1f534ddc0, 0xdb6295993c439041,
+    0xfe5163abdebbc561, 0xb7246e3a424dd2e0, 0x06492eea09d1921c,
+    0xfe1deb1cb129a73e, 0xe88235f52ebb4484, 0xe99c7026b45f7e41,
+    0x3991d639835339f4, 0x9c845f8bbdf9283b, 0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f, 0x6d367ecf27cb09b7, 0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b, 0x3d0739f78a5292ea, 0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab, 0xf0cfbc209af4361d, 0xa9e391615ee61b08,
+    0x6599855f14a06840, 0x8dffd8804d732731, 0x06061556ca73a8c9,
+];
+
+/* pi/2 */
+const PIO2: Soft = Soft::Num(false, 0, 0xc90fdaa22168c234c4c6628b80dc1cd1);
+
+/* the 128 bits of a starting at bit lo, with zeros beyond its ends */
+#[inline]
+fn bits(a: &[u64; 7], lo: i32) -> u128 {
+    let mut r: u128 = 0;
+    for (k, &w) in a.iter().enumerate() {
+        let pos = 64 * k as i32 - lo;
+        if (0..128).contains(&pos) {
+            r |= (w as u128) << pos;
+        } else if pos < 0 && pos > -64 {
+            r |= (w as u128) >> -pos;
+        }
+    }
+    r
+}
+
+/* whether any of the bits of a below bit n is set */
+#[inline]
+fn sticky(a: &[u64; 7], n: i32) -> bool {
+    a.iter().enumerate().any(|(k, &w)| {
+        let pos = 64 * k as i32;
+        if pos + 64 <= n {
+            w != 0
+        } else if pos < n {
+            w << (64 - (n - pos)) != 0
+        } else {
+            false
+        }
+    })
+}
+
+/* clears the bits of a from bit n up */
+#[inline]
+fn truncate(a: &mut [u64; 7], n: i32) {
+    for (k, w) in a.iter_mut().enumerate() {
+        let pos = 64 * k as i32;
+        if pos >= n {
+            *w = 0;
+        } else if pos + 64 > n {
+            *w &= (1 << (n - pos)) - 1;
+        }
+    }
+}
+
+/// Returns `n` and `r` such that `x = n*pi/2 + r` modulo `2*pi`, with
+/// `n` in `0..4` and `|r| <= pi/4`, for finite normal `x`. The relative
+/// error of `r` is below 2^-126.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn rem_pio2_cr(x: f64) -> (i32, Soft) {
+    let ix = x.to_bits();
+    let sign = ix >> 63 != 0;
+    let m = (ix & 0x000fffffffffffff) | 0x0010000000000000;
+    let e = (ix >> 52 & 0x7ff) as i32 - 1075;
+
+    /* the words of 2/pi before i0 only add multiples of 4 */
+    let i0 = if e > 2 { ((e - 2) / 64) as usize } else { 0 };
+    let mut a = [0u64; 7];
+    let mut carry: u64 = 0;
+    for k in 0..6 {
+        let p = m as u128 * i!(TWO_OVER_PI, i0 + 5 - k) as u128 + carry as u128;
+        i!(a, k, =, p as u64);
+        carry = (p >> 64) as u64;
+    }
+    i!(a, 6, =, carry);
+
+    /* x*2/pi = a*2^-f */
+    let f = 64 * (i0 as i32 + 6) - e;
+    let mut n = bits(&a, f) as i32 & 3;
+    truncate(&mut a, f);
+    let neg = bits(&a, f - 1) & 1 == 1;
+    if neg {
+        /* the fraction minus one, by its magnitude 2^f - a */
+        n += 1;
+        let mut borrow = false;
+        for w in a.iter_mut() {
+            let (d, b1) = 0u64.overflowing_sub(*w);
+            let (d, b2) = d.overflowing_sub(borrow as u64);
+            *w = d;
+            borrow = b1 || b2;
+        }
+        truncate(&mut a, f);
+    }
+
+    /* the leading bit of the fraction */
+    let mut top = -1;
+    for (k, &w) in a.iter().enumerate().rev() {
+        if w != 0 {
+            top = 64 * k as i32 + 63 - w.leading_zeros() as i32;
+            break;
+        }
+    }
+    let r = if top < 0 {
+        Soft::Zero(false)
+    } else {
+        let hi = bits(&a, top - 127) | sticky(&a, top - 127) as u128;
+        mul(Soft::Num(neg, top - f, hi), PIO2)
+    };
+    if sign {
+        ((4 - n) & 3, r.neg())
+    } else {
+        (n & 3, r)
+    }
+}
+
+/// As [`rem_pio2_cr`], with `r` rounded to a double-double `(rh, rl)`, and
+/// `x` itself returned for `|x| <= pi/4`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn rem_pio2_cr_dd(x: f64) -> (i32, f64, f64) {
+    if x.abs() <= 0.7853981633974483 {
+        return (0, x, 0.0);
+    }
+    let (n, r) = rem_pio2_cr(x);
+    let rh = r.to_f64();
+    (n, rh, sub(r, Soft::from_f64(rh)).to_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rem_pio2_cr;
+
+    #[test]
+    fn reduction() {
+        let (n, r) = rem_pio2_cr(2.0);
+        assert_eq!(n, 1);
+        /* 2 - pi/2 = 0.42920367320510338... */
+        assert_eq!(r.to_f64(), 0.4292036732051034);
+        let (n, r) = rem_pio2_cr(-2.0);
+        assert_eq!(n, 3);
+        assert_eq!(r.to_f64(), -0.4292036732051034);
+        /* the double closest to a multiple of pi/2, 6381956970095103*2^797 */
+        let (n, r) = rem_pio2_cr(f64::from_bits(0x7506ac5b262ca1ff));
+        assert_eq!(n, 1);
+        assert_eq!(r.to_f64(), 4.687165924254628e-19);
+        let (n, r) = rem_pio2_cr(f64::MAX);
+        assert_eq!(n, 2);
+        assert_eq!(r.to_f64(), -0.004961975150787273);
+    }
+}
@@ -0,0 +1,107 @@
+// origin: FreeBSD /usr/src/lib/msun/ld128/e_rem_pio2l.h
+//
+// ====================================================
+// Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+// Copyright (c) 2008 Steven G. Kargl, David Schultz, Bruce D. Evans.
+//
+// Developed at SunSoft, a Sun Microsystems, Inc. business.
+// Permission to use, copy, modify, and distribute this
+// software is freely granted, provided that this notice
+// is preserved.
+// ====================================================
+//
+// Optimized by Bruce D. Evans. */
+use super::{f128, rem_pio2_large, rintf128, truncf128};
+
+/// 113 bits of 2/pi
+const INVPIO2: f128 = f128::from_bits(0x3ffe45f306dc9c882a53f84eafa3ea6a); /* 0.636619772367581343075535053490057474 */
+/// first 68 bits of pi/2
+const PIO2_1: f128 = f128::from_bits(0x3fff921fb54442d18469800000000000); /* 1.57079632679489661922929942539095549 */
+/// pi/2 - PIO2_1
+const PIO2_1T: f128 = f128::from_bits(0x3fba3198a2e03707344a4093822299f3); /* 2.02226624879595073239968462009475768e-21 */
+/// second 68 bits of pi/2
+const PIO2_2: f128 = f128::from_bits(0x3fba3198a2e03707344a400000000000); /* 2.02226624879595073239947791688377508e-21 */
+/// pi/2 - (PIO2_1+PIO2_2)
+const PIO2_2T: f128 = f128::from_bits(0x3f7127044533e63a0105df531d89cd91); /* 2.06703210982639882364969030516048441e-43 */
+/// third 68 bits of pi/2
+const PIO2_3: f128 = f128::from_bits(0x3f7127044533e63a0105c00000000000); /* 2.06703210982639882363779248431838652e-43 */
+/// pi/2 - (PIO2_1+PIO2_2+PIO2_3)
+const PIO2_3T: f128 = f128::from_bits(0x3f2df531d89cd9128a5043cc71a026ef); /* 1.18978208420979500131494957701097117e-63 */
+
+#[inline]
+fn exponent(x: f128) -> i32 {
+    (x.to_bits() >> 112) as i32 & 0x7fff
+}
+
+// return the remainder of x rem pi/2 in y[0]+y[1]
+// use rem_pio2_large() for large x
+//
+// caller must handle the case when reduction is not needed: |x| ~<= pi/4 */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn rem_pio2f128(x: f128) -> (i32, f128, f128) {
+    let x1p24 = f128::from_bits(0x4017 << 112); // 0x1p24 === 2 ^ 24
+
+    let ix = x.to_bits();
+    let ex = exponent(x);
+
+    if ex < 0x3fff + 45
+        || (ex == 0x3fff + 45 && (ix >> 64) as u64 & 0xffffffffffff < 0x921fb54442d1)
+    {
+        /* |x| ~< 2^45*(pi/2), medium size */
+        let f_n = rintf128(x * INVPIO2);
+        let n = f_n.to_f64() as i64 as i32;
+        let mut r = x - f_n * PIO2_1;
+        let mut w = f_n * PIO2_1T; /* 1st round good to 180 bit */
+        let mut y0 = r - w;
+        if ex - exponent(y0) > 51 {
+            /* 2nd iteration needed, good to 248 */
+            let t = r;
+            w = f_n * PIO2_2;
+            r = t - w;
+            w = f_n * PIO2_2T - ((t - r) - w);
+            y0 = r - w;
+            if ex - exponent(y0) > 119 {
+                /* 3rd iteration need, 316 bits acc */
+                let t = r;
+                w = f_n * PIO2_3;
+                r = t - w;
+                w = f_n * PIO2_3T - ((t - r) - w);
+                y0 = r - w;
+            }
+        }
+        let y1 = (r - y0) - w;
+        return (n, y0, y1);
+    }
+
+    /*
+     * all other (large) arguments
+     */
+    if ex == 0x7fff {
+        /* x is inf or NaN */
+        let y0 = x - x;
+        return (0, y0, y0);
+    }
+    /* set z = scalbn(|x|,-ilogb(x)+23) */
+    let e0 = ex - 0x3fff - 23;
+    let mut z = f128::from_bits(ix & ((1 << 112) - 1) | ((ex - e0) as u128) << 112);
+    let mut tx = [0.0f64; 5];
+    for t in tx[..4].iter_mut() {
+        *t = truncf128(z).to_f64();
+        z = (z - f128::from(*t)) * x1p24;
+    }
+    tx[4] = z.to_f64();
+    let mut nx = 5;
+    while tx[nx - 1] == 0.0 && nx > 1 {
+        nx -= 1; /* skip zero term */
+    }
+    let mut ty = [0.0f64; 3];
+    let n = rem_pio2_large(&tx[..nx], &mut ty, e0, 3);
+    let t = f128::from(ty[2]) + f128::from(ty[1]);
+    let r = t + f128::from(ty[0]);
+    let w = f128::from(ty[0]) - (r - t);
+    if x.is_sign_negative() {
+        return (-n, -r, -w);
+    }
+    (n, r, w)
+}
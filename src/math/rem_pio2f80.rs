@@ -0,0 +1,104 @@
+// origin: FreeBSD /usr/src/lib/msun/ld80/e_rem_pio2l.h
+//
+// ====================================================
+// Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+// Copyright (c) 2008 Steven G. Kargl, David Schultz, Bruce D. Evans.
+//
+// Developed at SunSoft, a Sun Microsystems, Inc. business.
+// Permission to use, copy, modify, and distribute this
+// software is freely granted, provided that this notice
+// is preserved.
+// ====================================================
+//
+// Optimized by Bruce D. Evans. */
+use super::{f80, rem_pio2_large, rintf80, truncf80};
+
+/// 64 bits of 2/pi
+const INVPIO2: f80 = f80::from_bits(0x3ffea2f9836e4e44152a); /* 0.636619772367581343076 */
+/// first 39 bits of pi/2
+const PIO2_1: f80 = f80::from_bits(0x3fffc90fdaa222000000); /* 1.57079632679597125389 */
+/// pi/2 - PIO2_1
+const PIO2_1T: f80 = f80::from_bits(0xbfd7973dcb3b399d747f); /* -1.07463465549719416347e-12 */
+/// second 39 bits of pi/2
+const PIO2_2: f80 = f80::from_bits(0xbfd7973dcb3b3a000000); /* -1.07463465549783099519e-12 */
+/// pi/2 - (PIO2_1+PIO2_2)
+const PIO2_2T: f80 = f80::from_bits(0x3faec51701b839a25205); /* 6.36831716351095013962e-25 */
+/// third 39 bits of pi/2
+const PIO2_3: f80 = f80::from_bits(0x3faec51701b83a000000); /* 6.36831716351370313614e-25 */
+/// pi/2 - (PIO2_1+PIO2_2+PIO2_3)
+const PIO2_3T: f80 = f80::from_bits(0xbf85bb5bf6c7ddd660ce); /* -2.75299651904407171814e-37 */
+
+#[inline]
+fn exponent(x: f80) -> i32 {
+    (x.to_bits() >> 64) as i32 & 0x7fff
+}
+
+// return the remainder of x rem pi/2 in y[0]+y[1]
+// use rem_pio2_large() for large x
+//
+// caller must handle the case when reduction is not needed: |x| ~<= pi/4 */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn rem_pio2f80(x: f80) -> (i32, f80, f80) {
+    let x1p24 = f80::from_bits(0x4017 << 64 | 1 << 63); // 0x1p24 === 2 ^ 24
+
+    let ix = x.to_bits();
+    let ex = exponent(x);
+
+    if ex < 0x3fff + 25 || (ex == 0x3fff + 25 && ((ix >> 32) as u32) < 0xc90fdaa2) {
+        /* |x| ~< 2^25*(pi/2), medium size */
+        let f_n = rintf80(x * INVPIO2);
+        let n = f_n.to_f64() as i32;
+        let mut r = x - f_n * PIO2_1;
+        let mut w = f_n * PIO2_1T; /* 1st round good to 102 bit */
+        let mut y0 = r - w;
+        if ex - exponent(y0) > 22 {
+            /* 2nd iteration needed, good to 141 */
+            let t = r;
+            w = f_n * PIO2_2;
+            r = t - w;
+            w = f_n * PIO2_2T - ((t - r) - w);
+            y0 = r - w;
+            if ex - exponent(y0) > 61 {
+                /* 3rd iteration need, 180 bits acc */
+                let t = r;
+                w = f_n * PIO2_3;
+                r = t - w;
+                w = f_n * PIO2_3T - ((t - r) - w);
+                y0 = r - w;
+            }
+        }
+        let y1 = (r - y0) - w;
+        return (n, y0, y1);
+    }
+
+    /*
+     * all other (large) arguments
+     */
+    if ex == 0x7fff {
+        /* x is inf or NaN */
+        let y0 = x - x;
+        return (0, y0, y0);
+    }
+    /* set z = scalbn(|x|,-ilogb(x)+23) */
+    let e0 = ex - 0x3fff - 23;
+    let mut z = f80::from_bits(ix & 0xffffffffffffffff | ((ex - e0) as u128) << 64);
+    let mut tx = [0.0f64; 3];
+    for t in tx[..2].iter_mut() {
+        *t = truncf80(z).to_f64();
+        z = (z - f80::from(*t)) * x1p24;
+    }
+    tx[2] = z.to_f64();
+    let mut nx = 3;
+    while tx[nx - 1] == 0.0 && nx > 1 {
+        nx -= 1; /* skip zero term */
+    }
+    let mut ty = [0.0f64; 3];
+    let n = rem_pio2_large(&tx[..nx], &mut ty, e0, 2);
+    let r = f80::from(ty[0]) + f80::from(ty[1]);
+    let w = f80::from(ty[1]) - (r - f80::from(ty[0]));
+    if x.is_sign_negative() {
+        return (-n, -r, -w);
+    }
+    (n, r, w)
+}
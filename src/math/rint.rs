@@ -0,0 +1,79 @@
+use core::f64;
+
+use super::fenv::{fegetround, FE_DOWNWARD, FE_TOWARDZERO, FE_UPWARD};
+use super::{ceil, floor, trunc};
+
+const TOINT: f64 = 1. / f64::EPSILON;
+
+/// Round to integral value (f64)
+///
+/// Rounds `x` to an integer in the current rounding direction, as reported
+/// by `fegetround`. The default direction rounds halfway cases to even.
+/// Raises the inexact exception if the result differs from `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rint(x: f64) -> f64 {
+    match fegetround() {
+        FE_DOWNWARD => return floor(x),
+        FE_UPWARD => return ceil(x),
+        FE_TOWARDZERO => return trunc(x),
+        _ => {}
+    }
+
+    let u: u64 = x.to_bits();
+    let e = (u >> 52 & 0x7ff) as i32;
+    let s = (u >> 63) != 0;
+
+    if e >= 0x3ff + 52 {
+        return x;
+    }
+    let y = if s {
+        x - TOINT + TOINT
+    } else {
+        x + TOINT - TOINT
+    };
+    if y != x {
+        // raise inexact
+        force_eval!(x + TOINT);
+    }
+    if y == 0. {
+        return if s { -0. } else { 0. };
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rint;
+
+    #[test]
+    fn ties_to_even() {
+        assert_eq!(rint(0.5), 0.0);
+        assert_eq!(rint(1.5), 2.0);
+        assert_eq!(rint(2.5), 2.0);
+        assert_eq!(rint(-2.5), -2.0);
+        assert_eq!(rint(-3.5), -4.0);
+        assert_eq!(rint(4503599627370495.5), 4503599627370496.0);
+    }
+
+    #[test]
+    fn negative_zero() {
+        assert_eq!(rint(-0.25).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(rint(-0.0).to_bits(), (-0.0_f64).to_bits());
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INEXACT;
+    use super::rint;
+
+    #[test]
+    fn inexact() {
+        let cases = [(2.5, FE_INEXACT), (-0.25, FE_INEXACT), (3.0, 0), (-0.0, 0)];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(rint, x), e, "rint({:?})", x);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use core::f32;
+
+use super::fenv::{fegetround, FE_DOWNWARD, FE_TOWARDZERO, FE_UPWARD};
+use super::{ceilf, floorf, truncf};
+
+const TOINT: f32 = 1. / f32::EPSILON;
+
+/// Round to integral value (f32)
+///
+/// Rounds `x` to an integer in the current rounding direction, as reported
+/// by `fegetround`. The default direction rounds halfway cases to even.
+/// Raises the inexact exception if the result differs from `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rintf(x: f32) -> f32 {
+    match fegetround() {
+        FE_DOWNWARD => return floorf(x),
+        FE_UPWARD => return ceilf(x),
+        FE_TOWARDZERO => return truncf(x),
+        _ => {}
+    }
+
+    let u: u32 = x.to_bits();
+    let e = (u >> 23 & 0xff) as i32;
+    let s = (u >> 31) != 0;
+
+    if e >= 0x7f + 23 {
+        return x;
+    }
+    let y = if s {
+        x - TOINT + TOINT
+    } else {
+        x + TOINT - TOINT
+    };
+    if y != x {
+        // raise inexact
+        force_eval!(x + TOINT);
+    }
+    if y == 0. {
+        return if s { -0. } else { 0. };
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rintf;
+
+    #[test]
+    fn ties_to_even() {
+        assert_eq!(rintf(0.5), 0.0);
+        assert_eq!(rintf(1.5), 2.0);
+        assert_eq!(rintf(2.5), 2.0);
+        assert_eq!(rintf(-2.5), -2.0);
+        assert_eq!(rintf(8388607.5), 8388608.0);
+        assert_eq!(rintf(-0.5).to_bits(), (-0.0_f32).to_bits());
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INEXACT;
+    use super::rintf;
+
+    #[test]
+    fn inexact() {
+        let cases = [(2.5, FE_INEXACT), (-0.25, FE_INEXACT), (3.0, 0), (-0.0, 0)];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(rintf, x), e, "rintf({:?})", x);
+        }
+    }
+}
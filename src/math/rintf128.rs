@@ -0,0 +1,25 @@
+use super::f128;
+
+/// Round to integral value (f128)
+///
+/// Rounds `x` to the nearest integer, rounding halfway cases to even.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rintf128(x: f128) -> f128 {
+    let toint = f128::from_bits(0x406f << 112); // 0x1p112 === 2 ^ 112
+    let s = x.is_sign_negative();
+    let e = (x.to_bits() >> 112) as i32 & 0x7fff;
+
+    if e >= 0x3fff + 112 {
+        return x;
+    }
+    let y = if s {
+        x - toint + toint
+    } else {
+        x + toint - toint
+    };
+    if y == f128::ZERO {
+        return if s { -f128::ZERO } else { f128::ZERO };
+    }
+    y
+}
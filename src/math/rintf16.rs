@@ -0,0 +1,10 @@
+use super::{f16, rint};
+
+/// Round to integral value (f16)
+///
+/// Rounds `x` to the nearest integer, rounding halfway cases to even.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rintf16(x: f16) -> f16 {
+    f16::from_f64(rint(x.to_f64()))
+}
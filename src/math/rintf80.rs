@@ -0,0 +1,10 @@
+use super::{f128, f80, rintf128};
+
+/// Round to integral value (f80)
+///
+/// Rounds `x` to the nearest integer, rounding halfway cases to even.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rintf80(x: f80) -> f80 {
+    f80::from_f128(rintf128(f128::from(x)))
+}
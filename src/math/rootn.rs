@@ -0,0 +1,160 @@
+// rootn(x,n) return the n-th root of x
+//
+// Method:
+//      Let |x| = m * 2^e with 0.5 <= m < 1 and e = q*n + r with 0 <= r < |n|,
+//      then |x|^(1/n) = 2^q * 2^((r + log2(m))/n). The exponent of the
+//      second factor is in (-1, 1) and only suffers small absolute errors.
+//      The cases n = 2, 3 and -2 use sqrt, cbrt and rsqrt instead.
+//
+// Special cases:
+//      1.  rootn(x, 0) is NAN, raise invalid
+//      2.  rootn(NAN, n) is NAN
+//      3.  rootn(x, 1) is x
+//      4.  rootn(x, -1) is 1/x
+//      5.  rootn(+-0, odd n > 0) is +-0
+//      6.  rootn(+-0, even n > 0) is +0
+//      7.  rootn(+-0, odd n < 0) is +-INF, raise divbyzero
+//      8.  rootn(+-0, even n < 0) is +INF, raise divbyzero
+//      9.  rootn(+-INF, odd n) is rootn(+-0, -n) with the same sign
+//      10. rootn(+INF, even n) is rootn(+0, -n)
+//      11. rootn(x < 0, even n) is NAN, raise invalid
+//      12. rootn(-x, odd n) is -rootn(x, n)
+//
+use super::fenv::{divzero, invalid};
+use super::{cbrt, exp2, log2, rsqrt, scalbn, sqrt};
+
+/// n-th root (f64)
+///
+/// Computes the `n`-th root of `x`, that is `x` raised to the power `1/n`.
+/// Negative `x` has a real root only for odd `n`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rootn(x: f64, n: i64) -> f64 {
+    let x1p54 = f64::from_bits(0x4350000000000000); // 0x1p54 === 2 ^ 54
+
+    if n == 0 {
+        return invalid(f64::NAN);
+    }
+    if x.is_nan() || n == 1 {
+        return x;
+    }
+
+    let odd = n & 1 != 0;
+    if x < 0.0 && !odd {
+        return invalid(f64::NAN);
+    }
+    let sign = if odd && x.is_sign_negative() {
+        -1.0
+    } else {
+        1.0
+    };
+    if x == 0.0 || x.is_infinite() {
+        /* the sign of n selects between zero and infinity */
+        return if (x == 0.0) == (n > 0) {
+            sign * 0.0
+        } else if x == 0.0 {
+            divzero(sign * f64::INFINITY)
+        } else {
+            sign * f64::INFINITY
+        };
+    }
+    match n {
+        -1 => return 1.0 / x,
+        2 => return sqrt(x),
+        3 => return cbrt(x),
+        -2 => return rsqrt(x),
+        _ => {}
+    }
+
+    /* |x| = m*2^e with 0.5 <= m < 1 */
+    let mut ui = x.to_bits() & 0x7fffffffffffffff;
+    let mut e = (ui >> 52) as i64 - 0x3fe;
+    if ui >> 52 == 0 {
+        ui = (f64::from_bits(ui) * x1p54).to_bits();
+        e = (ui >> 52) as i64 - 0x3fe - 54;
+    }
+    let m = f64::from_bits((ui & 0x000fffffffffffff) | 0x3fe0000000000000);
+
+    /* e = q*n + r with 0 <= r < |n|, wide enough for n = i64::MIN */
+    let n = n as i128;
+    let mut r = e as i128 % n;
+    if r < 0 {
+        r += if n < 0 { -n } else { n };
+    }
+    let q = ((e as i128 - r) / n) as i32;
+
+    sign * scalbn(exp2((r as f64 + log2(m)) / n as f64), q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rootn;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert!(rootn(1.0, 0).is_nan());
+        assert!(rootn(f64::NAN, 3).is_nan());
+        assert!(rootn(-8.0, 4).is_nan());
+        assert_eq!(rootn(-8.0, 1), -8.0);
+        assert_eq!(rootn(-8.0, -1), -0.125);
+        assert_eq!(rootn(-0.0, 5).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(rootn(-0.0, 4).to_bits(), 0);
+        assert_eq!(rootn(-0.0, -5), f64::NEG_INFINITY);
+        assert_eq!(rootn(-0.0, -4), f64::INFINITY);
+        assert_eq!(rootn(-0.0, 2).to_bits(), 0);
+        assert_eq!(rootn(0.0, 2).to_bits(), 0);
+        assert_eq!(rootn(-0.0, -2), f64::INFINITY);
+        assert_eq!(rootn(0.0, -2), f64::INFINITY);
+        assert_eq!(rootn(-0.0, 3).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(rootn(0.0, 3).to_bits(), 0);
+        assert_eq!(rootn(-0.0, -3), f64::NEG_INFINITY);
+        assert_eq!(rootn(0.0, -3), f64::INFINITY);
+        assert_eq!(rootn(-0.0, -1), f64::NEG_INFINITY);
+        assert_eq!(rootn(f64::NEG_INFINITY, 5), f64::NEG_INFINITY);
+        assert_eq!(rootn(f64::NEG_INFINITY, -5).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(rootn(f64::INFINITY, -4).to_bits(), 0);
+        assert!(rootn(f64::NEG_INFINITY, 4).is_nan());
+        assert_eq!(rootn(1.0, i64::min_value()), 1.0);
+    }
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(rootn(16.0, 4), 2.0);
+        assert_eq!(rootn(-32.0, 5), -2.0);
+        assert_eq!(rootn(-27.0, 3), -3.0);
+        assert_eq!(rootn(0.0625, -4), 2.0);
+        assert_eq!(rootn(1024.0, 10), 2.0);
+        assert_eq!(rootn(f64::from_bits(1), 1074), 0.5);
+    }
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INVALID};
+    use super::rootn;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (0.0, 2, 0),
+            (-0.0, 2, 0),
+            (-0.0, 3, 0),
+            (0.0, -2, FE_DIVBYZERO),
+            (-0.0, -2, FE_DIVBYZERO),
+            (-0.0, -3, FE_DIVBYZERO),
+            (-0.0, -1, FE_DIVBYZERO),
+            (0.0, -4, FE_DIVBYZERO),
+            (f64::INFINITY, -2, 0),
+            (-4.0, 2, FE_INVALID),
+            (f64::NEG_INFINITY, 4, FE_INVALID),
+            (1.0, 0, FE_INVALID),
+        ];
+        for &(x, n, e) in cases.iter() {
+            let r = raised(|(x, n)| rootn(x, n), (x, n));
+            assert_eq!(r, e, "rootn({}, {})", x, n);
+        }
+    }
+}
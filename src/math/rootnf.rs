@@ -0,0 +1,42 @@
+use super::rootn;
+
+/// n-th root (f32)
+///
+/// Computes the `n`-th root of `x`, that is `x` raised to the power `1/n`,
+/// by evaluating `rootn` in double precision. Negative `x` has a real root
+/// only for odd `n`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rootnf(x: f32, n: i64) -> f32 {
+    rootn(x as f64, n) as f32
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_DIVBYZERO, FE_INVALID};
+    use super::rootnf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (0.0, 2, 0),
+            (-0.0, 2, 0),
+            (-0.0, 3, 0),
+            (0.0, -2, FE_DIVBYZERO),
+            (-0.0, -2, FE_DIVBYZERO),
+            (-0.0, -3, FE_DIVBYZERO),
+            (-0.0, -1, FE_DIVBYZERO),
+            (0.0, -4, FE_DIVBYZERO),
+            (f32::INFINITY, -2, 0),
+            (-4.0, 2, FE_INVALID),
+            (f32::NEG_INFINITY, 4, FE_INVALID),
+            (1.0, 0, FE_INVALID),
+        ];
+        for &(x, n, e) in cases.iter() {
+            let r = raised(|(x, n)| rootnf(x, n), (x, n));
+            assert_eq!(r, e, "rootnf({}, {})", x, n);
+        }
+    }
+}
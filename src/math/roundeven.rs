@@ -0,0 +1,89 @@
+/// Round to nearest integer, halfway cases to even (f64)
+///
+/// Rounds `x` to the nearest integer, rounding halfway cases to the even
+/// integer, regardless of the current rounding direction. The inexact
+/// exception is not raised.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundeven(x: f64) -> f64 {
+    let i = x.to_bits();
+    let e = (i >> 52 & 0x7ff) as i32;
+    let sign = i & 1 << 63;
+
+    if e >= 0x3ff + 52 {
+        /* already integral, or infinite or NaN, where x + x quiets NaN */
+        return if e == 0x7ff { x + x } else { x };
+    }
+    if e < 0x3ff - 1 {
+        /* |x| < 0.5 */
+        return f64::from_bits(sign);
+    }
+    if e == 0x3ff - 1 {
+        /* 0.5 <= |x| < 1, of which 0.5 alone goes down to the even 0 */
+        return f64::from_bits(if i ^ sign == 0x3fe0_0000_0000_0000 {
+            sign
+        } else {
+            sign | 0x3ff0_0000_0000_0000
+        });
+    }
+
+    /* 1 <= |x| < 2^52: the low n bits hold the fraction, and the bit
+     * above them, counting the implicit one, is the units digit */
+    let n = 0x3ff + 52 - e;
+    let unit: u64 = 1 << n;
+    let frac = i & (unit - 1);
+    let half = unit >> 1;
+    let odd = (i & ((1 << 52) - 1) | 1 << 52) & unit != 0;
+    let mut y = i - frac;
+    if frac > half || frac == half && odd {
+        /* a carry out of the mantissa steps the exponent, as it should */
+        y += unit;
+    }
+    f64::from_bits(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rint;
+    use super::roundeven;
+    use core::f64;
+
+    #[test]
+    fn ties_to_even() {
+        assert_eq!(roundeven(0.5).to_bits(), 0);
+        assert_eq!(roundeven(-0.5).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(roundeven(1.5), 2.0);
+        assert_eq!(roundeven(2.5), 2.0);
+        assert_eq!(roundeven(-2.5), -2.0);
+        assert_eq!(roundeven(0.5000000000000001), 1.0);
+        assert_eq!(roundeven(2.4999999999999996), 2.0);
+        assert_eq!(roundeven(4503599627370495.5), 4503599627370496.0);
+        assert_eq!(roundeven(4503599627370494.5), 4503599627370494.0);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(roundeven(f64::INFINITY), f64::INFINITY);
+        assert_eq!(roundeven(f64::MAX), f64::MAX);
+        assert!(roundeven(f64::NAN).is_nan());
+        assert_eq!(roundeven(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(roundeven(f64::from_bits(1)).to_bits(), 0);
+    }
+
+    #[test]
+    fn matches_rint() {
+        /* rint rounds to nearest, ties to even, in the default direction */
+        let quarters = (-4000..4000).map(|k| f64::from(k) * 0.25);
+        let sampled = (0..=u64::max_value())
+            .step_by(0x1234_5678_9abc)
+            .map(f64::from_bits);
+        for x in quarters.chain(sampled) {
+            let (y, r) = (roundeven(x), rint(x));
+            assert!(
+                y.to_bits() == r.to_bits() || y.is_nan() && r.is_nan(),
+                "{:?}",
+                x
+            );
+        }
+    }
+}
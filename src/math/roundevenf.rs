@@ -0,0 +1,86 @@
+/// Round to nearest integer, halfway cases to even (f32)
+///
+/// Rounds `x` to the nearest integer, rounding halfway cases to the even
+/// integer, regardless of the current rounding direction. The inexact
+/// exception is not raised.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundevenf(x: f32) -> f32 {
+    let i = x.to_bits();
+    let e = (i >> 23 & 0xff) as i32;
+    let sign = i & 1 << 31;
+
+    if e >= 0x7f + 23 {
+        /* already integral, or infinite or NaN, where x + x quiets NaN */
+        return if e == 0xff { x + x } else { x };
+    }
+    if e < 0x7f - 1 {
+        /* |x| < 0.5 */
+        return f32::from_bits(sign);
+    }
+    if e == 0x7f - 1 {
+        /* 0.5 <= |x| < 1, of which 0.5 alone goes down to the even 0 */
+        return f32::from_bits(if i ^ sign == 0x3f00_0000 {
+            sign
+        } else {
+            sign | 0x3f80_0000
+        });
+    }
+
+    /* 1 <= |x| < 2^23: the low n bits hold the fraction, and the bit
+     * above them, counting the implicit one, is the units digit */
+    let n = 0x7f + 23 - e;
+    let unit: u32 = 1 << n;
+    let frac = i & (unit - 1);
+    let half = unit >> 1;
+    let odd = (i & ((1 << 23) - 1) | 1 << 23) & unit != 0;
+    let mut y = i - frac;
+    if frac > half || frac == half && odd {
+        /* a carry out of the mantissa steps the exponent, as it should */
+        y += unit;
+    }
+    f32::from_bits(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rintf;
+    use super::roundevenf;
+    use core::f32;
+
+    #[test]
+    fn ties_to_even() {
+        assert_eq!(roundevenf(0.5).to_bits(), 0);
+        assert_eq!(roundevenf(-0.5).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(roundevenf(1.5), 2.0);
+        assert_eq!(roundevenf(2.5), 2.0);
+        assert_eq!(roundevenf(-3.5), -4.0);
+        assert_eq!(roundevenf(0.50000006), 1.0);
+        assert_eq!(roundevenf(2.4999998), 2.0);
+        assert_eq!(roundevenf(8388607.5), 8388608.0);
+        assert_eq!(roundevenf(8388606.5), 8388606.0);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(roundevenf(f32::INFINITY), f32::INFINITY);
+        assert_eq!(roundevenf(f32::MAX), f32::MAX);
+        assert!(roundevenf(f32::NAN).is_nan());
+        assert_eq!(roundevenf(-0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(roundevenf(f32::from_bits(1)).to_bits(), 0);
+    }
+
+    #[test]
+    fn matches_rintf() {
+        /* rintf rounds to nearest, ties to even, in the default direction */
+        for i in (0..=u32::max_value()).step_by(1009) {
+            let x = f32::from_bits(i);
+            let (y, r) = (roundevenf(x), rintf(x));
+            assert!(
+                y.to_bits() == r.to_bits() || y.is_nan() && r.is_nan(),
+                "{:?}",
+                x
+            );
+        }
+    }
+}
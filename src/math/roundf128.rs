@@ -0,0 +1,34 @@
+use super::f128;
+
+/// Round (f128)
+///
+/// Rounds `x` to the nearest integer, rounding halfway cases away from zero.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundf128(x: f128) -> f128 {
+    let toint = f128::from_bits(0x406f << 112); // 0x1p112 === 2 ^ 112
+    let half = f128::from_bits(0x3ffe << 112);
+    let s = x.is_sign_negative();
+    let e = (x.to_bits() >> 112) as i32 & 0x7fff;
+
+    if e >= 0x3fff + 112 {
+        return x;
+    }
+    let ax = if s { -x } else { x };
+    if e < 0x3fff - 1 {
+        return x * f128::ZERO;
+    }
+    let mut y = ax + toint - toint - ax;
+    if y > half {
+        y = y + ax - f128::ONE;
+    } else if y <= -half {
+        y = y + ax + f128::ONE;
+    } else {
+        y = y + ax;
+    }
+    if s {
+        -y
+    } else {
+        y
+    }
+}
@@ -0,0 +1,10 @@
+use super::{f16, round};
+
+/// Round (f16)
+///
+/// Rounds `x` to the nearest integer, rounding halfway cases away from zero.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundf16(x: f16) -> f16 {
+    f16::from_f64(round(x.to_f64()))
+}
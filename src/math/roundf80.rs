@@ -0,0 +1,10 @@
+use super::{f128, f80, roundf128};
+
+/// Round (f80)
+///
+/// Rounds `x` to the nearest integer, rounding halfway cases away from zero.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundf80(x: f80) -> f80 {
+    f80::from_f128(roundf128(f128::from(x)))
+}
@@ -0,0 +1,19 @@
+/// Rounding direction of the `_rm` functions
+///
+/// The rounding-direction attributes of IEEE 754. The other functions of
+/// this crate always round to nearest, ties to even; the `_rm` functions
+/// compute their result exactly in software and round it once in the
+/// given direction, independently of the floating-point environment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// To nearest, ties to the value with an even last digit.
+    TiesToEven,
+    /// To nearest, ties away from zero.
+    TiesToAway,
+    /// Toward zero (truncation).
+    TowardZero,
+    /// Toward positive infinity.
+    TowardPositive,
+    /// Toward negative infinity.
+    TowardNegative,
+}
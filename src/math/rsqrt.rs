@@ -0,0 +1,122 @@
+// rsqrt(x) return 1/sqrt(x), correctly rounded
+//
+// Method:
+//      1. Compute y = 1/sqrt(x) in double precision. The two roundings
+//         leave y within about one ulp of the exact result.
+//      2. Move y to the correctly rounded result by comparing the exact
+//         result against the midpoints between y and its neighbours. For
+//         a midpoint m, 1/sqrt(x) > m is equivalent to m*m*x < 1, which
+//         is decided exactly in integer arithmetic. The exact result is
+//         never a midpoint, so there are no ties.
+//
+// Special cases:
+//      1. rsqrt(+-0) is +-INF, raise divbyzero
+//      2. rsqrt(+INF) is +0
+//      3. rsqrt(x<0) is NAN, raise invalid
+//      4. rsqrt(NAN) is NAN
+//
+use super::sqrt;
+
+/* split a positive finite double into an integer mantissa and exponent */
+#[inline]
+fn split(bits: u64) -> (u64, i32) {
+    let e = (bits >> 52) as i32;
+    let m = bits & 0x000fffffffffffff;
+    if e == 0 {
+        (m, -1074)
+    } else {
+        (m | 0x0010000000000000, e - 1075)
+    }
+}
+
+/* is 1/sqrt(mx*2^ex) above the midpoint between y and its successor? */
+#[inline]
+fn above_mid(y: u64, mx: u64, ex: i32) -> bool {
+    let (my, ey) = split(y);
+
+    /* the midpoint is (2*my+1)*2^(ey-1), so check (2*my+1)^2*mx < 2^k */
+    let k = 2 - 2 * ey - ex;
+    let s = (2 * my + 1) as u128;
+    let s2 = s * s;
+    let lo = (s2 as u64 as u128) * (mx as u128);
+    let hi = (s2 >> 64) * (mx as u128) + (lo >> 64);
+    let lo = lo as u64;
+
+    if k < 0 {
+        false
+    } else if k < 64 {
+        hi == 0 && lo < (1 << k)
+    } else if k < 192 {
+        hi < (1 << (k - 64))
+    } else {
+        true
+    }
+}
+
+/// Reciprocal square root (f64)
+///
+/// Computes `1/sqrt(x)`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rsqrt(x: f64) -> f64 {
+    let ix = x.to_bits();
+
+    if x == 0.0 {
+        return 1.0 / x;
+    }
+    if ix >= 0x7ff0000000000000 {
+        if ix == 0x7ff0000000000000 {
+            return 0.0;
+        }
+        /* negative or nan */
+        return (x - x) / (x - x);
+    }
+
+    let (mx, ex) = split(ix);
+    let mut iy = (1.0 / sqrt(x)).to_bits();
+
+    /* the result is always normal, so the neighbours of iy are iy +- 1 */
+    while above_mid(iy, mx, ex) {
+        iy += 1;
+    }
+    while !above_mid(iy - 1, mx, ex) {
+        iy -= 1;
+    }
+    f64::from_bits(iy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rsqrt;
+    use core::f64;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(rsqrt(0.0), f64::INFINITY);
+        assert_eq!(rsqrt(-0.0), f64::NEG_INFINITY);
+        assert_eq!(rsqrt(f64::INFINITY).to_bits(), 0);
+        assert!(rsqrt(-1.0).is_nan());
+        assert!(rsqrt(f64::NEG_INFINITY).is_nan());
+        assert!(rsqrt(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(rsqrt(1.0), 1.0);
+        assert_eq!(rsqrt(4.0), 0.5);
+        assert_eq!(rsqrt(0.0625), 4.0);
+        assert_eq!(rsqrt(f64::from_bits(1)), f64::from_bits(0x6180000000000000));
+        assert_eq!(
+            rsqrt(f64::from_bits(0x7fd0000000000000)),
+            f64::from_bits(0x2000000000000000)
+        );
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        assert_eq!(rsqrt(2.0), f64::consts::FRAC_1_SQRT_2);
+        assert_eq!(rsqrt(3.0), 0.5773502691896257);
+        assert_eq!(rsqrt(f64::MAX), 7.458340731200207e-155);
+        assert_eq!(rsqrt(f64::from_bits(3)), 2.597449090340435e+161);
+    }
+}
@@ -0,0 +1,33 @@
+use super::{bf16, rsqrt};
+
+/// Reciprocal square root (bf16)
+///
+/// Computes `1/sqrt(x)`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rsqrtbf16(x: bf16) -> bf16 {
+    bf16::from_f64(rsqrt(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bfloat16::{check, check_f32};
+    use super::super::{rsqrt, rsqrtf};
+    use super::{bf16, rsqrtbf16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check_f32(x, rsqrtbf16(x), rsqrtf(x.to_f32()), 1);
+        }
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check(x, rsqrtbf16(x), rsqrt(x.to_f64()));
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::sqrt;
+
+/* is 1/sqrt(x) above the midpoint between y and its successor? */
+#[inline]
+fn above_mid(y: u32, x: f32) -> bool {
+    /* mx*2^ex and my*2^ey, both positive and x possibly subnormal */
+    let (mx, ex) = {
+        let ix = x.to_bits();
+        let e = (ix >> 23) as i32;
+        let m = (ix & 0x007fffff) as u128;
+        if e == 0 {
+            (m, -149)
+        } else {
+            (m | 0x00800000, e - 150)
+        }
+    };
+    let my = ((y & 0x007fffff) | 0x00800000) as u128;
+    let ey = (y >> 23) as i32 - 150;
+
+    /* the midpoint is (2*my+1)*2^(ey-1), so check (2*my+1)^2*mx < 2^k */
+    let k = 2 - 2 * ey - ex;
+    let s = 2 * my + 1;
+    if k < 0 {
+        false
+    } else if k < 128 {
+        s * s * mx < (1 << k)
+    } else {
+        true
+    }
+}
+
+/// Reciprocal square root (f32)
+///
+/// Computes `1/sqrt(x)`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rsqrtf(x: f32) -> f32 {
+    let ix = x.to_bits();
+
+    if x == 0.0 {
+        return 1.0 / x;
+    }
+    if ix >= 0x7f800000 {
+        if ix == 0x7f800000 {
+            return 0.0;
+        }
+        /* negative or nan */
+        return (x - x) / (x - x);
+    }
+
+    /* within an ulp of the result, see rsqrt for the correction */
+    let mut iy = ((1.0 / sqrt(x as f64)) as f32).to_bits();
+    while above_mid(iy, x) {
+        iy += 1;
+    }
+    while !above_mid(iy - 1, x) {
+        iy -= 1;
+    }
+    f32::from_bits(iy)
+}
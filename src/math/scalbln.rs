@@ -0,0 +1,17 @@
+use super::scalbn;
+
+/// Multiply by an integral power of two (f64)
+///
+/// Returns `x * 2^n` for a `long` exponent `n`, computed as `scalbn` does.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalbln(x: f64, n: i64) -> f64 {
+    let n = if n > i32::max_value() as i64 {
+        i32::max_value()
+    } else if n < i32::min_value() as i64 {
+        i32::min_value()
+    } else {
+        n as i32
+    };
+    scalbn(x, n)
+}
@@ -0,0 +1,39 @@
+use super::f128;
+
+/// Multiply by an integral power of two (f128)
+///
+/// Computes `x * 2^n`, rounding only when the result is subnormal.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalbnf128(x: f128, mut n: i32) -> f128 {
+    let x1p16383 = f128::from_bits(0x7ffe << 112); // 0x1p16383 === 2 ^ 16383
+    let x1p113 = f128::from_bits(0x4070 << 112); // 0x1p113 === 2 ^ 113
+    let x1p_16382 = f128::from_bits(1 << 112); // 0x1p-16382 === 2 ^ (-16382)
+
+    let mut y = x;
+
+    if n > 16383 {
+        y = y * x1p16383;
+        n -= 16383;
+        if n > 16383 {
+            y = y * x1p16383;
+            n -= 16383;
+            if n > 16383 {
+                n = 16383;
+            }
+        }
+    } else if n < -16382 {
+        /* make sure final n < -113 to avoid double
+        rounding in the subnormal range */
+        y = y * (x1p_16382 * x1p113);
+        n += 16382 - 113;
+        if n < -16382 {
+            y = y * (x1p_16382 * x1p113);
+            n += 16382 - 113;
+            if n < -16382 {
+                n = -16382;
+            }
+        }
+    }
+    y * f128::from_bits(((0x3fff + n) as u128) << 112)
+}
@@ -0,0 +1,39 @@
+use super::f80;
+
+/// Multiply by an integral power of two (f80)
+///
+/// Computes `x * 2^n`, rounding only when the result is subnormal.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalbnf80(x: f80, mut n: i32) -> f80 {
+    let x1p16383 = f80::from_bits(0x7ffe << 64 | 1 << 63); // 0x1p16383 === 2 ^ 16383
+    let x1p64 = f80::from_bits(0x403f << 64 | 1 << 63); // 0x1p64 === 2 ^ 64
+    let x1p_16382 = f80::from_bits(1 << 64 | 1 << 63); // 0x1p-16382 === 2 ^ (-16382)
+
+    let mut y = x;
+
+    if n > 16383 {
+        y = y * x1p16383;
+        n -= 16383;
+        if n > 16383 {
+            y = y * x1p16383;
+            n -= 16383;
+            if n > 16383 {
+                n = 16383;
+            }
+        }
+    } else if n < -16382 {
+        /* make sure final n < -64 to avoid double
+        rounding in the subnormal range */
+        y = y * (x1p_16382 * x1p64);
+        n += 16382 - 64;
+        if n < -16382 {
+            y = y * (x1p_16382 * x1p64);
+            n += 16382 - 64;
+            if n < -16382 {
+                n = -16382;
+            }
+        }
+    }
+    y * f80::from_bits(((0x3fff + n) as u128) << 64 | 1 << 63)
+}
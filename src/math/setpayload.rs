@@ -0,0 +1,96 @@
+/// Converts `pl` into a payload, if it is an integral value in `[0, 2^51)`.
+#[inline]
+fn payload(pl: f64) -> Option<u64> {
+    let ui = pl.to_bits();
+    // biased exponent, or more than 0x7ff if the sign bit is set
+    let e = (ui >> 52) as i32;
+
+    if ui == 0 {
+        return Some(0);
+    }
+    if e < 0x3ff || e >= 0x3ff + 51 {
+        return None;
+    }
+    // the 52 - (e - 0x3ff) low bits of the mantissa are fractional
+    let k = e - 0x3ff;
+    if ui & (0x000f_ffff_ffff_ffff >> k) != 0 {
+        return None;
+    }
+    Some(((ui & 0x000f_ffff_ffff_ffff) | 1 << 52) >> (52 - k))
+}
+
+/// Quiet NaN with a given payload (f64)
+///
+/// Returns a quiet NaN whose payload is `pl`. If `pl` isn't a valid payload,
+/// that is a non-negative integral value less than 2^51, +0 is returned
+/// instead.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayload(pl: f64) -> f64 {
+    match payload(pl) {
+        Some(p) => f64::from_bits(0x7ff0_0000_0000_0000 | 0x0008_0000_0000_0000 | p),
+        None => 0.0,
+    }
+}
+
+/// Signaling NaN with a given payload (f64)
+///
+/// Returns a signaling NaN whose payload is `pl`. If `pl` isn't a valid
+/// payload, that is a positive integral value less than 2^51, +0 is returned
+/// instead. Note that a signaling NaN may be quieted when it is passed through
+/// the x87 floating-point stack.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadsig(pl: f64) -> f64 {
+    match payload(pl) {
+        Some(p) if p != 0 => f64::from_bits(0x7ff0_0000_0000_0000 | p),
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{canonicalize, getpayload};
+    use super::{setpayload, setpayloadsig};
+    use core::f64;
+
+    #[test]
+    fn round_trip() {
+        for &pl in &[0.0, 1.0, 42.0, 2251799813685247.0] {
+            let q = setpayload(pl);
+            assert!(q.is_nan());
+            assert_eq!(getpayload(q), pl);
+            if pl != 0.0 {
+                let s = setpayloadsig(pl);
+                assert!(s.is_nan());
+                assert_eq!(s.to_bits() & 0x0008_0000_0000_0000, 0);
+                assert_eq!(getpayload(s), pl);
+                assert_eq!(canonicalize(s).to_bits(), q.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_payloads() {
+        for &pl in &[
+            -1.0,
+            -0.0,
+            0.5,
+            1.5,
+            2251799813685248.0,
+            f64::INFINITY,
+            f64::NAN,
+        ] {
+            assert_eq!(setpayload(pl).to_bits(), 0);
+            assert_eq!(setpayloadsig(pl).to_bits(), 0);
+        }
+        assert_eq!(setpayloadsig(0.0).to_bits(), 0);
+    }
+
+    #[test]
+    fn not_a_nan() {
+        assert_eq!(getpayload(1.0), -1.0);
+        assert_eq!(getpayload(f64::INFINITY), -1.0);
+        assert_eq!(canonicalize(-0.0).to_bits(), (-0.0_f64).to_bits());
+    }
+}
@@ -0,0 +1,49 @@
+/// Converts `pl` into a payload, if it is an integral value in `[0, 2^22)`.
+#[inline]
+fn payload(pl: f32) -> Option<u32> {
+    let ui = pl.to_bits();
+    // biased exponent, or more than 0xff if the sign bit is set
+    let e = (ui >> 23) as i32;
+
+    if ui == 0 {
+        return Some(0);
+    }
+    if e < 0x7f || e >= 0x7f + 22 {
+        return None;
+    }
+    // the 23 - (e - 0x7f) low bits of the mantissa are fractional
+    let k = e - 0x7f;
+    if ui & (0x007f_ffff >> k) != 0 {
+        return None;
+    }
+    Some(((ui & 0x007f_ffff) | 1 << 23) >> (23 - k))
+}
+
+/// Quiet NaN with a given payload (f32)
+///
+/// Returns a quiet NaN whose payload is `pl`. If `pl` isn't a valid payload,
+/// that is a non-negative integral value less than 2^22, +0 is returned
+/// instead.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadf(pl: f32) -> f32 {
+    match payload(pl) {
+        Some(p) => f32::from_bits(0x7f80_0000 | 0x0040_0000 | p),
+        None => 0.0,
+    }
+}
+
+/// Signaling NaN with a given payload (f32)
+///
+/// Returns a signaling NaN whose payload is `pl`. If `pl` isn't a valid
+/// payload, that is a positive integral value less than 2^22, +0 is returned
+/// instead. Note that a signaling NaN may be quieted when it is passed through
+/// the x87 floating-point stack.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadsigf(pl: f32) -> f32 {
+    match payload(pl) {
+        Some(p) if p != 0 => f32::from_bits(0x7f80_0000 | p),
+        _ => 0.0,
+    }
+}
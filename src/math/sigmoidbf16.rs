@@ -0,0 +1,63 @@
+use super::{bf16, exp};
+
+/// Logistic sigmoid (bf16)
+///
+/// Computes `1/(1 + exp(-x))`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sigmoidbf16(x: bf16) -> bf16 {
+    let x = x.to_f64();
+    /* exp(x)/(1 + exp(x)) does not overflow for very negative x */
+    let y = if x < 0.0 {
+        let t = exp(x);
+        t / (1.0 + t)
+    } else {
+        1.0 / (1.0 + exp(-x))
+    };
+    bf16::from_f64(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bfloat16::{check, check_f32};
+    use super::super::{cr_exp, expf};
+    use super::{bf16, sigmoidbf16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            let t = x.to_f32();
+            let y = if t < 0.0 {
+                expf(t) / (1.0 + expf(t))
+            } else {
+                1.0 / (1.0 + expf(-t))
+            };
+            check_f32(x, sigmoidbf16(x), y, 3);
+        }
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            let t = x.to_f64();
+            /* cr_exp, the sum and the quotient each round once, and the
+             * relative error of exp(x) is not magnified, so y is within
+             * 3 ulp of the sigmoid */
+            let y = if t < 0.0 {
+                let e = cr_exp(t);
+                e / (1.0 + e)
+            } else {
+                1.0 / (1.0 + cr_exp(-t))
+            };
+            /* the sigmoid then rounds like y if y - 3 ulp and y + 3 ulp do */
+            if y > 0.0 {
+                let lo = bf16::from_f64(f64::from_bits(y.to_bits().saturating_sub(3)));
+                let hi = bf16::from_f64(f64::from_bits(y.to_bits() + 3));
+                assert_eq!(lo.to_bits(), hi.to_bits(), "{:?}: {:e}", x, y);
+            }
+            check(x, sigmoidbf16(x), y);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use super::{ilogb, scalbn};
+
+/// Significand of a floating-point number (f64)
+///
+/// Returns the significand of `x` scaled to the range `[1, 2)`, that is
+/// `scalbn(x, -ilogb(x))`. Zeros, infinities and NaNs are returned unchanged.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn significand(x: f64) -> f64 {
+    if !x.is_finite() || x == 0.0 {
+        return x + x;
+    }
+    scalbn(x, -ilogb(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::significand;
+    use core::f64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(significand(12.0), 1.5);
+        assert_eq!(significand(-0.375), -1.5);
+        assert_eq!(significand(f64::from_bits(3)), 1.5);
+        assert_eq!(significand(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(significand(f64::INFINITY), f64::INFINITY);
+        assert!(significand(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,14 @@
+use super::{ilogbf, scalbnf};
+
+/// Significand of a floating-point number (f32)
+///
+/// Returns the significand of `x` scaled to the range `[1, 2)`, that is
+/// `scalbnf(x, -ilogbf(x))`. Zeros, infinities and NaNs are returned unchanged.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn significandf(x: f32) -> f32 {
+    if !x.is_finite() || x == 0.0 {
+        return x + x;
+    }
+    scalbnf(x, -ilogbf(x))
+}
@@ -9,6 +9,7 @@
 // is preserved.
 // ====================================================
 
+use super::fenv::{inexact, invalid};
 use super::{k_cos, k_sin, rem_pio2};
 
 // sin(x)
@@ -47,12 +48,17 @@ pub fn sin(x: f64) -> f64 {
 
     /* High word of x. */
     let ix = (f64::to_bits(x) >> 32) as u32 & 0x7fffffff;
+    /* sin(x) is inexact for finite x != 0 */
+    inexact(x != 0.0 && ix < 0x7ff00000);
 
     /* |x| ~< pi/4 */
     if ix <= 0x3fe921fb {
         if ix < 0x3e500000 {
             /* |x| < 2**-26 */
             /* raise inexact if x != 0 and underflow if subnormal*/
+            if x == 0.0 {
+                return x;
+            }
             if ix < 0x00100000 {
                 force_eval!(x / x1p120);
             } else {
@@ -65,7 +71,7 @@ pub fn sin(x: f64) -> f64 {
 
     /* sin(Inf or NaN) is NaN */
     if ix >= 0x7ff00000 {
-        return x - x;
+        return if x.is_nan() { x - x } else { invalid(x - x) };
     }
 
     /* argument reduction needed */
@@ -84,3 +90,25 @@ fn test_near_pi() {
     let sx = f64::from_bits(0x3ea50d15ced1a4a2); // 6.273720864039205e-7
     assert_eq!(sin(x), sx);
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::sin;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (f64::INFINITY, FE_INVALID),
+            (f64::NEG_INFINITY, FE_INVALID),
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f64::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(sin, x), e, "sin({:?})", x);
+        }
+    }
+}
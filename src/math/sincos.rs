@@ -10,8 +10,10 @@
  * ====================================================
  */
 
+use super::fenv::{inexact, invalid};
 use super::{get_high_word, k_cos, k_sin, rem_pio2};
 
+#[inline]
 pub fn sincos(x: f64) -> (f64, f64) {
     let s: f64;
     let c: f64;
@@ -19,6 +21,8 @@ pub fn sincos(x: f64) -> (f64, f64) {
 
     ix = get_high_word(x);
     ix &= 0x7fffffff;
+    /* sin(x) and cos(x) are inexact for finite x != 0 */
+    inexact(x != 0.0 && ix < 0x7ff00000);
 
     /* |x| ~< pi/4 */
     if ix <= 0x3fe921fb {
@@ -26,6 +30,9 @@ pub fn sincos(x: f64) -> (f64, f64) {
         if ix < 0x3e46a09e {
             /* raise inexact if x!=0 and underflow if subnormal */
             let x1p120 = f64::from_bits(0x4770000000000000); // 0x1p120 == 2^120
+            if x == 0.0 {
+                return (x, 1.0);
+            }
             if ix < 0x00100000 {
                 force_eval!(x / x1p120);
             } else {
@@ -38,7 +45,7 @@ pub fn sincos(x: f64) -> (f64, f64) {
 
     /* sincos(Inf or NaN) is NaN */
     if ix >= 0x7ff00000 {
-        let rv = x - x;
+        let rv = if x.is_nan() { x - x } else { invalid(x - x) };
         return (rv, rv);
     }
 
@@ -57,3 +64,19 @@ pub fn sincos(x: f64) -> (f64, f64) {
         _ => (0.0, 1.0),
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::sincos;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [(f64::INFINITY, FE_INVALID), (1.0, FE_INEXACT), (0.0, 0)];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(sincos, x), e, "sincos({:?})", x);
+        }
+    }
+}
@@ -14,6 +14,7 @@
  * ====================================================
  */
 
+use super::fenv::{inexact, invalid};
 use super::{k_cosf, k_sinf, rem_pio2f};
 
 /* Small multiples of pi/2 rounded to double precision. */
@@ -32,6 +33,8 @@ pub fn sincosf(x: f32) -> (f32, f32) {
     ix = x.to_bits();
     sign = (ix >> 31) != 0;
     ix &= 0x7fffffff;
+    /* sin(x) and cos(x) are inexact for finite x != 0 */
+    inexact(x != 0.0 && ix < 0x7f800000);
 
     /* |x| ~<= pi/4 */
     if ix <= 0x3f490fda {
@@ -40,6 +43,9 @@ pub fn sincosf(x: f32) -> (f32, f32) {
             /* raise inexact if x!=0 and underflow if subnormal */
 
             let x1p120 = f32::from_bits(0x7b800000); // 0x1p120 == 2^120
+            if x == 0.0 {
+                return (x, 1.0);
+            }
             if ix < 0x00100000 {
                 force_eval!(x / x1p120);
             } else {
@@ -102,7 +108,7 @@ pub fn sincosf(x: f32) -> (f32, f32) {
 
     /* sin(Inf or NaN) is NaN */
     if ix >= 0x7f800000 {
-        let rv = x - x;
+        let rv = if x.is_nan() { x - x } else { invalid(x - x) };
         return (rv, rv);
     }
 
@@ -121,3 +127,19 @@ pub fn sincosf(x: f32) -> (f32, f32) {
         _ => (0.0, 1.0),
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::sincosf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [(f32::INFINITY, FE_INVALID), (1.0, FE_INEXACT), (0.0, 0)];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(sincosf, x), e, "sincosf({:?})", x);
+        }
+    }
+}
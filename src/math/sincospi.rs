@@ -0,0 +1,10 @@
+use super::{cospi, sinpi};
+
+/// Sine and cosine of pi times x (f64)
+///
+/// Computes `(sinpi(x), cospi(x))`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sincospi(x: f64) -> (f64, f64) {
+    (sinpi(x), cospi(x))
+}
@@ -0,0 +1,11 @@
+use super::{cospi, sinpi};
+
+/// Sine and cosine of pi times x (f32)
+///
+/// Computes `(sinpif(x), cospif(x))`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sincospif(x: f32) -> (f32, f32) {
+    let x = x as f64;
+    (sinpi(x) as f32, cospi(x) as f32)
+}
@@ -14,6 +14,7 @@
  * ====================================================
  */
 
+use super::fenv::{inexact, invalid};
 use super::{k_cosf, k_sinf, rem_pio2f};
 
 use core::f64::consts::FRAC_PI_2;
@@ -34,12 +35,17 @@ pub fn sinf(x: f32) -> f32 {
     let mut ix = x.to_bits();
     let sign = (ix >> 31) != 0;
     ix &= 0x7fffffff;
+    /* sin(x) is inexact for finite x != 0 */
+    inexact(x != 0.0 && ix < 0x7f800000);
 
     if ix <= 0x3f490fda {
         /* |x| ~<= pi/4 */
         if ix < 0x39800000 {
             /* |x| < 2**-12 */
             /* raise inexact if x!=0 and underflow if subnormal */
+            if x == 0.0 {
+                return x;
+            }
             force_eval!(if ix < 0x00800000 {
                 x / x1p120
             } else {
@@ -80,7 +86,7 @@ pub fn sinf(x: f32) -> f32 {
 
     /* sin(Inf or NaN) is NaN */
     if ix >= 0x7f800000 {
-        return x - x;
+        return if x.is_nan() { x - x } else { invalid(x - x) };
     }
 
     /* general argument reduction needed */
@@ -92,3 +98,25 @@ pub fn sinf(x: f32) -> f32 {
         _ => -k_cosf(y),
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::sinf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (f32::INFINITY, FE_INVALID),
+            (f32::NEG_INFINITY, FE_INVALID),
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f32::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(sinf, x), e, "sinf({:?})", x);
+        }
+    }
+}
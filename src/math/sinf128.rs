@@ -0,0 +1,97 @@
+use super::{f128, k_cosf128, k_sinf128, rem_pio2f128};
+
+const PIO4: f128 = f128::from_bits(0x3ffe921fb54442d18469898cc51701b8); /* 0.785398163397448309615660845819875699 */
+
+/// Sine (f128)
+///
+/// Computes the sine of `x` (in radians).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinf128(x: f128) -> f128 {
+    let ix = x.to_bits() & !(1 << 127);
+    let e = (ix >> 112) as i32;
+
+    if e == 0x7fff {
+        return x - x;
+    }
+    if f128::from_bits(ix) < PIO4 {
+        if e < 0x3fff - 113 / 2 {
+            /* sin(x) rounds to x */
+            return x;
+        }
+        return k_sinf128(x, f128::ZERO, 0);
+    }
+    let (n, hi, lo) = rem_pio2f128(x);
+    match n & 3 {
+        0 => k_sinf128(hi, lo, 1),
+        1 => k_cosf128(hi, lo),
+        2 => -k_sinf128(hi, lo, 1),
+        _ => -k_cosf128(hi, lo),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cosf128;
+    use super::{f128, sinf128};
+
+    #[test]
+    fn special_values() {
+        assert!(sinf128(f128::NAN).is_nan());
+        assert!(sinf128(f128::INFINITY).is_nan());
+        assert!(cosf128(f128::NEG_INFINITY).is_nan());
+        assert_eq!(sinf128(-f128::ZERO).to_bits(), (-f128::ZERO).to_bits());
+        assert_eq!(cosf128(f128::ZERO), f128::ONE);
+        let tiny = f128::from(1e-30);
+        assert_eq!(sinf128(tiny), tiny);
+        assert_eq!(cosf128(tiny), f128::ONE);
+    }
+
+    #[test]
+    fn values() {
+        /* correctly rounded results, including reduction of huge arguments */
+        let cases = [
+            (
+                f128::from(1.0),
+                0x3ffeaed548f090cee0418dd3d2138a1e,
+                0x3ffe14a280fb5068b923848cdb2ed0e3,
+            ),
+            (
+                f128::from(-3.0),
+                0xbffc210386db6d55b4f1c817423418a8,
+                0xbffefae04be85e5d260fbff05fbed4e1,
+            ),
+            (
+                f128::from(100.0),
+                0xbffe03425b78c4db80708f6155d083eb,
+                0x3ffeb981dbf665fdf63f433736617a04,
+            ),
+            (
+                f128::from(1e22),
+                0xbffeb453ab76bf3970fa29bc83b9323e,
+                0x3ffe0be2cef01c8f3934b90dff9cec2a,
+            ),
+            (
+                f128::MAX,
+                0x3ffee761623db0b6ffc87a2204a2b017,
+                0xbffd39b7257ed4a6f0d97d1cb93ef07e,
+            ),
+        ];
+        for &(x, s, c) in cases.iter() {
+            let ys = sinf128(x).to_bits();
+            let yc = cosf128(x).to_bits();
+            assert!(
+                ys == s || ys == s + 1 || ys == s - 1,
+                "sin({:?}) = {:#x}",
+                x,
+                ys
+            );
+            assert!(
+                yc == c || yc == c + 1 || yc == c - 1,
+                "cos({:?}) = {:#x}",
+                x,
+                yc
+            );
+        }
+    }
+}
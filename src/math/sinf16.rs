@@ -0,0 +1,37 @@
+use super::{f16, sin};
+
+/// Sine (f16)
+///
+/// Computes the sine of `x` (in radians), correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinf16(x: f16) -> f16 {
+    f16::from_f64(sin(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cosf16;
+    use super::super::cr_sin;
+    use super::super::float16::check;
+    use super::{f16, sinf16};
+
+    #[test]
+    fn values() {
+        assert_eq!(sinf16(f16::from_bits(0x3c00)).to_bits(), 0x3abb); // 1
+        assert_eq!(sinf16(f16::from_bits(0x4248)).to_bits(), 0x13ed); // 3.140625
+        assert_eq!(sinf16(f16::MAX).to_bits(), 0x3bce);
+        assert_eq!(sinf16(-f16::ZERO).to_bits(), 0x8000);
+        assert_eq!(cosf16(f16::ZERO), f16::ONE);
+        assert!(sinf16(f16::INFINITY).is_nan());
+        assert!(cosf16(f16::NEG_INFINITY).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = f16::from_bits(i);
+            check(x, sinf16(x), cr_sin(x.to_f64()));
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use super::{f128, f80, k_cosf128, k_sinf128, rem_pio2f80};
+
+const PIO4: f80 = f80::from_bits(0x3ffec90fdaa22168c235); /* 0.785398163397448309616 */
+
+/// Sine (f80)
+///
+/// Computes the sine of `x` (in radians).
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinf80(x: f80) -> f80 {
+    let e = (x.to_bits() >> 64) as i32 & 0x7fff;
+
+    if e == 0x7fff {
+        return x - x;
+    }
+    if (if x.is_sign_negative() { -x } else { x }) < PIO4 {
+        if e < 0x3fff - 64 / 2 {
+            /* sin(x) rounds to x */
+            return x;
+        }
+        return f80::from_f128(k_sinf128(f128::from(x), f128::ZERO, 0));
+    }
+    let (n, y0, y1) = rem_pio2f80(x);
+    /* the kernels take the reduced argument as an f128 and a small tail */
+    let hi = f128::from(y0) + f128::from(y1);
+    let lo = f128::from(y1) - (hi - f128::from(y0));
+    f80::from_f128(match n & 3 {
+        0 => k_sinf128(hi, lo, 1),
+        1 => k_cosf128(hi, lo),
+        2 => -k_sinf128(hi, lo, 1),
+        _ => -k_cosf128(hi, lo),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{cosf80, tanf80};
+    use super::{f80, sinf80};
+
+    #[test]
+    fn special_values() {
+        assert!(sinf80(f80::NAN).is_nan());
+        assert!(cosf80(f80::INFINITY).is_nan());
+        assert!(tanf80(f80::NEG_INFINITY).is_nan());
+        assert_eq!(sinf80(-f80::ZERO).to_bits(), (-f80::ZERO).to_bits());
+        assert_eq!(cosf80(f80::ZERO), f80::ONE);
+        assert_eq!(tanf80(f80::ZERO).to_bits(), 0);
+    }
+
+    #[test]
+    fn values() {
+        /* correctly rounded results, including reduction of huge arguments */
+        let cases = [
+            (
+                f80::from(1.0),
+                [
+                    0x3ffed76aa47848677021,
+                    0x3ffe8a51407da8345c92,
+                    0x3fffc75922e5f71d2dc5,
+                ],
+            ),
+            (
+                f80::from(-3.0),
+                [
+                    0xbffc9081c36db6aada79,
+                    0xbffefd7025f42f2e9308,
+                    0x3ffc91f7b892a5c37866,
+                ],
+            ),
+            (
+                f80::from(100.0),
+                [
+                    0xbffe81a12dbc626dc038,
+                    0x3ffedcc0edfb32fefb20,
+                    0xbffe9653a6b15ae9bd7d,
+                ],
+            ),
+            (
+                f80::from(1e22),
+                [
+                    0xbffeda29d5bb5f9cb87d,
+                    0x3ffe85f167780e479c9a,
+                    0xbfffd07bce0db592bba5,
+                ],
+            ),
+            (
+                f80::MAX,
+                [
+                    0x3ffefdfd9d4b6d0e5f7c,
+                    0xbffc800bbd0061d4f543,
+                    0xc001fde654994ce86fdb,
+                ],
+            ),
+        ];
+        for &(x, r) in cases.iter() {
+            assert_eq!(sinf80(x).to_bits(), r[0], "sin({:?})", x);
+            assert_eq!(cosf80(x).to_bits(), r[1], "cos({:?})", x);
+            assert_eq!(tanf80(x).to_bits(), r[2], "tan({:?})", x);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::fenv::oflow;
 use super::{expm1, expo2};
 
 // sinh(x) = (exp(x) - 1/exp(x))/2
@@ -46,5 +47,29 @@ pub fn sinh(x: f64) -> f64 {
     /* |x| > log(DBL_MAX) or nan */
     /* note: the result is stored to handle overflow */
     t = 2.0 * h * expo2(absx);
+    if w < 0x7ff00000 && t.is_infinite() {
+        return oflow(t);
+    }
     t
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW};
+    use super::sinh;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1000.0, FE_OVERFLOW | FE_INEXACT),
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f64::INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(sinh, x), e, "sinh({:?})", x);
+        }
+    }
+}
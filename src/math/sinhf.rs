@@ -1,4 +1,5 @@
 use super::expm1f;
+use super::fenv::oflow;
 use super::k_expo2f;
 
 #[inline]
@@ -27,5 +28,30 @@ pub fn sinhf(x: f32) -> f32 {
     }
 
     /* |x| > logf(FLT_MAX) or nan */
-    2. * h * k_expo2f(absx)
+    let t = 2. * h * k_expo2f(absx);
+    if w < 0x7f800000 && t.is_infinite() {
+        return oflow(t);
+    }
+    t
+}
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_OVERFLOW};
+    use super::sinhf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (100.0, FE_OVERFLOW | FE_INEXACT),
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f32::INFINITY, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(sinhf, x), e, "sinhf({:?})", x);
+        }
+    }
 }
@@ -0,0 +1,144 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_sinpi.c */
+/*-
+ * Copyright (c) 2017 Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice unmodified, this list of conditions, and the following
+ *    disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ * IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ * OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ * IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ * INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ * NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ * DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ * THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ * (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ * THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use super::{get_high_word, k_cospi, k_sinpi, with_set_low_word};
+
+const PI_HI: f64 = 3.1415926814079285e+00; /* 0x400921fb, 0x58000000 */
+const PI_LO: f64 = -2.7818135228334233e-08; /* 0xbe5dde97, 0x3dcb3b3a */
+
+/// Sine of pi times x (f64)
+///
+/// Computes `sin(pi * x)` without the rounding error of the product
+/// `pi * x`. The result is exact at integers and half-integers, with
+/// `sinpi(n)` being +0 for a positive integer `n` and -0 for a negative one.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpi(x: f64) -> f64 {
+    let x1p53 = f64::from_bits(0x4340000000000000); // 0x1p53 === 2 ^ 53
+    let x1p_53 = f64::from_bits(0x3ca0000000000000); // 0x1p-53 === 2 ^ -53
+
+    let sign = x.is_sign_negative();
+    let ix = get_high_word(x) & 0x7fffffff;
+    let mut ax = f64::from_bits(x.to_bits() & 0x7fffffffffffffff);
+    let mut s: f64;
+
+    /* |x| < 1 */
+    if ix < 0x3ff00000 {
+        /* |x| < 0.25 */
+        if ix < 0x3fd00000 {
+            /* |x| < 0x1p-29 */
+            if ix < 0x3e200000 {
+                if x == 0.0 {
+                    return x;
+                }
+                /* scale to avoid subnormal intermediate values */
+                let hi = with_set_low_word(x, 0) * x1p53;
+                let lo = x * x1p53 - hi;
+                s = (PI_LO + PI_HI) * lo + PI_LO * hi + PI_HI * hi;
+                return s * x1p_53;
+            }
+            s = k_sinpi(ax);
+        } else if ix < 0x3fe00000 {
+            /* |x| < 0.5 */
+            s = k_cospi(0.5 - ax);
+        } else if ix < 0x3fe80000 {
+            /* |x| < 0.75 */
+            s = k_cospi(ax - 0.5);
+        } else {
+            s = k_sinpi(1.0 - ax);
+        }
+        return if sign { -s } else { s };
+    }
+
+    /* 1 <= |x| < 0x1p52 */
+    if ix < 0x43300000 {
+        /* integer part of |x| */
+        let j0 = ((ix >> 20) & 0x7ff) as i32 - 0x3ff;
+        let xi = f64::from_bits(ax.to_bits() & !(0x000fffffffffffff >> j0));
+        ax -= xi;
+
+        if ax == 0.0 {
+            s = 0.0;
+        } else {
+            if ax < 0.25 {
+                s = k_sinpi(ax);
+            } else if ax < 0.5 {
+                s = k_cospi(0.5 - ax);
+            } else if ax < 0.75 {
+                s = k_cospi(ax - 0.5);
+            } else {
+                s = k_sinpi(1.0 - ax);
+            }
+            if (xi as u64) & 1 != 0 {
+                s = -s;
+            }
+        }
+        return if sign { -s } else { s };
+    }
+
+    /* sinpi(Inf or NaN) is NaN */
+    if ix >= 0x7ff00000 {
+        return x - x;
+    }
+
+    /* |x| >= 0x1p52 is always an integer */
+    if sign {
+        -0.0
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sinpi;
+    use core::f64;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(sinpi(0.5), 1.0);
+        assert_eq!(sinpi(-0.5), -1.0);
+        assert_eq!(sinpi(1.5), -1.0);
+        assert_eq!(sinpi(2.5), 1.0);
+        assert_eq!(sinpi(1e300), 0.0);
+        assert_eq!(sinpi(3.0).to_bits(), 0);
+        assert_eq!(sinpi(-3.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(sinpi(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(sinpi(4503599627370495.5), -1.0);
+        assert!(sinpi(f64::INFINITY).is_nan());
+        assert!(sinpi(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(sinpi(0.25), f64::consts::FRAC_1_SQRT_2);
+        assert_eq!(sinpi(1.0 / 6.0), 0.5);
+        assert_eq!(sinpi(-7.0 / 6.0), 0.5000000000000002);
+        assert_eq!(sinpi(1e-300), 3.1415926535897936e-300);
+        assert_eq!(sinpi(f64::from_bits(1)), f64::from_bits(3));
+    }
+}
@@ -0,0 +1,11 @@
+use super::sinpi;
+
+/// Sine of pi times x (f32)
+///
+/// Computes `sin(pi * x)` by evaluating `sinpi` in double precision. Like
+/// `sinpi`, the result is exact at integers and half-integers.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpif(x: f32) -> f32 {
+    sinpi(x as f64) as f32
+}
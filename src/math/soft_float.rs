@@ -0,0 +1,489 @@
+// Exact arithmetic on unpacked binary floating-point numbers
+//
+// The software floating-point types do their arithmetic here. Operands
+// are unpacked into a sign, an exponent and a 128-bit significand, the
+// operation is carried out exactly and the result is truncated back to
+// 128 bits, with any nonzero bits that were cut off or-ed into the lowest
+// bit. Rounding such a result to a precision of at most 113 bits gives
+// the correctly rounded value, in any of the directions of `RoundingMode`.
+//
+// The `_rm` functions round through here even where fma.rs and sqrt.rs
+// already keep a sticky bit: those round with an integer to float
+// conversion or with 1 +- TINY, which always round to nearest, so the
+// directed modes would need their rounding and their subnormal handling
+// written again. `to_f64_rm` does that once for every operation.
+
+/// An unpacked floating-point number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Soft {
+    Nan,
+    Inf(bool),
+    Zero(bool),
+    /// The sign, exponent `e` and significand `m` of the value
+    /// `m*2^(e-127)`. Bit 127 of `m` is always set.
+    Num(bool, i32, u128),
+}
+
+use self::Soft::*;
+use super::RoundingMode;
+use core::cmp::Ordering;
+
+/* shift m right by n bits, or-ing the bits shifted out into bit 0 */
+#[inline]
+fn shr_jam(m: u128, n: u32) -> u128 {
+    if n == 0 {
+        m
+    } else if n < 128 {
+        (m >> n) | ((m << (128 - n) != 0) as u128)
+    } else {
+        (m != 0) as u128
+    }
+}
+
+/* the same for a 256-bit (hi, lo) pair */
+#[inline]
+fn shr256_jam(hi: u128, lo: u128, n: u32) -> (u128, u128) {
+    if n == 0 {
+        (hi, lo)
+    } else if n < 128 {
+        (hi >> n, shr_jam(lo, n) | (hi << (128 - n)))
+    } else if n < 256 {
+        (0, shr_jam(hi, n - 128) | (lo != 0) as u128)
+    } else {
+        (0, (hi | lo != 0) as u128)
+    }
+}
+
+/* the 256-bit product of a and b */
+#[inline]
+fn mul128(a: u128, b: u128) -> (u128, u128) {
+    let mask = 0xffffffffffffffff;
+    let (a1, a0) = (a >> 64, a & mask);
+    let (b1, b0) = (b >> 64, b & mask);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    let mid = (p00 >> 64) + (p01 & mask) + (p10 & mask);
+    let lo = (mid << 64) | (p00 & mask);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/* m*2^(e-127) with m != 0, normalized */
+#[inline]
+fn norm(s: bool, e: i32, m: u128) -> Soft {
+    let n = m.leading_zeros();
+    Num(s, e - n as i32, m << n)
+}
+
+/* (hi*2^128 + lo)*2^(e-255) with hi or lo != 0, normalized */
+#[inline]
+fn norm256(s: bool, e: i32, hi: u128, lo: u128) -> Soft {
+    if hi == 0 {
+        return norm(s, e - 128, lo);
+    }
+    let n = hi.leading_zeros();
+    let (hi, lo) = if n == 0 {
+        (hi, lo)
+    } else {
+        ((hi << n) | (lo >> (128 - n)), lo << n)
+    };
+    Num(s, e - n as i32, hi | (lo != 0) as u128)
+}
+
+impl Soft {
+    /// The value `m*2^e`.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn from_parts(s: bool, e: i32, m: u128) -> Soft {
+        if m == 0 {
+            Zero(s)
+        } else {
+            norm(s, e + 127, m)
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn from_f64(x: f64) -> Soft {
+        let ix = x.to_bits();
+        let s = ix >> 63 != 0;
+        let e = (ix >> 52 & 0x7ff) as i32;
+        let m = (ix & 0x000fffffffffffff) as u128;
+        if e == 0x7ff {
+            if m == 0 {
+                Inf(s)
+            } else {
+                Nan
+            }
+        } else if e == 0 {
+            Soft::from_parts(s, -1074, m)
+        } else {
+            Soft::from_parts(s, e - 1075, m | 1 << 52)
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn to_f64(self) -> f64 {
+        self.to_f64_rm(RoundingMode::TiesToEven)
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn to_f64_rm(self, rm: RoundingMode) -> f64 {
+        match self.round_rm(53, -1022, 1023, rm) {
+            Nan => 0.0 / 0.0,
+            Inf(s) => {
+                if s {
+                    -1.0 / 0.0
+                } else {
+                    1.0 / 0.0
+                }
+            }
+            Zero(s) => f64::from_bits((s as u64) << 63),
+            Num(s, e, m) => {
+                let ix = if e < -1022 {
+                    (m >> (75 - 1022 - e)) as u64
+                } else {
+                    ((e + 1023) as u64) << 52 | (m >> 75) as u64 & 0x000fffffffffffff
+                };
+                f64::from_bits((s as u64) << 63 | ix)
+            }
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn to_f32(self) -> f32 {
+        self.to_f32_rm(RoundingMode::TiesToEven)
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn to_f32_rm(self, rm: RoundingMode) -> f32 {
+        match self.round_rm(24, -126, 127, rm) {
+            Nan => 0.0 / 0.0,
+            Inf(s) => {
+                if s {
+                    -1.0 / 0.0
+                } else {
+                    1.0 / 0.0
+                }
+            }
+            Zero(s) => f32::from_bits((s as u32) << 31),
+            Num(s, e, m) => {
+                let ix = if e < -126 {
+                    (m >> (-22 - e)) as u32
+                } else {
+                    ((e + 127) as u32) << 23 | (m >> 104) as u32 & 0x7fffff
+                };
+                f32::from_bits((s as u32) << 31 | ix)
+            }
+        }
+    }
+
+    /// The value multiplied by `2^n`.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn scale(self, n: i32) -> Soft {
+        /* far beyond the exponent range of any format */
+        const LIMIT: i32 = 40000;
+        match self {
+            Num(s, e, m) if n > LIMIT => Num(s, e + LIMIT, m),
+            Num(s, e, m) if n < -LIMIT => Num(s, e - LIMIT, m),
+            Num(s, e, m) => Num(s, e + n, m),
+            x => x,
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn sign(self) -> bool {
+        match self {
+            Nan => false,
+            Inf(s) | Zero(s) | Num(s, _, _) => s,
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn neg(self) -> Soft {
+        match self {
+            Nan => Nan,
+            Inf(s) => Inf(!s),
+            Zero(s) => Zero(!s),
+            Num(s, e, m) => Num(!s, e, m),
+        }
+    }
+
+    /// Rounds to `p` significant bits, in a format whose normal numbers
+    /// have exponents `emin..=emax`. Values below `2^emin` keep only the
+    /// bits at or above `2^(emin-p+1)`.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn round(self, p: u32, emin: i32, emax: i32) -> Soft {
+        self.round_rm(p, emin, emax, RoundingMode::TiesToEven)
+    }
+
+    /// Rounds like `round`, in the direction `rm`.
+    #[inline]
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub(crate) fn round_rm(self, p: u32, emin: i32, emax: i32, rm: RoundingMode) -> Soft {
+        let (s, mut e, mut m) = match self {
+            Num(s, e, m) => (s, e, m),
+            x => return x,
+        };
+        if e < emin {
+            m = shr_jam(m, (emin - e) as u32);
+            e = emin;
+        }
+        let sh = 128 - p;
+        let half = 1 << (sh - 1);
+        let rest = m & ((1 << sh) - 1);
+        let mut q = m >> sh;
+        let away = match rm {
+            RoundingMode::TiesToEven => rest > half || (rest == half && q & 1 == 1),
+            RoundingMode::TiesToAway => rest >= half,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => rest != 0 && !s,
+            RoundingMode::TowardNegative => rest != 0 && s,
+        };
+        if away {
+            q += 1;
+            if q >> p != 0 {
+                q >>= 1;
+                e += 1;
+            }
+        }
+        if e > emax {
+            /* unless the direction is away from zero, the largest finite
+             * value is returned instead of infinity */
+            let inf = match rm {
+                RoundingMode::TowardZero => false,
+                RoundingMode::TowardPositive => !s,
+                RoundingMode::TowardNegative => s,
+                _ => true,
+            };
+            if inf {
+                Inf(s)
+            } else {
+                let max: u128 = (1 << p) - 1;
+                Num(s, emax, max << sh)
+            }
+        } else if q == 0 {
+            Zero(s)
+        } else {
+            norm(s, e, q << sh)
+        }
+    }
+}
+
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn add(a: Soft, b: Soft) -> Soft {
+    match (a, b) {
+        (Nan, _) | (_, Nan) => Nan,
+        (Inf(sa), Inf(sb)) => {
+            if sa == sb {
+                Inf(sa)
+            } else {
+                Nan
+            }
+        }
+        (Inf(s), _) | (_, Inf(s)) => Inf(s),
+        (Zero(sa), Zero(sb)) => Zero(sa && sb),
+        (Zero(_), x) | (x, Zero(_)) => x,
+        (Num(sa, ea, ma), Num(sb, eb, mb)) => {
+            let ((sa, ea, ma), (sb, eb, mb)) = if (ea, ma) >= (eb, mb) {
+                ((sa, ea, ma), (sb, eb, mb))
+            } else {
+                ((sb, eb, mb), (sa, ea, ma))
+            };
+            /* keep a bit of headroom for the carry */
+            let ma = shr_jam(ma, 1);
+            let mb = shr_jam(mb, (ea - eb) as u32 + 1);
+            if sa == sb {
+                norm(sa, ea + 1, ma + mb)
+            } else if ma == mb {
+                Zero(false)
+            } else {
+                norm(sa, ea + 1, ma - mb)
+            }
+        }
+    }
+}
+
+/* an exact zero sum is +0, unless both terms are -0, but it is -0 when
+ * rounding toward negative, unless both terms are +0 */
+#[inline]
+fn zero_sign(r: Soft, a: Soft, b: Soft, rm: RoundingMode) -> Soft {
+    match r {
+        Zero(false)
+            if rm == RoundingMode::TowardNegative && (a, b) != (Zero(false), Zero(false)) =>
+        {
+            Zero(true)
+        }
+        x => x,
+    }
+}
+
+/// `add` with the sign of an exact zero result for the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn add_rm(a: Soft, b: Soft, rm: RoundingMode) -> Soft {
+    zero_sign(add(a, b), a, b, rm)
+}
+
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn sub(a: Soft, b: Soft) -> Soft {
+    add(a, b.neg())
+}
+
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn mul(a: Soft, b: Soft) -> Soft {
+    match (a, b) {
+        (Nan, _) | (_, Nan) => Nan,
+        (Inf(_), Zero(_)) | (Zero(_), Inf(_)) => Nan,
+        (Inf(s), x) | (x, Inf(s)) => Inf(s != x.sign()),
+        (Zero(s), x) | (x, Zero(s)) => Zero(s != x.sign()),
+        (Num(sa, ea, ma), Num(sb, eb, mb)) => {
+            let (hi, lo) = mul128(ma, mb);
+            norm256(sa != sb, ea + eb + 1, hi, lo)
+        }
+    }
+}
+
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn div(a: Soft, b: Soft) -> Soft {
+    match (a, b) {
+        (Nan, _) | (_, Nan) => Nan,
+        (Inf(_), Inf(_)) | (Zero(_), Zero(_)) => Nan,
+        (Inf(s), x) => Inf(s != x.sign()),
+        (Zero(s), x) => Zero(s != x.sign()),
+        (x, Inf(s)) => Zero(s != x.sign()),
+        (x, Zero(s)) => Inf(s != x.sign()),
+        (Num(sa, ea, ma), Num(sb, eb, mb)) => {
+            /* long division, one quotient bit per step */
+            let b = mb >> 1;
+            let mut r = ma >> 1;
+            let mut q: u128 = 0;
+            for _ in 0..128 {
+                q <<= 1;
+                if r >= b {
+                    r -= b;
+                    q |= 1;
+                }
+                r <<= 1;
+            }
+            norm(sa != sb, ea - eb, q | (r != 0) as u128)
+        }
+    }
+}
+
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn sqrt(a: Soft) -> Soft {
+    match a {
+        Nan | Inf(true) | Num(true, _, _) => Nan,
+        Inf(false) | Zero(_) => a,
+        Num(false, e, m) => {
+            /* a = m*2^k with m < 2^113, and the root of m*2^t for an
+             * exponent t that makes k - t even has 120 bits */
+            let m = m >> 15;
+            let k = e - 112;
+            let t = if k & 1 == 0 { 126 } else { 127 };
+            let mut r: u128 = 0;
+            let mut rem: u128 = 0;
+            for i in (0..120).rev() {
+                let j = 2 * i - t;
+                let pair = if j >= 0 {
+                    (m >> j) & 3
+                } else if j == -1 {
+                    (m << 1) & 3
+                } else {
+                    0
+                };
+                rem = (rem << 2) | pair;
+                let trial = (r << 2) | 1;
+                r <<= 1;
+                if rem >= trial {
+                    rem -= trial;
+                    r |= 1;
+                }
+            }
+            norm(false, (k - t) / 2 + 126, (r << 1) | (rem != 0) as u128)
+        }
+    }
+}
+
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn fma(a: Soft, b: Soft, c: Soft) -> Soft {
+    let (sp, ep, ph, pl, sc, ec, mc) = match (a, b, c) {
+        (Num(sa, ea, ma), Num(sb, eb, mb), Num(sc, ec, mc)) => {
+            let (hi, lo) = mul128(ma, mb);
+            (sa != sb, ea + eb + 1, hi, lo, sc, ec, mc)
+        }
+        /* the product is exact or the sum does not depend on it */
+        _ => return add(mul(a, b), c),
+    };
+
+    /* both terms as 256-bit integers with the top bit clear, scaled by
+     * 2^(ep-254) and 2^(ec-254) */
+    let (ph, pl) = shr256_jam(ph, pl, 1);
+    let (ch, cl) = (mc >> 1, mc << 127);
+    let (s, e, (xh, xl), (yh, yl), ys) = if ep >= ec {
+        (sp, ep, (ph, pl), shr256_jam(ch, cl, (ep - ec) as u32), sc)
+    } else {
+        (sc, ec, (ch, cl), shr256_jam(ph, pl, (ec - ep) as u32), sp)
+    };
+    if s == ys {
+        let (lo, carry) = xl.overflowing_add(yl);
+        norm256(s, e + 1, xh + yh + carry as u128, lo)
+    } else if (xh, xl) == (yh, yl) {
+        Zero(false)
+    } else {
+        let (s, (xh, xl), (yh, yl)) = if (xh, xl) > (yh, yl) {
+            (s, (xh, xl), (yh, yl))
+        } else {
+            (ys, (yh, yl), (xh, xl))
+        };
+        let (lo, borrow) = xl.overflowing_sub(yl);
+        norm256(s, e + 1, xh - yh - borrow as u128, lo)
+    }
+}
+
+/// `fma` with the sign of an exact zero result for the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn fma_rm(a: Soft, b: Soft, c: Soft, rm: RoundingMode) -> Soft {
+    zero_sign(fma(a, b, c), mul(a, b), c, rm)
+}
+
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn cmp(a: Soft, b: Soft) -> Option<Ordering> {
+    /* order the magnitudes by class first, then by exponent and
+     * significand */
+    let mag = |x: Soft| match x {
+        Nan => None,
+        Zero(_) => Some((0, 0, 0)),
+        Num(_, e, m) => Some((1, e, m)),
+        Inf(_) => Some((2, 0, 0)),
+    };
+    let (ma, mb) = (mag(a)?, mag(b)?);
+    /* -0 compares equal to +0 */
+    let na = a.sign() && ma.0 != 0;
+    let nb = b.sign() && mb.0 != 0;
+    Some(match (na, nb) {
+        (false, false) => ma.cmp(&mb),
+        (true, true) => mb.cmp(&ma),
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    })
+}
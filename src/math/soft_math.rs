@@ -0,0 +1,683 @@
+// Elementary functions on unpacked numbers, for the accurate phase of
+// the correctly rounded functions
+//
+// The arithmetic of soft_float keeps 128 bits, so that every operation
+// commits an error below 2^-126 relative to its result, with one caveat:
+// a sum whose terms nearly cancel is only that accurate if both terms
+// have at most 126 significant bits. The functions here are arranged so
+// that every such sum involves a double and a constant with trailing zero
+// bits, and evaluate Taylor series with enough terms for a truncation
+// error below 2^-130. Their results are within 2^-122 relative of the
+// exact values, with these exceptions: pow, see there; hypot, as sqrt
+// rounds its argument to 113 bits, and tgamma, whose exp has a larger
+// argument, which are within 2^-112; and those that cancel a sum of
+// larger terms, erfc, lgamma and the negative arguments of tgamma, which
+// are within 2^-90 for the arguments of the f32 functions.
+
+use super::double_double::two_prod;
+use super::rem_pio2_cr::rem_pio2_cr;
+use super::soft_float::{add, div, fma, mul, sqrt, sub, Soft};
+use super::{cbrt as cbrt64, sqrt as sqrt64, trunc};
+
+const ONE: Soft = Soft::Num(false, 0, 1 << 127);
+/* ln2 */
+const LN2: Soft = Soft::Num(false, -1, 0xb17217f7d1cf79abc9e3b39803f2f6af);
+/* the first 112 bits of ln2, and the rest */
+const LN2_HI: Soft = Soft::Num(false, -1, 0xb17217f7d1cf79abc9e3b39803f30000);
+const LN2_LO: Soft = Soft::Num(true, -117, 0x950bf0cbcd98d6749d275f2e8a474550);
+/* the first 46 bits of ln2, and the rest */
+const LN2_A: Soft = Soft::Num(false, -1, 0xb17217f7d1d000000000000000000000);
+const LN2_B: Soft = Soft::Num(true, -49, 0x8654361c4c67fc0d0950bf0cbcd98d67);
+/* ln10 and 1/ln10 */
+const LN10: Soft = Soft::Num(false, 1, 0x935d8dddaaa8ac16ea56d62b82d30a29);
+const INVLN10: Soft = Soft::Num(false, -2, 0xde5bd8a937287195355baaafad33dc32);
+/* 1/ln2 */
+const INVLN2: Soft = Soft::Num(false, 0, 0xb8aa3b295c17f0bbbe87fed0691d3e89);
+/* pi and pi/2 */
+const PI: Soft = Soft::Num(false, 1, 0xc90fdaa22168c234c4c6628b80dc1cd1);
+const PIO2: Soft = Soft::Num(false, 0, 0xc90fdaa22168c234c4c6628b80dc1cd1);
+/* 1/sqrt(pi) */
+const INVSQRTPI: Soft = Soft::Num(false, -1, 0x906eba8214db688d71d48a7f6bfec344);
+/* log(2*pi)/2 */
+const HALF_LN_2PI: Soft = Soft::Num(false, -1, 0xeb3f8e4325f5a53494bc900144192024);
+/* the significand of sqrt(2) */
+const SQRT2: u128 = 0xb504f333f9de6484597d89b3754abe9f;
+/* B_2k/(2k*(2k - 1)) for k = 1..17, the coefficients of Stirling's series */
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const STIRLING: [(i64, i64); 17] = [
+    (1, 12), (-1, 360), (1, 1260), (-1, 1680), (1, 1188), (-691, 360360),
+    (1, 156), (-3617, 122400), (43867, 244188), (-174611, 125400),
+    (77683, 5796), (-236364091, 1506960), (657931, 300),
+    (-3392780147, 93960), (1723168255201, 2492028),
+    (-7709321041217, 505920), (151628697551, 396),
+];
+/* atan(k/8) for k = 1..8 */
+const ATAN8: [Soft; 8] = [
+    Soft::Num(false, -4, 0xfeadd4d5617b6e32c897989f3e888ef8),
+    Soft::Num(false, -3, 0xfadbafc96406eb156dc79ef5f7a217e6),
+    Soft::Num(false, -2, 0xb7b0ca0f26f784738aa32122dcfe4483),
+    Soft::Num(false, -2, 0xed63382b0dda7b456fe445ecbc3a8d03),
+    Soft::Num(false, -1, 0x8f005d5ef7f59f9b5c835e1665c43748),
+    Soft::Num(false, -1, 0xa4bc7d1934f7092419a87f2a457dac9f),
+    Soft::Num(false, -1, 0xb8053e2bc2319e73cb2da55210a4443d),
+    Soft::Num(false, -1, 0xc90fdaa22168c234c4c6628b80dc1cd1),
+];
+
+#[inline]
+fn int(n: i64) -> Soft {
+    Soft::from_parts(n < 0, 0, n.unsigned_abs() as u128)
+}
+
+/* the integer nearest to x, for |x| < 2^31 */
+#[inline]
+fn nearest(x: f64) -> i32 {
+    if x < 0.0 {
+        (x - 0.5) as i32
+    } else {
+        (x + 0.5) as i32
+    }
+}
+
+/* 1 + x/a + x^2/(a(a+b)) + x^3/(a(a+b)(a+2b)) + ..., to n terms */
+#[inline]
+fn series(x: Soft, a: i64, b: i64, n: i64) -> Soft {
+    let mut s = ONE;
+    for k in (0..n).rev() {
+        s = add(ONE, div(mul(x, s), int(a + b * k)));
+    }
+    s
+}
+
+/* 2^k*exp(r), for |r| <= 0.36 */
+#[inline]
+fn exp_reduced(k: i32, r: Soft) -> Soft {
+    series(r, 1, 1, 27).scale(k)
+}
+
+/// `exp(x)`, for `|x| < 2^11` with at most 126 significant bits.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn exp(x: Soft) -> Soft {
+    let k = nearest(x.to_f64() * 1.4426950408889634);
+    let kk = int(k as i64);
+    /* x - k*LN2_HI is exact */
+    let r = sub(sub(x, mul(kk, LN2_HI)), mul(kk, LN2_LO));
+    exp_reduced(k, r)
+}
+
+/// `exp(x) - 1`, for `|x| < 2^11`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn expm1(x: f64) -> Soft {
+    if x.abs() < 0.5 {
+        let x = Soft::from_f64(x);
+        mul(x, series(x, 2, 1, 26))
+    } else {
+        sub(exp(Soft::from_f64(x)), ONE)
+    }
+}
+
+/// `2^x`, for `|x| < 2^11`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn exp2(x: f64) -> Soft {
+    let x1p52 = f64::from_bits(0x4338000000000000); // 0x1.8p52 === 1.5 * 2 ^ 52
+
+    let k = (x + x1p52) - x1p52;
+    exp_reduced(k as i32, mul(Soft::from_f64(x - k), LN2))
+}
+
+/// `10^x`, for `|x| < 2^9`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn exp10(x: f64) -> Soft {
+    exp(truncate(mul(Soft::from_f64(x), LN10), 126))
+}
+
+/* 2*atanh(s) = log((1 + s)/(1 - s)), for |s| <= 0.172 */
+#[inline]
+fn log_series(s: Soft) -> Soft {
+    let z = mul(s, s);
+    let mut t = div(ONE, int(53));
+    for k in (0..26).rev() {
+        t = add(div(ONE, int(2 * k + 1)), mul(z, t));
+    }
+    mul(s, t).scale(1)
+}
+
+/* x = 2^e*f with sqrt(2)/2 <= f < sqrt(2), for positive finite x */
+#[inline]
+fn frexp_sqrt2(x: Soft) -> (i32, Soft) {
+    match x {
+        Soft::Num(_, e, m) if m > SQRT2 => (e + 1, Soft::Num(false, -1, m)),
+        Soft::Num(_, e, m) => (e, Soft::Num(false, 0, m)),
+        _ => (0, x),
+    }
+}
+
+/* log(f) for sqrt(2)/2 <= f < sqrt(2) */
+#[inline]
+fn log_reduced(f: Soft) -> Soft {
+    log_series(div(sub(f, ONE), add(f, ONE)))
+}
+
+/* log(x) for positive finite x */
+#[inline]
+fn log_soft(x: Soft) -> Soft {
+    let (e, f) = frexp_sqrt2(x);
+    add(mul(int(e as i64), LN2), log_reduced(f))
+}
+
+/// `log(x)`, for positive finite `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn log(x: f64) -> Soft {
+    log_soft(Soft::from_f64(x))
+}
+
+/// `log2(x)`, for positive finite `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn log2(x: f64) -> Soft {
+    let (e, f) = frexp_sqrt2(Soft::from_f64(x));
+    add(int(e as i64), mul(log_reduced(f), INVLN2))
+}
+
+/* log(1 + x) for finite x > -1 */
+#[inline]
+fn log1p_soft(x: Soft) -> Soft {
+    let v = x.to_f64();
+    if v > -0.2928 && v < 0.4142 {
+        /* 1 + x is in the range of log_reduced, avoid forming it */
+        let two = ONE.scale(1);
+        log_series(div(x, add(two, x)))
+    } else {
+        log_soft(add(ONE, x))
+    }
+}
+
+/// `log(1 + x)`, for finite `x > -1`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn log1p(x: f64) -> Soft {
+    log1p_soft(Soft::from_f64(x))
+}
+
+/// `log10(x)`, for positive finite `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn log10(x: f64) -> Soft {
+    mul(log(x), INVLN10)
+}
+
+/* sin(r)/r in terms of z = -r^2, 1 + z/3! + z^2/5! + ..., for |z| < 0.62 */
+#[inline]
+fn sin_series(z: Soft) -> Soft {
+    let mut s = ONE;
+    for k in (1..17).rev() {
+        s = add(ONE, div(mul(z, s), int((2 * k) * (2 * k + 1))));
+    }
+    s
+}
+
+/* cos(r) in terms of z = -r^2, 1 + z/2! + z^2/4! + ..., for |z| < 0.62 */
+#[inline]
+fn cos_series(z: Soft) -> Soft {
+    let mut s = ONE;
+    for k in (1..18).rev() {
+        s = add(ONE, div(mul(z, s), int((2 * k - 1) * (2 * k))));
+    }
+    s
+}
+
+/// `sin(x)` and `cos(x)`, for finite `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn sin_cos(x: f64) -> (Soft, Soft) {
+    let (n, r) = if x.abs() <= 0.7853981633974483 {
+        (0, Soft::from_f64(x))
+    } else {
+        rem_pio2_cr(x)
+    };
+    let z = mul(r, r).neg();
+    let s = mul(r, sin_series(z));
+    let c = cos_series(z);
+    match n {
+        0 => (s, c),
+        1 => (c, s.neg()),
+        2 => (s.neg(), c.neg()),
+        _ => (c.neg(), s),
+    }
+}
+
+/* atan(t) for |t| <= 1/16 */
+#[inline]
+fn atan_series(t: Soft) -> Soft {
+    let z = mul(t, t).neg();
+    let mut u = div(ONE, int(35));
+    for k in (0..17).rev() {
+        u = add(div(ONE, int(2 * k + 1)), mul(z, u));
+    }
+    mul(t, u)
+}
+
+/* atan(v) for 0 <= v <= 1 */
+#[inline]
+fn atan_reduced(v: Soft) -> Soft {
+    /* atan(v) = atan(c) + atan((v - c)/(1 + v*c)) for c = k/8 */
+    let k = nearest(v.to_f64() * 8.0);
+    if k == 0 {
+        return atan_series(v);
+    }
+    let c = int(k as i64).scale(-3);
+    let t = div(sub(v, c), add(ONE, mul(v, c)));
+    add(i!(ATAN8, k as usize - 1), atan_series(t))
+}
+
+/* atan(v) for v >= 0 */
+#[inline]
+fn atan_soft(v: Soft) -> Soft {
+    if v.to_f64() > 1.0 {
+        sub(PIO2, atan_reduced(div(ONE, v)))
+    } else {
+        atan_reduced(v)
+    }
+}
+
+/* x with the sign flipped if neg is set */
+#[inline]
+fn with_sign(x: Soft, neg: bool) -> Soft {
+    if neg {
+        x.neg()
+    } else {
+        x
+    }
+}
+
+/// `atan(x)`, for finite `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn atan(x: f64) -> Soft {
+    with_sign(atan_soft(Soft::from_f64(x.abs())), x.is_sign_negative())
+}
+
+/// `atan2(y, x)`, for finite nonzero `x` and `y`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn atan2(y: f64, x: f64) -> Soft {
+    let r = atan_soft(div(Soft::from_f64(y.abs()), Soft::from_f64(x.abs())));
+    let r = if x < 0.0 { sub(PI, r) } else { r };
+    with_sign(r, y < 0.0)
+}
+
+/// `asin(x)`, for `|x| <= 1`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn asin(x: f64) -> Soft {
+    /* asin(x) = atan(x/sqrt(1 - x^2)), where 1 - x^2 is exact */
+    let a = Soft::from_f64(x.abs());
+    let c = sqrt(sub(ONE, mul(a, a)));
+    let r = if x.abs() == 1.0 {
+        PIO2
+    } else {
+        atan_soft(div(a, c))
+    };
+    with_sign(r, x < 0.0)
+}
+
+/// `acos(x)`, for `|x| <= 1`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn acos(x: f64) -> Soft {
+    let a = Soft::from_f64(x.abs());
+    let c = sqrt(sub(ONE, mul(a, a)));
+    let r = if x == 0.0 { PIO2 } else { atan_soft(div(c, a)) };
+    if x < 0.0 {
+        sub(PI, r)
+    } else {
+        r
+    }
+}
+
+/// `sinh(x)`, for `|x| < 2^11`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn sinh(x: f64) -> Soft {
+    let xs = Soft::from_f64(x);
+    if x.abs() < 0.5 {
+        /* the series of sin with the signs of its terms flipped */
+        return mul(xs, sin_series(mul(xs, xs)));
+    }
+    let e = exp(Soft::from_f64(x.abs()));
+    let r = sub(e, div(ONE, e)).scale(-1);
+    if x < 0.0 {
+        r.neg()
+    } else {
+        r
+    }
+}
+
+/// `cosh(x)`, for `|x| < 2^11`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn cosh(x: f64) -> Soft {
+    let e = exp(Soft::from_f64(x.abs()));
+    add(e, div(ONE, e)).scale(-1)
+}
+
+/// `tanh(x)`, for `|x| < 2^10`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn tanh(x: f64) -> Soft {
+    let e = expm1(2.0 * x.abs());
+    with_sign(div(e, add(e, ONE.scale(1))), x < 0.0)
+}
+
+/// `asinh(x)`, for finite `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn asinh(x: f64) -> Soft {
+    /* log(a + sqrt(a^2 + 1)) = log1p(a + a^2/(1 + sqrt(a^2 + 1))) */
+    let a = Soft::from_f64(x.abs());
+    let a2 = mul(a, a);
+    let u = add(a, div(a2, add(ONE, sqrt(add(a2, ONE)))));
+    with_sign(log1p_soft(u), x < 0.0)
+}
+
+/// `acosh(x)`, for finite `x >= 1`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn acosh(x: f64) -> Soft {
+    /* log1p(t + sqrt(t^2 + 2t)), where t = x - 1 is exact */
+    let t = sub(Soft::from_f64(x), ONE);
+    log1p_soft(add(t, sqrt(mul(t, add(t, ONE.scale(1))))))
+}
+
+/// `atanh(x)`, for `|x| < 1`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn atanh(x: f64) -> Soft {
+    let a = Soft::from_f64(x.abs());
+    let r = log1p_soft(div(a.scale(1), sub(ONE, a))).scale(-1);
+    with_sign(r, x < 0.0)
+}
+
+/// `hypot(x, y)`, for finite `x` and `y`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn hypot(x: f64, y: f64) -> Soft {
+    let (x, y) = (Soft::from_f64(x), Soft::from_f64(y));
+    sqrt(add(mul(x, x), mul(y, y)))
+}
+
+/// `cbrt(x)`, for finite nonzero `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn cbrt(x: f64) -> Soft {
+    /* two Newton steps from the f64 root, which has 52 bits */
+    let a = Soft::from_f64(x.abs());
+    let mut r = Soft::from_f64(cbrt64(x.abs()));
+    for _ in 0..2 {
+        let r2 = mul(r, r);
+        r = sub(r, div(sub(mul(r2, r), a), mul(int(3), r2)));
+    }
+    with_sign(r, x < 0.0)
+}
+
+/* the exponent of x, with x = m*2^e and 1 <= m < 2 */
+#[inline]
+fn exponent(x: Soft) -> i32 {
+    match x {
+        Soft::Num(_, e, _) => e,
+        _ => i32::MIN / 2,
+    }
+}
+
+/// `erf(x)`, for finite `x`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn erf(x: f64) -> Soft {
+    if x == 0.0 {
+        return Soft::from_f64(x);
+    }
+    let a = x.abs();
+    let r = if a < 4.0 {
+        /* 2/sqrt(pi)*exp(-a^2)*(a + 2a^3/3 + 4a^5/15 + ...), whose terms
+         * are all positive */
+        let a = Soft::from_f64(a);
+        let z = mul(a, a);
+        let mut t = a;
+        let mut s = a;
+        let mut n = 1;
+        while exponent(t) > exponent(s) - 130 {
+            t = div(mul(t, z.scale(1)), int(2 * n + 1));
+            s = add(s, t);
+            n += 1;
+        }
+        mul(mul(INVSQRTPI.scale(1), exp(z.neg())), s)
+    } else {
+        sub(ONE, erfc_cf(a))
+    };
+    with_sign(r, x < 0.0)
+}
+
+/* erfc(x) for 4 <= x < 45, by its continued fraction
+ * exp(-x^2)/sqrt(pi) * 1/(x + (1/2)/(x + 1/(x + (3/2)/(x + ...)))) */
+#[inline]
+fn erfc_cf(x: f64) -> Soft {
+    let xs = Soft::from_f64(x);
+    let mut t = Soft::Zero(false);
+    for k in (1..=80).rev() {
+        t = div(int(k).scale(-1), add(xs, t));
+    }
+    div(mul(INVSQRTPI, exp(mul(xs, xs).neg())), add(xs, t))
+}
+
+/// `erfc(x)`, for `x < 45`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn erfc(x: f64) -> Soft {
+    if x < 4.0 {
+        sub(ONE, erf(x))
+    } else {
+        erfc_cf(x)
+    }
+}
+
+/* log(gamma(z)) by Stirling's series, for z >= 40 */
+#[inline]
+fn stirling(z: Soft) -> Soft {
+    let half = ONE.scale(-1);
+    let mut s = add(sub(mul(sub(z, half), log_soft(z)), z), HALF_LN_2PI);
+    let iz2 = div(ONE, mul(z, z));
+    let mut p = div(ONE, z);
+    for &(n, d) in STIRLING.iter() {
+        s = add(s, div(mul(int(n), p), int(d)));
+        p = mul(p, iz2);
+    }
+    s
+}
+
+/* (s, p) with gamma(x) = exp(s)/p, for finite x > 0 */
+#[inline]
+fn gamma_parts(x: Soft) -> (Soft, Soft) {
+    /* gamma(x) = gamma(x + n)/(x*(x + 1)*...*(x + n - 1)), x + n >= 40 */
+    let n = if x.to_f64() < 40.0 {
+        (40.0 - x.to_f64()) as i64 + 1
+    } else {
+        0
+    };
+    let mut p = ONE;
+    for k in 0..n {
+        p = mul(p, add(x, int(k)));
+    }
+    (stirling(add(x, int(n))), p)
+}
+
+/* sin(pi*x), for finite x */
+#[inline]
+fn sinpi(x: f64) -> Soft {
+    let k = trunc(x);
+    let (k, r) = if x - k > 0.5 {
+        (k + 1.0, x - k - 1.0)
+    } else if x - k < -0.5 {
+        (k - 1.0, x - k + 1.0)
+    } else {
+        (k, x - k)
+    };
+    /* x = k + r with |r| <= 1/2 exactly, sin(pi*x) = (-1)^k*sin(pi*r) */
+    let s = if r.abs() <= 0.25 {
+        let t = mul(PI, Soft::from_f64(r));
+        mul(t, sin_series(mul(t, t).neg()))
+    } else {
+        let t = mul(PI, Soft::from_f64(0.5 - r.abs()));
+        with_sign(cos_series(mul(t, t).neg()), r < 0.0)
+    };
+    with_sign(s, k.abs() < 9007199254740992.0 && k % 2.0 != 0.0)
+}
+
+/// `gamma(x)`, for `-300 < x < 171` that is not zero or a negative
+/// integer.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn tgamma(x: f64) -> Soft {
+    if x > 0.0 && x == trunc(x) && x < 35.0 {
+        /* (x - 1)!, which has at most 128 bits */
+        let mut p = ONE;
+        for k in 2..x as i64 {
+            p = mul(p, int(k));
+        }
+        return p;
+    }
+    if x > 0.0 {
+        let (s, p) = gamma_parts(Soft::from_f64(x));
+        return div(exp(truncate(s, 126)), p);
+    }
+    /* gamma(x) = pi/(sin(pi*x)*gamma(1 - x)) */
+    let (s, p) = gamma_parts(sub(ONE, Soft::from_f64(x)));
+    div(mul(PI, p), mul(sinpi(x), exp(truncate(s, 126))))
+}
+
+/// `log(|gamma(x)|)`, for finite `x` that is not zero or a negative
+/// integer.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn lgamma(x: f64) -> Soft {
+    if x == 1.0 || x == 2.0 {
+        return Soft::Zero(false);
+    }
+    if x > 0.0 {
+        let (s, p) = gamma_parts(Soft::from_f64(x));
+        return sub(s, log_soft(p));
+    }
+    /* log(pi/|sin(pi*x)|) - log(gamma(1 - x)) */
+    let (s, p) = gamma_parts(sub(ONE, Soft::from_f64(x)));
+    let sp = sinpi(x);
+    let sp = if sp.sign() { sp.neg() } else { sp };
+    sub(log_soft(div(mul(PI, p), sp)), s)
+}
+
+/* x with its significand cut to n bits */
+#[inline]
+fn truncate(x: Soft, n: u32) -> Soft {
+    match x {
+        Soft::Num(s, e, m) => Soft::Num(s, e, m & !((1 << (128 - n)) - 1)),
+        x => x,
+    }
+}
+
+/// `x^y`, for positive finite `x` and finite `y` with `|y*log(x)| < 2^10`.
+///
+/// `y*log(x)` is needed to about 2^-130 absolute, more than 128 bits for
+/// large results, so `log(x) = e*ln2 + log(f)` is kept as a sum of terms
+/// whose products by `y` are exact or small. The relative error is below
+/// 2^-122 for results between 1/e and e, and grows to 2^-119 near the
+/// overflow and underflow thresholds.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn pow(x: f64, y: f64) -> Soft {
+    let ln2 = 0.6931471805599453;
+
+    let (e, f) = frexp_sqrt2(Soft::from_f64(x));
+    let fm1 = sub(f, ONE);
+    let fp1 = add(f, ONE);
+    /* s = (f - 1)/(f + 1) = s1 + s2, with s1 of 60 bits */
+    let s = div(fm1, fp1);
+    let s1 = truncate(s, 60);
+    let s2 = div(fma(s1.neg(), fp1, fm1), fp1);
+    /* log(f) = 2*s1 + 2*s2 + 2*s^3*(1/3 + s^2/5 + ...) */
+    let z = mul(s, s);
+    let mut u = div(ONE, int(53));
+    for k in (1..26).rev() {
+        u = add(div(ONE, int(2 * k + 1)), mul(z, u));
+    }
+    let t3 = mul(mul(s, z), u).scale(1);
+
+    /* r = y*log(x) - k*ln2 = (y*e - k)*ln2 + y*log(f), where y*e - k,
+     * (y*e - k)*LN2_A and y*2*s1 are exact, and so is the sum of the
+     * last two, in which most of the cancellation occurs */
+    let yl = y * (e as f64 * ln2 + 2.0 * add(s, t3.scale(-1)).to_f64());
+    let k = nearest(yl / ln2);
+    let ys = Soft::from_f64(y);
+    let w = sub(mul(ys, int(e as i64)), int(k as i64));
+    let hi = add(mul(w, LN2_A), mul(ys, s1.scale(1)));
+    let lo = add(add(mul(w, LN2_B), mul(ys, s2.scale(1))), mul(ys, t3));
+    exp_reduced(k, add(hi, lo))
+}
+
+/// `x^y` if it is a dyadic number of at most 54 bits, as are the values
+/// of `pow` that are exact or halfway between two doubles or two floats,
+/// for positive finite `x` and `y` with `|y*log(x)| < 2^11`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn exact_pow(x: f64, y: f64) -> Option<Soft> {
+    let x1p54 = f64::from_bits(0x4350000000000000); // 0x1p54 === 2 ^ 54
+
+    /* x = mx*2^ex with mx odd */
+    let ix = x.to_bits();
+    let e = (ix >> 52) as i32;
+    let m = if e == 0 {
+        ix
+    } else {
+        ix & 0x000fffffffffffff | 1 << 52
+    };
+    let tz = m.trailing_zeros();
+    let mx = m >> tz;
+    let ex = if e == 0 { -1074 } else { e - 1075 } + tz as i32;
+
+    if mx == 1 {
+        /* a power of 2, which is exact if ex*y is an integer */
+        let (p, pe) = two_prod(ex as f64, y);
+        return if pe == 0.0 && p == trunc(p) {
+            Some(Soft::from_parts(false, p as i32, 1))
+        } else {
+            None
+        };
+    }
+    /* otherwise only positive y = n/2^k with mx a 2^k-th power, and k <= 5
+     * as the square of an odd mx > 1 adds a bit */
+    let mut k = 0;
+    while k <= 5 && y * (1 << k) as f64 != trunc(y * (1 << k) as f64) {
+        k += 1;
+    }
+    let n = y * (1 << k) as f64;
+    if k > 5 || n <= 0.0 || n > 54.0 || ex % (1 << k) != 0 {
+        return None;
+    }
+    let mut mz = mx;
+    for _ in 0..k {
+        let s = sqrt64(mz as f64) as u64;
+        if s * s != mz {
+            return None;
+        }
+        mz = s;
+    }
+    let mut c: u128 = 1;
+    for _ in 0..n as i32 {
+        c *= mz as u128;
+        if c as f64 > x1p54 {
+            return None;
+        }
+    }
+    Some(Soft::from_parts(false, (ex >> k) * n as i32, c))
+}
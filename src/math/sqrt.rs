@@ -76,6 +76,7 @@
  *      sqrt(NaN) = NaN         ... with invalid signal for signaling NaN
  */
 
+use super::fenv::{inexact, invalid};
 use core::f64;
 use core::num::Wrapping;
 
@@ -115,7 +116,12 @@ pub fn sqrt(x: f64) -> f64 {
 
     /* take care of Inf and NaN */
     if (ix0 & 0x7ff00000) == 0x7ff00000 {
-        return x * x + x; /* sqrt(NaN)=NaN, sqrt(+inf)=+inf, sqrt(-inf)=sNaN */
+        /* sqrt(NaN)=NaN, sqrt(+inf)=+inf, sqrt(-inf)=sNaN */
+        return if x < 0.0 {
+            invalid(x * x + x)
+        } else {
+            x * x + x
+        };
     }
     /* take care of zero */
     if ix0 <= 0 {
@@ -123,7 +129,7 @@ pub fn sqrt(x: f64) -> f64 {
             return x; /* sqrt(+-0) = +-0 */
         }
         if ix0 < 0 {
-            return (x - x) / (x - x); /* sqrt(-ve) = sNaN */
+            return invalid((x - x) / (x - x)); /* sqrt(-ve) = sNaN */
         }
     }
     /* normalize x */
@@ -196,6 +202,7 @@ pub fn sqrt(x: f64) -> f64 {
     }
 
     /* use floating add to find out rounding direction */
+    inexact((ix0 as u32 | ix1.0) != 0);
     if (ix0 as u32 | ix1.0) != 0 {
         z = 1.0 - TINY; /* raise inexact flag */
         if z >= 1.0 {
@@ -221,3 +228,27 @@ pub fn sqrt(x: f64) -> f64 {
     ix0 += m << 20;
     f64::from_bits((ix0 as u64) << 32 | ix1.0 as u64)
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::sqrt;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (-1.0, FE_INVALID),
+            (4.0, 0),
+            (2.0, FE_INEXACT),
+            (-0.0, 0),
+            (f64::NEG_INFINITY, FE_INVALID),
+            (f64::INFINITY, 0),
+            (f64::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(sqrt, x), e, "sqrt({:?})", x);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use super::soft_float::{self, Soft};
+use super::RoundingMode;
+
+/// Square root with explicit rounding (f64)
+///
+/// Returns the square root of `x`, correctly rounded in the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrt_rm(x: f64, rm: RoundingMode) -> f64 {
+    soft_float::sqrt(Soft::from_f64(x)).to_f64_rm(rm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fma, nextafter, sqrt};
+    use super::sqrt_rm;
+    use super::RoundingMode::*;
+
+    #[test]
+    fn directed() {
+        /* sqrt(2) = 1.41421356237309504880... */
+        assert_eq!(sqrt_rm(2.0, TowardNegative).to_bits(), 0x3ff6a09e667f3bcc);
+        assert_eq!(sqrt_rm(2.0, TowardZero).to_bits(), 0x3ff6a09e667f3bcc);
+        assert_eq!(sqrt_rm(2.0, TowardPositive).to_bits(), 0x3ff6a09e667f3bcd);
+        assert_eq!(sqrt_rm(2.0, TiesToEven).to_bits(), 0x3ff6a09e667f3bcd);
+        assert_eq!(sqrt_rm(2.0, TiesToAway).to_bits(), 0x3ff6a09e667f3bcd);
+        assert_eq!(sqrt_rm(4.0, TowardPositive), 2.0);
+        assert_eq!(sqrt_rm(-0.0, TowardNegative).to_bits(), 0x8000000000000000);
+        assert_eq!(sqrt_rm(1.0 / 0.0, TowardZero), 1.0 / 0.0);
+        assert!(sqrt_rm(-1.0, TowardPositive).is_nan());
+    }
+
+    #[test]
+    fn brackets_the_root() {
+        for i in 0..20000u64 {
+            let x = f64::from_bits(i.wrapping_mul(0x9e3779b97f4a7c15) >> 1);
+            if !x.is_finite() {
+                continue;
+            }
+            let lo = sqrt_rm(x, TowardNegative);
+            let hi = sqrt_rm(x, TowardPositive);
+            assert!(fma(lo, lo, -x) <= 0.0 && fma(hi, hi, -x) >= 0.0, "{:e}", x);
+            assert!(hi == lo || hi == nextafter(lo, 1.0 / 0.0), "{:e}", x);
+            assert_eq!(sqrt_rm(x, TowardZero), lo);
+            assert_eq!(sqrt_rm(x, TiesToEven), sqrt(x));
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::{bf16, sqrt};
+
+/// Square root (bf16)
+///
+/// Computes the square root of `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtbf16(x: bf16) -> bf16 {
+    bf16::from_f64(sqrt(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bfloat16::{check, check_f32};
+    use super::super::{sqrt, sqrtf};
+    use super::{bf16, sqrtbf16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check_f32(x, sqrtbf16(x), sqrtf(x.to_f32()), 1);
+        }
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check(x, sqrtbf16(x), sqrt(x.to_f64()));
+        }
+    }
+}
@@ -13,6 +13,8 @@
  * ====================================================
  */
 
+use super::fenv::{inexact, invalid};
+
 const TINY: f32 = 1.0e-30;
 
 #[inline]
@@ -44,7 +46,12 @@ pub fn sqrtf(x: f32) -> f32 {
 
     /* take care of Inf and NaN */
     if (ix as u32 & 0x7f800000) == 0x7f800000 {
-        return x * x + x; /* sqrt(NaN)=NaN, sqrt(+inf)=+inf, sqrt(-inf)=sNaN */
+        /* sqrt(NaN)=NaN, sqrt(+inf)=+inf, sqrt(-inf)=sNaN */
+        return if x < 0.0 {
+            invalid(x * x + x)
+        } else {
+            x * x + x
+        };
     }
 
     /* take care of zero */
@@ -53,7 +60,7 @@ pub fn sqrtf(x: f32) -> f32 {
             return x; /* sqrt(+-0) = +-0 */
         }
         if ix < 0 {
-            return (x - x) / (x - x); /* sqrt(-ve) = sNaN */
+            return invalid((x - x) / (x - x)); /* sqrt(-ve) = sNaN */
         }
     }
 
@@ -94,6 +101,7 @@ pub fn sqrtf(x: f32) -> f32 {
     }
 
     /* use floating add to find out rounding direction */
+    inexact(ix != 0);
     if ix != 0 {
         z = 1.0 - TINY; /* raise inexact flag */
         if z >= 1.0 {
@@ -110,3 +118,27 @@ pub fn sqrtf(x: f32) -> f32 {
     ix += m << 23;
     f32::from_bits(ix as u32)
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::sqrtf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (-1.0, FE_INVALID),
+            (4.0, 0),
+            (2.0, FE_INEXACT),
+            (-0.0, 0),
+            (f32::NEG_INFINITY, FE_INVALID),
+            (f32::INFINITY, 0),
+            (f32::NAN, 0),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(sqrtf, x), e, "sqrtf({:?})", x);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use super::f128;
+use super::soft_float;
+
+/// Square root (f128)
+///
+/// Computes the square root of `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf128(x: f128) -> f128 {
+    f128::pack(soft_float::sqrt(x.unpack()))
+}
@@ -0,0 +1,36 @@
+use super::{f16, sqrt};
+
+/// Square root (f16)
+///
+/// Computes the square root of `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf16(x: f16) -> f16 {
+    f16::from_f64(sqrt(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{f16, sqrtf16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0x7c00 {
+            let x = f16::from_bits(i);
+            let r = sqrtf16(x);
+            if i == 0 || i == 0x7c00 {
+                assert_eq!(r.to_bits(), i);
+                continue;
+            }
+            /* the halfway points on either side of r have 12 bits, so
+             * their squares are exact in f64 */
+            let y = r.to_f64();
+            let lo = 0.5 * (y + f16::from_bits(r.to_bits() - 1).to_f64());
+            let hi = 0.5 * (y + f16::from_bits(r.to_bits() + 1).to_f64());
+            let x = x.to_f64();
+            assert!(lo * lo < x && x < hi * hi, "sqrt({:e}) = {:e}", x, y);
+        }
+        assert!(sqrtf16(f16::from_bits(0xbc00)).is_nan());
+        assert_eq!(sqrtf16(f16::from_bits(0x8000)).to_bits(), 0x8000);
+    }
+}
@@ -0,0 +1,11 @@
+use super::f80;
+use super::soft_float;
+
+/// Square root (f80)
+///
+/// Computes the square root of `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf80(x: f80) -> f80 {
+    f80::pack(soft_float::sqrt(x.unpack()))
+}
@@ -0,0 +1,35 @@
+use super::soft_float::{self, Soft};
+use super::RoundingMode;
+
+/// Square root with explicit rounding (f32)
+///
+/// Returns the square root of `x`, correctly rounded in the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf_rm(x: f32, rm: RoundingMode) -> f32 {
+    soft_float::sqrt(Soft::from_f64(x as f64)).to_f32_rm(rm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sqrtf_rm;
+    use super::RoundingMode::*;
+
+    #[test]
+    fn brackets_the_root() {
+        /* the squares of f32 values are exact in f64 */
+        for ix in (0..0x7f800000).step_by(9973) {
+            let x = f32::from_bits(ix);
+            let lo = sqrtf_rm(x, TowardNegative);
+            let hi = sqrtf_rm(x, TowardPositive);
+            let (l, h, x) = (lo as f64, hi as f64, x as f64);
+            if lo == hi {
+                assert_eq!(l * l, x);
+            } else {
+                assert_eq!(hi.to_bits(), lo.to_bits() + 1);
+                assert!(l * l < x && x < h * h, "{:e}", x);
+            }
+            assert_eq!(sqrtf_rm(x as f32, TowardZero), lo);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use super::soft_float::{self, Soft};
+use super::RoundingMode;
+
+/// Subtraction with explicit rounding (f64)
+///
+/// Returns `x - y`, correctly rounded in the direction `rm`.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sub_rm(x: f64, y: f64, rm: RoundingMode) -> f64 {
+    soft_float::add_rm(Soft::from_f64(x), Soft::from_f64(y).neg(), rm).to_f64_rm(rm)
+}
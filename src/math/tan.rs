@@ -9,6 +9,7 @@
 // is preserved.
 // ====================================================
 
+use super::fenv::{inexact, invalid};
 use super::{k_tan, rem_pio2};
 
 // tan(x)
@@ -45,11 +46,16 @@ pub fn tan(x: f64) -> f64 {
     let x1p120 = f32::from_bits(0x7b800000); // 0x1p120f === 2 ^ 120
 
     let ix = (f64::to_bits(x) >> 32) as u32 & 0x7fffffff;
+    /* tan(x) is inexact for finite x != 0 */
+    inexact(x != 0.0 && ix < 0x7ff00000);
     /* |x| ~< pi/4 */
     if ix <= 0x3fe921fb {
         if ix < 0x3e400000 {
             /* |x| < 2**-27 */
             /* raise inexact if x!=0 and underflow if subnormal */
+            if x == 0.0 {
+                return x;
+            }
             force_eval!(if ix < 0x00100000 {
                 x / x1p120 as f64
             } else {
@@ -62,10 +68,26 @@ pub fn tan(x: f64) -> f64 {
 
     /* tan(Inf or NaN) is NaN */
     if ix >= 0x7ff00000 {
-        return x - x;
+        return if x.is_nan() { x - x } else { invalid(x - x) };
     }
 
     /* argument reduction */
     let (n, y0, y1) = rem_pio2(x);
     k_tan(y0, y1, n & 1)
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::{FE_INEXACT, FE_INVALID};
+    use super::tan;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [(f64::INFINITY, FE_INVALID), (1.0, FE_INEXACT), (0.0, 0)];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(tan, x), e, "tan({:?})", x);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::{f128, f80, k_cosf128, k_sinf128, rem_pio2f80};
+
+const PIO4: f80 = f80::from_bits(0x3ffec90fdaa22168c235); /* 0.785398163397448309616 */
+
+/// Tangent (f80)
+///
+/// Computes the tangent of `x` (in radians). The quotient of the f128 sine
+/// and cosine kernels is accurate to a few f128 ulps before it is rounded
+/// to f80.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanf80(x: f80) -> f80 {
+    let e = (x.to_bits() >> 64) as i32 & 0x7fff;
+
+    if e == 0x7fff {
+        return x - x;
+    }
+    if (if x.is_sign_negative() { -x } else { x }) < PIO4 {
+        if e < 0x3fff - 64 / 2 {
+            /* tan(x) rounds to x */
+            return x;
+        }
+        let hi = f128::from(x);
+        return f80::from_f128(k_sinf128(hi, f128::ZERO, 0) / k_cosf128(hi, f128::ZERO));
+    }
+    let (n, y0, y1) = rem_pio2f80(x);
+    let hi = f128::from(y0) + f128::from(y1);
+    let lo = f128::from(y1) - (hi - f128::from(y0));
+    let s = k_sinf128(hi, lo, 1);
+    let c = k_cosf128(hi, lo);
+    f80::from_f128(if n & 1 == 0 { s / c } else { -c / s })
+}
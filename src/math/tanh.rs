@@ -1,4 +1,5 @@
 use super::expm1;
+use super::fenv::inexact;
 
 /* tanh(x) = (exp(x) - exp(-x))/(exp(x) + exp(-x))
  *         = (exp(2*x) - 1)/(exp(2*x) - 1 + 2)
@@ -26,6 +27,7 @@ pub fn tanh(mut x: f64) -> f64 {
         if w > 0x40340000 {
             /* |x| > 20 or nan */
             /* note: this branch avoids raising overflow */
+            inexact(w < 0x7ff00000);
             t = 1.0 - 0.0 / x;
         } else {
             t = expm1(2.0 * x);
@@ -42,7 +44,9 @@ pub fn tanh(mut x: f64) -> f64 {
     } else {
         /* |x| is subnormal */
         /* note: the branch above would not raise underflow in [0x1p-1023,0x1p-1022) */
-        force_eval!(x as f32);
+        if x != 0.0 {
+            force_eval!(x as f32);
+        }
         t = x;
     }
 
@@ -52,3 +56,24 @@ pub fn tanh(mut x: f64) -> f64 {
         t
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INEXACT;
+    use super::tanh;
+    use core::f64;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f64::INFINITY, 0),
+            (30.0, FE_INEXACT),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(tanh, x), e, "tanh({:?})", x);
+        }
+    }
+}
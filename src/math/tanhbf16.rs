@@ -0,0 +1,33 @@
+use super::{bf16, tanh};
+
+/// Hyperbolic tangent (bf16)
+///
+/// Computes the hyperbolic tangent of `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanhbf16(x: bf16) -> bf16 {
+    bf16::from_f64(tanh(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bfloat16::{check, check_f32};
+    use super::super::{cr_tanhf, tanhf};
+    use super::{bf16, tanhbf16};
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check_f32(x, tanhbf16(x), tanhf(x.to_f32()), 1);
+        }
+    }
+
+    #[test]
+    fn correctly_rounded() {
+        for i in 0..=0xffff {
+            let x = bf16::from_bits(i);
+            check(x, tanhbf16(x), cr_tanhf(x.to_f32()) as f64);
+        }
+    }
+}
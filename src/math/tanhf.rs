@@ -1,4 +1,5 @@
 use super::expm1f;
+use super::fenv::inexact;
 
 #[inline]
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
@@ -14,6 +15,7 @@ pub fn tanhf(mut x: f32) -> f32 {
         /* |x| > log(3)/2 ~= 0.5493 or nan */
         if w > 0x41200000 {
             /* |x| > 10 */
+            inexact(w < 0x7f800000);
             1. + 0. / x
         } else {
             let t = expm1f(2. * x);
@@ -29,7 +31,9 @@ pub fn tanhf(mut x: f32) -> f32 {
         -t / (t + 2.)
     } else {
         /* |x| is subnormal */
-        force_eval!(x * x);
+        if x != 0.0 {
+            force_eval!(x * x);
+        }
         x
     };
     if sign {
@@ -38,3 +42,24 @@ pub fn tanhf(mut x: f32) -> f32 {
         tt
     }
 }
+
+#[cfg(all(test, feature = "soft-fenv"))]
+mod fenv_tests {
+    use super::super::fenv::raised;
+    use super::super::FE_INEXACT;
+    use super::tanhf;
+    use core::f32;
+
+    #[test]
+    fn exceptions() {
+        let cases = [
+            (1.0, FE_INEXACT),
+            (0.0, 0),
+            (f32::INFINITY, 0),
+            (30.0, FE_INEXACT),
+        ];
+        for &(x, e) in cases.iter() {
+            assert_eq!(raised(tanhf, x), e, "tanhf({:?})", x);
+        }
+    }
+}
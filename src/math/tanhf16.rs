@@ -0,0 +1,37 @@
+use super::{f16, tanh};
+
+/// Hyperbolic tangent (f16)
+///
+/// Computes the hyperbolic tangent of `x`, correctly rounded.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanhf16(x: f16) -> f16 {
+    f16::from_f64(tanh(x.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::float16::check;
+    use super::super::soft_math;
+    use super::{f16, tanhf16};
+
+    /* tanh(x), evaluated to within 2^-122 and rounded to f64 */
+    fn reference(x: f16) -> f64 {
+        let x = x.to_f64();
+        if x.is_nan() || x == 0.0 {
+            x
+        } else if x.abs() >= 1024.0 {
+            x.signum()
+        } else {
+            soft_math::tanh(x).to_f64()
+        }
+    }
+
+    #[test]
+    fn exhaustive() {
+        for i in 0..=0xffff {
+            let x = f16::from_bits(i);
+            check(x, tanhf16(x), reference(x));
+        }
+    }
+}
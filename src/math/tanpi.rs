@@ -0,0 +1,159 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_tanpi.c */
+/*-
+ * Copyright (c) 2017 Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice unmodified, this list of conditions, and the following
+ *    disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ * IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ * OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ * IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ * INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ * NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ * DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ * THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ * (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ * THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use super::{get_high_word, k_tan, with_set_low_word};
+
+const PI_HI: f64 = 3.1415926814079285e+00; /* 0x400921fb, 0x58000000 */
+const PI_LO: f64 = -2.7818135228334233e-08; /* 0xbe5dde97, 0x3dcb3b3a */
+
+/* kernel tanpi function on [0, 0.5), see k_sinpi for the product with pi */
+#[inline]
+fn k_tanpi(mut x: f64) -> f64 {
+    /* tan(pi * x) = 1 / tan(pi * (0.5 - x)) */
+    let odd = if x < 0.25 {
+        0
+    } else if x > 0.25 {
+        x = 0.5 - x;
+        1
+    } else {
+        return 1.0;
+    };
+
+    let mut hi = with_set_low_word(x, 0);
+    let mut lo = x - hi;
+    lo = lo * (PI_LO + PI_HI) + hi * PI_LO;
+    hi *= PI_HI;
+    let s = hi + lo;
+    lo -= s - hi;
+    hi = s;
+    let t = k_tan(hi, lo, odd);
+    if odd != 0 {
+        -t
+    } else {
+        t
+    }
+}
+
+/// Tangent of pi times x (f64)
+///
+/// Computes `tan(pi * x)` without the rounding error of the product
+/// `pi * x`. The result is exact at integers, where it is a signed zero, and
+/// at half-integers, where it is an infinity and raises the divide-by-zero
+/// exception.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpi(x: f64) -> f64 {
+    let x1p53 = f64::from_bits(0x4340000000000000); // 0x1p53 === 2 ^ 53
+    let x1p_53 = f64::from_bits(0x3ca0000000000000); // 0x1p-53 === 2 ^ -53
+
+    let sign = x.is_sign_negative();
+    let ix = get_high_word(x) & 0x7fffffff;
+    let mut ax = f64::from_bits(x.to_bits() & 0x7fffffffffffffff);
+    let t: f64;
+
+    /* |x| < 1 */
+    if ix < 0x3ff00000 {
+        /* |x| < 0.5 */
+        if ix < 0x3fe00000 {
+            /* |x| < 0x1p-29 */
+            if ix < 0x3e200000 {
+                if x == 0.0 {
+                    return x;
+                }
+                /* scale to avoid subnormal intermediate values */
+                let hi = with_set_low_word(x, 0) * x1p53;
+                let lo = x * x1p53 - hi;
+                let t = (PI_LO + PI_HI) * lo + PI_LO * hi + PI_HI * hi;
+                return t * x1p_53;
+            }
+            t = k_tanpi(ax);
+        } else if ax == 0.5 {
+            t = 1.0 / 0.0;
+        } else {
+            t = -k_tanpi(1.0 - ax);
+        }
+        return if sign { -t } else { t };
+    }
+
+    /* 1 <= |x| < 0x1p52 */
+    if ix < 0x43300000 {
+        /* integer part of |x| */
+        let j0 = ((ix >> 20) & 0x7ff) as i32 - 0x3ff;
+        let xi = f64::from_bits(ax.to_bits() & !(0x000fffffffffffff >> j0));
+        let odd = if (xi as u64) & 1 != 0 { -1.0 } else { 1.0 };
+        ax -= xi;
+
+        if ax == 0.0 {
+            t = odd * 0.0;
+        } else if ax < 0.5 {
+            t = k_tanpi(ax);
+        } else if ax == 0.5 {
+            t = odd / 0.0;
+        } else {
+            t = -k_tanpi(1.0 - ax);
+        }
+        return if sign { -t } else { t };
+    }
+
+    /* tanpi(Inf or NaN) is NaN */
+    if ix >= 0x7ff00000 {
+        return x - x;
+    }
+
+    /* |x| >= 0x1p52 is always an integer, and even from 0x1p53 on */
+    let t = if ix < 0x43400000 && x.to_bits() & 1 != 0 {
+        -0.0
+    } else {
+        0.0
+    };
+    if sign {
+        -t
+    } else {
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tanpi;
+    use core::f64;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(tanpi(0.25), 1.0);
+        assert_eq!(tanpi(-0.25), -1.0);
+        assert_eq!(tanpi(0.75), -1.0);
+        assert_eq!(tanpi(0.5), f64::INFINITY);
+        assert_eq!(tanpi(1.5), f64::NEG_INFINITY);
+        assert_eq!(tanpi(-1.5), f64::INFINITY);
+        assert_eq!(tanpi(2.0).to_bits(), 0);
+        assert_eq!(tanpi(1.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(tanpi(-1.0).to_bits(), 0);
+        assert_eq!(tanpi(-2.0).to_bits(), (-0.0_f64).to_bits());
+        assert!(tanpi(f64::INFINITY).is_nan());
+    }
+}
@@ -0,0 +1,11 @@
+use super::tanpi;
+
+/// Tangent of pi times x (f32)
+///
+/// Computes `tan(pi * x)` by evaluating `tanpi` in double precision. Like
+/// `tanpi`, the result is exact at integers and half-integers.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpif(x: f32) -> f32 {
+    tanpi(x as f64) as f32
+}
@@ -0,0 +1,7 @@
+// Helpers shared by the unit tests of the special functions
+
+/// Whether `x` agrees with `y` to a relative tolerance of `tol`; equal
+/// values, including infinities and zeros, always agree.
+pub(crate) fn close(x: f64, y: f64, tol: f64) -> bool {
+    x == y || (x - y).abs() <= tol * y.abs()
+}
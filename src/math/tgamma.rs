@@ -23,33 +23,10 @@ Gamma(x)*Gamma(-x) = -pi/(x sin(pi x))
 most ideas and constants are from boost and python
 */
 extern crate core;
-use super::{exp, floor, k_cos, k_sin, pow};
+use super::{exp, floor, pow, sinpi};
 
 const PI: f64 = 3.141592653589793238462643383279502884;
 
-/* sin(pi x) with x > 0x1p-100, if sin(pi*x)==0 the sign is arbitrary */
-fn sinpi(mut x: f64) -> f64 {
-    let mut n: isize;
-
-    /* argument reduction: x = |x| mod 2 */
-    /* spurious inexact when x is odd int */
-    x = x * 0.5;
-    x = 2.0 * (x - floor(x));
-
-    /* reduce x into [-.25,.25] */
-    n = (4.0 * x) as isize;
-    n = (n + 1) / 2;
-    x -= (n as f64) * 0.5;
-
-    x *= PI;
-    match n {
-        1 => k_cos(x, 0.0),
-        2 => k_sin(-x, 0.0, 0),
-        3 => -k_cos(x, 0.0),
-        0 | _ => k_sin(x, 0.0, 0),
-    }
-}
-
 const N: usize = 12;
 //static const double g = 6.024680040776729583740234375;
 const GMHALF: f64 = 5.524680040776729583740234375;
@@ -111,6 +88,7 @@ const FACT: [f64; 23] = [
 ];
 
 /* S(x) rational function for positive x */
+#[inline]
 fn s(x: f64) -> f64 {
     let mut num: f64 = 0.0;
     let mut den: f64 = 0.0;
@@ -118,18 +96,19 @@ fn s(x: f64) -> f64 {
     /* to avoid overflow handle large x differently */
     if x < 8.0 {
         for i in (0..=N).rev() {
-            num = num * x + SNUM[i];
-            den = den * x + SDEN[i];
+            num = num * x + i!(SNUM, i);
+            den = den * x + i!(SDEN, i);
         }
     } else {
         for i in 0..=N {
-            num = num / x + SNUM[i];
-            den = den / x + SDEN[i];
+            num = num / x + i!(SNUM, i);
+            den = den / x + i!(SDEN, i);
         }
     }
     return num / den;
 }
 
+#[inline]
 pub fn tgamma(mut x: f64) -> f64 {
     let u: u64 = x.to_bits();
     let absx: f64;
@@ -157,7 +136,7 @@ pub fn tgamma(mut x: f64) -> f64 {
             return 0.0 / 0.0;
         }
         if x <= FACT.len() as f64 {
-            return FACT[(x as usize) - 1];
+            return i!(FACT, (x as usize) - 1);
         }
     }
 
@@ -205,3 +184,60 @@ pub fn tgamma(mut x: f64) -> f64 {
     y = r * z * z;
     return y;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::testutil::close;
+    use super::tgamma;
+
+    #[test]
+    fn negative_arguments() {
+        /* (x, gamma(x)) from mpmath at 300 bits, rounded to nearest; the
+         * reflection takes sin(pi x) from sinpi */
+        let cases = [
+            (-0.5, -3.544907701811032),
+            (-1.5, 2.363271801207355),
+            (-2.5, -0.9453087204829419),
+            (-3.5, 0.2700882058522691),
+            (-10.5, -2.640121820547716e-7),
+            (-100.5, -3.3536908198076787e-159),
+            (-150.25, -2.2197139309494306e-263),
+            (-170.5, -3.3127395215386074e-308),
+            (-0.1, -10.686287021193193),
+            (-1e-5, -100000.57722555555),
+            (-22.976285743220608, -1.759573079092403e-21),
+            /* just above and below negative integers */
+            (-0.9999999, -10000000.428048035),
+            (-1.0000001, 9999999.571377134),
+            (-1.999999, 500000.4614342375),
+            (-2.000001, -499999.53853888006),
+            (-4.9999999999, -83333326.45252064),
+            (-5.0000000001, 83333326.42408533),
+            (-19.999999999, 4.1103172956383007e-10),
+            (-20.000000001, -4.110317270807675e-10),
+            (-99.9999, 1.0720044031405565e-154),
+            (-100.0001, -1.0710164359183313e-154),
+            (-0.9999999999999998, -4503599627370496.0),
+            (-1.0000000000000002, 4503599627370495.5),
+            (-2.9999999999999996, -375299968947541.56),
+            (-3.0000000000000004, 375299968947541.1),
+        ];
+        for &(x, y) in cases.iter() {
+            let r = tgamma(x);
+            assert!(
+                close(r, y, 1e-15),
+                "tgamma({:?}) = {:?}, expected {:?}",
+                x,
+                r,
+                y
+            );
+        }
+    }
+
+    #[test]
+    fn negative_integers() {
+        assert!(tgamma(-1.0).is_nan());
+        assert!(tgamma(-171.0).is_nan());
+        assert_eq!(tgamma(-0.0), -1.0 / 0.0);
+    }
+}
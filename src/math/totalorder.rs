@@ -0,0 +1,48 @@
+/// Total order predicate (f64)
+///
+/// Returns `true` if `x` is less than or equal to `y` in the total order of
+/// IEEE 754. The order is -NaN < -inf < ... < -0 < +0 < ... < +inf < +NaN,
+/// where NaNs of the same sign are ordered by their payload, with signaling
+/// NaNs below quiet NaNs for positive ones and above them for negative ones.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalorder(x: f64, y: f64) -> bool {
+    let mut ix = x.to_bits() as i64;
+    let mut iy = y.to_bits() as i64;
+    // flip all but the sign bit of negative values, so that their integer
+    // representations compare in the same order as the values
+    ix ^= (((ix >> 63) as u64) >> 1) as i64;
+    iy ^= (((iy >> 63) as u64) >> 1) as i64;
+    ix <= iy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::totalorder;
+    use core::f64;
+
+    #[test]
+    fn order() {
+        let values = [
+            f64::from_bits(0xfff8_0000_0000_0001), // -qNaN with payload
+            f64::from_bits(0xfff8_0000_0000_0000), // -qNaN
+            f64::from_bits(0xfff0_0000_0000_0001), // -sNaN
+            f64::NEG_INFINITY,
+            -1.0,
+            -f64::from_bits(1),
+            -0.0,
+            0.0,
+            f64::from_bits(1),
+            1.0,
+            f64::INFINITY,
+            f64::from_bits(0x7ff0_0000_0000_0001), // +sNaN
+            f64::from_bits(0x7ff8_0000_0000_0000), // +qNaN
+            f64::from_bits(0x7ff8_0000_0000_0001), // +qNaN with payload
+        ];
+        for (i, &x) in values.iter().enumerate() {
+            for (j, &y) in values.iter().enumerate() {
+                assert_eq!(totalorder(x, y), i <= j, "{} {}", i, j);
+            }
+        }
+    }
+}
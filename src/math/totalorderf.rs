@@ -0,0 +1,69 @@
+/// Total order predicate (f32)
+///
+/// Returns `true` if `x` is less than or equal to `y` in the total order of
+/// IEEE 754. The order is -NaN < -inf < ... < -0 < +0 < ... < +inf < +NaN,
+/// where NaNs of the same sign are ordered by their payload, with signaling
+/// NaNs below quiet NaNs for positive ones and above them for negative ones.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalorderf(x: f32, y: f32) -> bool {
+    let mut ix = x.to_bits() as i32;
+    let mut iy = y.to_bits() as i32;
+    // flip all but the sign bit of negative values, so that their integer
+    // representations compare in the same order as the values
+    ix ^= (((ix >> 31) as u32) >> 1) as i32;
+    iy ^= (((iy >> 31) as u32) >> 1) as i32;
+    ix <= iy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::totalorderf;
+    use core::f32;
+
+    #[test]
+    fn order() {
+        let values = [
+            f32::from_bits(0xffc0_0001), // -qNaN with payload
+            f32::from_bits(0xffc0_0000), // -qNaN
+            f32::from_bits(0xff80_0002), // -sNaN with payload
+            f32::from_bits(0xff80_0001), // -sNaN
+            f32::NEG_INFINITY,
+            -f32::MAX,
+            -1.0,
+            -f32::MIN_POSITIVE,
+            -f32::from_bits(1),
+            -0.0,
+            0.0,
+            f32::from_bits(1),
+            f32::MIN_POSITIVE,
+            1.0,
+            f32::MAX,
+            f32::INFINITY,
+            f32::from_bits(0x7f80_0001), // +sNaN
+            f32::from_bits(0x7f80_0002), // +sNaN with payload
+            f32::from_bits(0x7fc0_0000), // +qNaN
+            f32::from_bits(0x7fc0_0001), // +qNaN with payload
+        ];
+        for (i, &x) in values.iter().enumerate() {
+            for (j, &y) in values.iter().enumerate() {
+                assert_eq!(totalorderf(x, y), i <= j, "{} {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn nans() {
+        let qnan = f32::from_bits(0x7fc0_0000);
+        let snan = f32::from_bits(0x7fbf_ffff);
+        /* a signaling NaN of any payload is below every quiet one */
+        assert!(totalorderf(snan, qnan));
+        assert!(!totalorderf(qnan, snan));
+        assert!(totalorderf(-qnan, -snan));
+        assert!(!totalorderf(-snan, -qnan));
+        /* NaNs are equal to themselves, and beyond the infinities */
+        assert!(totalorderf(qnan, qnan));
+        assert!(totalorderf(f32::INFINITY, snan));
+        assert!(totalorderf(-snan, f32::NEG_INFINITY));
+    }
+}
@@ -0,0 +1,40 @@
+/// Total order predicate on magnitudes (f64)
+///
+/// Returns `true` if `|x|` is less than or equal to `|y|` in the total order
+/// of IEEE 754, as `totalorder(fabs(x), fabs(y))` does.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalordermag(x: f64, y: f64) -> bool {
+    (x.to_bits() & 0x7fff_ffff_ffff_ffff) <= (y.to_bits() & 0x7fff_ffff_ffff_ffff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::totalordermag;
+    use core::f64;
+
+    #[test]
+    fn order() {
+        let values = [
+            0.0,
+            f64::from_bits(1),
+            f64::MIN_POSITIVE,
+            1.0,
+            f64::MAX,
+            f64::INFINITY,
+            f64::from_bits(0x7ff0_0000_0000_0001), // sNaN
+            f64::from_bits(0x7ff7_ffff_ffff_ffff), // sNaN with payload
+            f64::from_bits(0x7ff8_0000_0000_0000), // qNaN
+            f64::from_bits(0x7ff8_0000_0000_0001), // qNaN with payload
+        ];
+        /* the signs of both arguments are ignored */
+        for (i, &x) in values.iter().enumerate() {
+            for (j, &y) in values.iter().enumerate() {
+                assert_eq!(totalordermag(x, y), i <= j, "{} {}", i, j);
+                assert_eq!(totalordermag(-x, y), i <= j, "-{} {}", i, j);
+                assert_eq!(totalordermag(x, -y), i <= j, "{} -{}", i, j);
+                assert_eq!(totalordermag(-x, -y), i <= j, "-{} -{}", i, j);
+            }
+        }
+    }
+}
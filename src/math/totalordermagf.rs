@@ -0,0 +1,40 @@
+/// Total order predicate on magnitudes (f32)
+///
+/// Returns `true` if `|x|` is less than or equal to `|y|` in the total order
+/// of IEEE 754, as `totalorderf(fabsf(x), fabsf(y))` does.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalordermagf(x: f32, y: f32) -> bool {
+    (x.to_bits() & 0x7fff_ffff) <= (y.to_bits() & 0x7fff_ffff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::totalordermagf;
+    use core::f32;
+
+    #[test]
+    fn order() {
+        let values = [
+            0.0,
+            f32::from_bits(1),
+            f32::MIN_POSITIVE,
+            1.0,
+            f32::MAX,
+            f32::INFINITY,
+            f32::from_bits(0x7f80_0001), // sNaN
+            f32::from_bits(0x7fbf_ffff), // sNaN with payload
+            f32::from_bits(0x7fc0_0000), // qNaN
+            f32::from_bits(0x7fc0_0001), // qNaN with payload
+        ];
+        /* the signs of both arguments are ignored */
+        for (i, &x) in values.iter().enumerate() {
+            for (j, &y) in values.iter().enumerate() {
+                assert_eq!(totalordermagf(x, y), i <= j, "{} {}", i, j);
+                assert_eq!(totalordermagf(-x, y), i <= j, "-{} {}", i, j);
+                assert_eq!(totalordermagf(x, -y), i <= j, "{} -{}", i, j);
+                assert_eq!(totalordermagf(-x, -y), i <= j, "-{} -{}", i, j);
+            }
+        }
+    }
+}
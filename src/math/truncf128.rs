@@ -0,0 +1,31 @@
+use super::f128;
+
+/// Truncate (f128)
+///
+/// Rounds `x` to the integer nearest to it in the direction of zero.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn truncf128(x: f128) -> f128 {
+    let toint = f128::from_bits(0x406f << 112); // 0x1p112 === 2 ^ 112
+    let s = x.is_sign_negative();
+    let e = (x.to_bits() >> 112) as i32 & 0x7fff;
+
+    if e >= 0x3fff + 112 {
+        return x;
+    }
+    if e < 0x3fff {
+        return x * f128::ZERO;
+    }
+    /* y = int(|x|) - |x|, where int(|x|) is an integer neighbor of |x| */
+    let x = if s { -x } else { x };
+    let mut y = x + toint - toint - x;
+    if y > f128::ZERO {
+        y = y - f128::ONE;
+    }
+    let x = x + y;
+    if s {
+        -x
+    } else {
+        x
+    }
+}
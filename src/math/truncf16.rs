@@ -0,0 +1,10 @@
+use super::{f16, trunc};
+
+/// Truncate (f16)
+///
+/// Rounds `x` to the integer nearest to it in the direction of zero.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn truncf16(x: f16) -> f16 {
+    f16::from_f64(trunc(x.to_f64()))
+}
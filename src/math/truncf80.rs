@@ -0,0 +1,10 @@
+use super::{f128, f80, truncf128};
+
+/// Truncate (f80)
+///
+/// Rounds `x` to the integer nearest to it in the direction of zero.
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn truncf80(x: f80) -> f80 {
+    f80::from_f128(truncf128(f128::from(x)))
+}